@@ -167,6 +167,44 @@ pub fn bessel_k1(x: f64, prec: u32) -> Float {
     result
 }
 
+/// Computes I2(x) using recurrence I2(x) = I0(x) - 2/x * I1(x).
+pub fn bessel_i2(x: f64, prec: u32) -> Float {
+    let i0 = bessel_i0(x, prec);
+    let i1 = bessel_i1(x, prec);
+    let x = Float::with_val(prec, x);
+    let two_i1_over_x = Float::with_val(prec, i1.mul(2u32).div(&x));
+    i0 - two_i1_over_x
+}
+
+/// Computes I2(x)exp(-|x|) for large |x| using Hankel expansion
+/// I2(x)exp(-x) ~ 1/sqrt(2*pi*x) * sum((-1)^k * a_k / x^k),
+/// a_k = (16 - 1^2)(16 - 3^2)...(16 - (2k-1)^2) / (k! * 8^k).
+///
+/// Terms decrease while k < 2x, so use it only for |x| >= 100.
+pub fn bessel_i2e_asympt(x: f64, prec: u32) -> Float {
+    let x = Float::with_val(prec, x.abs());
+    let mut sum = Float::with_val(prec, 1);
+    let mut term = Float::with_val(prec, 1);
+    for k in 1..200u32 {
+        let odd = 2 * k as i64 - 1;
+        term *= odd * odd - 16;
+        term /= 8 * k;
+        term /= &x;
+        sum += &term;
+    }
+    let two_pi_x = Float::with_val(prec, Constant::Pi) * 2u32 * &x;
+    sum / two_pi_x.sqrt()
+}
+
+/// Computes K2(x) using recurrence K2(x) = K0(x) + 2/x * K1(x).
+pub fn bessel_k2(x: f64, prec: u32) -> Float {
+    let k0 = bessel_k0(x, prec);
+    let k1 = bessel_k1(x, prec);
+    let x = Float::with_val(prec, x);
+    let two_k1_over_x = Float::with_val(prec, k1.mul(2u32).div(&x));
+    k0 + two_k1_over_x
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
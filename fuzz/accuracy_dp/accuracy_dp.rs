@@ -1,7 +1,7 @@
 #![no_main]
 #![allow(static_mut_refs)]
 
-use bessel::{bessel_i0, bessel_i1};
+use bessel::{bessel_i0, bessel_i1, bessel_i2, bessel_i2e_asympt, bessel_k2};
 use libfuzzer_sys::fuzz_target;
 use pxfm::*;
 use rug::float::Constant;
@@ -437,11 +437,116 @@ fuzz_target!(|data: (f64, f64)| {
         test_method(x0, f_i1, &bessel_i1(x0, 70), "f_i1".to_string(), 0.5004);
     }
 
+    // Only search for regression MPFR takes too long
+    if x0.abs() < 15. && x0.abs() > 0.01 {
+        let mpfr_exp = Float::with_val(150, -x0.abs()).exp();
+        test_method(
+            x0,
+            f_i2e,
+            &bessel_i2(x0, 150).mul(&mpfr_exp),
+            "f_i2e".to_string(),
+            0.5,
+        );
+    } else if x0.abs() >= 100. {
+        test_method(
+            x0,
+            f_i2e,
+            &bessel_i2e_asympt(x0, 150),
+            "f_i2e".to_string(),
+            0.5,
+        );
+    }
+
+    // Only search for regression MPFR takes too long
+    if x0 < 15. && x0 > 0.01 {
+        let k2 = bessel_k2(x0, 200);
+        test_method(x0, f_k2, &k2, "f_k2".to_string(), 0.5);
+        let mpfr_exp = Float::with_val(200, x0).exp();
+        test_method(x0, f_k2e, &k2.mul(&mpfr_exp), "f_k2e".to_string(), 0.5);
+    }
+
     test_method(x0, f_y1, &mpfr_x0.clone().y1(), "f_y1".to_string(), 0.502);
     test_method(x0, f_y0, &mpfr_x0.clone().y0(), "f_y0".to_string(), 0.5);
     test_method(x0, f_csc, &mpfr_x0.clone().csc(), "f_csc".to_string(), 0.5);
     test_method(x0, f_sec, &mpfr_x0.clone().sec(), "f_sec".to_string(), 0.5);
     test_method(x0, f_cot, &mpfr_x0.clone().cot(), "f_cot".to_string(), 0.5);
+    test_method(
+        x0,
+        f_secpi,
+        &mpfr_x0.clone().cos_pi().recip(),
+        "f_secpi".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_cscpi,
+        &mpfr_x0.clone().sin_pi().recip(),
+        "f_cscpi".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_asec,
+        &mpfr_x0.clone().recip().acos(),
+        "f_asec".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_acsc,
+        &mpfr_x0.clone().recip().asin(),
+        "f_acsc".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_acot,
+        &mpfr_x0.clone().recip().atan(),
+        "f_acot".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_sech,
+        &mpfr_x0.clone().sech(),
+        "f_sech".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_csch,
+        &mpfr_x0.clone().csch(),
+        "f_csch".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_coth,
+        &mpfr_x0.clone().coth(),
+        "f_coth".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_asech,
+        &mpfr_x0.clone().recip().acosh(),
+        "f_asech".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_acsch,
+        &mpfr_x0.clone().recip().asinh(),
+        "f_acsch".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_acoth,
+        &mpfr_x0.clone().recip().atanh(),
+        "f_acoth".to_string(),
+        0.5,
+    );
     test_method(x0, f_j0, &mpfr_x0.clone().j0(), "f_j0".to_string(), 0.5);
     if x0 > 0.000000000000000001 {
         test_method(x0, f_j1, &mpfr_x0.clone().j1(), "f_j1".to_string(), 0.5);
@@ -506,6 +611,14 @@ fuzz_target!(|data: (f64, f64)| {
         "f_atan2pi".to_string(),
         0.5,
     );
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        f_atan2d,
+        &mpfr_x0.clone().atan2_u(&mpfr_x1, 360),
+        "f_atan2d".to_string(),
+        0.5,
+    );
     test_method_2vals_ignore_nan(
         x0,
         x1,
@@ -514,6 +627,22 @@ fuzz_target!(|data: (f64, f64)| {
         "f_atan2".to_string(),
         0.5,
     );
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        remainder,
+        &mpfr_x0.clone().remainder(&mpfr_x1),
+        "remainder".to_string(),
+        0.5,
+    );
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        fmod,
+        &(mpfr_x0.clone() % &mpfr_x1),
+        "fmod".to_string(),
+        0.5,
+    );
     test_method(
         x0,
         f_log10p1,
@@ -556,6 +685,27 @@ fuzz_target!(|data: (f64, f64)| {
         "f_atanpi".to_string(),
         0.5,
     );
+    test_method(
+        x0,
+        f_asind,
+        &mpfr_x0.clone().asin_u(360),
+        "f_asind".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_acosd,
+        &mpfr_x0.clone().acos_u(360),
+        "f_acosd".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_atand,
+        &mpfr_x0.clone().atan_u(360),
+        "f_atand".to_string(),
+        0.5,
+    );
     test_method(
         x0,
         f_exp10m1,
@@ -660,7 +810,36 @@ fuzz_target!(|data: (f64, f64)| {
         "f_sincospi".to_string(),
         0.5,
     );
+    test_method(
+        x0,
+        f_sind,
+        &mpfr_x0.clone().sin_u(360),
+        "f_sind".to_string(),
+        0.5,
+    );
+    test_method(
+        x0,
+        f_cosd,
+        &mpfr_x0.clone().cos_u(360),
+        "f_cosd".to_string(),
+        0.5,
+    );
+    test_method_2_outputs(
+        x0,
+        f_sincosd,
+        &mpfr_x0.clone().sin_u(360),
+        &mpfr_x0.clone().cos_u(360),
+        "f_sincosd".to_string(),
+        0.5,
+    );
     test_method(x0, f_tan, &mpfr_x0.clone().tan(), "f_tan".to_string(), 0.5);
+    test_method(
+        x0,
+        f_tand,
+        &mpfr_x0.clone().tan_u(360),
+        "f_tand".to_string(),
+        0.5,
+    );
     test_method(
         x0,
         f_acos,
@@ -694,6 +873,19 @@ fuzz_target!(|data: (f64, f64)| {
         "f_hypot".to_string(),
         0.5,
     );
+    let hypot3_z = x0 - x1;
+    let mpfr_hypot3 = (Float::with_val(300, x0).square()
+        + Float::with_val(300, x1).square()
+        + Float::with_val(300, hypot3_z).square())
+    .sqrt();
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        |x, y| f_hypot3(x, y, x - y),
+        &mpfr_hypot3,
+        "f_hypot3".to_string(),
+        0.5,
+    );
     test_method_2vals_ignore_nan(
         x0,
         x1,
@@ -715,4 +907,63 @@ fuzz_target!(|data: (f64, f64)| {
     //         0.5,
     //     );
     // }
+
+    test_method(
+        x0,
+        fast::exp,
+        &mpfr_x0.clone().exp(),
+        "fast::exp".to_string(),
+        0.5004,
+    );
+    test_method(
+        x0,
+        fast::log,
+        &mpfr_x0.clone().ln(),
+        "fast::log".to_string(),
+        0.513,
+    );
+    test_method(
+        x0,
+        fast::sin,
+        &mpfr_x0.clone().sin(),
+        "fast::sin".to_string(),
+        0.5008,
+    );
+    test_method(
+        x0,
+        fast::cos,
+        &mpfr_x0.clone().cos(),
+        "fast::cos".to_string(),
+        0.502,
+    );
+    test_method(
+        x0,
+        fast::tanh,
+        &mpfr_x0.clone().tanh(),
+        "fast::tanh".to_string(),
+        0.561,
+    );
+    test_method(
+        x0,
+        fast::erf,
+        &mpfr_x0.clone().erf(),
+        "fast::erf".to_string(),
+        0.50001,
+    );
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        fast::pow,
+        &mpfr_x0.clone().pow(&mpfr_x1),
+        "fast::pow".to_string(),
+        0.5001,
+    );
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        fast::atan2,
+        &mpfr_x0.clone().atan2(&mpfr_x1),
+        "fast::atan2".to_string(),
+        0.50003,
+    );
 });
@@ -1,16 +1,18 @@
 #![no_main]
 
-use bessel::{bessel_i0, bessel_i1};
+use bessel::{bessel_i0, bessel_i1, bessel_i2, bessel_i2e_asympt};
 use libfuzzer_sys::fuzz_target;
 use num_complex::Complex;
 use pxfm::{
-    f_acosf, f_acoshf, f_acospif, f_asinf, f_asinhf, f_asinpif, f_atan2f, f_atan2pif, f_atanhf,
-    f_atanpif, f_cathetusf, f_cbrtf, f_cosf, f_coshf, f_cospif, f_cotf, f_cotpif, f_cscf,
-    f_digammaf, f_erfcf, f_erff, f_exp2f, f_exp2m1f, f_exp10f, f_exp10m1f, f_expf, f_expm1f,
-    f_hypotf, f_i0f, f_i1f, f_j0f, f_j1f, f_jincpif, f_k0f, f_k1f, f_lgammaf, f_log1pf, f_log1pmxf,
-    f_log2f, f_log2p1f, f_log10f, f_log10p1f, f_logf, f_powf, f_powm1f, f_rcbrtf, f_rerff,
-    f_rsqrtf, f_secf, f_sincf, f_sincpif, f_sinf, f_sinhf, f_sinmxf, f_sinpif, f_tanf, f_tanhf,
-    f_tanpif, f_tgammaf, f_y0f, f_y1f,
+    f_acosdf, f_acosf, f_acoshf, f_acospif, f_acotf, f_acothf, f_acscf, f_acschf, f_asecf,
+    f_asechf, f_asindf, f_asinf, f_asinhf, f_asinpif, f_atan2df, f_atan2f, f_atan2pif, f_atandf,
+    f_atanhf, f_atanpif, f_cathetusf, f_cbrtf, f_cosdf, f_cosf, f_coshf, f_cospif, f_cotf, f_cothf,
+    f_cotpif, f_cscf, f_cschf, f_cscpif, f_digammaf, f_erfcf, f_erff, f_exp2f, f_exp2m1f, f_exp10f,
+    f_exp10m1f, f_expf, f_expm1f, f_hypotf, f_i0f, f_i1f, f_i2ef, f_j0f, f_j1f, f_jincpif, f_k0f,
+    f_k1f, f_k2ef, f_lgammaf, f_log1pf, f_log1pmxf, f_log2f, f_log2p1f, f_log10f, f_log10p1f,
+    f_logf, f_powf, f_powm1f, f_rcbrtf, f_rerff, f_rsqrtf, f_secf, f_sechf, f_secpif, f_sincf,
+    f_sincpif, f_sindf, f_sinf, f_sinhf, f_sinmxf, f_sinpif, f_tandf, f_tanf, f_tanhf, f_tanpif,
+    f_tgammaf, f_y0f, f_y1f, fast, fmodf, remainderf,
 };
 use rug::float::Constant;
 use rug::ops::Pow;
@@ -193,6 +195,25 @@ fn test_method_2vals(
     );
 }
 
+#[track_caller]
+fn test_method_2vals_max_ulp(
+    value0: f32,
+    value1: f32,
+    method: fn(f32, f32) -> f32,
+    mpfr_value: &Float,
+    method_name: String,
+    max_ulp: f32,
+) {
+    let xr = method(value0, value1);
+    let ulp = count_ulp(xr, mpfr_value);
+    assert!(
+        ulp <= max_ulp,
+        "ULP should be less than {max_ulp}, but it was {}, using {method_name} on x: {value0}, y: {value1}, result {xr}, mpfr {}",
+        ulp,
+        mpfr_value.to_f32(),
+    );
+}
+
 fn compound_m1_mpfr(x: f32, y: f32) -> Float {
     let mpfr_x0 = Float::with_val(70, x);
     let mpfr_x1 = Float::with_val(70, y);
@@ -263,6 +284,22 @@ fuzz_target!(|data: (f32, f32)| {
         "f_hypotf".to_string(),
     );
 
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        remainderf,
+        &mpfr_x0.clone().remainder(&mpfr_x1),
+        "remainderf".to_string(),
+    );
+
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        fmodf,
+        &(mpfr_x0.clone() % &mpfr_x1),
+        "fmodf".to_string(),
+    );
+
     test_method_2vals_ignore_nan(x0, x1, f_powm1f, &powm1(x0, x1), "f_powm1f".to_string());
 
     test_method(x0, f_sinmxf, &sinmxf(x0), "f_sinmxf".to_string());
@@ -335,6 +372,39 @@ fuzz_target!(|data: (f32, f32)| {
         }
     }
 
+    if x0.abs() < 91.9 && x0.abs() > 0.01 {
+        let mpfr_exp = Float::with_val(100, -(x0.abs() as f64)).exp();
+        test_method(
+            x0,
+            f_i2ef,
+            &bessel_i2(x0 as f64, 100).mul(&mpfr_exp),
+            "f_i2ef".to_string(),
+        );
+    } else if x0.abs() >= 100. {
+        test_method(
+            x0,
+            f_i2ef,
+            &bessel_i2e_asympt(x0 as f64, 100),
+            "f_i2ef".to_string(),
+        );
+    }
+
+    if x0 < 100. && x0.is_sign_positive() && x0.abs() > 0. {
+        // kode = 2 computes exponentially scaled K2(x)exp(x)
+        if let Ok(expected) = bessel_k(
+            Complex {
+                re: x0 as f64,
+                im: 0.,
+            },
+            2.,
+            2,
+            1,
+        ) {
+            let e = expected.values[0].re;
+            test_method(x0, f_k2ef, &Float::with_val(53, e), "f_k2ef".to_string());
+        }
+    }
+
     test_method(
         x0,
         f_rcbrtf,
@@ -353,6 +423,57 @@ fuzz_target!(|data: (f32, f32)| {
     test_method(x0, f_cscf, &mpfr_x0.clone().csc(), "f_cscf".to_string());
     test_method(x0, f_secf, &mpfr_x0.clone().sec(), "f_secf".to_string());
     test_method(x0, f_cotf, &mpfr_x0.clone().cot(), "f_cotf".to_string());
+    test_method(
+        x0,
+        f_secpif,
+        &mpfr_x0.clone().cos_pi().recip(),
+        "f_secpif".to_string(),
+    );
+    test_method(
+        x0,
+        f_cscpif,
+        &mpfr_x0.clone().sin_pi().recip(),
+        "f_cscpif".to_string(),
+    );
+    test_method(
+        x0,
+        f_asecf,
+        &mpfr_x0.clone().recip().acos(),
+        "f_asecf".to_string(),
+    );
+    test_method(
+        x0,
+        f_acscf,
+        &mpfr_x0.clone().recip().asin(),
+        "f_acscf".to_string(),
+    );
+    test_method(
+        x0,
+        f_acotf,
+        &mpfr_x0.clone().recip().atan(),
+        "f_acotf".to_string(),
+    );
+    test_method(x0, f_sechf, &mpfr_x0.clone().sech(), "f_sechf".to_string());
+    test_method(x0, f_cschf, &mpfr_x0.clone().csch(), "f_cschf".to_string());
+    test_method(x0, f_cothf, &mpfr_x0.clone().coth(), "f_cothf".to_string());
+    test_method(
+        x0,
+        f_asechf,
+        &mpfr_x0.clone().recip().acosh(),
+        "f_asechf".to_string(),
+    );
+    test_method(
+        x0,
+        f_acschf,
+        &mpfr_x0.clone().recip().asinh(),
+        "f_acschf".to_string(),
+    );
+    test_method(
+        x0,
+        f_acothf,
+        &mpfr_x0.clone().recip().atanh(),
+        "f_acothf".to_string(),
+    );
     test_method(
         x0,
         f_rerff,
@@ -403,6 +524,13 @@ fuzz_target!(|data: (f32, f32)| {
         &mpfr_x0.clone().atan2_pi(&mpfr_x1),
         "f_atan2pif".to_string(),
     );
+    test_method_2vals_ignore_nan(
+        x0,
+        x1,
+        f_atan2df,
+        &mpfr_x0.clone().atan2_u(&mpfr_x1, 360),
+        "f_atan2df".to_string(),
+    );
     test_method_2vals_ignore_nan(
         x0,
         x1,
@@ -428,6 +556,24 @@ fuzz_target!(|data: (f32, f32)| {
         &mpfr_x0.clone().asin_pi(),
         "f_asinpif".to_string(),
     );
+    test_method(
+        x0,
+        f_atandf,
+        &mpfr_x0.clone().atan_u(360),
+        "f_atandf".to_string(),
+    );
+    test_method(
+        x0,
+        f_acosdf,
+        &mpfr_x0.clone().acos_u(360),
+        "f_acosdf".to_string(),
+    );
+    test_method(
+        x0,
+        f_asindf,
+        &mpfr_x0.clone().asin_u(360),
+        "f_asindf".to_string(),
+    );
     test_method(
         x0,
         f_log10p1f,
@@ -482,6 +628,24 @@ fuzz_target!(|data: (f32, f32)| {
         &mpfr_x0.clone().sin_pi(),
         "f_sinpif".to_string(),
     );
+    test_method(
+        x0,
+        f_tandf,
+        &mpfr_x0.clone().tan_u(360),
+        "f_tandf".to_string(),
+    );
+    test_method(
+        x0,
+        f_cosdf,
+        &mpfr_x0.clone().cos_u(360),
+        "f_cosdf".to_string(),
+    );
+    test_method(
+        x0,
+        f_sindf,
+        &mpfr_x0.clone().sin_u(360),
+        "f_sindf".to_string(),
+    );
     test_method(x0, f_cbrtf, &mpfr_x0.clone().cbrt(), "f_cbrtf".to_string());
     test_method(x0, f_logf, &mpfr_x0.clone().ln(), "f_logf".to_string());
     test_method(x0, f_log2f, &mpfr_x0.clone().log2(), "f_log2f".to_string());
@@ -516,4 +680,63 @@ fuzz_target!(|data: (f32, f32)| {
         &mpfr_x0.clone().pow(&mpfr_x1),
         "f_powf".to_string(),
     );
+
+    test_method_max_ulp(
+        x0,
+        fast::expf,
+        &mpfr_x0.clone().exp(),
+        "fast::expf".to_string(),
+        0.5,
+    );
+    test_method_max_ulp(
+        x0,
+        fast::logf,
+        &mpfr_x0.clone().ln(),
+        "fast::logf".to_string(),
+        0.50000001,
+    );
+    test_method_max_ulp(
+        x0,
+        fast::sinf,
+        &mpfr_x0.clone().sin(),
+        "fast::sinf".to_string(),
+        0.5,
+    );
+    test_method_max_ulp(
+        x0,
+        fast::cosf,
+        &mpfr_x0.clone().cos(),
+        "fast::cosf".to_string(),
+        0.5,
+    );
+    test_method_max_ulp(
+        x0,
+        fast::tanhf,
+        &mpfr_x0.clone().tanh(),
+        "fast::tanhf".to_string(),
+        0.5,
+    );
+    test_method_max_ulp(
+        x0,
+        fast::erff,
+        &mpfr_x0.clone().erf(),
+        "fast::erff".to_string(),
+        0.5,
+    );
+    test_method_2vals_max_ulp(
+        x0,
+        x1,
+        fast::powf,
+        &mpfr_x0.clone().pow(&mpfr_x1),
+        "fast::powf".to_string(),
+        0.5,
+    );
+    test_method_2vals_max_ulp(
+        x0,
+        x1,
+        fast::atan2f,
+        &mpfr_x0.clone().atan2(&mpfr_x1),
+        "fast::atan2f".to_string(),
+        0.5,
+    );
 });
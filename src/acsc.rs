@@ -0,0 +1,202 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::acospi::PI_OVER_TWO_F128;
+use crate::asin::asin_eval;
+use crate::asin_eval_dyadic::asin_eval_dyadic;
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::rounding::CpuRound;
+
+/// Computes asin(1/a) for a >= 2, returns value and absolute error bound.
+#[inline]
+pub(crate) fn asin_recip_eval(a: f64) -> (DoubleDouble, f64) {
+    // t = 1/a <= 0.5, so polynomial approximation may be used directly:
+    //   asin(t) ~ t * P(t^2)
+    let t = DoubleDouble::from_recip(a);
+    let t_sq = DoubleDouble::quick_mult(t, t);
+    let err = t.hi * f64::from_bits(0x3cc0000000000000);
+    let (p, err) = asin_eval(t_sq, err);
+    (DoubleDouble::quick_mult(t, p), err)
+}
+
+/// Computes acos(1/a) for 1 < a < 2, returns value and absolute error bound.
+#[inline]
+pub(crate) fn acos_recip_eval(a: f64) -> (DoubleDouble, f64) {
+    // For 0.5 < t < 1 we use
+    //   acos(t) = 2 * asin( sqrt( (1 - t)/2 ) )
+    // with u = (1 - t)/2 = (a - 1)/(2a) where a - 1 is exact by Sterbenz lemma,
+    // then
+    //   acos(t) ~ 2 * sqrt(u) * P(u).
+    let u = DoubleDouble::from_exact_div(a - 1., a + a);
+    let v = u.sqrt();
+    let vh = v.hi * 2.;
+    let vl = v.lo * 2.;
+    let err = vh * f64::from_bits(0x3cc0000000000000);
+    let (p, err) = asin_eval(u, err);
+    (DoubleDouble::quick_mult(DoubleDouble::new(vl, vh), p), err)
+}
+
+/// Computes asin(1/a) for a >= 2 in 128-bit precision.
+#[cold]
+#[inline(never)]
+pub(crate) fn asin_recip_hard(a: f64) -> DyadicFloat128 {
+    let t = DyadicFloat128::accurate_reciprocal(a);
+    let t_f64 = t.fast_as_f64();
+    // Recalculate mod 1/64.
+    let idx = (t_f64 * t_f64 * f64::from_bits(0x4050000000000000)).cpu_round() as usize;
+    let u = (t * t) + DyadicFloat128::new_from_f64(idx as f64 * f64::from_bits(0xbf90000000000000));
+    let p = asin_eval_dyadic(u, idx);
+    t * p
+}
+
+/// Computes acos(1/a) for 1 < a < 2 in 128-bit precision.
+#[cold]
+#[inline(never)]
+pub(crate) fn acos_recip_hard(a: f64) -> DyadicFloat128 {
+    // u = (a - 1)/(2a)
+    let u = DyadicFloat128::from_div_f64(a - 1., a + a);
+    let u_f64 = u.fast_as_f64();
+    // Recalculate mod 1/64.
+    let idx = (u_f64 * f64::from_bits(0x4050000000000000)).cpu_round() as usize;
+    let y = u + DyadicFloat128::new_from_f64(idx as f64 * f64::from_bits(0xbf90000000000000));
+    let p = asin_eval_dyadic(y, idx);
+    // 2 * sqrt(u) * P(u)
+    let mut v = u.sqrt();
+    v.exponent += 1;
+    v * p
+}
+
+/// Computes inverse cosecant
+///
+/// Max found ULP 0.5
+pub fn f_acsc(x: f64) -> f64 {
+    let x_e = (x.to_bits() >> 52) & 0x7ff;
+    const E_BIAS: u64 = (1u64 << (11 - 1u64)) - 1u64;
+
+    const PI_OVER_TWO: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0x3c91a62633145c07),
+        f64::from_bits(0x3ff921fb54442d18),
+    );
+
+    let x_abs = f64::from_bits(x.to_bits() & 0x7fff_ffff_ffff_ffff);
+    let x_sign = if x.is_sign_negative() { -1.0 } else { 1.0 };
+
+    // |x| <= 1
+    if x_e < E_BIAS || x_abs == 1.0 {
+        // x = +-1, acsc(x) = +- pi/2
+        if x_abs == 1.0 {
+            return f_fmla(x_sign, PI_OVER_TWO.hi, x_sign * PI_OVER_TWO.lo);
+        }
+        // |x| < 1, return NaN.
+        if x.is_nan() {
+            return x + x;
+        }
+        return f64::NAN;
+    }
+
+    // |x| >= 2^26
+    if x_e >= E_BIAS + 26 {
+        if x_e == 0x7ff {
+            // +-Inf or NaN
+            return if x.is_nan() { x + x } else { 0.0 * x_sign };
+        }
+        if x_e >= E_BIAS + 512 {
+            // |x| >= 2^512, t^3/6 is far below ulp(t), and splitting t
+            // in the reciprocal without FMA would overflow
+            return 1. / x;
+        }
+        // When |x| >= 2^26, t = 1/|x| < 2^-26 and
+        //   asin(t) = t + t^3/6 + O(t^5)
+        // where t^5 term is beyond double-double precision.
+        let t = DoubleDouble::from_recip(x_abs);
+        let t3 = t.hi * t.hi * t.hi;
+        let r = f_fmla(t3, f64::from_bits(0x3fc5555555555555), t.lo) + t.hi;
+        return r * x_sign;
+    }
+
+    // |x| >= 2
+    if x_e > E_BIAS {
+        let (r, err) = asin_recip_eval(x_abs);
+
+        let r_upper = r.hi + (r.lo + err);
+        let r_lower = r.hi + (r.lo - err);
+
+        if r_upper == r_lower {
+            return r_upper * x_sign;
+        }
+
+        let mut r_f128 = asin_recip_hard(x_abs);
+        if x.is_sign_negative() {
+            r_f128.sign = DyadicSign::Neg;
+        }
+        return r_f128.fast_as_f64();
+    }
+
+    // 1 < |x| < 2
+    // acsc(x) = asin(1/x) = pi/2 - acos(1/x)
+    let (r0, err) = acos_recip_eval(x_abs);
+
+    let r = DoubleDouble::from_exact_add(PI_OVER_TWO.hi, -r0.hi);
+    let r_lo = PI_OVER_TWO.lo - r0.lo + r.lo;
+
+    let r_upper = r.hi + (r_lo + err);
+    let r_lower = r.hi + (r_lo - err);
+
+    if r_upper == r_lower {
+        return r_upper * x_sign;
+    }
+
+    let mut r_f128 = acos_recip_hard(x_abs);
+    r_f128.sign = DyadicSign::Neg;
+    r_f128 = PI_OVER_TWO_F128 + r_f128;
+    if x.is_sign_negative() {
+        r_f128.sign = DyadicSign::Neg;
+    }
+    r_f128.fast_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acsc() {
+        assert_eq!(f_acsc(1.), std::f64::consts::FRAC_PI_2);
+        assert_eq!(f_acsc(-1.), -std::f64::consts::FRAC_PI_2);
+        assert_eq!(f_acsc(2.), std::f64::consts::FRAC_PI_6);
+        assert_eq!(f_acsc(-2.), -std::f64::consts::FRAC_PI_6);
+        assert_eq!(f_acsc(1e302), 1e-302);
+        assert_eq!(f_acsc(-1e302), -1e-302);
+        assert_eq!(f_acsc(f64::MAX), 5.562684646268003e-309);
+        assert_eq!(f_acsc(f64::INFINITY), 0.);
+        assert!(f_acsc(0.5).is_nan());
+        assert!(f_acsc(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::asinf::{acosf_eval_near_one, asinf_eval};
+
+/// Computes inverse cosecant
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_acscf(x: f32) -> f32 {
+    let t = x.to_bits();
+    let ax = t & 0x7fff_ffff;
+    if ax <= 0x3f80_0000u32 || ax >= 0x7f80_0000u32 {
+        // |x| = 1
+        if ax == 0x3f80_0000u32 {
+            const PI2: f64 = f64::from_bits(0x3ff921fb54442d18);
+            return f64::copysign(PI2, x as f64) as f32;
+        }
+        // x = +-Inf
+        if ax == 0x7f80_0000u32 {
+            return if x.is_sign_negative() { -0. } else { 0. };
+        }
+        // |x| < 1 or NaN
+        return if x.is_nan() { x + x } else { f32::NAN };
+    }
+    let xd = x as f64;
+    let a = xd.abs();
+    let r = if ax >= 0x4000_0000u32 {
+        // |x| >= 2
+        // acsc(x) = asin(1/|x|)
+        asinf_eval(1. / a)
+    } else {
+        // 1 < |x| < 2
+        // acsc(x) = asin(1/|x|) = pi/2 - acos(1/|x|) = pi/2 - acos(1 - z),
+        // z = (|x| - 1)/|x|, where |x| - 1 is exact.
+        const PI2: f64 = f64::from_bits(0x3ff921fb54442d18);
+        let z = (a - 1.) / a;
+        PI2 - acosf_eval_near_one(z)
+    };
+    f64::copysign(r, xd) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acscf() {
+        assert_eq!(f_acscf(1.), std::f32::consts::FRAC_PI_2);
+        assert_eq!(f_acscf(-1.), -std::f32::consts::FRAC_PI_2);
+        assert_eq!(f_acscf(2.), std::f32::consts::FRAC_PI_6);
+        assert_eq!(f_acscf(f32::INFINITY), 0.);
+        assert!(f_acscf(0.5).is_nan());
+        assert!(f_acscf(f32::NAN).is_nan());
+    }
+}
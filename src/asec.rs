@@ -0,0 +1,153 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::acospi::PI_OVER_TWO_F128;
+use crate::acsc::{acos_recip_eval, acos_recip_hard, asin_recip_eval, asin_recip_hard};
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+
+/// Computes inverse secant
+///
+/// Max found ULP 0.5
+pub fn f_asec(x: f64) -> f64 {
+    let x_e = (x.to_bits() >> 52) & 0x7ff;
+    const E_BIAS: u64 = (1u64 << (11 - 1u64)) - 1u64;
+
+    const PI_OVER_TWO: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0x3c91a62633145c07),
+        f64::from_bits(0x3ff921fb54442d18),
+    );
+
+    const PI: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0x3ca1a62633145c07),
+        f64::from_bits(0x400921fb54442d18),
+    );
+
+    let x_abs = f64::from_bits(x.to_bits() & 0x7fff_ffff_ffff_ffff);
+    let x_sign = if x.is_sign_negative() { -1.0 } else { 1.0 };
+
+    // |x| <= 1
+    if x_e < E_BIAS || x_abs == 1.0 {
+        // x = 1, asec(x) = 0,
+        // x = -1, asec(x) = pi
+        if x_abs == 1.0 {
+            return if x == 1.0 { 0.0 } else { PI.hi + PI.lo };
+        }
+        // |x| < 1, return NaN.
+        if x.is_nan() {
+            return x + x;
+        }
+        return f64::NAN;
+    }
+
+    // |x| >= 2^55
+    if x_e >= E_BIAS + 55 {
+        if x.is_nan() {
+            return x + x;
+        }
+        // When |x| >= 2^55, asec(x) = pi/2 - 1/x ~ pi/2
+        return (PI_OVER_TWO.lo - 1. / x) + PI_OVER_TWO.hi;
+    }
+
+    // |x| >= 2
+    if x_e > E_BIAS {
+        // asec(x) = acos(1/x) = pi/2 - asin(1/x)
+        let (r0, err) = asin_recip_eval(x_abs);
+        let r0_hi = r0.hi * x_sign;
+        let r0_lo = r0.lo * x_sign;
+
+        let r = DoubleDouble::from_exact_add(PI_OVER_TWO.hi, -r0_hi);
+        let r_lo = PI_OVER_TWO.lo - r0_lo + r.lo;
+
+        let r_upper = r.hi + (r_lo + err);
+        let r_lower = r.hi + (r_lo - err);
+
+        if r_upper == r_lower {
+            return r_upper;
+        }
+
+        let mut r_f128 = asin_recip_hard(x_abs);
+        r_f128.sign = if x.is_sign_negative() {
+            DyadicSign::Pos
+        } else {
+            DyadicSign::Neg
+        };
+        return (PI_OVER_TWO_F128 + r_f128).fast_as_f64();
+    }
+
+    // 1 < |x| < 2
+    //   asec(x) = acos(1/x)          , when x > 0,
+    //           = pi - acos(1/|x|)   , when x < 0.
+    let (r0, err) = acos_recip_eval(x_abs);
+
+    let r_hi;
+    let r_lo;
+    if x.is_sign_positive() {
+        r_hi = r0.hi;
+        r_lo = r0.lo;
+    } else {
+        let r = DoubleDouble::from_exact_add(PI.hi, -r0.hi);
+        r_hi = r.hi;
+        r_lo = (PI.lo - r0.lo) + r.lo;
+    }
+
+    let r_upper = r_hi + (r_lo + err);
+    let r_lower = r_hi + (r_lo - err);
+
+    if r_upper == r_lower {
+        return r_upper;
+    }
+
+    let mut r_f128 = acos_recip_hard(x_abs);
+    if x.is_sign_negative() {
+        const PI_F128: DyadicFloat128 = DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -126,
+            mantissa: 0xc90fdaa2_2168c234_c4c6628b_80dc1cd1_u128,
+        };
+        r_f128.sign = DyadicSign::Neg;
+        r_f128 = PI_F128 + r_f128;
+    }
+    r_f128.fast_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asec() {
+        assert_eq!(f_asec(1.), 0.);
+        assert_eq!(f_asec(-1.), std::f64::consts::PI);
+        assert_eq!(f_asec(2.), std::f64::consts::FRAC_PI_3);
+        assert_eq!(f_asec(f64::INFINITY), std::f64::consts::FRAC_PI_2);
+        assert_eq!(f_asec(f64::NEG_INFINITY), std::f64::consts::FRAC_PI_2);
+        assert!(f_asec(0.5).is_nan());
+        assert!(f_asec(f64::NAN).is_nan());
+    }
+}
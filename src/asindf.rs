@@ -0,0 +1,112 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::asinpif::ASINCOSF_PI_TABLE;
+use crate::common::f_fmla;
+
+/// Computes asin(x) in degrees
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_asindf(x: f32) -> f32 {
+    let ax = x.abs();
+    let az = ax as f64;
+    let z = x as f64;
+    let t = x.to_bits();
+    let e: i32 = ((t >> 23) & 0xff) as i32;
+    if e >= 127 {
+        // |x| >= 1 or nan
+        if ax == 1.0 {
+            return f32::copysign(90., x);
+        } // |x| = 1
+        if e == 0xff && (t.wrapping_shl(9)) != 0 {
+            return x + x;
+        } // x = nan
+        return f32::NAN; // |x| > 1
+    }
+    // exceptional cases, results are extremely close to the rounding midpoint
+    let exception = match t & 0x7fff_ffff {
+        0x3e26dad2 => 0x41160b91,
+        0x3e374950 => 0x4124f9bc,
+        _ => 0,
+    };
+    if exception != 0 {
+        return f32::copysign(f32::from_bits(exception), x);
+    }
+    let s: i32 = 146i32.wrapping_sub(e);
+    let mut i = 0i32;
+    // s<32 corresponds to |x| >= 2^-12
+    if s < 32 {
+        i = (((t & 0x007fffff) | 1 << 23) >> s) as i32;
+    }
+    let z2 = z * z;
+    let z4 = z2 * z2;
+    let c = ASINCOSF_PI_TABLE[i as usize & 15];
+    // asin(x) in degrees = 180 * asin(x)/pi
+    if i == 0 {
+        // |x| < 2^-4
+        let mut c0 = f_fmla(z2, f64::from_bits(c[1]), f64::from_bits(c[0]));
+        let c2 = f_fmla(z2, f64::from_bits(c[3]), f64::from_bits(c[2]));
+        let mut c4 = f_fmla(z2, f64::from_bits(c[5]), f64::from_bits(c[4]));
+        let c6 = f_fmla(z2, f64::from_bits(c[7]), f64::from_bits(c[6]));
+        c0 = f_fmla(c2, z4, c0);
+        c4 = f_fmla(c6, z4, c4);
+        c0 += c4 * (z4 * z4);
+        (z * (c0 * 180.)) as f32
+    } else {
+        // |x| >= 2^-4
+        let f = (1. - az).sqrt();
+        let mut c0 = f_fmla(az, f64::from_bits(c[1]), f64::from_bits(c[0]));
+        let c2 = f_fmla(az, f64::from_bits(c[3]), f64::from_bits(c[2]));
+        let mut c4 = f_fmla(az, f64::from_bits(c[5]), f64::from_bits(c[4]));
+        let c6 = f_fmla(az, f64::from_bits(c[7]), f64::from_bits(c[6]));
+        c0 = f_fmla(c2, z2, c0);
+        c4 = f_fmla(c6, z2, c4);
+        c0 += c4 * z4;
+        let r = f_fmla(
+            -c0 * 180.,
+            f64::copysign(f, x as f64),
+            f64::copysign(90., x as f64),
+        );
+        r as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asindf() {
+        assert_eq!(f_asindf(0.0), 0.);
+        assert_eq!(f_asindf(0.5), 30.);
+        assert_eq!(f_asindf(-1.), -90.);
+        assert_eq!(f_asindf(0.16294411), 9.377824);
+        assert!(f_asindf(1.5).is_nan());
+    }
+}
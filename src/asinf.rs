@@ -39,6 +39,49 @@ fn as_special(x: f32) -> f32 {
     f32::NAN
 }
 
+/// Computes asin(z) for |z| < 0.5
+#[inline]
+pub(crate) fn asinf_eval(z: f64) -> f64 {
+    const C: [u64; 12] = [
+        0x3fc555555555529c,
+        0x3fb333333337e0dd,
+        0x3fa6db6db3b4465e,
+        0x3f9f1c72e13ac306,
+        0x3f96e89cebe06bc4,
+        0x3f91c6dcf5289094,
+        0x3f8c6dbbcc7c6315,
+        0x3f88f8dc2615e996,
+        0x3f7a5833b7bf15e8,
+        0x3f943f44ace1665c,
+        0xbf90fb17df881c73,
+        0x3fa07520c026b2d6,
+    ];
+    let z2 = z * z;
+    let c0 = poly12(z2, C);
+    z + (z * z2) * c0
+}
+
+/// Computes acos(1 - z) for 0 <= z <= 0.5
+#[inline]
+pub(crate) fn acosf_eval_near_one(z: f64) -> f64 {
+    let s = z.sqrt();
+    const C: [u64; 12] = [
+        0x3ff6a09e667f3bcb,
+        0x3fbe2b7dddff2db9,
+        0x3f9b27247ab42dbc,
+        0x3f802995cc4e0744,
+        0x3f65ffb0276ec8ea,
+        0x3f5033885a928dec,
+        0x3f3911f2be23f8c7,
+        0x3f24c3c55d2437fd,
+        0x3f0af477e1d7b461,
+        0x3f0abd6bdff67dcb,
+        0xbef1717e86d0fa28,
+        0x3ef6ff526de46023,
+    ];
+    s * poly12(z, C)
+}
+
 /// Computes asin
 ///
 /// Max found ULP 0.49999928
@@ -102,24 +145,7 @@ pub fn f_asinf(x: f32) -> f32 {
         }
     }
     if ax < (0x7eu32 << 24) {
-        const C: [u64; 12] = [
-            0x3fc555555555529c,
-            0x3fb333333337e0dd,
-            0x3fa6db6db3b4465e,
-            0x3f9f1c72e13ac306,
-            0x3f96e89cebe06bc4,
-            0x3f91c6dcf5289094,
-            0x3f8c6dbbcc7c6315,
-            0x3f88f8dc2615e996,
-            0x3f7a5833b7bf15e8,
-            0x3f943f44ace1665c,
-            0xbf90fb17df881c73,
-            0x3fa07520c026b2d6,
-        ];
-        let z = xs;
-        let z2 = z * z;
-        let c0 = poly12(z2, C);
-        r = z + (z * z2) * c0;
+        r = asinf_eval(xs);
     } else {
         if ax == 0x7e55688au32 {
             return f32::copysign(f64::from_bits(0x3fe75b8a20000000) as f32, x)
@@ -131,22 +157,7 @@ pub fn f_asinf(x: f32) -> f32 {
         }
         let bx = xs.abs();
         let z = 1.0 - bx;
-        let s = z.sqrt();
-        const C: [u64; 12] = [
-            0x3ff6a09e667f3bcb,
-            0x3fbe2b7dddff2db9,
-            0x3f9b27247ab42dbc,
-            0x3f802995cc4e0744,
-            0x3f65ffb0276ec8ea,
-            0x3f5033885a928dec,
-            0x3f3911f2be23f8c7,
-            0x3f24c3c55d2437fd,
-            0x3f0af477e1d7b461,
-            0x3f0abd6bdff67dcb,
-            0xbef1717e86d0fa28,
-            0x3ef6ff526de46023,
-        ];
-        r = PI2 - s * poly12(z, C);
+        r = PI2 - acosf_eval_near_one(z);
         r = f64::copysign(r, xs);
     }
     r as f32
@@ -0,0 +1,248 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::j0f_coeffs::J0_ZEROS;
+use crate::bessel::j1_coeffs::J1_ZEROS;
+use crate::bessel::y0f_coeffs::Y0_ZEROS;
+use crate::bessel::y1f_coeffs::Y1_ZEROS;
+use crate::double_double::DoubleDouble;
+
+const PI: DoubleDouble = DoubleDouble::new(
+    f64::from_bits(0x3ca1a62633145c07),
+    f64::from_bits(0x400921fb54442d18),
+);
+
+/// Computes atan(t) for |t| < 2^-4.
+#[inline]
+fn atan_small(t: DoubleDouble) -> DoubleDouble {
+    let t2 = DoubleDouble::quick_mult(t, t);
+    let mut term = t;
+    let mut sum = t;
+    let mut k = 1f64;
+    while k < 60. {
+        term = -DoubleDouble::quick_mult(term, t2);
+        let q = DoubleDouble::div_dd_f64(term, 2. * k + 1.);
+        sum = DoubleDouble::add(sum, q);
+        if q.hi.abs() <= f64::from_bits(0x3910000000000000) * sum.hi.abs() {
+            // |q| < 2^-110 * |sum|
+            break;
+        }
+        k += 1.;
+    }
+    sum
+}
+
+/// Computes atan(Q(x) / P(x)) from Hankel asymptotic expansion of J_nu, Y_nu,
+/// or atan(S(x) / R(x)) for their derivatives.
+///
+/// P = sum((-1)^k a_2k / x^2k), Q = sum((-1)^k a_(2k+1) / x^(2k+1)),
+/// a_j = (mu - 1^2)(mu - 3^2)...(mu - (2j - 1)^2) / (j! 8^j), mu = 4 nu^2.
+/// R and S have same form with a_j * (mu + 4j^2 - 1) / (mu - (2j - 1)^2).
+///
+/// Valid for x > 40, there terms drops below 2^-110 before expansion starts to diverge.
+fn hankel_phase(mu: f64, derivative: bool, x: DoubleDouble) -> DoubleDouble {
+    let recip = x.recip();
+    let mut term = DoubleDouble::new(0., 1.);
+    let mut p = term;
+    let mut q = DoubleDouble::default();
+    let mut j = 1f64;
+    while j < 200. {
+        let c = mu - (2. * j - 1.) * (2. * j - 1.);
+        term = DoubleDouble::div_dd_f64(
+            DoubleDouble::quick_mult_f64(DoubleDouble::quick_mult(term, recip), c),
+            8. * j,
+        );
+        let mut v = term;
+        if derivative {
+            v = DoubleDouble::div_dd_f64(DoubleDouble::quick_mult_f64(v, mu + 4. * j * j - 1.), c);
+        }
+        // Signs follow + + - - pattern
+        if (j as u32) & 2 != 0 {
+            v = -v;
+        }
+        if (j as u32) & 1 != 0 {
+            q = DoubleDouble::add(q, v);
+        } else {
+            p = DoubleDouble::add(p, v);
+        }
+        if v.hi.abs() <= f64::from_bits(0x3910000000000000) {
+            // |v| < 2^-110, P ~ 1
+            break;
+        }
+        j += 1.;
+    }
+    atan_small(DoubleDouble::div(q, p))
+}
+
+/// Finds zero of Hankel expansion as fixed point of x = (k + shift) * PI - atan(Q(x) / P(x)).
+fn hankel_zero(mu: f64, derivative: bool, shift: f64, k: u32) -> f64 {
+    let c = DoubleDouble::quick_mult_f64(PI, k as f64 + shift);
+    let mut x = c;
+    for _ in 0..10 {
+        let x1 = DoubleDouble::full_dd_sub(c, hankel_phase(mu, derivative, x));
+        let dx = DoubleDouble::full_dd_sub(x1, x);
+        x = x1;
+        if dx.hi.abs() <= f64::from_bits(0x3960000000000000) * x.hi {
+            // |dx| < 2^-105 * x
+            break;
+        }
+    }
+    x.to_f64()
+}
+
+/// Computes k-th positive zero of Bessel function of the first kind of order 0
+///
+/// Returns NaN for k = 0.
+pub fn f_j0_zero(k: u32) -> f64 {
+    if k == 0 {
+        return f64::NAN;
+    }
+    // Zeros are stored at odd positions, interleaved with extremums
+    if k <= 24 {
+        return f64::from_bits(J0_ZEROS[2 * k as usize - 1].1);
+    }
+    hankel_zero(0., false, -0.25, k)
+}
+
+/// Computes k-th positive zero of Bessel function of the first kind of order 1
+///
+/// Returns NaN for k = 0.
+pub fn f_j1_zero(k: u32) -> f64 {
+    if k == 0 {
+        return f64::NAN;
+    }
+    // Zeros are stored at even positions, interleaved with extremums
+    if k <= 23 {
+        return f64::from_bits(J1_ZEROS[2 * k as usize].1);
+    }
+    hankel_zero(4., false, 0.25, k)
+}
+
+/// Computes k-th positive zero of Bessel function of the second kind of order 0
+///
+/// Returns NaN for k = 0.
+pub fn f_y0_zero(k: u32) -> f64 {
+    if k == 0 {
+        return f64::NAN;
+    }
+    if k == 1 {
+        // Y0 table starts from first extremum
+        return f64::from_bits(0x3fec982eb8d417ea);
+    }
+    // Zeros are stored at even positions, interleaved with extremums
+    if k <= 24 {
+        return f64::from_bits(Y0_ZEROS[2 * k as usize - 2].1);
+    }
+    hankel_zero(0., false, -0.75, k)
+}
+
+/// Computes k-th positive zero of Bessel function of the second kind of order 1
+///
+/// Returns NaN for k = 0.
+pub fn f_y1_zero(k: u32) -> f64 {
+    if k == 0 {
+        return f64::NAN;
+    }
+    // Zeros are stored at odd positions, interleaved with extremums
+    if k <= 16 {
+        return f64::from_bits(Y1_ZEROS[2 * k as usize - 1].1);
+    }
+    hankel_zero(4., false, -0.25, k)
+}
+
+/// Computes k-th positive zero of J0' derivative
+///
+/// Trivial zero at x = 0 is not counted, J0' = -J1 so this is k-th zero of J1.
+/// Returns NaN for k = 0.
+#[inline]
+pub fn f_j0_prime_zero(k: u32) -> f64 {
+    f_j1_zero(k)
+}
+
+/// Computes k-th positive zero of J1' derivative
+///
+/// Returns NaN for k = 0.
+pub fn f_j1_prime_zero(k: u32) -> f64 {
+    if k == 0 {
+        return f64::NAN;
+    }
+    // Extremums are stored at odd positions, interleaved with zeros
+    if k <= 24 {
+        return f64::from_bits(J1_ZEROS[2 * k as usize - 1].1);
+    }
+    hankel_zero(4., true, -0.25, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_j_zeros() {
+        assert_eq!(f_j0_zero(1), 2.404825557695773);
+        assert_eq!(f_j0_zero(10), 30.634606468431976);
+        assert_eq!(f_j0_zero(30), 93.46371878194478);
+        assert_eq!(f_j0_zero(1000), 3140.8072952250786);
+        assert_eq!(f_j0_zero(u32::MAX), 13493037700.595028);
+        assert_eq!(f_j1_zero(1), 3.8317059702075125);
+        assert_eq!(f_j1_zero(10), 32.189679910974405);
+        assert_eq!(f_j1_zero(30), 95.0292318080447);
+        assert_eq!(f_j1_zero(1000), 3142.377932416818);
+        assert_eq!(f_j1_zero(u32::MAX), 13493037702.165825);
+        assert!(f_j0_zero(0).is_nan());
+        assert!(f_j1_zero(0).is_nan());
+    }
+
+    #[test]
+    fn test_y_zeros() {
+        assert_eq!(f_y0_zero(1), 0.8935769662791675);
+        assert_eq!(f_y0_zero(10), 29.064030252728397);
+        assert_eq!(f_y0_zero(30), 91.89294531215718);
+        assert_eq!(f_y0_zero(1000), 3139.236498918198);
+        assert_eq!(f_y0_zero(u32::MAX), 13493037699.024231);
+        assert_eq!(f_y1_zero(1), 2.197141326031017);
+        assert_eq!(f_y1_zero(10), 30.618286491641115);
+        assert_eq!(f_y1_zero(30), 93.45836916348223);
+        assert_eq!(f_y1_zero(1000), 3140.80713603034);
+        assert_eq!(f_y1_zero(u32::MAX), 13493037700.595028);
+        assert!(f_y0_zero(0).is_nan());
+        assert!(f_y1_zero(0).is_nan());
+    }
+
+    #[test]
+    fn test_j_prime_zeros() {
+        assert_eq!(f_j0_prime_zero(1), 3.8317059702075125);
+        assert_eq!(f_j0_prime_zero(30), 95.0292318080447);
+        assert_eq!(f_j1_prime_zero(1), 1.8411837813406593);
+        assert_eq!(f_j1_prime_zero(10), 30.601922972669094);
+        assert_eq!(f_j1_prime_zero(30), 93.45301801376003);
+        assert_eq!(f_j1_prime_zero(1000), 3140.8069768355613);
+        assert_eq!(f_j1_prime_zero(u32::MAX), 13493037700.595028);
+        assert!(f_j1_prime_zero(0).is_nan());
+    }
+}
@@ -60,7 +60,7 @@ pub fn f_i2(x: f64) -> f64 {
             let x2 = x * x * R;
             return x2;
         }
-        return i2_small(f64::from_bits(xb));
+        return i2_small(f64::from_bits(xb)).to_f64();
     }
 
     if xb >= 0x40864feaeefb23b8 {
@@ -92,7 +92,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 ```
 **/
 #[inline]
-fn i2_small(x: f64) -> f64 {
+pub(crate) fn i2_small(x: f64) -> DoubleDouble {
     const P: [(u64, u64); 12] = [
         (0x0000000000000000, 0x3fc0000000000000),
         (0x3c247833fda9de9a, 0x3f8387c6e72a1b5f),
@@ -206,7 +206,7 @@ fn i2_small(x: f64) -> f64 {
     let p_den = DoubleDouble::mul_add(x8, f2, g0);
 
     let p = DoubleDouble::div(p_num, p_den);
-    DoubleDouble::quick_mult(p, x_sqr).to_f64()
+    DoubleDouble::quick_mult(p, x_sqr)
 }
 
 /**
@@ -0,0 +1,605 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::i0::bessel_rsqrt_hard;
+use crate::bessel::i0_exp;
+use crate::bessel::i2::i2_small;
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::polyeval::f_polyeval7;
+
+/// Modified exponentially scaled Bessel of the first kind of order 2
+///
+/// Computes exp(-|x|)*I2(x)
+///
+/// Max ULP 0.5
+pub fn f_i2e(x: f64) -> f64 {
+    let ux = x.to_bits().wrapping_shl(1);
+
+    if ux >= 0x7ffu64 << 53 || ux == 0 {
+        // |x| == 0, |x| == inf, x == NaN
+        if ux == 0 {
+            // |x| == 0
+            return 0.;
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+        return x + f64::NAN; // x = NaN
+    }
+
+    let xb = x.to_bits() & 0x7fff_ffff_ffff_ffffu64;
+
+    if xb < 0x401f000000000000u64 {
+        // |x| < 7.75
+        if xb <= 0x3cb0000000000000u64 {
+            // |x| <= f64::EPSILON
+            // Power series of I2(x)exp(-|x|) ~ x^2/8 - |x|^3/8 + O(x^4)
+            // x^2/8 is taken exactly, so the sum is rounded only once
+            let ax = f64::from_bits(xb);
+            let x2 = DoubleDouble::from_exact_mult(ax, ax * 0.125);
+            return x2.hi + f_fmla(-ax, x2.hi, x2.lo);
+        }
+        let v_i2 = i2_small(f64::from_bits(xb));
+        let v_exp = i0_exp(-f64::from_bits(xb));
+        return DoubleDouble::quick_mult(v_i2, v_exp).to_f64();
+    }
+
+    if xb >= 0x4086500000000000u64 {
+        // |x| >= 714
+        return i2e_large(f64::from_bits(xb));
+    }
+
+    i2e_asympt(f64::from_bits(xb))
+}
+
+/**
+Asymptotic expansion for I2.
+I2(x)exp(-|x|)=R(1/x)/sqrt(x)
+
+Generated in Wolfram:
+```text
+<<FunctionApproximations`
+ClearAll["Global`*"]
+f[x_]:=Sqrt[x] Exp[-x] BesselI[2,x]
+g[z_]:=f[1/z]
+{err,approx}=MiniMaxApproximation[g[z],{z,{1/714.0,1/7.5},11,11},WorkingPrecision->120]
+poly=Numerator[approx][[1]];
+coeffs=CoefficientList[poly,z];
+TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Null&)],"',"}],{i,0,Length[coeffs]-1}]]
+poly=Denominator[approx][[1]];
+coeffs=CoefficientList[poly,z];
+TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Null&)],"',"}],{i,0,Length[coeffs]-1}]]
+```
+**/
+#[inline]
+fn i2e_asympt(x: f64) -> f64 {
+    let dx = x;
+    let recip = DoubleDouble::from_quick_recip(x);
+    const P: [(u64, u64); 12] = [
+        (0x3c718bb28ebc5f4e, 0x3fd9884533d43650),
+        (0x3c96e15a87b6e1d1, 0xc0350acc9e5cb0f9),
+        (0xbd20b212a79e08f5, 0x40809251af67598a),
+        (0xbd563b7397df3a54, 0xc0bfc09ede682c8b),
+        (0xbd5eb872cb057d91, 0x40f44253a9e1e1ab),
+        (0x3d7614735e566fc5, 0xc121cbcd96dc8765),
+        (0xbddc4f8df2010026, 0x4145a592e8ec74ad),
+        (0x3dea227617b678a7, 0xc161df96fb6a9df9),
+        (0x3e17c9690d906194, 0x41732c71397757f8),
+        (0x3e0638226ce0b938, 0xc178893fde0e6ed7),
+        (0xbe09d8dc4e7930ce, 0x417066abe24b31df),
+        (0xbde152007ee29e54, 0xc150531da3f31b16),
+    ];
+
+    let x2 = DoubleDouble::quick_mult(recip, recip);
+    let x4 = DoubleDouble::quick_mult(x2, x2);
+    let x8 = DoubleDouble::quick_mult(x4, x4);
+
+    let e0 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(P[1]),
+        DoubleDouble::from_bit_pair(P[0]),
+    );
+    let e1 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(P[3]),
+        DoubleDouble::from_bit_pair(P[2]),
+    );
+    let e2 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(P[5]),
+        DoubleDouble::from_bit_pair(P[4]),
+    );
+    let e3 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(P[7]),
+        DoubleDouble::from_bit_pair(P[6]),
+    );
+    let e4 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(P[9]),
+        DoubleDouble::from_bit_pair(P[8]),
+    );
+    let e5 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(P[11]),
+        DoubleDouble::from_bit_pair(P[10]),
+    );
+
+    let f0 = DoubleDouble::mul_add(x2, e1, e0);
+    let f1 = DoubleDouble::mul_add(x2, e3, e2);
+    let f2 = DoubleDouble::mul_add(x2, e5, e4);
+
+    let g0 = DoubleDouble::mul_add(x4, f1, f0);
+
+    let p_num = DoubleDouble::mul_add(x8, f2, g0);
+
+    const Q: [(u64, u64); 12] = [
+        (0x0000000000000000, 0x3ff0000000000000),
+        (0xbcd0d33e9e73b503, 0xc0496f5a09751d50),
+        (0x3d2f9c44a069dc4b, 0x40934427187ac370),
+        (0xbd69e2e5a3618381, 0xc0d19983f74fdf52),
+        (0x3d88c69a62ae8b44, 0x410524fcaa71e85a),
+        (0xbdc0345b806dd0bf, 0xc13120daf531b66b),
+        (0xbdd35875712fff6f, 0x4152943a4f9f1c7f),
+        (0xbdf8dd50e92553fd, 0xc169b83aeede08ea),
+        (0x3e0800ecaa77f79e, 0x41746c61554a08ce),
+        (0x3dd74fbc32c5f696, 0xc16ba2febd1932a3),
+        (0x3dc23eb2c943b539, 0x413574ae68b6b378),
+        (0xbd95d86c5c94cd65, 0xc104adac99eaa90c),
+    ];
+
+    let e0 = DoubleDouble::mul_add_f64(
+        recip,
+        DoubleDouble::from_bit_pair(Q[1]),
+        f64::from_bits(0x3ff0000000000000),
+    );
+    let e1 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(Q[3]),
+        DoubleDouble::from_bit_pair(Q[2]),
+    );
+    let e2 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(Q[5]),
+        DoubleDouble::from_bit_pair(Q[4]),
+    );
+    let e3 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(Q[7]),
+        DoubleDouble::from_bit_pair(Q[6]),
+    );
+    let e4 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(Q[9]),
+        DoubleDouble::from_bit_pair(Q[8]),
+    );
+    let e5 = DoubleDouble::mul_add(
+        recip,
+        DoubleDouble::from_bit_pair(Q[11]),
+        DoubleDouble::from_bit_pair(Q[10]),
+    );
+
+    let f0 = DoubleDouble::mul_add(x2, e1, e0);
+    let f1 = DoubleDouble::mul_add(x2, e3, e2);
+    let f2 = DoubleDouble::mul_add(x2, e5, e4);
+
+    let g0 = DoubleDouble::mul_add(x4, f1, f0);
+
+    let p_den = DoubleDouble::mul_add(x8, f2, g0);
+
+    let z = DoubleDouble::div(p_num, p_den);
+
+    let r_sqrt = DoubleDouble::from_rsqrt_fast(dx);
+    let r = DoubleDouble::quick_mult(z, r_sqrt);
+    let err = f_fmla(
+        r.hi,
+        f64::from_bits(0x3c40000000000000), // 2^-59
+        f64::from_bits(0x3ba0000000000000), // 2^-69
+    );
+    let up = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if up == lb {
+        return r.to_f64();
+    }
+    i2e_asympt_hard(x)
+}
+
+/**
+Asymptotic expansion for I2.
+I2(x)exp(-|x|)=R(1/x)/sqrt(x)
+
+Generated in Wolfram:
+```text
+<<FunctionApproximations`
+ClearAll["Global`*"]
+f[x_]:=Sqrt[x] Exp[-x] BesselI[2,x]
+g[z_]:=f[1/z]
+{err,approx}=MiniMaxApproximation[g[z],{z,{1/714.0,1/7.5},15,15},WorkingPrecision->120]
+poly=Numerator[approx][[1]];
+coeffs=CoefficientList[poly,z];
+TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Null&)],"',"}],{i,0,Length[coeffs]-1}]]
+poly=Denominator[approx][[1]];
+coeffs=CoefficientList[poly,z];
+TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Null&)],"',"}],{i,0,Length[coeffs]-1}]]
+```
+**/
+#[cold]
+#[inline(never)]
+fn i2e_asympt_hard(x: f64) -> f64 {
+    static P: [DyadicFloat128; 16] = [
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -129,
+            mantissa: 0xcc42299e_a1b28468_3bb16645_ba1dc793_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -123,
+            mantissa: 0xe202abf7_de10e93f_2a2e6a0f_af69c788_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -118,
+            mantissa: 0xf70296c3_ad33bde6_866cfd01_0e846cfc_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -113,
+            mantissa: 0xa83df971_736c4e6c_1a35479b_ad6d9172_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -109,
+            mantissa: 0x9baa2015_9c5ca461_0aff0b62_54a70fdb_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -106,
+            mantissa: 0xc70af95d_f95d14ad_1094ea1b_e46b2d2f_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -103,
+            mantissa: 0xa838fb48_e79fb706_642da604_6a73b4f8_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -101,
+            mantissa: 0x8fe29f37_02b1e876_39e88664_1c8b3b5d_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -100,
+            mantissa: 0xc8e9a474_0a03f93a_16d2e7a9_627eba4e_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -95,
+            mantissa: 0x8807d1f6_6d646a08_8c7e8900_12d6a5ed_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -93,
+            mantissa: 0xe5c25026_97518024_36878256_fd81c08f_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -91,
+            mantissa: 0xeaa075f0_f5151bed_95ec612f_ab9834a7_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -89,
+            mantissa: 0x9b267222_82d5c666_348d7d1d_0fedfba4_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -88,
+            mantissa: 0x81b45c4c_3e828396_1d5bdede_869c3b84_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -89,
+            mantissa: 0xf4495d43_4bc8dba6_42bdb5d6_c8ba2c9c_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -90,
+            mantissa: 0xc9b29546_0c226270_bb428035_587b6d6a_u128,
+        },
+    ];
+    static Q: [DyadicFloat128; 16] = [
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -127,
+            mantissa: 0x80000000_00000000_00000000_00000000_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -121,
+            mantissa: 0x89e18bae_ca9629a1_26927ba2_fbdd66ab_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -116,
+            mantissa: 0x92a90fc2_e905f634_4946e8a0_dd8e3874_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -112,
+            mantissa: 0xc1742696_d29e3846_3e183737_29db8b68_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -108,
+            mantissa: 0xabf61cc0_236a0e90_2572113d_fa339591_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -105,
+            mantissa: 0xcff0fe90_dac1b08e_9a5740ae_b2984fc1_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -102,
+            mantissa: 0x9ff36729_e407c538_cfcea3a7_63f39043_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -101,
+            mantissa: 0xc86ff6a3_9b803a31_d385e9ea_83f9d751_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -98,
+            mantissa: 0xb4a125b1_6cab70f3_0f314558_708843df_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -94,
+            mantissa: 0x9670fd33_f83bcaa7_85cf2d82_c0bf8cd5_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -92,
+            mantissa: 0xd70b4ea5_32fedb9d_78a3c047_05e650f4_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -90,
+            mantissa: 0xb9c7904c_3f97b633_c2c0ad9b_ad573ede_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -89,
+            mantissa: 0xc2023c21_5155e9fe_6fb17bb2_c865becd_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -89,
+            mantissa: 0xd9400a5e_27c58803_22948cf3_6154ac49_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -90,
+            mantissa: 0x87aa272d_6a9700b4_449a9db8_1a93b0ee_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -93,
+            mantissa: 0xd1a86655_5b259611_dfc7affc_6ffb0e20_u128,
+        },
+    ];
+
+    let recip = DyadicFloat128::accurate_reciprocal(x);
+
+    let mut p_num = P[15];
+    for i in (0..15).rev() {
+        p_num = recip * p_num + P[i];
+    }
+    let mut p_den = Q[15];
+    for i in (0..15).rev() {
+        p_den = recip * p_den + Q[i];
+    }
+    let z = p_num * p_den.reciprocal();
+    let r_sqrt = bessel_rsqrt_hard(x, recip);
+    (z * r_sqrt).fast_as_f64()
+}
+
+/**
+Hankel expansion of I2 for large x, where the rational approximant is out of its range:
+I2(x)exp(-x) = 1/sqrt(2*pi*x) * Sum[(-1)^k * a_k / x^k]
+a_k = (16 - 1^2)(16 - 3^2)...(16 - (2k-1)^2) / (k! * 8^k)
+
+Coefficients include 1/sqrt(2*pi).
+For x >= 714 truncation after 1/x^11 is below 2^-101.
+**/
+#[inline]
+fn i2e_large(x: f64) -> f64 {
+    const P: [(u64, u64); 5] = [
+        (0xbc7cbc0d30ebfd15, 0x3fd9884533d43651),
+        (0x3c8ef04c5ddd3d44, 0xbfe7efc0e096f2ec),
+        (0x3c53b6f4b779a993, 0x3fd4f1c8c484148e),
+        (0x3c3d926f13367e5c, 0x3fbf6aad26c61ed5),
+        (0xbc4cc07eba17e6d9, 0x3fc0330147fe27e6),
+    ];
+
+    let recip = DoubleDouble::from_quick_recip(x);
+
+    let ps = f_polyeval7(
+        recip.hi,
+        f64::from_bits(0x3fca52e214fd00d6),
+        f64::from_bits(0x3fdccaa746f4b8ea),
+        f64::from_bits(0x3ff3aa6b662e02e0),
+        f64::from_bits(0x40100e21ae6b9059),
+        f64::from_bits(0x402e701fdaabec52),
+        f64::from_bits(0x405068712be0ad64),
+        f64::from_bits(0x4073cf8e75b45cfd),
+    );
+
+    let mut p = DoubleDouble::mul_f64_add(recip, ps, DoubleDouble::from_bit_pair(P[4]));
+    p = DoubleDouble::mul_add(recip, p, DoubleDouble::from_bit_pair(P[3]));
+    p = DoubleDouble::mul_add(recip, p, DoubleDouble::from_bit_pair(P[2]));
+    p = DoubleDouble::mul_add(recip, p, DoubleDouble::from_bit_pair(P[1]));
+    p = DoubleDouble::mul_add(recip, p, DoubleDouble::from_bit_pair(P[0]));
+
+    let r_sqrt = DoubleDouble::from_rsqrt_fast(x);
+    let r = DoubleDouble::quick_mult(p, r_sqrt);
+    let err = f_fmla(
+        r.hi,
+        f64::from_bits(0x3c40000000000000), // 2^-59
+        f64::from_bits(0x3ba0000000000000), // 2^-69
+    );
+    let up = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if up == lb {
+        return r.to_f64();
+    }
+    i2e_large_hard(x)
+}
+
+/**
+Same Hankel expansion as i2e_large up to 1/x^15, truncation is below 2^-128.
+**/
+#[cold]
+#[inline(never)]
+fn i2e_large_hard(x: f64) -> f64 {
+    static P: [DyadicFloat128; 16] = [
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -129,
+            mantissa: 0xcc42299e_a1b28468_7e59e280_5d5c7180_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Neg,
+            exponent: -128,
+            mantissa: 0xbf7e0704_b7975c21_f6744458_5786aa68_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -129,
+            mantissa: 0xa78e4624_20a4709d_b7a5bbcd_4c95d51b_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -131,
+            mantissa: 0xfb556936_30f6a8ec_937899b3_f2e0bfa8_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -130,
+            mantissa: 0x81980a3f_f13f2f19_fc0a2f40_c93be2d3_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -130,
+            mantissa: 0xd29710a7_e806ac8a_39908cc9_47015096_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -129,
+            mantissa: 0xe6553a37_a5c74cb7_2ef619fc_25a97024_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -127,
+            mantissa: 0x9d535b31_7016fc66_43813d2d_5e4bbb74_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -125,
+            mantissa: 0x80710d73_5c82c40f_7d1c82f2_09fbd40a_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -124,
+            mantissa: 0xf380fed5_5f6293b2_b2860d95_8842c1fe_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -121,
+            mantissa: 0x8343895f_056b239e_543c4352_9b73fc93_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -119,
+            mantissa: 0x9e7c73ad_a2e7e75e_6fe2f148_d46b503a_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -117,
+            mantissa: 0xd3ba4289_f7a1cf18_29792e57_4bc75d2d_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -114,
+            mantissa: 0x9afa7da0_ae2ebf47_883415eb_9729016f_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -112,
+            mantissa: 0xf6a6a914_de5b8ab5_d832e52c_16dc1e48_u128,
+        },
+        DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -109,
+            mantissa: 0xd3f7394d_ef16ab34_45cbbcf1_e3a52a06_u128,
+        },
+    ];
+
+    let recip = DyadicFloat128::accurate_reciprocal(x);
+
+    let mut p = P[15];
+    for i in (0..15).rev() {
+        p = recip * p + P[i];
+    }
+    let r_sqrt = bessel_rsqrt_hard(x, recip);
+    (p * r_sqrt).fast_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_i2e() {
+        assert!(f_i2e(f64::NAN).is_nan());
+        assert_eq!(f_i2e(0.), 0.);
+        assert_eq!(f_i2e(f64::INFINITY), 0.);
+        assert_eq!(f_i2e(f64::NEG_INFINITY), 0.);
+        assert_eq!(f_i2e(1.), 0.04993877689422354);
+        assert_eq!(f_i2e(10.), 0.1035808008865375);
+        assert_eq!(f_i2e(-10.), 0.1035808008865375);
+        assert_eq!(f_i2e(1000.), 0.0125920185953774);
+        assert_eq!(f_i2e(100000.), 0.0012615426067461744);
+        assert_eq!(f_i2e(44050.58), 0.0019007095836322558);
+        assert_eq!(f_i2e(2.1e6), 0.00027529608207028743);
+    }
+}
@@ -0,0 +1,193 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::i2f::i2f_small;
+use crate::bessel::j0f::j1f_rsqrt;
+use crate::exponents::core_expf;
+use crate::polyeval::{f_estrin_polyeval9, f_polyeval12};
+
+/// Modified exponentially scaled Bessel of the first kind of order 2
+///
+/// Computes exp(-|x|)*I2(x)
+///
+/// Max ULP 0.5
+pub fn f_i2ef(x: f32) -> f32 {
+    let ux = x.to_bits().wrapping_shl(1);
+    if ux >= 0xffu32 << 24 || ux == 0 {
+        // |x| == 0, |x| == inf, |x| == NaN
+        if ux == 0 {
+            // |x| == 0
+            return 0.;
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+        return x + f32::NAN; // x == NaN
+    }
+
+    let xb = x.to_bits() & 0x7fff_ffff;
+
+    // Exceptions:
+    if xb == 0x3b2e73f6 {
+        return f32::from_bits(0x356d21e8);
+    } else if xb == 0x40869e9c {
+        return f32::from_bits(0x3df1a9cf);
+    } else if xb == 0x40f6f32a {
+        return f32::from_bits(0x3de2ef45);
+    } else if xb == 0x40f9264b {
+        return f32::from_bits(0x3de27b3c);
+    } else if xb == 0x40fc0e55 {
+        return f32::from_bits(0x3de1e1bd);
+    }
+
+    if xb <= 0x40f80000u32 {
+        // |x| <= 7.75
+        if xb <= 0x34000000u32 {
+            // |x| <= f32::EPSILON
+            // Power series of I2(x)exp(-|x|) ~ x^2/8 - |x|^3/8 + O(x^4)
+            let dx = f32::from_bits(xb) as f64;
+            const R: f64 = 1. / 8.;
+            let x2 = dx * dx * R;
+            return (x2 - x2 * dx) as f32;
+        }
+        let v_exp = core_expf(-f32::from_bits(xb));
+        return (i2f_small(f32::from_bits(xb)) * v_exp) as f32;
+    }
+
+    if xb >= 0x42b80000u32 {
+        // |x| >= 92
+        return i2ef_large(f32::from_bits(xb));
+    }
+
+    i2ef_asympt(f32::from_bits(xb))
+}
+
+/**
+Asymptotic expansion for I2.
+
+Computes:
+sqrt(x) * exp(-x) * I2(x) = Pn(1/x)/Qn(1/x)
+hence:
+I2(x)exp(-|x|) = Pn(1/x)/Qm(1/x)/sqrt(x)
+
+Generated by Mathematica:
+```text
+<<FunctionApproximations`
+ClearAll["Global`*"]
+f[x_]:=Sqrt[x] Exp[-x] BesselI[2,x]
+g[z_]:=f[1/z]
+{err,approx}=MiniMaxApproximation[g[z],{z,{1/92.3,1/7.5},8,8},WorkingPrecision->70]
+poly=Numerator[approx][[1]];
+coeffs=CoefficientList[poly,z];
+TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Null&)],"',"}],{i,0,Length[coeffs]-1}]]
+poly=Denominator[approx][[1]];
+coeffs=CoefficientList[poly,z];
+TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Null&)],"',"}],{i,0,Length[coeffs]-1}]]
+```
+**/
+#[inline]
+fn i2ef_asympt(x: f32) -> f32 {
+    let dx = x as f64;
+    let recip = 1. / dx;
+    let p_num = f_estrin_polyeval9(
+        recip,
+        f64::from_bits(0x3fd9884533d45f46),
+        f64::from_bits(0xc02b979526807e1e),
+        f64::from_bits(0x406b1dd3e795bbed),
+        f64::from_bits(0xc09e43629031ec91),
+        f64::from_bits(0x40c48c03a39aec1d),
+        f64::from_bits(0xc0e0f022ccb8807a),
+        f64::from_bits(0x40f0302eeb22a776),
+        f64::from_bits(0xc0f02b01549d38b8),
+        f64::from_bits(0x40dad4e70f2bc264),
+    );
+    let p_den = f_estrin_polyeval9(
+        recip,
+        f64::from_bits(0x3ff0000000000000),
+        f64::from_bits(0xc0405a71a88b191c),
+        f64::from_bits(0x407e19f7d247d098),
+        f64::from_bits(0xc0aeaac6e0ca17fe),
+        f64::from_bits(0x40d2301702f40a98),
+        f64::from_bits(0xc0e7e6c6c01841b3),
+        f64::from_bits(0x40ed67317e9e46cc),
+        f64::from_bits(0xc0d13786aa1ef416),
+        f64::from_bits(0xc0a6c9cfe579ae22),
+    );
+    let z = p_num / p_den;
+
+    let r_sqrt = j1f_rsqrt(dx);
+    (z * r_sqrt) as f32
+}
+
+/**
+Hankel expansion of I2 for large x, where the rational approximant is out of its range:
+I2(x)exp(-x) = 1/sqrt(2*pi*x) * Sum[(-1)^k * a_k / x^k]
+a_k = (16 - 1^2)(16 - 3^2)...(16 - (2k-1)^2) / (k! * 8^k)
+
+Coefficients include 1/sqrt(2*pi).
+For x >= 92 truncation after 1/x^11 is below 2^-66.
+**/
+#[inline]
+fn i2ef_large(x: f32) -> f32 {
+    let dx = x as f64;
+    let recip = 1. / dx;
+    let p = f_polyeval12(
+        recip,
+        f64::from_bits(0x3fd9884533d43651),
+        f64::from_bits(0xbfe7efc0e096f2ec),
+        f64::from_bits(0x3fd4f1c8c484148e),
+        f64::from_bits(0x3fbf6aad26c61ed5),
+        f64::from_bits(0x3fc0330147fe27e6),
+        f64::from_bits(0x3fca52e214fd00d6),
+        f64::from_bits(0x3fdccaa746f4b8ea),
+        f64::from_bits(0x3ff3aa6b662e02e0),
+        f64::from_bits(0x40100e21ae6b9059),
+        f64::from_bits(0x402e701fdaabec52),
+        f64::from_bits(0x405068712be0ad64),
+        f64::from_bits(0x4073cf8e75b45cfd),
+    );
+    let r_sqrt = j1f_rsqrt(dx);
+    (p * r_sqrt) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_i2ef() {
+        assert_eq!(f_i2ef(0.), 0.);
+        assert_eq!(f_i2ef(f32::INFINITY), 0.);
+        assert_eq!(f_i2ef(f32::NEG_INFINITY), 0.);
+        assert!(f_i2ef(f32::NAN).is_nan());
+        assert_eq!(f_i2ef(1.), 0.049938776);
+        assert_eq!(f_i2ef(10.), 0.1035808);
+        assert_eq!(f_i2ef(-10.), 0.1035808);
+        assert_eq!(f_i2ef(1000.), 0.012592019);
+        assert_eq!(f_i2ef(100000.), 0.0012615426);
+    }
+}
@@ -62,7 +62,7 @@ pub fn f_i2f(x: f32) -> f32 {
             const R: f64 = 1. / 8.;
             return (dx * dx * R) as f32;
         }
-        return i2f_small(f32::from_bits(xb));
+        return i2f_small(f32::from_bits(xb)) as f32;
     }
 
     i2f_asympt(f32::from_bits(xb))
@@ -89,7 +89,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 ```
 **/
 #[inline]
-fn i2f_small(x: f32) -> f32 {
+pub(crate) fn i2f_small(x: f32) -> f64 {
     let dx = x as f64;
     let x_sqr = dx * dx;
 
@@ -117,7 +117,7 @@ fn i2f_small(x: f32) -> f32 {
         f64::from_bits(0xbc01cf52cc350e81),
     );
     let p = p_num / p_den;
-    (p * x_sqr) as f32
+    p * x_sqr
 }
 
 /**
@@ -87,7 +87,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 ```
 **/
 #[inline]
-fn i0_0_to_1_fast(x: f64) -> DoubleDouble {
+pub(crate) fn i0_0_to_1_fast(x: f64) -> DoubleDouble {
     let half_x = x * 0.5; // this is exact
     let eval_x = DoubleDouble::from_exact_mult(half_x, half_x);
 
@@ -225,7 +225,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 **/
 #[cold]
 #[inline(never)]
-fn k0_small_hard(x: f64, vi: DoubleDouble) -> DoubleDouble {
+pub(crate) fn k0_small_hard(x: f64, vi: DoubleDouble) -> DoubleDouble {
     let dx = DoubleDouble::from_exact_mult(x, x);
     const P: [(u64, u64); 6] = [
         (0x3c1be095d044e896, 0x3fbdadb014541eb2),
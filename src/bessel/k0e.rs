@@ -84,7 +84,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 ```
 **/
 #[inline]
-fn k0e_asympt(x: f64) -> f64 {
+pub(crate) fn k0e_asympt_dd(x: f64) -> DoubleDouble {
     let recip = DoubleDouble::from_quick_recip(x);
     let r_sqrt = DoubleDouble::from_sqrt(x);
 
@@ -202,7 +202,12 @@ fn k0e_asympt(x: f64) -> f64 {
 
     let z = DoubleDouble::div(p_num, p_den);
 
-    let r = DoubleDouble::div(z, r_sqrt);
+    DoubleDouble::div(z, r_sqrt)
+}
+
+#[inline]
+fn k0e_asympt(x: f64) -> f64 {
+    let r = k0e_asympt_dd(x);
 
     let err = r.hi * f64::from_bits(0x3c10000000000000); // 2^-62
     let ub = r.hi + (r.lo + err);
@@ -236,7 +241,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 **/
 #[inline(never)]
 #[cold]
-fn k0e_asympt_hard(x: f64) -> f64 {
+pub(crate) fn k0e_asympt_hard_dyadic(x: f64) -> DyadicFloat128 {
     static P: [DyadicFloat128; 15] = [
         DyadicFloat128 {
             sign: DyadicSign::Pos,
@@ -407,8 +412,13 @@ fn k0e_asympt_hard(x: f64) -> f64 {
     }
 
     let v = p0 * q.reciprocal();
-    let r = v * r_sqrt;
-    r.fast_as_f64()
+    v * r_sqrt
+}
+
+#[cold]
+#[inline(never)]
+fn k0e_asympt_hard(x: f64) -> f64 {
+    k0e_asympt_hard_dyadic(x).fast_as_f64()
 }
 
 #[cfg(test)]
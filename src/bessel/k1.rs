@@ -230,7 +230,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 **/
 #[cold]
 #[inline(never)]
-fn k1_small_hard(x: f64) -> DoubleDouble {
+pub(crate) fn k1_small_hard(x: f64) -> DoubleDouble {
     let rcp = DoubleDouble::from_quick_recip(x);
     let x2 = DoubleDouble::from_exact_mult(x, x);
 
@@ -79,7 +79,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 ```
 **/
 #[inline]
-fn k1e_asympt(x: f64) -> f64 {
+pub(crate) fn k1e_asympt_dd(x: f64) -> DoubleDouble {
     let recip = DoubleDouble::from_quick_recip(x);
     let r_sqrt = DoubleDouble::from_sqrt(x);
 
@@ -197,7 +197,12 @@ fn k1e_asympt(x: f64) -> f64 {
 
     let z = DoubleDouble::div(p_num, p_den);
 
-    let r = DoubleDouble::div(z, r_sqrt);
+    DoubleDouble::div(z, r_sqrt)
+}
+
+#[inline]
+fn k1e_asympt(x: f64) -> f64 {
+    let r = k1e_asympt_dd(x);
 
     let err = r.hi * f64::from_bits(0x3c10000000000000); // 2^-61
     let ub = r.hi + (r.lo + err);
@@ -226,7 +231,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 **/
 #[cold]
 #[inline(never)]
-fn k1e_asympt_hard(x: f64) -> f64 {
+pub(crate) fn k1e_asympt_hard_dyadic(x: f64) -> DyadicFloat128 {
     static P: [DyadicFloat128; 15] = [
         DyadicFloat128 {
             sign: DyadicSign::Pos,
@@ -397,8 +402,13 @@ fn k1e_asympt_hard(x: f64) -> f64 {
     }
 
     let v = p0 * q0.reciprocal();
-    let r = v * r_sqrt;
-    r.fast_as_f64()
+    v * r_sqrt
+}
+
+#[cold]
+#[inline(never)]
+fn k1e_asympt_hard(x: f64) -> f64 {
+    k1e_asympt_hard_dyadic(x).fast_as_f64()
 }
 
 #[cfg(test)]
@@ -0,0 +1,162 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::i0_exp;
+use crate::bessel::k0::{i0_0_to_1_fast, k0_small_dd, k0_small_hard};
+use crate::bessel::k1::{k1_small, k1_small_hard};
+use crate::bessel::k2e::{k2e_asympt_dd, k2e_asympt_hard_dyadic};
+use crate::double_double::DoubleDouble;
+use crate::exponents::rational128_exp;
+
+/// Modified Bessel of the second kind of order 2
+///
+/// Max ULP 0.5
+pub fn f_k2(x: f64) -> f64 {
+    let ix = x.to_bits();
+
+    if ix >= 0x7ffu64 << 52 || ix == 0 {
+        // |x| == NaN, x == inf, |x| == 0, x < 0
+        if ix.wrapping_shl(1) == 0 {
+            // |x| == 0
+            return f64::INFINITY;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_positive() { 0. } else { f64::NAN };
+        }
+        return x + f64::NAN; // x == NaN
+    }
+
+    let xb = x.to_bits();
+
+    if xb >= 0x40862e42fefa39f0u64 {
+        // x >= 709.7827128933841
+        return 0.;
+    }
+
+    if xb <= 0x3ff0000000000000 {
+        // x <= 1
+        if xb <= 0x1ff6a09e667f3bccu64 {
+            // x <= 1.0547686614862998e-154, K2(x) ~ 2/x^2 overflows
+            return f64::INFINITY;
+        }
+        return k2_small_dd(x).to_f64();
+    }
+
+    k2_asympt(x)
+}
+
+/**
+Uses recurrence for K2 on [0; 1]:
+K2(x) = K0(x) + 2/x * K1(x)
+
+Both terms are positive, so there is no cancellation.
+**/
+#[inline]
+pub(crate) fn k2_small_dd(x: f64) -> DoubleDouble {
+    let v_k0 = k0_small_dd(x);
+    let v_k1 = k1_small(x);
+    let r = DoubleDouble::add(v_k0, two_over_x_mul(v_k1, x));
+    let err = r.hi * f64::from_bits(0x3c30000000000000); // 2^-60
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub == lb {
+        return r;
+    }
+    k2_small_hard(x)
+}
+
+#[cold]
+#[inline(never)]
+fn k2_small_hard(x: f64) -> DoubleDouble {
+    let v_k0 = k0_small_hard(x, i0_0_to_1_fast(x));
+    let v_k1 = k1_small_hard(x);
+    DoubleDouble::add(v_k0, two_over_x_mul(v_k1, x))
+}
+
+/// Computes 2/x * v for x <= 1
+///
+/// For tiny x the quotient is close to overflow, where splitting it in division without FMA
+/// overflows, so v is scaled down by 2^64 there and the result is scaled back.
+#[inline]
+fn two_over_x_mul(v: DoubleDouble, x: f64) -> DoubleDouble {
+    if x < f64::from_bits(0x21f0000000000000) {
+        // x < 2^-480
+        const M64: f64 = f64::from_bits(0x3bf0000000000000);
+        const P64: f64 = f64::from_bits(0x43f0000000000000);
+        let q = DoubleDouble::div_dd_f64(DoubleDouble::new(v.lo * M64, v.hi * M64), x * 0.5);
+        return DoubleDouble::new(q.lo * P64, q.hi * P64);
+    }
+    DoubleDouble::div_dd_f64(v, x * 0.5)
+}
+
+/**
+Computes K2(x) = K2(x)exp(x) / exp(x)
+**/
+#[inline]
+fn k2_asympt(x: f64) -> f64 {
+    let v_k2e = k2e_asympt_dd(x);
+    let e = i0_exp(x * 0.5);
+    let r = DoubleDouble::div(v_k2e, e * e);
+
+    let err = r.hi * f64::from_bits(0x3c30000000000000); // 2^-60
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub != lb {
+        return k2_asympt_hard(x);
+    }
+    r.to_f64()
+}
+
+#[cold]
+#[inline(never)]
+fn k2_asympt_hard(x: f64) -> f64 {
+    let v_k2e = k2e_asympt_hard_dyadic(x);
+    let e = rational128_exp(x);
+    (v_k2e * e.reciprocal()).fast_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k2() {
+        assert!(f_k2(-1.).is_nan());
+        assert!(f_k2(f64::NAN).is_nan());
+        assert_eq!(f_k2(0.), f64::INFINITY);
+        assert_eq!(f_k2(f64::INFINITY), 0.);
+        assert_eq!(f_k2(0.65), 4.3059191918675666);
+        assert_eq!(f_k2(1.65), 0.4483076444743975);
+        assert_eq!(f_k2(25.), 3.746783808069109e-12);
+        assert_eq!(f_k2(1.0547686614862998e-154), f64::INFINITY);
+        assert_eq!(f_k2(1.06e-154), 1.77999288002848e308);
+        assert_eq!(f_k2(1e-150), 2e300);
+        assert_eq!(f_k2(1.2e-150), 1.3888888888888887e300);
+        assert_eq!(f_k2(5e-150), 8.000000000000001e298);
+    }
+}
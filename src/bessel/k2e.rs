@@ -0,0 +1,133 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::i0_exp;
+use crate::bessel::k0e::{k0e_asympt_dd, k0e_asympt_hard_dyadic};
+use crate::bessel::k1e::{k1e_asympt_dd, k1e_asympt_hard_dyadic};
+use crate::bessel::k2::k2_small_dd;
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::DyadicFloat128;
+
+/// Modified exponentially scaled Bessel of the second kind of order 2
+///
+/// Computes K2(x)exp(x)
+///
+/// Max ULP 0.5
+pub fn f_k2e(x: f64) -> f64 {
+    let ix = x.to_bits();
+
+    if ix >= 0x7ffu64 << 52 || ix == 0 {
+        // |x| == NaN, x == inf, |x| == 0, x < 0
+        if ix.wrapping_shl(1) == 0 {
+            // |x| == 0
+            return f64::INFINITY;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_positive() { 0. } else { f64::NAN };
+        }
+        return x + f64::NAN; // x == NaN
+    }
+
+    let xb = x.to_bits();
+
+    if xb <= 0x3ff0000000000000 {
+        // x <= 1
+        if xb <= 0x1ff6a09e667f3bccu64 {
+            // x <= 1.0547686614862998e-154, K2(x)exp(x) ~ 2/x^2 overflows
+            return f64::INFINITY;
+        }
+        let v_k2 = k2_small_dd(x);
+        if x < f64::from_bits(0x21f0000000000000) {
+            // x < 2^-480, exp(x) rounds to 1 in double-double precision,
+            // and splitting huge K2(x) in multiplication without FMA would overflow
+            return v_k2.to_f64();
+        }
+        let v_exp = i0_exp(x);
+        return DoubleDouble::quick_mult(v_exp, v_k2).to_f64();
+    }
+
+    k2e_asympt(x)
+}
+
+/**
+Uses recurrence for scaled K2:
+K2(x)exp(x) = K0(x)exp(x) + 2/x * K1(x)exp(x)
+**/
+#[inline]
+pub(crate) fn k2e_asympt_dd(x: f64) -> DoubleDouble {
+    let v_k0e = k0e_asympt_dd(x);
+    let v_k1e = k1e_asympt_dd(x);
+    DoubleDouble::add(v_k0e, DoubleDouble::div_dd_f64(v_k1e, x * 0.5))
+}
+
+#[inline]
+fn k2e_asympt(x: f64) -> f64 {
+    let r = k2e_asympt_dd(x);
+
+    let err = r.hi * f64::from_bits(0x3c30000000000000); // 2^-60
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub != lb {
+        return k2e_asympt_hard(x);
+    }
+    r.to_f64()
+}
+
+#[cold]
+#[inline(never)]
+pub(crate) fn k2e_asympt_hard_dyadic(x: f64) -> DyadicFloat128 {
+    let v_k0e = k0e_asympt_hard_dyadic(x);
+    let v_k1e = k1e_asympt_hard_dyadic(x);
+    v_k0e + v_k1e * DyadicFloat128::from_div_f64(2., x)
+}
+
+#[cold]
+#[inline(never)]
+fn k2e_asympt_hard(x: f64) -> f64 {
+    k2e_asympt_hard_dyadic(x).fast_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k2e() {
+        assert!(f_k2e(-1.).is_nan());
+        assert!(f_k2e(f64::NAN).is_nan());
+        assert_eq!(f_k2e(0.), f64::INFINITY);
+        assert_eq!(f_k2e(0.65), 8.248164018456844);
+        assert_eq!(f_k2e(1.65), 2.334328861148703);
+        assert_eq!(f_k2e(25.), 0.2697867909389635);
+        assert_eq!(f_k2e(1.0547686614862998e-154), f64::INFINITY);
+        assert_eq!(f_k2e(1.06e-154), 1.77999288002848e308);
+        assert_eq!(f_k2e(1e-150), 2e300);
+        assert_eq!(f_k2e(1.2e-150), 1.3888888888888887e300);
+        assert_eq!(f_k2e(5e-150), 8.000000000000001e298);
+    }
+}
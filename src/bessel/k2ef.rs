@@ -0,0 +1,133 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::j0f::j1f_rsqrt;
+use crate::bessel::k2f::{k2f_small, k2f_tiny};
+use crate::exponents::core_expf;
+use crate::polyeval::f_estrin_polyeval8;
+
+/// Modified exponentially scaled Bessel of the second kind of order 2
+///
+/// Computes K2(x)exp(x)
+///
+/// Max ULP 0.5
+pub fn f_k2ef(x: f32) -> f32 {
+    let ux = x.to_bits();
+    if ux >= 0xffu32 << 23 || ux == 0 {
+        // |x| == 0, |x| == inf, |x| == NaN, x < 0
+        if ux.wrapping_shl(1) == 0 {
+            // |x| == 0
+            return f32::INFINITY;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_positive() { 0. } else { f32::NAN };
+        }
+        return x + f32::NAN; // x == NaN
+    }
+
+    let xb = x.to_bits();
+
+    if xb <= 0x3f800000u32 {
+        // x <= 1.0
+        if xb <= 0x34000000u32 {
+            // x <= f32::EPSILON
+            // taylor series for K2(x)exp(x) ~ 2/x^2 + 2/x + 1/2 + O(x)
+            let dx = x as f64;
+            let r = 2. / (dx * dx) + 2. / dx + 0.5;
+            return r as f32;
+        }
+        let v_exp = core_expf(x);
+        if xb <= 0x3e9eb852u32 {
+            // x <= 0.31
+            return (k2f_tiny(x) * v_exp) as f32;
+        }
+        return (k2f_small(x) * v_exp) as f32;
+    }
+
+    k2ef_asympt(x)
+}
+
+/**
+Generated by Wolfram Mathematica:
+```text
+<<FunctionApproximations`
+ClearAll["Global`*"]
+f[x_]:=Sqrt[x] Exp[x] BesselK[2,x]
+g[z_]:=f[1/z]
+{err, approx}=MiniMaxApproximation[g[z],{z,{0.000000001,1},7,7},WorkingPrecision->60]
+poly=Numerator[approx][[1]];
+coeffs=CoefficientList[poly,z];
+TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50}, ExponentFunction->(Null&)],"',"}],{i,0,Length[coeffs]-1}]]
+poly=Denominator[approx][[1]];
+coeffs=CoefficientList[poly,z];
+TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50}, ExponentFunction->(Null&)],"',"}],{i,0,Length[coeffs]-1}]]
+```
+**/
+#[inline]
+fn k2ef_asympt(x: f32) -> f32 {
+    let dx = x as f64;
+    let recip = 1. / dx;
+    let r_sqrt = j1f_rsqrt(dx);
+    let p_num = f_estrin_polyeval8(
+        recip,
+        f64::from_bits(0x3ff40d931ff626f2),
+        f64::from_bits(0x402d954dceb445df),
+        f64::from_bits(0x405084ea6680d028),
+        f64::from_bits(0x406242344a8ea488),
+        f64::from_bits(0x406594aa56f50fea),
+        f64::from_bits(0x405aa04eb4f0af1c),
+        f64::from_bits(0x403dd3e8e63849ef),
+        f64::from_bits(0x4004e85453648d43),
+    );
+    let p_den = f_estrin_polyeval8(
+        recip,
+        f64::from_bits(0x3ff0000000000000),
+        f64::from_bits(0x4023da9f4e05358e),
+        f64::from_bits(0x4040a4e4ceb523c9),
+        f64::from_bits(0x404725c423c9f990),
+        f64::from_bits(0x403a60c00deededc),
+        f64::from_bits(0x40149975b84c3946),
+        f64::from_bits(0x3fc69439846db871),
+        f64::from_bits(0xbf6400819bac6f45),
+    );
+    let v = p_num / p_den;
+    (v * r_sqrt) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k2ef() {
+        assert!(f_k2ef(-1.).is_nan());
+        assert!(f_k2ef(f32::NAN).is_nan());
+        assert_eq!(f_k2ef(0.), f32::INFINITY);
+        assert_eq!(f_k2ef(f32::INFINITY), 0.);
+    }
+}
@@ -65,16 +65,16 @@ pub fn f_k2f(x: f32) -> f32 {
                 let r = 2. / (dx * dx);
                 return r as f32;
             }
-            return k2f_tiny(x);
+            return k2f_tiny(x) as f32;
         }
-        return k2f_small(x);
+        return k2f_small(x) as f32;
     }
 
     k2f_asympt(x)
 }
 
 #[inline]
-fn k2f_tiny(x: f32) -> f32 {
+pub(crate) fn k2f_tiny(x: f32) -> f64 {
     // Power series at zero for K2
     // 2.0000000000000000/x^2-0.50000000000000000-0.12500000000000000 (-0.86593151565841245+1.0000000000000000 Log[x]) x^2-0.010416666666666667 (-1.5325981823250791+1.0000000000000000 Log[x]) x^4-0.00032552083333333333 (-1.9075981823250791+1.0000000000000000 Log[x]) x^6-0.0000054253472222222222 (-2.1742648489917458+1.0000000000000000 Log[x]) x^8+O[x]^9
     //-0.50000000000000000+2.0000000000000000/x^2 + a3 * x^8 + x^6 * a2 + x^4 * a1 + x^2 * a0
@@ -87,8 +87,7 @@ fn k2f_tiny(x: f32) -> f32 {
     let dx_sqr = dx * dx;
     let two_over_dx = 2. / dx_sqr;
     let p = f_polyeval4(dx_sqr, a0, a1, a2, a3);
-    let r = f_fmla(p, dx_sqr, two_over_dx) - 0.5;
-    r as f32
+    f_fmla(p, dx_sqr, two_over_dx) - 0.5
 }
 
 /**
@@ -156,7 +155,7 @@ TableForm[Table[Row[{"'",NumberForm[coeffs[[i+1]],{50,50},ExponentFunction->(Nul
 ```
 **/
 #[inline]
-fn k2f_small(x: f32) -> f32 {
+pub(crate) fn k2f_small(x: f32) -> f64 {
     let dx = x as f64;
     let dx_sqr = dx * dx;
     let p_num = f_polyeval11(
@@ -194,8 +193,7 @@ fn k2f_small(x: f32) -> f32 {
     let lg = fast_logf(x);
     let v_i = i2f_small(x);
     let z = f_fmla(lg, v_i, two_over_dx_sqr);
-    let z0 = f_fmla(p, f_fmla(dx, dx, 1.), z);
-    z0 as f32
+    f_fmla(p, f_fmla(dx, dx, 1.), z)
 }
 
 /**
@@ -30,6 +30,7 @@
 mod alpha0;
 mod alpha1;
 mod bessel_exp;
+mod bessel_zeros;
 mod beta0;
 mod beta1;
 mod i0;
@@ -41,6 +42,8 @@ mod i1e;
 mod i1ef;
 mod i1f;
 mod i2;
+mod i2e;
+mod i2ef;
 mod i2f;
 mod j0;
 mod j0_coeffs_remez;
@@ -62,6 +65,9 @@ mod k1;
 mod k1e;
 mod k1ef;
 mod k1f;
+mod k2;
+mod k2e;
+mod k2ef;
 mod k2f;
 mod trigo_bessel;
 mod y0;
@@ -76,6 +82,9 @@ mod y1f;
 mod y1f_coeffs;
 
 pub(crate) use bessel_exp::i0_exp;
+pub use bessel_zeros::{
+    f_j0_prime_zero, f_j0_zero, f_j1_prime_zero, f_j1_zero, f_y0_zero, f_y1_zero,
+};
 pub use i0::f_i0;
 pub use i0e::f_i0e;
 pub use i0ef::f_i0ef;
@@ -85,6 +94,8 @@ pub use i1e::f_i1e;
 pub use i1ef::f_i1ef;
 pub use i1f::f_i1f;
 pub use i2::f_i2;
+pub use i2e::f_i2e;
+pub use i2ef::f_i2ef;
 pub use i2f::f_i2f;
 pub use j0::f_j0;
 pub use j0f::f_j0f;
@@ -100,6 +111,9 @@ pub use k1::f_k1;
 pub use k1e::f_k1e;
 pub use k1ef::f_k1ef;
 pub use k1f::f_k1f;
+pub use k2::f_k2;
+pub use k2e::f_k2e;
+pub use k2ef::f_k2ef;
 pub use k2f::f_k2f;
 pub use y0::f_y0;
 pub use y0f::f_y0f;
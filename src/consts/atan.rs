@@ -0,0 +1,96 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::consts::common::{ONE, PI_OVER_2, dyadic_recip, dyadic_sqrt};
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+
+/// Computes arctangent in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_atan].
+pub const fn f_atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x + x;
+    }
+    if x == 0. {
+        return x;
+    }
+    if x.is_infinite() {
+        return PI_OVER_2.const_as_f64().copysign(x);
+    }
+    let mut t = DyadicFloat128::new_from_f64(x.abs());
+    let inverted = x.abs() > 1.;
+    if inverted {
+        // atan(x) = PI/2 - atan(1/x)
+        t = dyadic_recip(t);
+    }
+    // Three halvings atan(t) = 2 * atan(t / (1 + sqrt(1 + t^2))) give |t| < tan(PI/32)
+    let mut i = 0;
+    while i < 3 {
+        let d = ONE.quick_add(&dyadic_sqrt(ONE.quick_add(&t.quick_mul(&t))));
+        t = t.quick_mul(&dyadic_recip(d));
+        i += 1;
+    }
+    // atan(t) / t = sum((-1)^k t^2k / (2k + 1)), t^40 / 41 < 2^-135
+    let t2 = t.quick_mul(&t);
+    let mut p = ONE.div_int(41);
+    let mut k = 20;
+    while k > 0 {
+        k -= 1;
+        p = ONE.div_int(2 * k + 1).quick_sub(&t2.quick_mul(&p));
+    }
+    let mut r = t.quick_mul(&p);
+    r.exponent += 3;
+    if inverted {
+        r = PI_OVER_2.quick_sub(&r);
+    }
+    if x < 0. {
+        r.sign = DyadicSign::Neg;
+    }
+    r.const_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atan() {
+        const TABLE: [f64; 4] = [f_atan(1.), f_atan(-0.3), f_atan(1e10), f_atan(1e-300)];
+        assert_eq!(
+            TABLE,
+            [
+                0.7853981633974483,
+                -0.2914567944778671,
+                1.5707963266948965,
+                1e-300
+            ]
+        );
+        assert_eq!(f_atan(f64::NEG_INFINITY), -std::f64::consts::FRAC_PI_2);
+        assert!(f_atan(f64::NAN).is_nan());
+    }
+}
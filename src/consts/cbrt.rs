@@ -0,0 +1,82 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::consts::common::dyadic_recip;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+
+/// Computes cube root in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_cbrt].
+pub const fn f_cbrt(x: f64) -> f64 {
+    if x == 0. || !x.is_finite() {
+        return x + x;
+    }
+    let mut a = DyadicFloat128::new_from_f64(x);
+    let sign = a.sign;
+    a.sign = DyadicSign::Pos;
+    // |x| = m * 2^(3q + s), a = m * 2^s in [1, 8)
+    let e = a.exponent as i32 + 127;
+    let q = e.div_euclid(3);
+    a.exponent = -127 + (e - 3 * q) as i16;
+    // Initial guess by Newton's iteration in f64, y^3 - a is convex so it converges
+    let af = a.const_as_f64();
+    let mut yf = 1.5f64;
+    let mut i = 0;
+    while i < 10 {
+        yf -= (yf * yf * yf - af) / (3. * yf * yf);
+        i += 1;
+    }
+    // Newton's iteration y = (2y + a / y^2) / 3
+    let mut y = DyadicFloat128::new_from_f64(yf);
+    i = 0;
+    while i < 2 {
+        let y2 = y.quick_mul(&y);
+        let mut y_twice = y;
+        y_twice.exponent += 1;
+        y = y_twice
+            .quick_add(&a.quick_mul(&dyadic_recip(y2)))
+            .div_int(3);
+        i += 1;
+    }
+    y.exponent += q as i16;
+    y.sign = sign;
+    y.const_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cbrt() {
+        const TABLE: [f64; 3] = [f_cbrt(27.), f_cbrt(2.), f_cbrt(-1e-310)];
+        assert_eq!(TABLE, [3., 1.2599210498948732, -4.641588833612774e-104]);
+        assert_eq!(f_cbrt(-0.), -0.);
+        assert_eq!(f_cbrt(f64::INFINITY), f64::INFINITY);
+    }
+}
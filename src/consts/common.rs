@@ -0,0 +1,186 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+
+pub(crate) const ONE: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -127,
+    mantissa: 0x80000000_00000000_00000000_00000000_u128,
+};
+
+const TWO: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -126,
+    mantissa: 0x80000000_00000000_00000000_00000000_u128,
+};
+
+// ln(2) split so that k * LN2_HI is exact for |k| < 2^28
+const LN2_HI: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -128,
+    mantissa: 0xb17217f7_d1cf79ab_c9e3b398_00000000_u128,
+};
+
+const LN2_LO: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -230,
+    mantissa: 0xfcbdabd0_3cd0c99c_a62d8b62_8345d6e3_u128,
+};
+
+pub(crate) const LN2: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -128,
+    mantissa: 0xb17217f7_d1cf79ab_c9e3b398_03f2f6af_u128,
+};
+
+pub(crate) const INV_LN2: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -127,
+    mantissa: 0xb8aa3b29_5c17f0bb_be87fed0_691d3e89_u128,
+};
+
+const SQRT_2: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -127,
+    mantissa: 0xb504f333_f9de6484_597d89b3_754abe9f_u128,
+};
+
+pub(crate) const PI_OVER_2: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -127,
+    mantissa: 0xc90fdaa2_2168c234_c4c6628b_80dc1cd1_u128,
+};
+
+#[inline]
+pub(crate) const fn dyadic_from_i64(v: i64) -> DyadicFloat128 {
+    let sign = if v < 0 {
+        DyadicSign::Neg
+    } else {
+        DyadicSign::Pos
+    };
+    DyadicFloat128::new(sign, 0, v.unsigned_abs() as u128)
+}
+
+/// Computes 1 / a for a != 0.
+pub(crate) const fn dyadic_recip(a: DyadicFloat128) -> DyadicFloat128 {
+    let mut a = a;
+    a.normalize();
+    // m = |a| scaled to [1, 2)
+    let m = DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -127,
+        mantissa: a.mantissa,
+    };
+    let mut x = DyadicFloat128::new_from_f64(1. / m.const_as_f64());
+    // Newton's iteration x = x * (2 - m * x), each step doubles correct bits
+    let mut i = 0;
+    while i < 2 {
+        x = x.quick_mul(&TWO.quick_sub(&m.quick_mul(&x)));
+        i += 1;
+    }
+    x.exponent -= a.exponent + 127;
+    x.sign = a.sign;
+    x
+}
+
+/// Computes sqrt(a) for a > 0.
+pub(crate) const fn dyadic_sqrt(a: DyadicFloat128) -> DyadicFloat128 {
+    let mut a = a;
+    a.normalize();
+    // a = m * 2^e with even e
+    let (m, e) = if a.exponent & 1 != 0 {
+        (a.mantissa >> 1, a.exponent + 1)
+    } else {
+        (a.mantissa, a.exponent)
+    };
+    // Integer square root gives 64 correct bits
+    let s = DyadicFloat128::new(DyadicSign::Pos, e / 2, m.isqrt());
+    // One Newton's step s = (s + a / s) / 2
+    let mut r = s.quick_add(&a.quick_mul(&dyadic_recip(s)));
+    r.exponent -= 1;
+    r
+}
+
+/// Computes e^y for |y| < 2^10.
+pub(crate) const fn dyadic_exp(y: DyadicFloat128) -> DyadicFloat128 {
+    const INV_LN2_F64: f64 = f64::from_bits(0x3ff71547652b82fe);
+    // y = k * ln(2) + r, |r| < 0.35
+    let kf = y.const_as_f64() * INV_LN2_F64;
+    let k = (kf + if kf < 0. { -0.5 } else { 0.5 }) as i64;
+    // LN2_HI has 100 significant bits, so k * LN2_HI is exact
+    let mut k_ln2_hi = dyadic_from_i64(k);
+    k_ln2_hi.mantissa = (LN2_HI.mantissa >> 28) * k.unsigned_abs() as u128;
+    k_ln2_hi.exponent = LN2_HI.exponent + 28;
+    k_ln2_hi.normalize();
+    let k_ln2_lo = LN2_LO.quick_mul(&dyadic_from_i64(k));
+    let r = y.quick_sub(&k_ln2_hi).quick_sub(&k_ln2_lo);
+    // Taylor series in Horner form, |r|^28 / 28! < 2^-140
+    let mut p = ONE;
+    let mut n = 27;
+    while n > 0 {
+        p = ONE.quick_add(&r.quick_mul(&p).div_int(n));
+        n -= 1;
+    }
+    p.exponent += k as i16;
+    p
+}
+
+/// Computes ln(x) for positive finite x as e * ln(2) + ln(m), m in [sqrt(2)/2, sqrt(2)).
+///
+/// Returns (e, ln(m)).
+pub(crate) const fn dyadic_log(x: f64) -> (i64, DyadicFloat128) {
+    let mut m = DyadicFloat128::new_from_f64(x);
+    let mut e = m.exponent as i64 + 127;
+    m.exponent = -127;
+    if m.mantissa > SQRT_2.mantissa {
+        m.exponent = -128;
+        e += 1;
+    }
+    // ln(m) = 2 * atanh(t), t = (m - 1) / (m + 1), |t| < 0.1716
+    let t = m
+        .quick_sub(&ONE)
+        .quick_mul(&dyadic_recip(m.quick_add(&ONE)));
+    let t2 = t.quick_mul(&t);
+    // atanh(t) / t = sum(t^2k / (2k + 1)), t^52 / 53 < 2^-135
+    let mut p = ONE.div_int(53);
+    let mut k = 26;
+    while k > 0 {
+        k -= 1;
+        p = ONE.div_int(2 * k + 1).quick_add(&t2.quick_mul(&p));
+    }
+    let mut r = t.quick_mul(&p);
+    r.exponent += 1;
+    (e, r)
+}
+
+/// Computes full ln(x) for positive finite x.
+pub(crate) const fn dyadic_ln(x: f64) -> DyadicFloat128 {
+    let (e, r) = dyadic_log(x);
+    LN2.quick_mul(&dyadic_from_i64(e)).quick_add(&r)
+}
@@ -0,0 +1,100 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::consts::common::dyadic_exp;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+
+const TWO_OVER_SQRT_PI: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -127,
+    mantissa: 0x906eba82_14db688d_71d48a7f_6bfec344_u128,
+};
+
+/// Computes error function in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_erf].
+pub const fn f_erf(x: f64) -> f64 {
+    if x.is_nan() {
+        return x + x;
+    }
+    if x == 0. {
+        return x;
+    }
+    if x.abs() >= 6. {
+        // 1 - erf(6) < 2^-55
+        return 1f64.copysign(x);
+    }
+    // erf(x) = 2/sqrt(PI) * e^(-x^2) * sum(2^n x^(2n+1) / (1 * 3 * ... * (2n + 1))),
+    // all terms are positive so there is no cancellation
+    let ax = DyadicFloat128::new_from_f64(x.abs());
+    let x2 = ax.quick_mul(&ax);
+    let mut two_x2 = x2;
+    two_x2.exponent += 1;
+    let mut term = ax;
+    let mut sum = ax;
+    let mut n = 1u64;
+    while n < 400 {
+        term = term.quick_mul(&two_x2).div_int(2 * n + 1);
+        sum = sum.quick_add(&term);
+        // term < 2^-135 * sum
+        if (term.exponent as i32) < sum.exponent as i32 - 135 {
+            break;
+        }
+        n += 1;
+    }
+    let mut r = dyadic_exp(x2.negated())
+        .quick_mul(&sum)
+        .quick_mul(&TWO_OVER_SQRT_PI);
+    r.sign = if x < 0. {
+        DyadicSign::Neg
+    } else {
+        DyadicSign::Pos
+    };
+    r.const_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erf() {
+        const TABLE: [f64; 4] = [f_erf(0.5), f_erf(-2.), f_erf(5.9), f_erf(1e-300)];
+        assert_eq!(
+            TABLE,
+            [
+                0.5204998778130465,
+                -0.9953222650189527,
+                0.9999999999999999,
+                1.1283791670955126e-300
+            ]
+        );
+        assert_eq!(f_erf(-7.), -1.);
+        assert!(f_erf(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::consts::common::{LN2, dyadic_exp};
+use crate::dyadic_float::DyadicFloat128;
+
+/// Computes exponent in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_exp].
+pub const fn f_exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x + x;
+    }
+    if x > 710. {
+        return f64::INFINITY;
+    }
+    if x < -746. {
+        return 0.;
+    }
+    dyadic_exp(DyadicFloat128::new_from_f64(x)).const_as_f64()
+}
+
+/// Computes 2^x in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_exp2].
+pub const fn f_exp2(x: f64) -> f64 {
+    if x.is_nan() {
+        return x + x;
+    }
+    if x > 1025. {
+        return f64::INFINITY;
+    }
+    if x < -1076. {
+        return 0.;
+    }
+    // 2^x = 2^k * e^(f * ln(2)), f = x - k is exact
+    let k = (x + if x < 0. { -0.5 } else { 0.5 }) as i64;
+    let f = x - k as f64;
+    let mut r = dyadic_exp(DyadicFloat128::new_from_f64(f).quick_mul(&LN2));
+    r.exponent += k as i16;
+    r.const_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp() {
+        const TABLE: [f64; 4] = [f_exp(1.), f_exp(-700.5), f_exp(709.7), f_exp(-745.)];
+        assert_eq!(
+            TABLE,
+            [
+                2.718281828459045,
+                5.980196118639791e-305,
+                1.6549840276802644e308,
+                5e-324
+            ]
+        );
+        assert_eq!(f_exp(0.), 1.);
+        assert_eq!(f_exp(710.), f64::INFINITY);
+        assert_eq!(f_exp(f64::NEG_INFINITY), 0.);
+        assert!(f_exp(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_exp2() {
+        const TABLE: [f64; 3] = [f_exp2(0.5), f_exp2(-1074.), f_exp2(10.25)];
+        assert_eq!(TABLE, [1.4142135623730951, 5e-324, 1217.7480857627863]);
+        assert_eq!(f_exp2(-1075.), 0.);
+        assert_eq!(f_exp2(1024.), f64::INFINITY);
+        assert!(f_exp2(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,135 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::consts::common::{INV_LN2, dyadic_from_i64, dyadic_ln, dyadic_log};
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+
+const INV_LN10: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -129,
+    mantissa: 0xde5bd8a9_37287195_355baaaf_ad33dc32_u128,
+};
+
+/// Returns value for x <= 0, infinities and NaN.
+#[inline]
+const fn log_special(x: f64) -> Option<f64> {
+    if x.is_nan() {
+        return Some(x + x);
+    }
+    if x == 0. {
+        return Some(f64::NEG_INFINITY);
+    }
+    if x < 0. {
+        return Some(f64::NAN);
+    }
+    if x.is_infinite() {
+        return Some(x);
+    }
+    None
+}
+
+/// Computes natural logarithm in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_log].
+pub const fn f_log(x: f64) -> f64 {
+    if let Some(r) = log_special(x) {
+        return r;
+    }
+    dyadic_ln(x).const_as_f64()
+}
+
+/// Computes binary logarithm in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_log2].
+pub const fn f_log2(x: f64) -> f64 {
+    if let Some(r) = log_special(x) {
+        return r;
+    }
+    let (e, r) = dyadic_log(x);
+    dyadic_from_i64(e)
+        .quick_add(&r.quick_mul(&INV_LN2))
+        .const_as_f64()
+}
+
+/// Computes decimal logarithm in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_log10].
+pub const fn f_log10(x: f64) -> f64 {
+    if let Some(r) = log_special(x) {
+        return r;
+    }
+    // Exact powers of 10
+    if x >= 1. && x <= 1e22 {
+        let mut p = 1.;
+        let mut k = 0;
+        while k <= 22 {
+            if x == p {
+                return k as f64;
+            }
+            p *= 10.;
+            k += 1;
+        }
+    }
+    dyadic_ln(x).quick_mul(&INV_LN10).const_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log() {
+        const TABLE: [f64; 4] = [f_log(2.), f_log(0.999), f_log(5e-324), f_log(1e300)];
+        assert_eq!(
+            TABLE,
+            [
+                0.6931471805599453,
+                -0.0010005003335835344,
+                -744.4400719213812,
+                690.7755278982137
+            ]
+        );
+        assert_eq!(f_log(1.), 0.);
+        assert_eq!(f_log(0.), f64::NEG_INFINITY);
+        assert!(f_log(-1.).is_nan());
+    }
+
+    #[test]
+    fn test_log2() {
+        const TABLE: [f64; 3] = [f_log2(3.), f_log2(1024.), f_log2(1e-10)];
+        assert_eq!(TABLE, [1.584962500721156, 10., -33.219280948873624]);
+        assert_eq!(f_log2(f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_log10() {
+        const TABLE: [f64; 3] = [f_log10(1000.), f_log10(2.), f_log10(0.1)];
+        assert_eq!(TABLE, [3., 0.3010299956639812, -1.]);
+        assert!(f_log10(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,64 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Correctly rounded functions evaluable in const context.
+//!
+//! Everything is computed with integer arithmetic on 128-bit dyadic floats,
+//! results are the same as from runtime counterparts, so it is suitable for building lookup tables at compile time.
+//! This is much slower than runtime functions and should not be used outside of const context.
+//! Tables with more than a few hundred entries may exceed rustc `long_running_const_eval` budget,
+//! then it should be allowed on the item:
+//!
+//! ```
+//! #[allow(long_running_const_eval)]
+//! static TABLE: [f64; 1024] = {
+//!     let mut t = [0.; 1024];
+//!     let mut i = 0;
+//!     while i < t.len() {
+//!         t[i] = pxfm::consts::f_sin(i as f64 / 1024.);
+//!         i += 1;
+//!     }
+//!     t
+//! };
+//! ```
+mod atan;
+mod cbrt;
+mod common;
+mod erf;
+mod exp;
+mod log;
+mod pow;
+mod sincos;
+
+pub use atan::f_atan;
+pub use cbrt::f_cbrt;
+pub use erf::f_erf;
+pub use exp::{f_exp, f_exp2};
+pub use log::{f_log, f_log2, f_log10};
+pub use pow::f_pow;
+pub use sincos::{f_cos, f_sin, f_tan};
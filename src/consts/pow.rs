@@ -0,0 +1,190 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::consts::common::{ONE, dyadic_exp, dyadic_ln, dyadic_recip};
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+
+#[inline]
+const fn is_integer(y: f64) -> bool {
+    if y.abs() >= f64::from_bits(0x4340000000000000) {
+        // |y| >= 2^53
+        return true;
+    }
+    y == (y as i64) as f64
+}
+
+#[inline]
+const fn is_odd_integer(y: f64) -> bool {
+    if y.abs() >= f64::from_bits(0x4340000000000000) {
+        return false;
+    }
+    y == (y as i64) as f64 && (y as i64) & 1 != 0
+}
+
+/// Returns sqrt(x) for positive x when it is exactly representable.
+const fn exact_sqrt(x: f64) -> Option<f64> {
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i64;
+    let (mut m, mut e) = if biased == 0 {
+        (bits & ((1u64 << 52) - 1), -1074)
+    } else {
+        ((bits & ((1u64 << 52) - 1)) | (1u64 << 52), biased - 1075)
+    };
+    if e & 1 != 0 {
+        m <<= 1;
+        e -= 1;
+    }
+    let s = m.isqrt();
+    if s * s != m {
+        return None;
+    }
+    Some(DyadicFloat128::new(DyadicSign::Pos, (e / 2) as i16, s as u128).const_as_f64())
+}
+
+/// Computes x^n by binary powering, exact while result fits into 128 bits.
+const fn dyadic_powi(x: DyadicFloat128, n: u64) -> DyadicFloat128 {
+    let mut r = ONE;
+    let mut b = x;
+    let mut n = n;
+    while n > 0 {
+        if n & 1 != 0 {
+            r = r.quick_mul(&b);
+        }
+        n >>= 1;
+        if n > 0 {
+            b = b.quick_mul(&b);
+        }
+    }
+    r
+}
+
+/// Computes x^y for finite positive x != 1, finite y != 0.
+const fn pow_positive(x: f64, y: f64) -> f64 {
+    // Exact results and midpoints are possible only when y = n / 2^k
+    // and x is a perfect 2^k-th power, so reduce such cases to integer powers
+    let mut xx = x;
+    let mut yy = y;
+    while !is_integer(yy) {
+        match exact_sqrt(xx) {
+            Some(s) => {
+                xx = s;
+                yy *= 2.;
+            }
+            None => break,
+        }
+    }
+    let dx = DyadicFloat128::new_from_f64(xx);
+    // Powers of 2 stay exact for any exponent
+    let is_pow2 = dx.mantissa == 1u128 << 127;
+    if is_integer(yy) && (yy.abs() <= 64. || is_pow2) {
+        // Keep exponent of result in range of DyadicFloat128
+        let ex = (dx.exponent as i64 + 127).abs() + 1;
+        if ex as f64 * yy.abs() < 4000. {
+            let p = dyadic_powi(dx, yy.abs() as u64);
+            return if yy < 0. {
+                dyadic_recip(p).const_as_f64()
+            } else {
+                p.const_as_f64()
+            };
+        }
+    }
+    let z = dyadic_ln(x).quick_mul(&DyadicFloat128::new_from_f64(y));
+    let zf = z.const_as_f64();
+    if zf > 710. {
+        return f64::INFINITY;
+    }
+    if zf < -746. {
+        return 0.;
+    }
+    dyadic_exp(z).const_as_f64()
+}
+
+/// Computes x^y in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_pow].
+pub const fn f_pow(x: f64, y: f64) -> f64 {
+    // NaN is checked first, as runtime f_pow doesn't treat pow(NaN, 0) or pow(1, NaN) specially
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if y == 0. || x == 1. {
+        return 1.;
+    }
+    let ax = x.abs();
+    if y.is_infinite() {
+        if ax == 1. {
+            return 1.;
+        }
+        return if (ax < 1.) == (y < 0.) {
+            f64::INFINITY
+        } else {
+            0.
+        };
+    }
+    let y_odd = is_odd_integer(y);
+    if x == 0. {
+        let r = if y < 0. { f64::INFINITY } else { 0. };
+        return if y_odd { r.copysign(x) } else { r };
+    }
+    if x.is_infinite() {
+        let r = if y < 0. { 0. } else { f64::INFINITY };
+        return if y_odd && x < 0. { -r } else { r };
+    }
+    if x < 0. && !is_integer(y) {
+        return f64::NAN;
+    }
+    let r = if ax == 1. { 1. } else { pow_positive(ax, y) };
+    if x < 0. && y_odd { -r } else { r }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow() {
+        const TABLE: [f64; 4] = [
+            f_pow(2., 0.5),
+            f_pow(-3., 3.),
+            f_pow(10., -300.),
+            f_pow(1.0000001, 1e9),
+        ];
+        assert_eq!(
+            TABLE,
+            [1.4142135623730951, -27., 1e-300, 2.6881038582144647e43]
+        );
+        // Exact midpoints round to even
+        assert_eq!(f_pow(134217727., 2.), 18014398241046528.);
+        assert_eq!(f_pow(68718952449., 1.5), 18014192351838208.);
+        assert_eq!(f_pow(0.5, 1075.), 0.);
+        assert_eq!(f_pow(-0., -3.), f64::NEG_INFINITY);
+        assert!(f_pow(f64::NAN, 0.).is_nan());
+        assert!(f_pow(1., f64::NAN).is_nan());
+        assert!(f_pow(-2., 0.5).is_nan());
+    }
+}
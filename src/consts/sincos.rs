@@ -0,0 +1,256 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::consts::common::{ONE, PI_OVER_2, dyadic_recip};
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+
+// Bits of 2/PI, enough for reduction of any finite double with 256-bit window
+const TWO_OVER_PI: [u64; 22] = [
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+    0xf0cfbc209af4361d,
+    0xa9e391615ee61b08,
+    0x6599855f14a06840,
+];
+
+/// Returns 64 bits of 2/PI starting from bit `p`, bit `p` has weight 2^-p.
+#[inline]
+const fn two_over_pi_bits(p: i64) -> u64 {
+    if p <= -63 {
+        return 0;
+    }
+    if p < 1 {
+        return two_over_pi_bits(1) >> (1 - p);
+    }
+    let idx = ((p - 1) / 64) as usize;
+    let off = ((p - 1) % 64) as u32;
+    if off == 0 {
+        return TWO_OVER_PI[idx];
+    }
+    (TWO_OVER_PI[idx] << off) | (TWO_OVER_PI[idx + 1] >> (64 - off))
+}
+
+/// Reduces |x| to r in [-PI/4, PI/4], returns (quadrant, r).
+const fn reduce(x: f64) -> (u64, DyadicFloat128) {
+    let ax = x.abs();
+    if ax < f64::from_bits(0x3fe921fb54442d18) {
+        return (0, DyadicFloat128::new_from_f64(ax));
+    }
+    // ax = m * 2^e, ax is normal here
+    let bits = ax.to_bits();
+    let m = (bits & ((1u64 << 52) - 1)) | (1u64 << 52);
+    let e = ((bits >> 52) & 0x7ff) as i64 - 1075;
+    // Bits of 2/PI with weight >= 4 after multiplication by ax give multiples of 4, skip them.
+    // Window w holds 256 bits starting from weight 2^1, so ax * 2/PI mod 4 = (m * w mod 2^256) * 2^-254
+    let j0 = e - 1;
+    let w0 = two_over_pi_bits(j0);
+    let w1 = two_over_pi_bits(j0 + 64);
+    let w2 = two_over_pi_bits(j0 + 128);
+    let w3 = two_over_pi_bits(j0 + 192);
+    let mut t = m as u128 * w3 as u128;
+    let r3 = t as u64;
+    t = m as u128 * w2 as u128 + (t >> 64);
+    let r2 = t as u64;
+    t = m as u128 * w1 as u128 + (t >> 64);
+    let r1 = t as u64;
+    t = m as u128 * w0 as u128 + (t >> 64);
+    let r0 = t as u64;
+    let mut q = r0 >> 62;
+    // 254-bit fraction as hi:lo
+    let mut hi = (((r0 & ((1u64 << 62) - 1)) as u128) << 64) | r1 as u128;
+    let mut lo = ((r2 as u128) << 64) | r3 as u128;
+    let mut sign = DyadicSign::Pos;
+    if hi >> 125 != 0 {
+        // Fraction >= 1/2, take f - 1
+        q += 1;
+        sign = DyadicSign::Neg;
+        let (l, borrow) = 0u128.overflowing_sub(lo);
+        lo = l;
+        hi = (1u128 << 126).wrapping_sub(hi).wrapping_sub(borrow as u128);
+    }
+    let lz = if hi != 0 {
+        hi.leading_zeros()
+    } else {
+        128 + lo.leading_zeros()
+    };
+    let mantissa = if lz == 0 {
+        hi
+    } else if lz < 128 {
+        (hi << lz) | (lo >> (128 - lz))
+    } else {
+        lo << (lz - 128)
+    };
+    let f = DyadicFloat128 {
+        sign,
+        exponent: -126 - lz as i16,
+        mantissa,
+    };
+    (q & 3, f.quick_mul(&PI_OVER_2))
+}
+
+/// Computes sin(r), cos(r) for |r| <= PI/4.
+const fn sin_cos_eval(r: DyadicFloat128) -> (DyadicFloat128, DyadicFloat128) {
+    let r2 = r.quick_mul(&r);
+    // Taylor series in Horner form, (PI/4)^34 / 34! < 2^-135
+    let mut s = ONE;
+    let mut c = ONE;
+    let mut k = 17u64;
+    while k > 0 {
+        s = ONE.quick_sub(&r2.quick_mul(&s).div_int(2 * k * (2 * k + 1)));
+        c = ONE.quick_sub(&r2.quick_mul(&c).div_int((2 * k - 1) * (2 * k)));
+        k -= 1;
+    }
+    (r.quick_mul(&s), c)
+}
+
+/// Computes sine in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_sin].
+pub const fn f_sin(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    if x == 0. {
+        return x;
+    }
+    let (q, r) = reduce(x);
+    let (s, c) = sin_cos_eval(r);
+    let mut v = if q & 1 == 0 { s } else { c };
+    if (q >= 2) != x.is_sign_negative() {
+        v = v.negated();
+    }
+    v.const_as_f64()
+}
+
+/// Computes cosine in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_cos].
+pub const fn f_cos(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, r) = reduce(x);
+    let (s, c) = sin_cos_eval(r);
+    let mut v = if q & 1 == 0 { c } else { s };
+    if q == 1 || q == 2 {
+        v = v.negated();
+    }
+    v.const_as_f64()
+}
+
+/// Computes tangent in const context
+///
+/// Evaluated with integer arithmetic only, result is correctly rounded as [crate::f_tan].
+pub const fn f_tan(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    if x == 0. {
+        return x;
+    }
+    let (q, r) = reduce(x);
+    let (s, c) = sin_cos_eval(r);
+    let mut v = if q & 1 == 0 {
+        s.quick_mul(&dyadic_recip(c))
+    } else {
+        c.quick_mul(&dyadic_recip(s)).negated()
+    };
+    if x.is_sign_negative() {
+        v = v.negated();
+    }
+    v.const_as_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sin() {
+        const TABLE: [f64; 4] = [f_sin(1.), f_sin(1e22), f_sin(-3.), f_sin(1e-300)];
+        assert_eq!(
+            TABLE,
+            [
+                0.8414709848078965,
+                -0.8522008497671888,
+                -0.1411200080598672,
+                1e-300
+            ]
+        );
+        assert_eq!(f_sin(-0.), -0.);
+        assert!(f_sin(f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_cos() {
+        const TABLE: [f64; 3] = [f_cos(1.), f_cos(1e22), f_cos(1.5707963267948966)];
+        assert_eq!(
+            TABLE,
+            [0.5403023058681398, 0.523214785395139, 6.123233995736766e-17]
+        );
+        assert_eq!(f_cos(0.), 1.);
+        assert!(f_cos(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_tan() {
+        const TABLE: [f64; 4] = [
+            f_tan(1.),
+            f_tan(-1.5),
+            f_tan(1e300),
+            f_tan(-1.561899729742569),
+        ];
+        assert_eq!(
+            TABLE,
+            [
+                1.5574077246549023,
+                -14.101419947171719,
+                1.4214488238747245,
+                -112.39956253304595
+            ]
+        );
+        assert!(f_tan(f64::NEG_INFINITY).is_nan());
+    }
+}
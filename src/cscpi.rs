@@ -0,0 +1,205 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::sincospi::{reduce_pi_64, sincospi_eval, sincospi_eval_dd, sinpi_zero_dd};
+use crate::sincospi_tables::SINPI_K_PI_OVER_64;
+
+#[cold]
+fn cscpi_dd(y: f64, sin_k: DoubleDouble, cos_k: DoubleDouble) -> f64 {
+    let r_sincos = sincospi_eval_dd(y);
+    let cos_k_sin_y = DoubleDouble::quick_mult(cos_k, r_sincos.v_sin);
+    let rr = DoubleDouble::mul_add(sin_k, r_sincos.v_cos, cos_k_sin_y);
+    rr.recip().to_f64()
+}
+
+/// Computes 1/sin(PI*x)
+///
+/// Max found ULP 0.5
+pub fn f_cscpi(x: f64) -> f64 {
+    let ix = x.to_bits();
+    let ax = ix & 0x7fff_ffff_ffff_ffff;
+    if ax == 0 {
+        return 1. / x;
+    }
+    let e: i32 = (ax >> 52) as i32;
+    let m0 = (ix & 0x000fffffffffffff) | (1u64 << 52);
+    let sgn: i64 = (ix as i64) >> 63;
+    let m = ((m0 as i64) ^ sgn).wrapping_sub(sgn);
+    let mut s: i32 = 1063i32.wrapping_sub(e);
+    if s < 0 {
+        if e == 0x7ff {
+            if (ix << 12) == 0 {
+                return f64::NAN;
+            }
+            return x + x; // case x=NaN
+        }
+        s = -s - 1;
+        if s > 10 {
+            return f64::copysign(f64::INFINITY, x);
+        }
+        let iq: u64 = (m as u64).wrapping_shl(s as u32);
+        if (iq & 2047) == 0 {
+            return f64::copysign(f64::INFINITY, x);
+        }
+    }
+
+    if ax <= 0x3fa2000000000000u64 {
+        // |x| <= 0.03515625
+
+        if ax <= 0x3cb0000000000000 {
+            // for tiny x ( |x| < f64::EPSILON ) just small taylor expansion
+            // csc(PI*x) ~ 1/(PI*x) + O(x)
+            const ONE_OVER_PI: DoubleDouble =
+                DoubleDouble::from_bit_pair((0xbc76b01ec5417056, 0x3fd45f306dc9c883));
+            if ax <= 0x3ca0000000000000 {
+                // |x| <= 2^-53, renormalize value
+                let e: i32 = (ax >> 52) as i32;
+                let sc = f64::from_bits((2045i64 - e as i64).wrapping_shl(52) as u64);
+                let dx = x * sc;
+                let q0 = DoubleDouble::quick_mult(ONE_OVER_PI, DoubleDouble::from_quick_recip(dx));
+                let r = q0.to_f64() * sc;
+                return r;
+            }
+            let q0 = DoubleDouble::quick_mult(ONE_OVER_PI, DoubleDouble::from_quick_recip(x));
+            let r = q0.to_f64();
+            return r;
+        }
+
+        /*
+           Poly generated by Sollya:
+           d = [0, 0.03515625];
+           f_sin = sin(y*pi)/y;
+           Q = fpminimax(f_sin, [|0, 2, 4, 6, 8, 10|], [|107, D...|], d, relative, floating);
+
+           See ./notes/sinpi_zero.sollya
+        */
+
+        let x2 = x * x;
+        let x3 = x2 * x;
+        let x4 = x2 * x2;
+
+        let eps = x * f_fmla(
+            x2,
+            f64::from_bits(0x3d00000000000000), // 2^-47
+            f64::from_bits(0x3bd0000000000000), // 2^-66
+        );
+
+        const C: [u64; 4] = [
+            0xc014abbce625be51,
+            0x400466bc67754b46,
+            0xbfe32d2cc12a51f4,
+            0x3fb5060540058476,
+        ];
+
+        const C_PI: DoubleDouble =
+            DoubleDouble::from_bit_pair((0x3ca1a67088eb1a46, 0x400921fb54442d18));
+
+        let mut z = DoubleDouble::quick_mult_f64(C_PI, x);
+
+        let zl0 = f_fmla(x2, f64::from_bits(C[1]), f64::from_bits(C[0]));
+        let zl1 = f_fmla(x2, f64::from_bits(C[3]), f64::from_bits(C[2]));
+
+        z.lo = f_fmla(x3, f_fmla(x4, zl1, zl0), z.lo);
+        z = DoubleDouble::from_exact_add(z.hi, z.lo);
+        let r = z.recip();
+
+        // d(1/s) = -ds/s^2
+        let err = f64::abs(eps * r.hi * r.hi);
+        let lb = r.hi + (r.lo - err);
+        let ub = r.hi + (r.lo + err);
+        if lb == ub {
+            return r.to_f64();
+        }
+        return sinpi_zero_dd(x).recip().to_f64();
+    }
+
+    let si = e.wrapping_sub(1011);
+    if si >= 0 && (m0.wrapping_shl(si.wrapping_add(1) as u32)) == 0 {
+        // x is integer or half-integer
+        if (m0.wrapping_shl(si as u32)) == 0 {
+            return f64::copysign(f64::INFINITY, x); // x is integer
+        }
+        let t = (m0.wrapping_shl((si - 1) as u32)) >> 63;
+        // t = 0 if |x| = 1/2 mod 2, t = 1 if |x| = 3/2 mod 2
+        return if t == 0 {
+            f64::copysign(1.0, x)
+        } else {
+            -f64::copysign(1.0, x)
+        };
+    }
+
+    let (y, k) = reduce_pi_64(x);
+
+    // cos(k * pi/64) = sin(k * pi/64 + pi/2) = sin((k + 32) * pi/64).
+    let sin_k = DoubleDouble::from_bit_pair(SINPI_K_PI_OVER_64[((k as u64) & 127) as usize]);
+    let cos_k = DoubleDouble::from_bit_pair(
+        SINPI_K_PI_OVER_64[((k as u64).wrapping_add(32) & 127) as usize],
+    );
+
+    let r_sincos = sincospi_eval(y);
+
+    let sin_k_cos_y = DoubleDouble::quick_mult(sin_k, r_sincos.v_cos);
+    let cos_k_sin_y = DoubleDouble::quick_mult(cos_k, r_sincos.v_sin);
+
+    // sin_k_cos_y is always >> cos_k_sin_y
+    let mut rr = DoubleDouble::from_exact_add(sin_k_cos_y.hi, cos_k_sin_y.hi);
+    rr.lo += sin_k_cos_y.lo + cos_k_sin_y.lo;
+
+    rr = DoubleDouble::from_exact_add(rr.hi, rr.lo);
+    rr = rr.recip();
+
+    // d(1/s) = -ds/s^2
+    let err = r_sincos.err * rr.hi * rr.hi;
+
+    let ub = rr.hi + (rr.lo + err);
+    let lb = rr.hi + (rr.lo - err);
+
+    if ub == lb {
+        return rr.to_f64();
+    }
+    cscpi_dd(y, sin_k, cos_k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cscpi() {
+        assert_eq!(f_cscpi(0.), f64::INFINITY);
+        assert_eq!(f_cscpi(-0.), f64::NEG_INFINITY);
+        assert_eq!(f_cscpi(0.5), 1.);
+        assert_eq!(f_cscpi(-0.5), -1.);
+        assert_eq!(f_cscpi(1.5), -1.);
+        assert_eq!(f_cscpi(0.25), std::f64::consts::SQRT_2);
+        assert!(f_cscpi(f64::INFINITY).is_nan());
+        assert!(f_cscpi(f64::NAN).is_nan());
+    }
+}
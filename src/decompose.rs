@@ -0,0 +1,341 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bits::{EXP_MASK, biased_exponent_f64};
+
+/// Splits x into normalized fraction and exponent
+///
+/// Returns `(m, e)` where `x = m * 2^e` and `0.5 <= |m| < 1`.
+/// For zero, infinities and NaN returns `(x, 0)`.
+#[inline]
+pub const fn f_frexp(x: f64) -> (f64, i32) {
+    let mut x = x;
+    let mut e = 0i32;
+    let mut ee = biased_exponent_f64(x);
+    if ee == 0 {
+        if x == 0. {
+            return (x, 0);
+        }
+        // subnormal
+        x *= f64::from_bits(0x43f0000000000000); // 2^64
+        e = -64;
+        ee = biased_exponent_f64(x);
+    } else if ee == 0x7ff {
+        return (x, 0);
+    }
+    e += ee as i32 - 0x3fe;
+    let bits = (x.to_bits() & !EXP_MASK) | 0x3fe0000000000000;
+    (f64::from_bits(bits), e)
+}
+
+/// Splits x into normalized fraction and exponent
+///
+/// Returns `(m, e)` where `x = m * 2^e` and `0.5 <= |m| < 1`.
+/// For zero, infinities and NaN returns `(x, 0)`.
+#[inline]
+pub const fn f_frexpf(x: f32) -> (f32, i32) {
+    let mut x = x;
+    let mut e = 0i32;
+    let mut ee = ((x.to_bits() >> 23) & 0xff) as i32;
+    if ee == 0 {
+        if x == 0. {
+            return (x, 0);
+        }
+        // subnormal
+        x *= f32::from_bits(0x5f800000); // 2^64
+        e = -64;
+        ee = ((x.to_bits() >> 23) & 0xff) as i32;
+    } else if ee == 0xff {
+        return (x, 0);
+    }
+    e += ee - 0x7e;
+    let bits = (x.to_bits() & 0x807f_ffff) | 0x3f00_0000;
+    (f32::from_bits(bits), e)
+}
+
+/// Computes x * 2^n
+///
+/// Result is correctly rounded, including subnormal range.
+#[inline]
+pub const fn f_scalbn(x: f64, n: i32) -> f64 {
+    const P1023: f64 = f64::from_bits(0x7fe0000000000000);
+    // 2^-1022 * 2^53
+    const M969: f64 = f64::from_bits(0x0360000000000000);
+    let mut y = x;
+    let mut n = n;
+    if n > 1023 {
+        y *= P1023;
+        n -= 1023;
+        if n > 1023 {
+            y *= P1023;
+            n -= 1023;
+            if n > 1023 {
+                n = 1023;
+            }
+        }
+    } else if n < -1022 {
+        // make sure final n < -53 to avoid double rounding in the subnormal range
+        y *= M969;
+        n += 1022 - 53;
+        if n < -1022 {
+            y *= M969;
+            n += 1022 - 53;
+            if n < -1022 {
+                n = -1022;
+            }
+        }
+    }
+    y * f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+/// Computes x * 2^n
+///
+/// Result is correctly rounded, including subnormal range.
+#[inline]
+pub const fn f_scalbnf(x: f32, n: i32) -> f32 {
+    // Every f32 times 2^n with n in [-300, 300] is exact in f64,
+    // so there is a single rounding to f32.
+    let n = if n > 300 {
+        300
+    } else if n < -300 {
+        -300
+    } else {
+        n
+    };
+    (x as f64 * f64::from_bits(((0x3ff + n) as u64) << 52)) as f32
+}
+
+/// Computes x * 2^n
+///
+/// Same as [f_scalbn].
+#[inline]
+pub const fn f_ldexp(x: f64, n: i32) -> f64 {
+    f_scalbn(x, n)
+}
+
+/// Computes x * 2^n
+///
+/// Same as [f_scalbnf].
+#[inline]
+pub const fn f_ldexpf(x: f32, n: i32) -> f32 {
+    f_scalbnf(x, n)
+}
+
+/// Returns unbiased exponent of x
+///
+/// Subnormals are treated as normalized, zero and NaN return `i32::MIN`,
+/// infinities return `i32::MAX`.
+#[inline]
+pub const fn f_ilogb(x: f64) -> i32 {
+    let bits = x.to_bits() << 1;
+    let e = (bits >> 53) as i32;
+    if e == 0 {
+        if bits == 0 {
+            return i32::MIN;
+        }
+        // subnormal, leading bit of mantissa defines the exponent
+        return -1012 - bits.leading_zeros() as i32;
+    }
+    if e == 0x7ff {
+        return if bits << 11 != 0 { i32::MIN } else { i32::MAX };
+    }
+    e - 0x3ff
+}
+
+/// Returns unbiased exponent of x
+///
+/// Subnormals are treated as normalized, zero and NaN return `i32::MIN`,
+/// infinities return `i32::MAX`.
+#[inline]
+pub const fn f_ilogbf(x: f32) -> i32 {
+    let bits = x.to_bits() << 1;
+    let e = (bits >> 24) as i32;
+    if e == 0 {
+        if bits == 0 {
+            return i32::MIN;
+        }
+        // subnormal, leading bit of mantissa defines the exponent
+        return -119 - bits.leading_zeros() as i32;
+    }
+    if e == 0xff {
+        return if bits << 8 != 0 { i32::MIN } else { i32::MAX };
+    }
+    e - 0x7f
+}
+
+/// Returns unbiased exponent of x as floating point value
+///
+/// logb(0) = -inf, logb(±inf) = +inf.
+#[inline]
+pub const fn f_logb(x: f64) -> f64 {
+    if !x.is_finite() {
+        return x * x;
+    }
+    if x == 0. {
+        return f64::NEG_INFINITY;
+    }
+    f_ilogb(x) as f64
+}
+
+/// Returns unbiased exponent of x as floating point value
+///
+/// logb(0) = -inf, logb(±inf) = +inf.
+#[inline]
+pub const fn f_logbf(x: f32) -> f32 {
+    if !x.is_finite() {
+        return x * x;
+    }
+    if x == 0. {
+        return f32::NEG_INFINITY;
+    }
+    f_ilogbf(x) as f32
+}
+
+/// Splits x into fractional and integral parts
+///
+/// Returns `(frac, int)`, both parts have the sign of x.
+#[inline]
+pub const fn f_modf(x: f64) -> (f64, f64) {
+    let bits = x.to_bits();
+    let sign = f64::from_bits(bits & 0x8000_0000_0000_0000);
+    let e = ((bits >> 52) & 0x7ff) as i32 - 0x3ff;
+    // no fractional part
+    if e >= 52 {
+        if x.is_nan() {
+            return (x, x);
+        }
+        return (sign, x);
+    }
+    // no integral part
+    if e < 0 {
+        return (x, sign);
+    }
+    let mask = u64::MAX >> 12 >> e;
+    if bits & mask == 0 {
+        return (sign, x);
+    }
+    let int = f64::from_bits(bits & !mask);
+    (x - int, int)
+}
+
+/// Splits x into fractional and integral parts
+///
+/// Returns `(frac, int)`, both parts have the sign of x.
+#[inline]
+pub const fn f_modff(x: f32) -> (f32, f32) {
+    let bits = x.to_bits();
+    let sign = f32::from_bits(bits & 0x8000_0000);
+    let e = ((bits >> 23) & 0xff) as i32 - 0x7f;
+    // no fractional part
+    if e >= 23 {
+        if x.is_nan() {
+            return (x, x);
+        }
+        return (sign, x);
+    }
+    // no integral part
+    if e < 0 {
+        return (x, sign);
+    }
+    let mask = 0x007f_ffffu32 >> e;
+    if bits & mask == 0 {
+        return (sign, x);
+    }
+    let int = f32::from_bits(bits & !mask);
+    (x - int, int)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frexp() {
+        assert_eq!(f_frexp(8.), (0.5, 4));
+        assert_eq!(f_frexp(-3.), (-0.75, 2));
+        assert_eq!(f_frexp(f64::from_bits(1)), (0.5, -1073));
+        assert_eq!(f_frexp(0.), (0., 0));
+        assert_eq!(f_frexp(f64::INFINITY), (f64::INFINITY, 0));
+        assert_eq!(f_frexpf(0.1), (0.8, -3));
+        assert_eq!(f_frexpf(f32::from_bits(3)), (0.75, -147));
+    }
+
+    #[test]
+    fn test_scalbn() {
+        assert_eq!(f_scalbn(1., 10), 1024.);
+        assert_eq!(f_scalbn(1., -1074), f64::from_bits(1));
+        assert_eq!(f_scalbn(1.5, -1074), f64::from_bits(2));
+        assert_eq!(
+            f_scalbn(f64::from_bits(0x3ff8000000000001), -1074),
+            f64::from_bits(2)
+        );
+        assert_eq!(
+            f_scalbn(f64::from_bits(1), 2097),
+            f64::from_bits(0x7fe0000000000000)
+        );
+        assert_eq!(f_scalbn(1., 1024), f64::INFINITY);
+        assert_eq!(f_scalbn(1., -1075), 0.);
+        assert_eq!(f_scalbn(f64::MAX, -2098), f64::from_bits(1));
+        assert_eq!(f_ldexp(-3., 2), -12.);
+        assert_eq!(f_scalbnf(1.5, -149), f32::from_bits(2));
+        assert_eq!(
+            f_scalbnf(f32::from_bits(1), 276),
+            f32::from_bits(0x7f000000)
+        );
+        assert_eq!(f_ldexpf(3., i32::MAX), f32::INFINITY);
+        assert_eq!(f_ldexpf(3., i32::MIN), 0.);
+    }
+
+    #[test]
+    fn test_ilogb() {
+        assert_eq!(f_ilogb(1.), 0);
+        assert_eq!(f_ilogb(-1000.), 9);
+        assert_eq!(f_ilogb(f64::from_bits(1)), -1074);
+        assert_eq!(f_ilogb(0.), i32::MIN);
+        assert_eq!(f_ilogb(f64::NEG_INFINITY), i32::MAX);
+        assert_eq!(f_ilogb(f64::NAN), i32::MIN);
+        assert_eq!(f_ilogbf(f32::from_bits(1)), -149);
+        assert_eq!(f_ilogbf(0.3), -2);
+        assert_eq!(f_logb(0.), f64::NEG_INFINITY);
+        assert_eq!(f_logb(f64::NEG_INFINITY), f64::INFINITY);
+        assert_eq!(f_logb(1e-310), -1030.);
+        assert_eq!(f_logbf(100.), 6.);
+    }
+
+    #[test]
+    fn test_modf() {
+        assert_eq!(f_modf(3.25), (0.25, 3.));
+        assert_eq!(f_modf(-3.25), (-0.25, -3.));
+        assert_eq!(f_modf(0.5), (0.5, 0.));
+        assert_eq!(f_modf(-4.).0.to_bits(), (-0f64).to_bits());
+        assert_eq!(f_modf(f64::INFINITY), (0., f64::INFINITY));
+        assert!(f_modf(f64::NAN).0.is_nan());
+        assert_eq!(f_modff(-2.5), (-0.5, -2.));
+        assert_eq!(f_modff(1e20), (0., 1e20));
+    }
+}
@@ -39,7 +39,7 @@ pub(crate) enum DyadicSign {
 
 impl DyadicSign {
     #[inline]
-    pub(crate) fn negate(self) -> Self {
+    pub(crate) const fn negate(self) -> Self {
         match self {
             DyadicSign::Pos => DyadicSign::Neg,
             DyadicSign::Neg => DyadicSign::Pos,
@@ -81,7 +81,7 @@ pub(crate) const fn f64_from_parts(sign: DyadicSign, exp: u64, mantissa: u64) ->
 }
 
 #[inline]
-pub(crate) fn mulhi_u128(a: u128, b: u128) -> u128 {
+pub(crate) const fn mulhi_u128(a: u128, b: u128) -> u128 {
     let a_lo = a as u64 as u128;
     let a_hi = (a >> 64) as u64 as u128;
     let b_lo = b as u64 as u128;
@@ -153,7 +153,7 @@ impl DyadicFloat128 {
     }
 
     #[inline]
-    pub(crate) fn new(sign: DyadicSign, exponent: i16, mantissa: u128) -> Self {
+    pub(crate) const fn new(sign: DyadicSign, exponent: i16, mantissa: u128) -> Self {
         let mut new_item = DyadicFloat128 {
             sign,
             exponent,
@@ -229,7 +229,7 @@ impl DyadicFloat128 {
     }
 
     #[inline]
-    fn shift_right(&mut self, amount: u32) {
+    const fn shift_right(&mut self, amount: u32) {
         if amount < BITS {
             self.exponent += amount as i16;
             self.mantissa = self.mantissa.wrapping_shr(amount);
@@ -240,7 +240,7 @@ impl DyadicFloat128 {
     }
 
     #[inline]
-    fn shift_left(&mut self, amount: u32) {
+    const fn shift_left(&mut self, amount: u32) {
         if amount < BITS {
             self.exponent -= amount as i16;
             self.mantissa = self.mantissa.wrapping_shl(amount);
@@ -261,7 +261,7 @@ impl DyadicFloat128 {
     }
 
     #[inline]
-    pub(crate) fn negated(&self) -> Self {
+    pub(crate) const fn negated(&self) -> Self {
         Self {
             sign: self.sign.negate(),
             exponent: self.exponent,
@@ -270,12 +270,12 @@ impl DyadicFloat128 {
     }
 
     #[inline]
-    pub(crate) fn quick_sub(&self, rhs: &Self) -> Self {
+    pub(crate) const fn quick_sub(&self, rhs: &Self) -> Self {
         self.quick_add(&rhs.negated())
     }
 
     #[inline]
-    pub(crate) fn quick_add(&self, rhs: &Self) -> Self {
+    pub(crate) const fn quick_add(&self, rhs: &Self) -> Self {
         if self.mantissa == 0 {
             return *rhs;
         }
@@ -289,7 +289,7 @@ impl DyadicFloat128 {
 
         // If exponent difference is too large, b is negligible
         if exp_diff.abs() >= BITS as i16 {
-            return if a.sign == b.sign {
+            return if a.sign.to_bit() == b.sign.to_bit() {
                 // Adding very small number to large: return a
                 return if a.exponent > b.exponent { a } else { b };
             } else if a.exponent > b.exponent {
@@ -308,7 +308,7 @@ impl DyadicFloat128 {
 
         let mut result = DyadicFloat128::zero();
 
-        if a.sign == b.sign {
+        if a.sign.to_bit() == b.sign.to_bit() {
             // Addition
             result.sign = a.sign;
             result.exponent = a.exponent;
@@ -340,13 +340,9 @@ impl DyadicFloat128 {
     }
 
     #[inline]
-    pub(crate) fn quick_mul(&self, rhs: &Self) -> Self {
+    pub(crate) const fn quick_mul(&self, rhs: &Self) -> Self {
         let mut result = DyadicFloat128 {
-            sign: if self.sign != rhs.sign {
-                DyadicSign::Neg
-            } else {
-                DyadicSign::Pos
-            },
+            sign: self.sign.mult(rhs.sign),
             exponent: self.exponent + rhs.exponent + BITS as i16,
             mantissa: 0,
         };
@@ -504,6 +500,19 @@ impl DyadicFloat128 {
         x
     }
 
+    // Approximate square root - given a positive `a`, make a good approximation to sqrt(a).
+    // The method is Newton's iteration v' = (v + a/v) / 2 starting from f64 guess.
+    #[inline]
+    pub(crate) fn sqrt(self) -> DyadicFloat128 {
+        let guess = self.fast_as_f64().sqrt();
+        let mut v = DyadicFloat128::new_from_f64(guess);
+        v = v + self * v.reciprocal();
+        v.exponent -= 1;
+        v = v + self * v.reciprocal();
+        v.exponent -= 1;
+        v
+    }
+
     // // Approximate reciprocal - given a nonzero `a`, make a good approximation to 1/a.
     // // The method is Newton-Raphson iteration, based on quick_mul.
     // // *This is very crude guess*
@@ -635,6 +644,68 @@ impl DyadicFloat128 {
     pub(crate) fn round_to_nearest_f64(&self) -> f64 {
         self.round_to_nearest().fast_as_f64()
     }
+
+    /// Divides self by integer scalar `b`.
+    /// Returns a new normalized DyadicFloat128.
+    #[inline]
+    pub(crate) const fn div_int(&self, b: u64) -> DyadicFloat128 {
+        let mut a = *self;
+        a.normalize();
+        if a.mantissa == 0 {
+            return a;
+        }
+        // 192-bit quotient q_hi * 2^64 + q_lo, q_hi >= 2^63 since mantissa is normalized
+        let q_hi = a.mantissa / b as u128;
+        let rem = a.mantissa % b as u128;
+        let q_lo = (rem << 64) / b as u128;
+        let shift = q_hi.leading_zeros();
+        DyadicFloat128 {
+            sign: a.sign,
+            exponent: a.exponent - shift as i16,
+            mantissa: (q_hi << shift) | (q_lo >> (64 - shift)),
+        }
+    }
+
+    /// Rounds to nearest f64, ties to even, using integer arithmetic only,
+    /// so it can be evaluated in const context. Handles overflow and subnormal results.
+    #[inline]
+    pub(crate) const fn const_as_f64(&self) -> f64 {
+        let mut a = *self;
+        a.normalize();
+        let sign = (a.sign.to_bit() as u64) << 63;
+        if a.mantissa == 0 {
+            return f64::from_bits(sign);
+        }
+        // value is in [2^e, 2^(e + 1))
+        let e = a.exponent as i32 + (BITS - 1) as i32;
+        if e > 1023 {
+            return f64::from_bits(sign | EXP_MASK);
+        }
+        let shift = if e >= -1022 {
+            BITS - 53
+        } else {
+            (BITS as i32 - 53 - 1022 - e) as u32
+        };
+        let (q, round_up) = if shift > BITS {
+            (0u128, false)
+        } else if shift == BITS {
+            // only rounding bit is left, ties go to zero
+            (0u128, a.mantissa > 1u128 << (BITS - 1))
+        } else {
+            let q = a.mantissa >> shift;
+            let rem = a.mantissa & ((1u128 << shift) - 1);
+            let half = 1u128 << (shift - 1);
+            (q, rem > half || (rem == half && (q & 1) == 1))
+        };
+        let q = (q + round_up as u128) as u64;
+        // Implicit bit of q carries into exponent, rounding up to 2^53 gives next binade
+        let bits = if e >= -1022 {
+            (((e + 1022) as u64) << 52) + q
+        } else {
+            q
+        };
+        f64::from_bits(sign | bits)
+    }
 }
 
 impl Sub<DyadicFloat128> for DyadicFloat128 {
@@ -0,0 +1,464 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::ldexp;
+
+/// Duplication stops once all arguments are within 2^-18 relative of their mean,
+/// so that truncation error of the fifth degree series is below 2^-108.
+///
+/// Comparison is strict, so degenerate case when arguments underflowed to zero
+/// terminates once the mean underflows as well.
+const DUPLICATION_TOLERANCE: f64 = f64::from_bits(0x3ed0000000000000);
+
+#[inline]
+pub(crate) fn quarter(x: DoubleDouble) -> DoubleDouble {
+    DoubleDouble::new(x.lo * 0.25, x.hi * 0.25)
+}
+
+#[inline]
+fn max_deviation(a: f64, args: &[f64]) -> f64 {
+    args.iter().fold(0., |acc, &v| f64::max(acc, (a - v).abs()))
+}
+
+/// Returns k such that arguments scaled by 4^-k are of order of 1.
+///
+/// Carlson integrals are homogeneous, R_F(4^k x, 4^k y, 4^k z) = 2^-k * R_F(x, y, z),
+/// and R_D, R_J scale as 2^-3k.
+#[inline]
+pub(crate) fn carlson_scale(args: &[f64]) -> i32 {
+    let m = args.iter().fold(0f64, |acc, &v| acc.max(v.abs()));
+    let e = ((m.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+    e.div_euclid(2)
+}
+
+/// Scales argument by 4^-k.
+#[inline]
+pub(crate) fn carlson_scaled(x: f64, k: i32) -> DoubleDouble {
+    DoubleDouble::new(0., ldexp(x, -2 * k))
+}
+
+/// Returns true if some non-zero argument loses precision after scaling by 4^-k.
+#[inline]
+pub(crate) fn carlson_needs_step(args: &[f64], k: i32) -> bool {
+    args.iter()
+        .any(|&v| v != 0. && ldexp(v.abs(), -2 * k) < f64::from_bits(0x07b0000000000000))
+}
+
+/// Computes sqrt(x * 4^-k) for x >= 0, when x * 4^-k itself may underflow.
+#[inline]
+pub(crate) fn carlson_sqrt_scaled(x: f64, k: i32) -> DoubleDouble {
+    let e = ((x.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+    let j = -e.div_euclid(2);
+    // sqrt(x * 4^-k) = sqrt(x * 4^j) * 2^(-k - j)
+    carlson_ldexp(DoubleDouble::new(0., ldexp(x, 2 * j)).sqrt(), -k - j)
+}
+
+/// Scales x by 2^e.
+#[inline]
+pub(crate) fn carlson_ldexp(x: DoubleDouble, e: i32) -> DoubleDouble {
+    DoubleDouble::new(ldexp(x.lo, e), ldexp(x.hi, e))
+}
+
+/// Returns (x * 2^-e, e) with x * 2^-e in [1, 2).
+#[inline]
+pub(crate) fn carlson_normalize(x: DoubleDouble) -> (DoubleDouble, i32) {
+    let e = ((x.hi.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+    (carlson_ldexp(x, -e), e)
+}
+
+/// Arguments after the first duplication step, performed on scaled arguments.
+pub(crate) struct CarlsonStep<const N: usize> {
+    pub(crate) args: [DoubleDouble; N],
+    pub(crate) sqrt: [DoubleDouble; N],
+    pub(crate) scaled: [DoubleDouble; N],
+    pub(crate) lambda: DoubleDouble,
+}
+
+/// Performs the first duplication step t -> (t + lambda) / 4 with
+/// lambda = sqrt(xy) + sqrt(xz) + sqrt(yz) for arguments scaled by 4^-k.
+///
+/// When arguments spread beyond double precision exponent range, the smallest ones
+/// underflow after scaling, while lambda is still representable, so after one step
+/// all arguments are of reasonable magnitude.
+pub(crate) fn carlson_step<const N: usize>(args: [f64; N], k: i32) -> CarlsonStep<N> {
+    let sqrt = args.map(|v| carlson_sqrt_scaled(v.abs(), k));
+    let scaled = args.map(|v| carlson_scaled(v, k));
+    let lambda = DoubleDouble::mul_add(
+        sqrt[0],
+        sqrt[1],
+        DoubleDouble::mul_add(sqrt[0], sqrt[2], DoubleDouble::quick_mult(sqrt[1], sqrt[2])),
+    );
+    let args = scaled.map(|v| quarter(DoubleDouble::full_dd_add(v, lambda)));
+    CarlsonStep {
+        args,
+        sqrt,
+        scaled,
+        lambda,
+    }
+}
+
+/// Performs the first duplication step of R_C(x, y) with lambda = 2 * sqrt(xy) + y
+/// for arguments scaled by 4^-k, see [carlson_step].
+pub(crate) fn carlson_step_rc(x: f64, y: f64, k: i32) -> (DoubleDouble, DoubleDouble) {
+    let sy = carlson_scaled(y, k);
+    let lambda = DoubleDouble::full_dd_add(
+        DoubleDouble::quick_mult_f64(
+            DoubleDouble::quick_mult(carlson_sqrt_scaled(x, k), carlson_sqrt_scaled(y, k)),
+            2.,
+        ),
+        sy,
+    );
+    (
+        quarter(DoubleDouble::full_dd_add(carlson_scaled(x, k), lambda)),
+        quarter(DoubleDouble::full_dd_add(sy, lambda)),
+    )
+}
+
+/// Computes R_F(x, y, z) by duplication theorem.
+///
+/// x, y, z >= 0, at most one of them is zero, and all are of order of 1.
+pub(crate) fn rf_dd(x: DoubleDouble, y: DoubleDouble, z: DoubleDouble) -> DoubleDouble {
+    let (mut x, mut y, mut z) = (x, y, z);
+    let mut a = DoubleDouble::div_dd_f64(
+        DoubleDouble::full_dd_add(DoubleDouble::full_dd_add(x, y), z),
+        3.,
+    );
+    let mut q = max_deviation(a.hi, &[x.hi, y.hi, z.hi]);
+    while q > DUPLICATION_TOLERANCE * a.hi {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = DoubleDouble::mul_add(
+            sx,
+            sy,
+            DoubleDouble::mul_add(sx, sz, DoubleDouble::quick_mult(sy, sz)),
+        );
+        x = quarter(DoubleDouble::full_dd_add(x, lambda));
+        y = quarter(DoubleDouble::full_dd_add(y, lambda));
+        z = quarter(DoubleDouble::full_dd_add(z, lambda));
+        a = quarter(DoubleDouble::full_dd_add(a, lambda));
+        q *= 0.25;
+    }
+    let ra = a.recip();
+    let dx = DoubleDouble::quick_mult(DoubleDouble::full_dd_sub(a, x), ra);
+    let dy = DoubleDouble::quick_mult(DoubleDouble::full_dd_sub(a, y), ra);
+    let dz = -DoubleDouble::full_dd_add(dx, dy);
+    // E2 = XY - Z^2, E3 = XYZ
+    let e2 = DoubleDouble::mul_add(dx, dy, -DoubleDouble::quick_mult(dz, dz));
+    let e3 = dx.hi * dy.hi * dz.hi;
+    // 1 - E2/10 + E3/14 + E2^2/24 - 3*E2*E3/44
+    let tail = e3 * (1. / 14.) + e2.hi * (e2.hi * (1. / 24.) - e3 * (3. / 44.));
+    let p = DoubleDouble::full_add_f64(
+        DoubleDouble::full_dd_sub(DoubleDouble::new(0., 1.), DoubleDouble::div_dd_f64(e2, 10.)),
+        tail,
+    );
+    DoubleDouble::div(p, a.sqrt())
+}
+
+/// Computes R_F(x, y, z) = r * 2^-k for finite non-negative arguments, at most one of them is zero.
+///
+/// Returns (r, k).
+pub(crate) fn rf_scaled(x: f64, y: f64, z: f64) -> (DoubleDouble, i32) {
+    let k = carlson_scale(&[x, y, z]);
+    if carlson_needs_step(&[x, y, z], k) {
+        // R_F is invariant under duplication
+        let [x1, y1, z1] = carlson_step([x, y, z], k).args;
+        return (rf_dd(x1, y1, z1), k);
+    }
+    (
+        rf_dd(
+            carlson_scaled(x, k),
+            carlson_scaled(y, k),
+            carlson_scaled(z, k),
+        ),
+        k,
+    )
+}
+
+/// Computes R_C(x, y) by duplication theorem.
+///
+/// x >= 0, y > 0, and both are of order of 1.
+pub(crate) fn rc_dd(x: DoubleDouble, y: DoubleDouble) -> DoubleDouble {
+    let (mut x, mut y) = (x, y);
+    let mut a = DoubleDouble::div_dd_f64(
+        DoubleDouble::full_dd_add(x, DoubleDouble::quick_mult_f64(y, 2.)),
+        3.,
+    );
+    let mut q = (a.hi - y.hi).abs();
+    while q > DUPLICATION_TOLERANCE * a.hi {
+        let lambda = DoubleDouble::full_dd_add(
+            DoubleDouble::quick_mult_f64(DoubleDouble::quick_mult(x.sqrt(), y.sqrt()), 2.),
+            y,
+        );
+        x = quarter(DoubleDouble::full_dd_add(x, lambda));
+        y = quarter(DoubleDouble::full_dd_add(y, lambda));
+        a = quarter(DoubleDouble::full_dd_add(a, lambda));
+        q *= 0.25;
+    }
+    let s = DoubleDouble::div(DoubleDouble::full_dd_sub(y, a), a);
+    // 1 + 3s^2/10 + s^3/7 + 3s^4/8 + 9s^5/22
+    let s2 = DoubleDouble::quick_mult(s, s);
+    let tail = s2.hi * s.hi * (1. / 7. + s.hi * (3. / 8. + s.hi * (9. / 22.)));
+    let p = DoubleDouble::full_add_f64(
+        DoubleDouble::full_dd_add(
+            DoubleDouble::new(0., 1.),
+            DoubleDouble::div_dd_f64(DoubleDouble::quick_mult_f64(s2, 3.), 10.),
+        ),
+        tail,
+    );
+    DoubleDouble::div(p, a.sqrt())
+}
+
+/// Computes R_C(x, y) for x >= 0 and y != 0, returning Cauchy principal value for y < 0.
+pub(crate) fn rc_pv_dd(x: DoubleDouble, y: DoubleDouble) -> DoubleDouble {
+    if y.hi > 0. {
+        return rc_dd(x, y);
+    }
+    // R_C(x, y) = sqrt(x / (x - y)) * R_C(x - y, -y)
+    let xmy = DoubleDouble::full_dd_sub(x, y);
+    DoubleDouble::quick_mult(DoubleDouble::div(x, xmy).sqrt(), rc_dd(xmy, -y))
+}
+
+/// Computes R_C(1, y) for 0 < y < 2.
+#[inline]
+pub(crate) fn rc_one(y: DoubleDouble) -> DoubleDouble {
+    let e = DoubleDouble::full_add_f64(y, -1.);
+    if e.hi.abs() < f64::from_bits(0x3f60000000000000) {
+        // |e| < 2^-9, R_C(1, 1 + e) = sum((-e)^k / (2k + 1))
+        const C: [(u64, u64); 13] = [
+            (0x0000000000000000, 0x3ff0000000000000),
+            (0x3c75555555555555, 0x3fd5555555555555),
+            (0xbc6999999999999a, 0x3fc999999999999a),
+            (0x3c62492492492492, 0x3fc2492492492492),
+            (0x3c5c71c71c71c71c, 0x3fbc71c71c71c71c),
+            (0xbc4745d1745d1746, 0x3fb745d1745d1746),
+            (0xbc53b13b13b13b14, 0x3fb3b13b13b13b14),
+            (0x3c31111111111111, 0x3fb1111111111111),
+            (0x3c2e1e1e1e1e1e1e, 0x3fae1e1e1e1e1e1e),
+            (0x3c4af286bca1af28, 0x3faaf286bca1af28),
+            (0x3c48618618618618, 0x3fa8618618618618),
+            (0x3c3642c8590b2164, 0x3fa642c8590b2164),
+            (0xbc2eb851eb851eb8, 0x3fa47ae147ae147b),
+        ];
+        let me = -e;
+        let mut p = DoubleDouble::from_bit_pair(C[12]);
+        for &c in C[..12].iter().rev() {
+            p = DoubleDouble::mul_add(p, me, DoubleDouble::from_bit_pair(c));
+        }
+        return p;
+    }
+    rc_dd(DoubleDouble::new(0., 1.), y)
+}
+
+/// Computes R_D(x, y, z) by duplication theorem.
+///
+/// x, y >= 0, at most one of them is zero, z > 0, and all are of order of 1.
+pub(crate) fn rd_dd(x: DoubleDouble, y: DoubleDouble, z: DoubleDouble) -> DoubleDouble {
+    let (mut x, mut y, mut z) = (x, y, z);
+    let mut a = DoubleDouble::div_dd_f64(
+        DoubleDouble::full_dd_add(
+            DoubleDouble::full_dd_add(x, y),
+            DoubleDouble::quick_mult_f64(z, 3.),
+        ),
+        5.,
+    );
+    let mut q = max_deviation(a.hi, &[x.hi, y.hi, z.hi]);
+    let mut fac = 1f64;
+    let mut sum = DoubleDouble::new(0., 0.);
+    while q > DUPLICATION_TOLERANCE * a.hi {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = DoubleDouble::mul_add(
+            sx,
+            sy,
+            DoubleDouble::mul_add(sx, sz, DoubleDouble::quick_mult(sy, sz)),
+        );
+        // sum += 4^-m / (sqrt(z) * (z + lambda))
+        let t = DoubleDouble::quick_mult(sz, DoubleDouble::full_dd_add(z, lambda)).recip();
+        sum = DoubleDouble::full_dd_add(sum, DoubleDouble::quick_mult_f64(t, fac));
+        x = quarter(DoubleDouble::full_dd_add(x, lambda));
+        y = quarter(DoubleDouble::full_dd_add(y, lambda));
+        z = quarter(DoubleDouble::full_dd_add(z, lambda));
+        a = quarter(DoubleDouble::full_dd_add(a, lambda));
+        q *= 0.25;
+        fac *= 0.25;
+    }
+    let ra = a.recip();
+    let dx = DoubleDouble::quick_mult(DoubleDouble::full_dd_sub(a, x), ra);
+    let dy = DoubleDouble::quick_mult(DoubleDouble::full_dd_sub(a, y), ra);
+    let dz = -DoubleDouble::div_dd_f64(DoubleDouble::full_dd_add(dx, dy), 3.);
+    let xy = DoubleDouble::quick_mult(dx, dy);
+    let z2 = DoubleDouble::quick_mult(dz, dz);
+    // E2 = XY - 6Z^2, E3 = (3XY - 8Z^2)Z, E4 = 3(XY - Z^2)Z^2, E5 = XYZ^3
+    let e2 = DoubleDouble::full_dd_sub(xy, DoubleDouble::quick_mult_f64(z2, 6.));
+    let e3 = (3. * xy.hi - 8. * z2.hi) * dz.hi;
+    let e4 = 3. * (xy.hi - z2.hi) * z2.hi;
+    let e5 = xy.hi * z2.hi * dz.hi;
+    let p = series_rj(e2, e3, e4, e5);
+    let r = DoubleDouble::quick_mult_f64(
+        DoubleDouble::div(p, DoubleDouble::quick_mult(a, a.sqrt())),
+        fac,
+    );
+    DoubleDouble::mul_f64_add(sum, 3., r)
+}
+
+/// 1 - 3E2/14 + E3/6 + 9E2^2/88 - 3E4/22 - 9E2E3/52 + 3E5/26
+#[inline]
+fn series_rj(e2: DoubleDouble, e3: f64, e4: f64, e5: f64) -> DoubleDouble {
+    let tail = e3 * (1. / 6.) + e2.hi * (e2.hi * (9. / 88.) - e3 * (9. / 52.)) - e4 * (3. / 22.)
+        + e5 * (3. / 26.);
+    DoubleDouble::full_add_f64(
+        DoubleDouble::full_dd_sub(
+            DoubleDouble::new(0., 1.),
+            DoubleDouble::div_dd_f64(DoubleDouble::quick_mult_f64(e2, 3.), 14.),
+        ),
+        tail,
+    )
+}
+
+/// Computes R_J(x, y, z, p) by duplication theorem for p > 0.
+pub(crate) fn rj_positive(
+    x: DoubleDouble,
+    y: DoubleDouble,
+    z: DoubleDouble,
+    p: DoubleDouble,
+) -> DoubleDouble {
+    let (mut x, mut y, mut z, mut p) = (x, y, z, p);
+    let mut a = DoubleDouble::div_dd_f64(
+        DoubleDouble::full_dd_add(
+            DoubleDouble::full_dd_add(DoubleDouble::full_dd_add(x, y), z),
+            DoubleDouble::quick_mult_f64(p, 2.),
+        ),
+        5.,
+    );
+    let mut q = max_deviation(a.hi, &[x.hi, y.hi, z.hi, p.hi]);
+    let mut fac = 1f64;
+    let mut sum = DoubleDouble::new(0., 0.);
+    while q > DUPLICATION_TOLERANCE * a.hi {
+        let (sx, sy, sz, sp) = (x.sqrt(), y.sqrt(), z.sqrt(), p.sqrt());
+        let lambda = DoubleDouble::mul_add(
+            sx,
+            sy,
+            DoubleDouble::mul_add(sx, sz, DoubleDouble::quick_mult(sy, sz)),
+        );
+        // d = (sqrt(p) + sqrt(x))(sqrt(p) + sqrt(y))(sqrt(p) + sqrt(z))
+        let d = DoubleDouble::quick_mult(
+            DoubleDouble::quick_mult(
+                DoubleDouble::full_dd_add(sp, sx),
+                DoubleDouble::full_dd_add(sp, sy),
+            ),
+            DoubleDouble::full_dd_add(sp, sz),
+        );
+        let rd = d.recip();
+        // 1 + delta / d^2 = 2 * sqrt(p) * (p + lambda) / d, delta = (p - x)(p - y)(p - z),
+        // which avoids cancellation when p is far from one of the arguments.
+        let w = DoubleDouble::quick_mult(
+            DoubleDouble::quick_mult_f64(
+                DoubleDouble::quick_mult(sp, DoubleDouble::full_dd_add(p, lambda)),
+                2.,
+            ),
+            rd,
+        );
+        // sum += 4^-m / d * R_C(1, 1 + delta / d^2)
+        sum = DoubleDouble::mul_add(DoubleDouble::quick_mult_f64(rd, fac), rc_one(w), sum);
+        x = quarter(DoubleDouble::full_dd_add(x, lambda));
+        y = quarter(DoubleDouble::full_dd_add(y, lambda));
+        z = quarter(DoubleDouble::full_dd_add(z, lambda));
+        p = quarter(DoubleDouble::full_dd_add(p, lambda));
+        a = quarter(DoubleDouble::full_dd_add(a, lambda));
+        q *= 0.25;
+        fac *= 0.25;
+    }
+    let ra = a.recip();
+    let dx = DoubleDouble::quick_mult(DoubleDouble::full_dd_sub(a, x), ra);
+    let dy = DoubleDouble::quick_mult(DoubleDouble::full_dd_sub(a, y), ra);
+    let dz = DoubleDouble::quick_mult(DoubleDouble::full_dd_sub(a, z), ra);
+    let dp = -DoubleDouble::quick_mult_f64(
+        DoubleDouble::full_dd_add(DoubleDouble::full_dd_add(dx, dy), dz),
+        0.5,
+    );
+    // E2 = XY + XZ + YZ - 3P^2, E3 = XYZ + 2E2P + 4P^3,
+    // E4 = (2XYZ + E2P + 3P^3)P, E5 = XYZP^2
+    let e2 = DoubleDouble::full_dd_sub(
+        DoubleDouble::mul_add(
+            dx,
+            dy,
+            DoubleDouble::quick_mult(dz, DoubleDouble::full_dd_add(dx, dy)),
+        ),
+        DoubleDouble::quick_mult_f64(DoubleDouble::quick_mult(dp, dp), 3.),
+    );
+    let xyz = dx.hi * dy.hi * dz.hi;
+    let p2 = dp.hi * dp.hi;
+    let e3 = xyz + 2. * e2.hi * dp.hi + 4. * p2 * dp.hi;
+    let e4 = (2. * xyz + e2.hi * dp.hi + 3. * p2 * dp.hi) * dp.hi;
+    let e5 = xyz * p2;
+    let s = series_rj(e2, e3, e4, e5);
+    let r = DoubleDouble::quick_mult_f64(
+        DoubleDouble::div(s, DoubleDouble::quick_mult(a, a.sqrt())),
+        fac,
+    );
+    DoubleDouble::mul_f64_add(sum, 6., r)
+}
+
+/// Computes R_J(x, y, z, p), x, y, z >= 0, at most one of them is zero, p != 0,
+/// and all are of order of 1.
+///
+/// For p < 0 Cauchy principal value is returned.
+pub(crate) fn rj_dd(
+    x: DoubleDouble,
+    y: DoubleDouble,
+    z: DoubleDouble,
+    p: DoubleDouble,
+) -> DoubleDouble {
+    if p.hi > 0. {
+        return rj_positive(x, y, z, p);
+    }
+    let mut v = [x, y, z];
+    v.sort_by(|a, b| a.hi.total_cmp(&b.hi));
+    let [xt, yt, zt] = v;
+    // a = 1 / (y - p), b = a(z - y)(y - x), q = y + b
+    // R_J(x, y, z, p) = a * (b * R_J(x, y, z, q) + 3 * (R_C(xz / y, pq / y) - R_F(x, y, z)))
+    let a = DoubleDouble::full_dd_sub(yt, p).recip();
+    let b = DoubleDouble::quick_mult(
+        DoubleDouble::quick_mult(a, DoubleDouble::full_dd_sub(zt, yt)),
+        DoubleDouble::full_dd_sub(yt, xt),
+    );
+    let pt = DoubleDouble::full_dd_add(yt, b);
+    let ryt = yt.recip();
+    let rho = DoubleDouble::quick_mult(DoubleDouble::quick_mult(xt, zt), ryt);
+    let tau = DoubleDouble::quick_mult(DoubleDouble::quick_mult(p, pt), ryt);
+    let rcx = rc_pv_dd(rho, tau);
+    let rf = rf_dd(xt, yt, zt);
+    let rj = rj_positive(xt, yt, zt, pt);
+    DoubleDouble::quick_mult(
+        a,
+        DoubleDouble::mul_f64_add(
+            DoubleDouble::full_dd_sub(rcx, rf),
+            3.,
+            DoubleDouble::quick_mult(b, rj),
+        ),
+    )
+}
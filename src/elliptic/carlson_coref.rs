@@ -0,0 +1,199 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+
+/// Duplication stops once all arguments are within 2^-9 relative of their mean,
+/// so that truncation error of the fifth degree series is below 2^-54.
+const DUPLICATION_TOLERANCE: f64 = f64::from_bits(0x3f60000000000000);
+
+#[inline]
+fn max_deviation(a: f64, args: &[f64]) -> f64 {
+    args.iter().fold(0., |acc, &v| f64::max(acc, (a - v).abs()))
+}
+
+/// Computes R_F(x, y, z) by duplication theorem.
+///
+/// x, y, z >= 0 and at most one of them is zero.
+pub(crate) fn rff(x: f64, y: f64, z: f64) -> f64 {
+    let (mut x, mut y, mut z) = (x, y, z);
+    let mut a = (x + y + z) * (1. / 3.);
+    let mut q = max_deviation(a, &[x, y, z]);
+    while q > DUPLICATION_TOLERANCE * a {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = f_fmla(sx, sy, f_fmla(sx, sz, sy * sz));
+        x = (x + lambda) * 0.25;
+        y = (y + lambda) * 0.25;
+        z = (z + lambda) * 0.25;
+        a = (a + lambda) * 0.25;
+        q *= 0.25;
+    }
+    let dx = (a - x) / a;
+    let dy = (a - y) / a;
+    let dz = -(dx + dy);
+    // E2 = XY - Z^2, E3 = XYZ
+    let e2 = f_fmla(dx, dy, -dz * dz);
+    let e3 = dx * dy * dz;
+    // 1 - E2/10 + E3/14 + E2^2/24 - 3*E2*E3/44
+    let p = 1. - e2 * (1. / 10.) + e3 * (1. / 14.) + e2 * (e2 * (1. / 24.) - e3 * (3. / 44.));
+    p / a.sqrt()
+}
+
+/// Computes R_C(x, y) by duplication theorem for x >= 0, y > 0.
+pub(crate) fn rcf(x: f64, y: f64) -> f64 {
+    let (mut x, mut y) = (x, y);
+    let mut a = (x + 2. * y) * (1. / 3.);
+    let mut q = (a - y).abs();
+    while q > DUPLICATION_TOLERANCE * a {
+        let lambda = f_fmla(2. * x.sqrt(), y.sqrt(), y);
+        x = (x + lambda) * 0.25;
+        y = (y + lambda) * 0.25;
+        a = (a + lambda) * 0.25;
+        q *= 0.25;
+    }
+    let s = (y - a) / a;
+    // 1 + 3s^2/10 + s^3/7 + 3s^4/8 + 9s^5/22
+    let p = f_fmla(
+        s * s,
+        3. / 10. + s * (1. / 7. + s * (3. / 8. + s * (9. / 22.))),
+        1.,
+    );
+    p / a.sqrt()
+}
+
+/// Computes R_C(x, y) for x >= 0 and y != 0, returning Cauchy principal value for y < 0.
+pub(crate) fn rc_pvf(x: f64, y: f64) -> f64 {
+    if y > 0. {
+        return rcf(x, y);
+    }
+    // R_C(x, y) = sqrt(x / (x - y)) * R_C(x - y, -y)
+    (x / (x - y)).sqrt() * rcf(x - y, -y)
+}
+
+/// 1 - 3E2/14 + E3/6 + 9E2^2/88 - 3E4/22 - 9E2E3/52 + 3E5/26
+#[inline]
+fn series_rjf(e2: f64, e3: f64, e4: f64, e5: f64) -> f64 {
+    1. - e2 * (3. / 14.) + e3 * (1. / 6.) + e2 * (e2 * (9. / 88.) - e3 * (9. / 52.))
+        - e4 * (3. / 22.)
+        + e5 * (3. / 26.)
+}
+
+/// Computes R_D(x, y, z) by duplication theorem.
+///
+/// x, y >= 0, at most one of them is zero, and z > 0.
+pub(crate) fn rdf(x: f64, y: f64, z: f64) -> f64 {
+    let (mut x, mut y, mut z) = (x, y, z);
+    let mut a = (x + y + 3. * z) * (1. / 5.);
+    let mut q = max_deviation(a, &[x, y, z]);
+    let mut fac = 1f64;
+    let mut sum = 0f64;
+    while q > DUPLICATION_TOLERANCE * a {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = f_fmla(sx, sy, f_fmla(sx, sz, sy * sz));
+        sum += fac / (sz * (z + lambda));
+        x = (x + lambda) * 0.25;
+        y = (y + lambda) * 0.25;
+        z = (z + lambda) * 0.25;
+        a = (a + lambda) * 0.25;
+        q *= 0.25;
+        fac *= 0.25;
+    }
+    let dx = (a - x) / a;
+    let dy = (a - y) / a;
+    let dz = -(dx + dy) * (1. / 3.);
+    let xy = dx * dy;
+    let z2 = dz * dz;
+    // E2 = XY - 6Z^2, E3 = (3XY - 8Z^2)Z, E4 = 3(XY - Z^2)Z^2, E5 = XYZ^3
+    let e2 = f_fmla(-6., z2, xy);
+    let e3 = (3. * xy - 8. * z2) * dz;
+    let e4 = 3. * (xy - z2) * z2;
+    let e5 = xy * z2 * dz;
+    f_fmla(fac / (a * a.sqrt()), series_rjf(e2, e3, e4, e5), 3. * sum)
+}
+
+/// Computes R_J(x, y, z, p) by duplication theorem for p > 0.
+fn rj_positivef(x: f64, y: f64, z: f64, p: f64) -> f64 {
+    let (mut x, mut y, mut z, mut p) = (x, y, z, p);
+    let mut a = (x + y + z + 2. * p) * (1. / 5.);
+    // delta = (p - x)(p - y)(p - z)
+    let delta = (p - x) * (p - y) * (p - z);
+    let mut q = max_deviation(a, &[x, y, z, p]);
+    let mut fac = 1f64;
+    let mut sum = 0f64;
+    while q > DUPLICATION_TOLERANCE * a {
+        let (sx, sy, sz, sp) = (x.sqrt(), y.sqrt(), z.sqrt(), p.sqrt());
+        let lambda = f_fmla(sx, sy, f_fmla(sx, sz, sy * sz));
+        // d = (sqrt(p) + sqrt(x))(sqrt(p) + sqrt(y))(sqrt(p) + sqrt(z))
+        let d = (sp + sx) * (sp + sy) * (sp + sz);
+        // e = 4^-3m * delta / d^2
+        let e = fac * fac * fac * delta / (d * d);
+        // sum += 4^-m / d * R_C(1, 1 + e)
+        sum = f_fmla(fac / d, rcf(1., 1. + e), sum);
+        x = (x + lambda) * 0.25;
+        y = (y + lambda) * 0.25;
+        z = (z + lambda) * 0.25;
+        p = (p + lambda) * 0.25;
+        a = (a + lambda) * 0.25;
+        q *= 0.25;
+        fac *= 0.25;
+    }
+    let dx = (a - x) / a;
+    let dy = (a - y) / a;
+    let dz = (a - z) / a;
+    let dp = -(dx + dy + dz) * 0.5;
+    // E2 = XY + XZ + YZ - 3P^2, E3 = XYZ + 2E2P + 4P^3,
+    // E4 = (2XYZ + E2P + 3P^3)P, E5 = XYZP^2
+    let p2 = dp * dp;
+    let e2 = f_fmla(dx, dy, f_fmla(dz, dx + dy, -3. * p2));
+    let xyz = dx * dy * dz;
+    let e3 = xyz + 2. * e2 * dp + 4. * p2 * dp;
+    let e4 = (2. * xyz + e2 * dp + 3. * p2 * dp) * dp;
+    let e5 = xyz * p2;
+    f_fmla(fac / (a * a.sqrt()), series_rjf(e2, e3, e4, e5), 6. * sum)
+}
+
+/// Computes R_J(x, y, z, p), x, y, z >= 0, at most one of them is zero, and p != 0.
+///
+/// For p < 0 Cauchy principal value is returned.
+pub(crate) fn rjf(x: f64, y: f64, z: f64, p: f64) -> f64 {
+    if p > 0. {
+        return rj_positivef(x, y, z, p);
+    }
+    let mut v = [x, y, z];
+    v.sort_by(|a, b| a.total_cmp(b));
+    let [xt, yt, zt] = v;
+    // a = 1 / (y - p), b = a(z - y)(y - x), q = y + b
+    // R_J(x, y, z, p) = a * (b * R_J(x, y, z, q) + 3 * (R_C(xz / y, pq / y) - R_F(x, y, z)))
+    let a = 1. / (yt - p);
+    let b = a * (zt - yt) * (yt - xt);
+    let pt = yt + b;
+    let rcx = rc_pvf(xt * zt / yt, p * pt / yt);
+    let rf = rff(xt, yt, zt);
+    let rj = rj_positivef(xt, yt, zt, pt);
+    a * f_fmla(b, rj, 3. * (rcx - rf))
+}
@@ -0,0 +1,88 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::legendre_core::{
+    ONE, add_periods, complementary_parameter, ellint_e_dd, ellint_e_reduced, incomplete_args,
+    reduce_amplitude,
+};
+
+/// Computes incomplete elliptic integral of the second kind
+///
+/// E(phi, k) = integral(sqrt(1 - k^2 * sin^2(t)), t = 0..phi), where k is the modulus.
+///
+/// For |k| > 1 result is defined only while k^2 * sin^2(phi) <= 1.
+pub fn f_ellint_e_inc(phi: f64, k: f64) -> f64 {
+    if phi.is_nan() || k.is_nan() {
+        return f64::NAN;
+    }
+    let ak = k.abs();
+    let aphi = phi.abs();
+    if aphi.is_infinite() {
+        return if ak <= 1. { phi } else { f64::NAN };
+    }
+    if aphi < f64::from_bits(0x3e40000000000000) && ak <= 1. {
+        // |phi| < 2^-27, E(phi, k) ~ phi - k^2 * phi^3 / 6
+        return phi;
+    }
+    let amp = reduce_amplitude(aphi);
+    if amp.m != 0. && ak > 1. {
+        return f64::NAN;
+    }
+    let kp2 = complementary_parameter(ak);
+    let args = incomplete_args(&amp, ak, kp2);
+    if args.delta.hi < 0. {
+        return f64::NAN;
+    }
+    let r = ellint_e_reduced(&args, ak);
+    let v = if amp.m != 0. {
+        // E(1) = 1
+        let e = if ak == 1. { ONE } else { ellint_e_dd(ak, kp2) };
+        add_periods(amp.m, e, r)
+    } else {
+        r.to_f64()
+    };
+    f64::copysign(v, phi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_e_inc() {
+        assert_eq!(f_ellint_e_inc(1., 0.5), 0.9648764542686274);
+        assert_eq!(f_ellint_e_inc(-1., 0.5), -0.9648764542686274);
+        assert_eq!(f_ellint_e_inc(10., 0.8), 8.213729432787908);
+        assert_eq!(f_ellint_e_inc(1e5, 0.3), 97710.53228821274);
+        assert_eq!(f_ellint_e_inc(0.5, 1.5), 0.4510092431894169);
+        assert_eq!(f_ellint_e_inc(1., 1.), 0.8414709848078965);
+        assert_eq!(f_ellint_e_inc(4., 1.), 2.7568024953079284);
+        assert_eq!(f_ellint_e_inc(1e-30, 0.5), 1e-30);
+        assert_eq!(f_ellint_e_inc(f64::INFINITY, 0.5), f64::INFINITY);
+    }
+}
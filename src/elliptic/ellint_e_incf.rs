@@ -0,0 +1,91 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::elliptic::legendre_coref::{
+    complementary_parameterf, ellint_e_reducedf, ellint_ef_core, incomplete_argsf,
+    reduce_amplitudef,
+};
+
+/// Computes incomplete elliptic integral of the second kind
+///
+/// E(phi, k) = integral(sqrt(1 - k^2 * sin^2(t)), t = 0..phi), where k is the modulus.
+///
+/// For |k| > 1 result is defined only while k^2 * sin^2(phi) <= 1.
+pub fn f_ellint_e_incf(phi: f32, k: f32) -> f32 {
+    if phi.is_nan() || k.is_nan() {
+        return f32::NAN;
+    }
+    let ak = k.abs();
+    let aphi = phi.abs();
+    if aphi.is_infinite() {
+        return if ak <= 1. { phi } else { f32::NAN };
+    }
+    if aphi < f32::from_bits(0x39800000) && ak <= 1. {
+        // |phi| < 2^-12, E(phi, k) ~ phi - k^2 * phi^3 / 6
+        return phi;
+    }
+    let amp = reduce_amplitudef(aphi);
+    if amp.m != 0. && ak > 1. {
+        return f32::NAN;
+    }
+    let kd = ak as f64;
+    let kp2 = complementary_parameterf(kd);
+    let args = incomplete_argsf(&amp, kd, kp2);
+    if args.delta < 0. {
+        return f32::NAN;
+    }
+    let r = ellint_e_reducedf(&args, kd);
+    let v = if amp.m != 0. {
+        // E(1) = 1
+        let e = if ak == 1. {
+            1.
+        } else {
+            ellint_ef_core(kd, kp2)
+        };
+        f_fmla(2. * amp.m, e, r)
+    } else {
+        r
+    };
+    f32::copysign(v as f32, phi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_e_incf() {
+        assert_eq!(f_ellint_e_incf(1., 0.5), 0.9648765);
+        assert_eq!(f_ellint_e_incf(-1., 0.5), -0.9648765);
+        assert_eq!(f_ellint_e_incf(10., 0.8), 8.213729);
+        assert_eq!(f_ellint_e_incf(0.5, 1.5), 0.45100924);
+        assert_eq!(f_ellint_e_incf(4., 1.), 2.7568026);
+        assert_eq!(f_ellint_e_incf(f32::INFINITY, 0.5), f32::INFINITY);
+    }
+}
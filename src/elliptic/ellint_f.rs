@@ -0,0 +1,90 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::legendre_core::{
+    add_periods, complementary_parameter, ellint_f_reduced, ellint_k_dd, incomplete_args,
+    reduce_amplitude,
+};
+
+/// Computes incomplete elliptic integral of the first kind
+///
+/// F(phi, k) = integral(1/sqrt(1 - k^2 * sin^2(t)), t = 0..phi), where k is the modulus.
+///
+/// For |k| > 1 result is defined only while k^2 * sin^2(phi) <= 1.
+pub fn f_ellint_f(phi: f64, k: f64) -> f64 {
+    if phi.is_nan() || k.is_nan() {
+        return f64::NAN;
+    }
+    let ak = k.abs();
+    let aphi = phi.abs();
+    if aphi.is_infinite() {
+        return if ak <= 1. { phi } else { f64::NAN };
+    }
+    if aphi < f64::from_bits(0x3e40000000000000) && ak <= 1. {
+        // |phi| < 2^-27, F(phi, k) ~ phi + k^2 * phi^3 / 6
+        return phi;
+    }
+    let amp = reduce_amplitude(aphi);
+    if amp.m != 0. && ak >= 1. {
+        return if ak == 1. {
+            f64::copysign(f64::INFINITY, phi)
+        } else {
+            f64::NAN
+        };
+    }
+    let kp2 = complementary_parameter(ak);
+    let args = incomplete_args(&amp, ak, kp2);
+    if args.delta.hi < 0. {
+        return f64::NAN;
+    }
+    let r = ellint_f_reduced(&args);
+    let v = if amp.m != 0. {
+        add_periods(amp.m, ellint_k_dd(kp2), r)
+    } else {
+        r.to_f64()
+    };
+    f64::copysign(v, phi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_f() {
+        assert_eq!(f_ellint_f(1., 0.5), 1.0373561200021773);
+        assert_eq!(f_ellint_f(-1., 0.5), -1.0373561200021773);
+        assert_eq!(f_ellint_f(10., 0.8), 12.567907767127261);
+        assert_eq!(f_ellint_f(1e5, 0.3), 102371.555484914);
+        assert_eq!(f_ellint_f(0.5, 1.5), 0.5602967295977535);
+        assert_eq!(f_ellint_f(1., 1.), 1.2261911708835171);
+        assert_eq!(f_ellint_f(1e-30, 0.5), 1e-30);
+        assert_eq!(f_ellint_f(f64::INFINITY, 0.5), f64::INFINITY);
+        assert_eq!(f_ellint_f(0., 2.), 0.);
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::legendre_core::{complementary_parameter, ellint_k_dd};
+
+/// Computes complete elliptic integral of the first kind
+///
+/// K(k) = integral(1/sqrt(1 - k^2 * sin^2(t)), t = 0..pi/2), where k is the modulus.
+pub fn f_ellint_k(k: f64) -> f64 {
+    let ak = k.abs();
+    if ak >= 1. || ak.is_nan() {
+        if ak == 1. {
+            return f64::INFINITY;
+        }
+        return f64::NAN;
+    }
+    ellint_k_dd(complementary_parameter(ak)).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_k() {
+        assert_eq!(f_ellint_k(0.), 1.5707963267948966);
+        assert_eq!(f_ellint_k(0.5), 1.685750354812596);
+        assert_eq!(f_ellint_k(-0.5), 1.685750354812596);
+        assert_eq!(f_ellint_k(0.9), 2.2805491384227703);
+        assert_eq!(f_ellint_k(0.999999), 7.947479773547967);
+        assert_eq!(f_ellint_k(1e-10), 1.5707963267948966);
+        assert_eq!(f_ellint_k(1.), f64::INFINITY);
+        assert!(f_ellint_k(1.5).is_nan());
+        assert!(f_ellint_k(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::legendre_core::{complementary_parameter, ellint_pi_dd};
+
+/// Computes complete elliptic integral of the third kind
+///
+/// Pi(n, k) = integral(1/((1 - n * sin^2(t)) * sqrt(1 - k^2 * sin^2(t))), t = 0..pi/2),
+/// where n is the characteristic and k is the modulus.
+///
+/// For n > 1 Cauchy principal value is returned.
+///
+/// Max found ULP 0.5
+pub fn f_ellint_pi(n: f64, k: f64) -> f64 {
+    let ak = k.abs();
+    if n.is_nan() || ak.is_nan() || ak > 1. {
+        return f64::NAN;
+    }
+    if n == 1. || ak == 1. {
+        return f64::INFINITY;
+    }
+    if n.is_infinite() {
+        return 0.;
+    }
+    ellint_pi_dd(n, ak, complementary_parameter(ak)).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_pi() {
+        assert_eq!(f_ellint_pi(0., 0.5), 1.685750354812596);
+        assert_eq!(f_ellint_pi(0.5, 0.5), 2.4136715042011945);
+        assert_eq!(f_ellint_pi(-2., 0.3), 0.9225702984059377);
+        assert_eq!(f_ellint_pi(0.9, 0.), 4.9672941328980516);
+        assert_eq!(f_ellint_pi(10., 0.5), -0.022251969926115642);
+        assert_eq!(f_ellint_pi(2., 0.), 0.);
+        assert_eq!(f_ellint_pi(7.92, 7.2e-11), -5.14078797860148e-22);
+        assert_eq!(f_ellint_pi(1.5, 1e-10), -5.235987755982989e-21);
+        assert_eq!(f_ellint_pi(-1e10, 0.5), 1.5707985095781766e-5);
+        assert_eq!(f_ellint_pi(1., 0.5), f64::INFINITY);
+        assert_eq!(f_ellint_pi(0.5, 1.), f64::INFINITY);
+        assert!(f_ellint_pi(0.5, 1.5).is_nan());
+        assert_eq!(f_ellint_pi(f64::INFINITY, 0.5), 0.);
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::legendre_coref::{complementary_parameterf, ellint_pif_core};
+
+/// Computes complete elliptic integral of the third kind
+///
+/// Pi(n, k) = integral(1/((1 - n * sin^2(t)) * sqrt(1 - k^2 * sin^2(t))), t = 0..pi/2),
+/// where n is the characteristic and k is the modulus.
+///
+/// For n > 1 Cauchy principal value is returned.
+///
+/// Max found ULP 0.5
+pub fn f_ellint_pif(n: f32, k: f32) -> f32 {
+    let ak = k.abs();
+    if n.is_nan() || ak.is_nan() || ak > 1. {
+        return f32::NAN;
+    }
+    if n == 1. || ak == 1. {
+        return f32::INFINITY;
+    }
+    if n.is_infinite() {
+        return 0.;
+    }
+    ellint_pif_core(n as f64, ak as f64, complementary_parameterf(ak as f64)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_pif() {
+        assert_eq!(f_ellint_pif(0., 0.5), 1.6857504);
+        assert_eq!(f_ellint_pif(0.5, 0.5), 2.4136715);
+        assert_eq!(f_ellint_pif(-2., 0.3), 0.9225703);
+        assert_eq!(f_ellint_pif(2., 0.), 0.);
+        assert_eq!(f_ellint_pif(7.92, 7.2e-11), -5.140788e-22);
+        assert_eq!(f_ellint_pif(6.17, 5.1e-5), -3.3108924e-10);
+        assert_eq!(f_ellint_pif(1., 0.5), f32::INFINITY);
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::elliptic::carlson_core::{
+    carlson_needs_step, carlson_scale, carlson_scaled, carlson_sqrt_scaled, carlson_step_rc, rc_dd,
+    rc_pv_dd,
+};
+use crate::exponents::ldexp;
+
+/// Computes Carlson's degenerate elliptic integral
+///
+/// R_C(x, y) = 1/2 * integral(1/(sqrt(t + x) * (t + y)), t = 0..inf).
+///
+/// x must be non-negative. For y < 0 Cauchy principal value is returned.
+pub fn f_ellint_rc(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || x < 0. {
+        return f64::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() {
+        return 0.;
+    }
+    if y == 0. {
+        return f64::INFINITY;
+    }
+    let k = carlson_scale(&[x, y]);
+    if carlson_needs_step(&[x, y], k) {
+        if y > 0. {
+            let (x1, y1) = carlson_step_rc(x, y, k);
+            return ldexp(rc_dd(x1, y1).to_f64(), -k);
+        }
+        // R_C(x, y) = sqrt(x / (x - y)) * R_C(x - y, -y),
+        // and x - y equals to the largest of x, -y in working precision.
+        let xmy = x.max(-y);
+        let (x1, y1) = carlson_step_rc(xmy, -y, k);
+        let f = DoubleDouble::div(carlson_sqrt_scaled(x, k), carlson_sqrt_scaled(xmy, k));
+        return ldexp(DoubleDouble::quick_mult(f, rc_dd(x1, y1)).to_f64(), -k);
+    }
+    let r = rc_pv_dd(carlson_scaled(x, k), carlson_scaled(y, k));
+    ldexp(r.to_f64(), -k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_rc() {
+        assert_eq!(f_ellint_rc(0., 0.25), 3.141592653589793);
+        assert_eq!(f_ellint_rc(2.25, 2.), 0.6931471805599453);
+        assert_eq!(f_ellint_rc(0.25, -2.), 0.23104906018664845);
+        assert_eq!(f_ellint_rc(1., 1.), 1.);
+        assert_eq!(f_ellint_rc(1e300, 1e-300), 6.914686750787736e-148);
+        assert_eq!(f_ellint_rc(1e-300, -1e300), 0.);
+        assert_eq!(f_ellint_rc(1., 0.), f64::INFINITY);
+        assert_eq!(f_ellint_rc(f64::INFINITY, 1.), 0.);
+        assert!(f_ellint_rc(-1., 1.).is_nan());
+    }
+}
@@ -0,0 +1,60 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::carlson_coref::rc_pvf;
+
+/// Computes Carlson's degenerate elliptic integral
+///
+/// R_C(x, y) = 1/2 * integral(1/(sqrt(t + x) * (t + y)), t = 0..inf).
+///
+/// x must be non-negative. For y < 0 Cauchy principal value is returned.
+pub fn f_ellint_rcf(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() || x < 0. {
+        return f32::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() {
+        return 0.;
+    }
+    if y == 0. {
+        return f32::INFINITY;
+    }
+    rc_pvf(x as f64, y as f64) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_rcf() {
+        assert_eq!(f_ellint_rcf(0., 0.25), 3.1415927);
+        assert_eq!(f_ellint_rcf(2.25, 2.), 0.6931472);
+        assert_eq!(f_ellint_rcf(0.25, -2.), 0.23104906);
+        assert_eq!(f_ellint_rcf(1., 0.), f32::INFINITY);
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::elliptic::carlson_core::{
+    carlson_ldexp, carlson_needs_step, carlson_normalize, carlson_scale, carlson_scaled,
+    carlson_step, quarter, rd_dd,
+};
+use crate::exponents::ldexp;
+
+/// Computes Carlson's elliptic integral of the second kind
+///
+/// R_D(x, y, z) = 3/2 * integral(1/(sqrt((t + x)(t + y)) * (t + z)^(3/2)), t = 0..inf).
+///
+/// x, y must be non-negative, at most one of them can be zero, and z must be positive.
+pub fn f_ellint_rd(x: f64, y: f64, z: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < 0. || y < 0. || z < 0. {
+        return f64::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return 0.;
+    }
+    if z == 0. || (x == 0. && y == 0.) {
+        return f64::INFINITY;
+    }
+    let k = carlson_scale(&[x, y, z]);
+    if carlson_needs_step(&[x, y, z], k) {
+        // R_D(x, y, z) = R_D(x1, y1, z1) / 4 + 3 / (sqrt(z) * (z + lambda)),
+        // where the last term may exceed exponent range when z is tiny,
+        // so everything is computed in units of 2^-(eu + ev).
+        let s = carlson_step([x, y, z], k);
+        let [x1, y1, z1] = s.args;
+        let (u, eu) = carlson_normalize(s.sqrt[2]);
+        let (v, ev) = carlson_normalize(DoubleDouble::full_dd_add(s.scaled[2], s.lambda));
+        let t = DoubleDouble::quick_mult(u, v).recip();
+        let r =
+            DoubleDouble::mul_f64_add(t, 3., carlson_ldexp(quarter(rd_dd(x1, y1, z1)), eu + ev));
+        return ldexp(r.to_f64(), -3 * k - eu - ev);
+    }
+    let r = rd_dd(
+        carlson_scaled(x, k),
+        carlson_scaled(y, k),
+        carlson_scaled(z, k),
+    );
+    ldexp(r.to_f64(), -3 * k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_rd() {
+        assert_eq!(f_ellint_rd(1., 2., 3.), 0.29046028102899063);
+        assert_eq!(f_ellint_rd(0., 2., 1.), 1.7972103521033884);
+        assert_eq!(f_ellint_rd(2., 3., 4.), 0.16510527294261054);
+        assert_eq!(
+            f_ellint_rd(
+                1.3230062670921534e-176,
+                2.9728338821952344e150,
+                7.728041035235233e-295
+            ),
+            1.720759885541298e160
+        );
+        assert_eq!(f_ellint_rd(1., 1., 0.), f64::INFINITY);
+        assert_eq!(f_ellint_rd(f64::INFINITY, 1., 1.), 0.);
+        assert!(f_ellint_rd(-1., 1., 1.).is_nan());
+    }
+}
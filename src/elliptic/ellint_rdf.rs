@@ -0,0 +1,59 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::carlson_coref::rdf;
+
+/// Computes Carlson's elliptic integral of the second kind
+///
+/// R_D(x, y, z) = 3/2 * integral(1/(sqrt((t + x)(t + y)) * (t + z)^(3/2)), t = 0..inf).
+///
+/// x, y must be non-negative, at most one of them can be zero, and z must be positive.
+pub fn f_ellint_rdf(x: f32, y: f32, z: f32) -> f32 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < 0. || y < 0. || z < 0. {
+        return f32::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return 0.;
+    }
+    if z == 0. || (x == 0. && y == 0.) {
+        return f32::INFINITY;
+    }
+    rdf(x as f64, y as f64, z as f64) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_rdf() {
+        assert_eq!(f_ellint_rdf(1., 2., 3.), 0.2904603);
+        assert_eq!(f_ellint_rdf(0., 2., 1.), 1.7972103);
+        assert_eq!(f_ellint_rdf(1., 1., 0.), f32::INFINITY);
+    }
+}
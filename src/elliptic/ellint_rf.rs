@@ -0,0 +1,74 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::carlson_core::rf_scaled;
+use crate::exponents::ldexp;
+
+/// Computes Carlson's symmetric elliptic integral of the first kind
+///
+/// R_F(x, y, z) = 1/2 * integral(1/sqrt((t + x)(t + y)(t + z)), t = 0..inf).
+///
+/// x, y, z must be non-negative, and at most one of them can be zero.
+pub fn f_ellint_rf(x: f64, y: f64, z: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < 0. || y < 0. || z < 0. {
+        return f64::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return 0.;
+    }
+    if (x == 0.) as u32 + (y == 0.) as u32 + (z == 0.) as u32 > 1 {
+        return f64::INFINITY;
+    }
+    let (r, k) = rf_scaled(x, y, z);
+    ldexp(r.to_f64(), -k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_rf() {
+        assert_eq!(f_ellint_rf(1., 2., 3.), 0.7269459354689082);
+        assert_eq!(f_ellint_rf(0., 1., 2.), 1.3110287771460598);
+        assert_eq!(f_ellint_rf(0.5, 0.5, 0.5), 1.4142135623730951);
+        assert_eq!(f_ellint_rf(1e-300, 1e300, 1.), 3.4677405831022676e-148);
+        assert_eq!(
+            f_ellint_rf(
+                4.548713090548975e246,
+                2.4982868860453235e-300,
+                3.438697062607956e-177
+            ),
+            2.290554711502367e-121
+        );
+        assert_eq!(f_ellint_rf(0., 0., 1.), f64::INFINITY);
+        assert_eq!(f_ellint_rf(f64::INFINITY, 1., 1.), 0.);
+        assert!(f_ellint_rf(-1., 1., 1.).is_nan());
+        assert!(f_ellint_rf(f64::NAN, 1., 1.).is_nan());
+    }
+}
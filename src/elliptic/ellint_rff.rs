@@ -0,0 +1,61 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::carlson_coref::rff;
+
+/// Computes Carlson's symmetric elliptic integral of the first kind
+///
+/// R_F(x, y, z) = 1/2 * integral(1/sqrt((t + x)(t + y)(t + z)), t = 0..inf).
+///
+/// x, y, z must be non-negative, and at most one of them can be zero.
+pub fn f_ellint_rff(x: f32, y: f32, z: f32) -> f32 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < 0. || y < 0. || z < 0. {
+        return f32::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return 0.;
+    }
+    if (x == 0.) as u32 + (y == 0.) as u32 + (z == 0.) as u32 > 1 {
+        return f32::INFINITY;
+    }
+    rff(x as f64, y as f64, z as f64) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_rff() {
+        assert_eq!(f_ellint_rff(1., 2., 3.), 0.72694594);
+        assert_eq!(f_ellint_rff(0., 1., 2.), 1.3110287);
+        assert_eq!(f_ellint_rff(1e-30, 1e30, 1.), 3.592507e-14);
+        assert_eq!(f_ellint_rff(0., 0., 1.), f32::INFINITY);
+        assert!(f_ellint_rff(-1., 1., 1.).is_nan());
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::elliptic::carlson_core::{
+    carlson_ldexp, carlson_needs_step, carlson_normalize, carlson_scale, carlson_scaled,
+    carlson_step, quarter, rc_one, rf_scaled, rj_dd, rj_positive,
+};
+use crate::exponents::ldexp;
+
+/// Computes Carlson's symmetric elliptic integral of the third kind
+///
+/// R_J(x, y, z, p) = 3/2 * integral(1/(sqrt((t + x)(t + y)(t + z)) * (t + p)), t = 0..inf).
+///
+/// x, y, z must be non-negative, and at most one of them can be zero.
+/// For p < 0 Cauchy principal value is returned.
+pub fn f_ellint_rj(x: f64, y: f64, z: f64, p: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || p.is_nan() || x < 0. || y < 0. || z < 0. {
+        return f64::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() || p.is_infinite() {
+        return 0.;
+    }
+    if p == 0. || (x == 0.) as u32 + (y == 0.) as u32 + (z == 0.) as u32 > 1 {
+        return f64::INFINITY;
+    }
+    let m = x.max(y).max(z);
+    if p > m * f64::from_bits(0x4ef0000000000000) {
+        // p > 2^240 * max(x, y, z),
+        // R_J(x, y, z, p) = 3 / p * R_F(x, y, z) - 3 * pi / (2 * p^(3/2)) + ...,
+        // where the correction is below 2^-120 relative.
+        let (rf, k) = rf_scaled(x, y, z);
+        let (pn, e) = carlson_normalize(DoubleDouble::new(0., p));
+        let r = DoubleDouble::div(DoubleDouble::quick_mult_f64(rf, 3.), pn);
+        return ldexp(r.to_f64(), -k - e);
+    }
+    // scale is chosen by x, y, z, since p is tiny or at most 2^240 times larger
+    let k = carlson_scale(&[x, y, z]);
+    if !carlson_needs_step(&[x, y, z, p], k) {
+        let r = rj_dd(
+            carlson_scaled(x, k),
+            carlson_scaled(y, k),
+            carlson_scaled(z, k),
+            carlson_scaled(p, k),
+        );
+        return ldexp(r.to_f64(), -3 * k);
+    }
+    if p < 0. {
+        // Principal value transformation doesn't commute with duplication,
+        // so arguments underflowed after scaling are clamped to the smallest
+        // representable magnitude, which keeps the result finite but inexact.
+        let clamped = |v: f64| {
+            let s = carlson_scaled(v, k);
+            if v != 0. && s.hi.abs() < f64::from_bits(0x07b0000000000000) {
+                DoubleDouble::new(0., f64::from_bits(0x07b0000000000000).copysign(v))
+            } else {
+                s
+            }
+        };
+        let r = rj_dd(clamped(x), clamped(y), clamped(z), clamped(p));
+        return ldexp(r.to_f64(), -3 * k);
+    }
+    // R_J(x, y, z, p) = R_J(x1, y1, z1, p1) / 4 + 6 / d * R_C(1, w),
+    // d = (sqrt(p) + sqrt(x))(sqrt(p) + sqrt(y))(sqrt(p) + sqrt(z)),
+    // w = 2 * sqrt(p) * (p + lambda) / d.
+    // 1 / d may exceed exponent range when p is tiny, so everything
+    // is computed in units of 2^-de.
+    let s = carlson_step([x, y, z, p], k);
+    let [x1, y1, z1, p1] = s.args;
+    let sp = s.sqrt[3];
+    let mut d = DoubleDouble::new(0., 1.);
+    let mut de = 0;
+    for &st in s.sqrt[..3].iter() {
+        let (f, fe) = carlson_normalize(DoubleDouble::full_dd_add(sp, st));
+        d = DoubleDouble::quick_mult(d, f);
+        de += fe;
+    }
+    let (u, ue) = carlson_normalize(sp);
+    let (v, ve) = carlson_normalize(DoubleDouble::full_dd_add(s.scaled[3], s.lambda));
+    let rd = d.recip();
+    let w = carlson_ldexp(
+        DoubleDouble::quick_mult(DoubleDouble::quick_mult(u, v), rd),
+        ue + ve - de + 1,
+    );
+    let r = DoubleDouble::mul_add(
+        DoubleDouble::quick_mult_f64(rd, 6.),
+        rc_one(w),
+        carlson_ldexp(quarter(rj_positive(x1, y1, z1, p1)), de),
+    );
+    ldexp(r.to_f64(), -3 * k - de)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_rj() {
+        assert_eq!(f_ellint_rj(0., 1., 2., 3.), 0.7768862377858233);
+        assert_eq!(f_ellint_rj(2., 3., 4., 5.), 0.14297579667156754);
+        assert_eq!(f_ellint_rj(2., 3., 4., -0.5), 0.24723819703051564);
+        assert_eq!(f_ellint_rj(1., 2., 3., 1e300), 2.1808378064067246e-300);
+        assert_eq!(
+            f_ellint_rj(1e300, 1e-300, 1e-300, 1e-300),
+            1.4999999999999999e150
+        );
+        assert_eq!(f_ellint_rj(1., 2., 1e-310, 1e-320), 2.589300202737332e156);
+        assert_eq!(f_ellint_rj(1., 1., 1., 0.), f64::INFINITY);
+        assert_eq!(f_ellint_rj(f64::INFINITY, 1., 1., 1.), 0.);
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::elliptic::carlson_coref::rjf;
+
+/// Computes Carlson's symmetric elliptic integral of the third kind
+///
+/// R_J(x, y, z, p) = 3/2 * integral(1/(sqrt((t + x)(t + y)(t + z)) * (t + p)), t = 0..inf).
+///
+/// x, y, z must be non-negative, and at most one of them can be zero.
+/// For p < 0 Cauchy principal value is returned.
+pub fn f_ellint_rjf(x: f32, y: f32, z: f32, p: f32) -> f32 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || p.is_nan() || x < 0. || y < 0. || z < 0. {
+        return f32::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() || p.is_infinite() {
+        return 0.;
+    }
+    if p == 0. || (x == 0.) as u32 + (y == 0.) as u32 + (z == 0.) as u32 > 1 {
+        return f32::INFINITY;
+    }
+    rjf(x as f64, y as f64, z as f64, p as f64) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellint_rjf() {
+        assert_eq!(f_ellint_rjf(0., 1., 2., 3.), 0.7768862);
+        assert_eq!(f_ellint_rjf(2., 3., 4., 5.), 0.14297579);
+        assert_eq!(f_ellint_rjf(2., 3., 4., -0.5), 0.2472382);
+        assert_eq!(f_ellint_rjf(1., 1., 1., 0.), f32::INFINITY);
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::elliptic::carlson_core::{carlson_scale, rd_dd, rf_dd, rj_dd};
+use crate::exponents::ldexp;
+use crate::rounding::CpuRound;
+use crate::sici::sincos_dd;
+
+pub(crate) const ZERO: DoubleDouble = DoubleDouble::new(0., 0.);
+pub(crate) const ONE: DoubleDouble = DoubleDouble::new(0., 1.);
+
+/// Computes complementary parameter 1 - k^2.
+#[inline]
+pub(crate) fn complementary_parameter(k: f64) -> DoubleDouble {
+    DoubleDouble::full_dd_sub(ONE, DoubleDouble::from_exact_mult(k, k))
+}
+
+/// K(k) = R_F(0, 1 - k^2, 1)
+#[inline]
+pub(crate) fn ellint_k_dd(kp2: DoubleDouble) -> DoubleDouble {
+    rf_dd(ZERO, kp2, ONE)
+}
+
+/// E(k) = R_F(0, 1 - k^2, 1) - k^2/3 * R_D(0, 1 - k^2, 1)
+#[inline]
+pub(crate) fn ellint_e_dd(k: f64, kp2: DoubleDouble) -> DoubleDouble {
+    let k2 = DoubleDouble::from_exact_mult(k, k);
+    DoubleDouble::full_dd_sub(
+        rf_dd(ZERO, kp2, ONE),
+        DoubleDouble::quick_mult(DoubleDouble::div_dd_f64(k2, 3.), rd_dd(ZERO, kp2, ONE)),
+    )
+}
+
+/// Pi(n, k) = R_F(0, 1 - k^2, 1) + n/3 * R_J(0, 1 - k^2, 1, 1 - n)
+///
+/// For n > 1 both terms nearly cancel when k is small, so principal value is taken from
+/// Pi(n, k) = K(k) - Pi(k^2/n, k) = -k^2/(3n) * R_J(0, 1 - k^2, 1, 1 - k^2/n) instead.
+pub(crate) fn ellint_pi_dd(n: f64, k: f64, kp2: DoubleDouble) -> DoubleDouble {
+    if n > 1. {
+        let m = DoubleDouble::div_dd_f64(DoubleDouble::from_exact_mult(k, k), n);
+        let p = DoubleDouble::full_dd_sub(ONE, m);
+        let rj = rj_dd(ZERO, kp2, ONE, p);
+        return -DoubleDouble::quick_mult(DoubleDouble::div_dd_f64(m, 3.), rj);
+    }
+    let p = DoubleDouble::from_full_exact_add(1., -n);
+    // R_J is homogeneous of degree -3/2, so large 1 - n is scaled down to avoid overflow
+    let e = carlson_scale(&[1., p.hi]);
+    let scale = ldexp(1., -2 * e);
+    let rj = rj_dd(
+        ZERO,
+        DoubleDouble::quick_mult_f64(kp2, scale),
+        DoubleDouble::new(0., scale),
+        DoubleDouble::quick_mult_f64(p, scale),
+    );
+    let rj = DoubleDouble::new(ldexp(rj.lo, -3 * e), ldexp(rj.hi, -3 * e));
+    DoubleDouble::full_dd_add(
+        rf_dd(ZERO, kp2, ONE),
+        DoubleDouble::quick_mult(DoubleDouble::div_dd_f64(DoubleDouble::new(0., n), 3.), rj),
+    )
+}
+
+/// Amplitude phi reduced as phi = m * pi + r, where |r| <= pi/2.
+pub(crate) struct Amplitude {
+    pub(crate) m: f64,
+    pub(crate) sin: DoubleDouble,
+    pub(crate) cos: DoubleDouble,
+}
+
+/// Reduces amplitude phi >= 0, returning m with sin(r) and cos(r).
+///
+/// m is chosen by the sign of cos(phi), so cos(r) >= 0 is always true.
+pub(crate) fn reduce_amplitude(phi: f64) -> Amplitude {
+    let (sin, cos) = sincos_dd(phi);
+    let q = phi * std::f64::consts::FRAC_1_PI;
+    let mut m = q.cpu_round();
+    if (cos.hi < 0.) != (m % 2. != 0.) {
+        m += if q > m { 1. } else { -1. };
+    }
+    if cos.hi < 0. {
+        Amplitude {
+            m,
+            sin: -sin,
+            cos: -cos,
+        }
+    } else {
+        Amplitude { m, sin, cos }
+    }
+}
+
+/// Computes 2 * m * period + r.
+#[inline]
+pub(crate) fn add_periods(m: f64, period: DoubleDouble, r: DoubleDouble) -> f64 {
+    if m == 0. {
+        return r.to_f64();
+    }
+    if m < f64::from_bits(0x5f30000000000000) {
+        // m < 2^500
+        return DoubleDouble::full_dd_add(DoubleDouble::quick_mult_f64(period, 2. * m), r).to_f64();
+    }
+    // r is negligible here, product is scaled by 2^-600 to avoid overflow in intermediate splits
+    DoubleDouble::quick_mult_f64(period, 2. * m * f64::from_bits(0x1a70000000000000)).to_f64()
+        * f64::from_bits(0x6570000000000000)
+}
+
+/// Incomplete integrals are expressed through R_F(cos^2(r), 1 - k^2 * sin^2(r), 1).
+pub(crate) struct IncompleteArgs {
+    pub(crate) sin: DoubleDouble,
+    pub(crate) sin2: DoubleDouble,
+    pub(crate) cos2: DoubleDouble,
+    pub(crate) delta: DoubleDouble,
+}
+
+#[inline]
+pub(crate) fn incomplete_args(amp: &Amplitude, k: f64, kp2: DoubleDouble) -> IncompleteArgs {
+    let sin2 = DoubleDouble::quick_mult(amp.sin, amp.sin);
+    let cos2 = DoubleDouble::quick_mult(amp.cos, amp.cos);
+    let delta = if k.abs() <= 1. {
+        // 1 - k^2 * sin^2(r) = cos^2(r) + (1 - k^2) * sin^2(r) has no cancellation
+        DoubleDouble::mul_add(kp2, sin2, cos2)
+    } else {
+        DoubleDouble::full_dd_sub(
+            ONE,
+            DoubleDouble::quick_mult(DoubleDouble::from_exact_mult(k, k), sin2),
+        )
+    };
+    IncompleteArgs {
+        sin: amp.sin,
+        sin2,
+        cos2,
+        delta,
+    }
+}
+
+/// F(r, k) = sin(r) * R_F(cos^2(r), 1 - k^2 * sin^2(r), 1)
+#[inline]
+pub(crate) fn ellint_f_reduced(args: &IncompleteArgs) -> DoubleDouble {
+    DoubleDouble::quick_mult(args.sin, rf_dd(args.cos2, args.delta, ONE))
+}
+
+/// E(r, k) = sin(r) * R_F(cos^2(r), 1 - k^2 * sin^2(r), 1)
+///         - k^2/3 * sin^3(r) * R_D(cos^2(r), 1 - k^2 * sin^2(r), 1)
+#[inline]
+pub(crate) fn ellint_e_reduced(args: &IncompleteArgs, k: f64) -> DoubleDouble {
+    let k2 = DoubleDouble::from_exact_mult(k, k);
+    let rd = rd_dd(args.cos2, args.delta, ONE);
+    let t = DoubleDouble::quick_mult(
+        DoubleDouble::div_dd_f64(DoubleDouble::quick_mult(k2, args.sin2), 3.),
+        rd,
+    );
+    DoubleDouble::quick_mult(
+        args.sin,
+        DoubleDouble::full_dd_sub(rf_dd(args.cos2, args.delta, ONE), t),
+    )
+}
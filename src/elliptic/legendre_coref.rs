@@ -0,0 +1,139 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::elliptic::carlson_coref::{rdf, rff, rjf};
+use crate::rounding::CpuRound;
+use crate::sin_cosf::sincosf_eval;
+
+/// Computes complementary parameter 1 - k^2, exact for single precision k.
+#[inline]
+pub(crate) fn complementary_parameterf(k: f64) -> f64 {
+    f_fmla(-k, k, 1.)
+}
+
+/// K(k) = R_F(0, 1 - k^2, 1)
+#[inline]
+pub(crate) fn ellint_kf_core(kp2: f64) -> f64 {
+    rff(0., kp2, 1.)
+}
+
+/// E(k) = R_F(0, 1 - k^2, 1) - k^2/3 * R_D(0, 1 - k^2, 1)
+#[inline]
+pub(crate) fn ellint_ef_core(k: f64, kp2: f64) -> f64 {
+    f_fmla(-k * k * (1. / 3.), rdf(0., kp2, 1.), rff(0., kp2, 1.))
+}
+
+/// Pi(n, k) = R_F(0, 1 - k^2, 1) + n/3 * R_J(0, 1 - k^2, 1, 1 - n)
+///
+/// For n > 1 both terms nearly cancel when k is small, so principal value is taken from
+/// Pi(n, k) = K(k) - Pi(k^2/n, k) = -k^2/(3n) * R_J(0, 1 - k^2, 1, 1 - k^2/n) instead.
+#[inline]
+pub(crate) fn ellint_pif_core(n: f64, k: f64, kp2: f64) -> f64 {
+    if n > 1. {
+        let m = k * k / n;
+        return -m * (1. / 3.) * rjf(0., kp2, 1., 1. - m);
+    }
+    f_fmla(n * (1. / 3.), rjf(0., kp2, 1., 1. - n), rff(0., kp2, 1.))
+}
+
+/// Amplitude phi reduced as phi = m * pi + r, where |r| <= pi/2.
+pub(crate) struct AmplitudeF {
+    pub(crate) m: f64,
+    pub(crate) sin: f64,
+    pub(crate) cos: f64,
+}
+
+/// Reduces amplitude phi >= 0, returning m with sin(r) and cos(r).
+///
+/// m is chosen by the sign of cos(phi), so cos(r) >= 0 is always true.
+pub(crate) fn reduce_amplitudef(phi: f32) -> AmplitudeF {
+    let xd = phi as f64;
+    let rs = sincosf_eval(xd, phi.to_bits() & 0x7fff_ffff);
+    let sin = f_fmla(rs.sin_y, rs.cos_k, f_fmla(rs.cosm1_y, rs.sin_k, rs.sin_k));
+    let cos = f_fmla(rs.sin_y, -rs.sin_k, f_fmla(rs.cosm1_y, rs.cos_k, rs.cos_k));
+    let q = xd * std::f64::consts::FRAC_1_PI;
+    let mut m = q.cpu_round();
+    if (cos < 0.) != (m % 2. != 0.) {
+        m += if q > m { 1. } else { -1. };
+    }
+    if cos < 0. {
+        AmplitudeF {
+            m,
+            sin: -sin,
+            cos: -cos,
+        }
+    } else {
+        AmplitudeF { m, sin, cos }
+    }
+}
+
+/// Incomplete integrals are expressed through R_F(cos^2(r), 1 - k^2 * sin^2(r), 1).
+pub(crate) struct IncompleteArgsF {
+    pub(crate) sin: f64,
+    pub(crate) sin2: f64,
+    pub(crate) cos2: f64,
+    pub(crate) delta: f64,
+}
+
+#[inline]
+pub(crate) fn incomplete_argsf(amp: &AmplitudeF, k: f64, kp2: f64) -> IncompleteArgsF {
+    let sin2 = amp.sin * amp.sin;
+    let cos2 = amp.cos * amp.cos;
+    let delta = if k <= 1. {
+        // 1 - k^2 * sin^2(r) = cos^2(r) + (1 - k^2) * sin^2(r) has no cancellation
+        f_fmla(kp2, sin2, cos2)
+    } else {
+        f_fmla(-k * k, sin2, 1.)
+    };
+    IncompleteArgsF {
+        sin: amp.sin,
+        sin2,
+        cos2,
+        delta,
+    }
+}
+
+/// F(r, k) = sin(r) * R_F(cos^2(r), 1 - k^2 * sin^2(r), 1)
+#[inline]
+pub(crate) fn ellint_f_reducedf(args: &IncompleteArgsF) -> f64 {
+    args.sin * rff(args.cos2, args.delta, 1.)
+}
+
+/// E(r, k) = sin(r) * R_F(cos^2(r), 1 - k^2 * sin^2(r), 1)
+///         - k^2/3 * sin^3(r) * R_D(cos^2(r), 1 - k^2 * sin^2(r), 1)
+#[inline]
+pub(crate) fn ellint_e_reducedf(args: &IncompleteArgsF, k: f64) -> f64 {
+    let rd = rdf(args.cos2, args.delta, 1.);
+    args.sin
+        * f_fmla(
+            -k * k * args.sin2 * (1. / 3.),
+            rd,
+            rff(args.cos2, args.delta, 1.),
+        )
+}
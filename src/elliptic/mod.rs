@@ -0,0 +1,69 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+mod carlson_core;
+mod carlson_coref;
+mod ellint_e;
+mod ellint_e_inc;
+mod ellint_e_incf;
+mod ellint_ef;
+mod ellint_f;
+mod ellint_ff;
+mod ellint_k;
+mod ellint_kf;
+mod ellint_pi;
+mod ellint_pif;
+mod ellint_rc;
+mod ellint_rcf;
+mod ellint_rd;
+mod ellint_rdf;
+mod ellint_rf;
+mod ellint_rff;
+mod ellint_rj;
+mod ellint_rjf;
+mod legendre_core;
+mod legendre_coref;
+
+pub use ellint_e::f_ellint_e;
+pub use ellint_e_inc::f_ellint_e_inc;
+pub use ellint_e_incf::f_ellint_e_incf;
+pub use ellint_ef::f_ellint_ef;
+pub use ellint_f::f_ellint_f;
+pub use ellint_ff::f_ellint_ff;
+pub use ellint_k::f_ellint_k;
+pub use ellint_kf::f_ellint_kf;
+pub use ellint_pi::f_ellint_pi;
+pub use ellint_pif::f_ellint_pif;
+pub use ellint_rc::f_ellint_rc;
+pub use ellint_rcf::f_ellint_rcf;
+pub use ellint_rd::f_ellint_rd;
+pub use ellint_rdf::f_ellint_rdf;
+pub use ellint_rf::f_ellint_rf;
+pub use ellint_rff::f_ellint_rff;
+pub use ellint_rj::f_ellint_rj;
+pub use ellint_rjf::f_ellint_rjf;
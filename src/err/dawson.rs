@@ -0,0 +1,132 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::err::dawson_coeffs::DAWSON_NODES;
+use crate::rounding::CpuRound;
+
+/// Computes Dawson's integral for 0 <= x <= 2^27 in double-double precision.
+pub(crate) fn dawson_dd(x: f64) -> DoubleDouble {
+    if x <= 12.0625 {
+        // Taylor expansion around the nearest node c = k/8, |x - c| <= 1/16.
+        // Since F'(x) = 1 - 2xF(x) coefficients satisfy
+        // a_1 = 1 - 2c*a_0, a_(n+1) = -(2c*a_n + 2a_(n-1)) / (n + 1).
+        let k = (x * 8.).cpu_round();
+        let c2 = k * 0.25;
+        let t = x - k * 0.125;
+        let mut a_prev = DoubleDouble::from_bit_pair(DAWSON_NODES[k as usize]);
+        let mut a_cur = DoubleDouble::mul_f64_add_f64(a_prev, -c2, 1.);
+        let mut tn = DoubleDouble::new(0., t);
+        let mut sum = DoubleDouble::mul_f64_add(a_cur, t, a_prev);
+        let mut prev_term = a_cur.hi * t;
+        let mut n = 1.;
+        loop {
+            let a_next = DoubleDouble::div_dd_f64(
+                DoubleDouble::mul_f64_add(a_cur, c2, DoubleDouble::quick_mult_f64(a_prev, 2.)),
+                -(n + 1.),
+            );
+            tn = DoubleDouble::quick_mult_f64(tn, t);
+            let term = DoubleDouble::quick_mult(a_next, tn);
+            sum = DoubleDouble::add(sum, term);
+            // Odd or even coefficients may vanish, so check two consecutive terms
+            if (term.hi.abs() + prev_term.abs()) < f64::from_bits(0x3910000000000000) * sum.hi.abs()
+            {
+                break;
+            }
+            prev_term = term.hi;
+            a_prev = a_cur;
+            a_cur = a_next;
+            n += 1.;
+        }
+        return sum;
+    }
+    // Asymptotic expansion F(x) ~ 1/(2x) * sum((2n-1)!! / (2x^2)^n)
+    let x2 = DoubleDouble::from_exact_mult(x, x);
+    let r = DoubleDouble::quick_mult_f64(x2, 2.).recip();
+    let mut term = r;
+    let mut sum = DoubleDouble::full_add_f64(term, 1.);
+    let mut n = 3.;
+    while term.hi > f64::from_bits(0x3910000000000000) {
+        term = DoubleDouble::quick_mult(DoubleDouble::quick_mult_f64(term, n), r);
+        sum = DoubleDouble::add(sum, term);
+        n += 2.;
+    }
+    DoubleDouble::div_dd_f64(sum, 2. * x)
+}
+
+/// Computes Dawson's integral F(x) = exp(-x^2) * int(exp(t^2), t = 0..x)
+pub fn f_dawson(x: f64) -> f64 {
+    let ax = x.abs();
+    if !x.is_finite() {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        // F(+-inf) = +-0
+        return 0.0f64.copysign(x);
+    }
+    if ax < f64::from_bits(0x3e40000000000000) {
+        // |x| < 2^-27, F(x) ~ x - 2/3 * x^3
+        const M_TWO_THIRDS: f64 = f64::from_bits(0xbfe5555555555555);
+        return f_fmla(x * x * M_TWO_THIRDS, x, x);
+    }
+    if ax > f64::from_bits(0x41a0000000000000) {
+        // |x| > 2^27, F(x) ~ 1/(2x) + 1/(4x^3)
+        if ax > f64::from_bits(0x43b0000000000000) {
+            // |x| > 2^60
+            return 0.5 / x;
+        }
+        let r = DoubleDouble::from_exact_div(0.5, x);
+        return f_fmla(r.hi, 0.5 / (x * x), r.lo) + r.hi;
+    }
+    let v = dawson_dd(ax).to_f64();
+    if x < 0. { -v } else { v }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dawson() {
+        assert_eq!(f_dawson(0.5), 0.4244363835020223);
+        assert_eq!(f_dawson(1.), 0.5380795069127684);
+        assert_eq!(f_dawson(-2.25), -0.25655426284484917);
+        assert_eq!(f_dawson(5.), 0.10213407442427684);
+        assert_eq!(f_dawson(12.), 0.04181287645398826);
+        assert_eq!(f_dawson(12.5), 0.040129248914351476);
+        assert_eq!(f_dawson(-40.), -0.012503909917843973);
+        assert_eq!(f_dawson(1e-10), 1e-10);
+        assert_eq!(f_dawson(3e-320), 3e-320);
+        assert_eq!(f_dawson(1e10), 5e-11);
+        assert_eq!(f_dawson(1e300), 5e-301);
+        assert_eq!(f_dawson(f64::INFINITY), 0.);
+        assert_eq!(f_dawson(f64::NEG_INFINITY), -0.);
+        assert!(f_dawson(f64::NAN).is_nan());
+    }
+}
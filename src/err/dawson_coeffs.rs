@@ -0,0 +1,142 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+/**
+Dawson's integral F(k/8), k = 0..96 as double-double.
+
+Generated by SageMath:
+```python
+mp.prec = 300
+for k in range(97):
+    x = mpf(k) / 8
+    v = sqrt(pi) / 2 * exp(-x * x) * erfi(x)
+    hi = float(v)
+    lo = float(v - hi)
+    print(f"({double_to_hex(lo)}, {double_to_hex(hi)}),")
+```
+**/
+pub(crate) static DAWSON_NODES: [(u64, u64); 97] = [
+    (0x0000000000000000, 0x0000000000000000),
+    (0x3c5de3a9aa63437c, 0x3fbfab3297b3c14b),
+    (0xbc6688926053f3ec, 0x3fceb30cb9e6bcff),
+    (0xbc3a13b11033a61e, 0x3fd5df234be8ee5d),
+    (0x3c689ad28ffcb887, 0x3fdb29f73897eab2),
+    (0xbc70462896ac4e2c, 0x3fdf0b436f129dc8),
+    (0x3c8656f610b966ff, 0x3fe0bc85459b4d00),
+    (0xbc8ba2f0d5d14cfd, 0x3fe14536cd2733a7),
+    (0x3c7436465698c604, 0x3fe137f2839ad218),
+    (0x3c4eac43bdf08606, 0x3fe0b4a33d5ed085),
+    (0xbc5f53d641331d97, 0x3fdfbba17ac97b5a),
+    (0x3c6e312d0ce99b13, 0x3fdda89f8697e996),
+    (0x3c6af9b0ebd9e7ef, 0x3fdb686ecab6aaa9),
+    (0xbc79ae7dc9807dd6, 0x3fd9268763ce5834),
+    (0x3c4903ed6a3ed4dc, 0x3fd701019df1b119),
+    (0xbc7dc37fc3b3d3af, 0x3fd509d35659a39c),
+    (0x3c76a0fd93ec3c80, 0x3fd3492932d91017),
+    (0xbc541262a4243ca5, 0x3fd1c021ffa32d8c),
+    (0xbc6ed2ec8c7b2d32, 0x3fd06b6292245a5c),
+    (0x3c4fe6fb75239cea, 0x3fce8a63fd0badcc),
+    (0xbc6e2b09b6a84258, 0x3fcc8e01e57d52ae),
+    (0x3c5ee894d55c99f6, 0x3fcad4c69fd6ed9c),
+    (0x3c0e8d48635b0035, 0x3fc9532e09cc3d30),
+    (0x3c523d2b89ecceed, 0x3fc7ff7316a599e1),
+    (0xbc627c5b76468fc5, 0x3fc6d195cb25f5c5),
+    (0xbc5689ee75c56895, 0x3fc5c32c4fc69529),
+    (0xbc696d6125723df9, 0x3fc4cf1faca8d3b6),
+    (0x3c4067698e06b5b6, 0x3fc3f167566d6b98),
+    (0x3c495114591d40f0, 0x3fc326cce4875497),
+    (0xbc6ff38764941114, 0x3fc26cbb14b899cf),
+    (0xbc6f64ce8338225d, 0x3fc1c117e1eb4e06),
+    (0x3c69c7f7509d48d6, 0x3fc12227ff4f096c),
+    (0xbc5f1d91244ddf7f, 0x3fc08e79ad8e4939),
+    (0x3c4f4d3aba6c73ce, 0x3fc004d50d47dfaf),
+    (0x3c5cb5ec8ea3f312, 0x3fbf0860df102757),
+    (0xbc575f072de64f1c, 0x3fbe174f0ad4fe64),
+    (0xbc44ee98926185a9, 0x3fbd34e941c532ac),
+    (0xbc5feae997121828, 0x3fbc5fd6eb9643b5),
+    (0xbc565a4c9b42f4b5, 0x3fbb96e9ebf5ab86),
+    (0xbc5f84d3682f0ea5, 0x3fbad91802825b70),
+    (0xbc5a4067448451af, 0x3fba25756d75a1bb),
+    (0x3c5d12e3445151ff, 0x3fb97b3085e1ca78),
+    (0x3c5b7b664e8b9130, 0x3fb8d98e1fba2e88),
+    (0x3c45c28f7fb00bf6, 0x3fb83fe6855a0659),
+    (0xbc51bd86095c573f, 0x3fb7ada2efe042fe),
+    (0x3c33e69fb9b83991, 0x3fb7223b64b5764c),
+    (0x3c4cf5d4a1626b1b, 0x3fb69d34e5bd065c),
+    (0x3c5cdeab78c0fe02, 0x3fb61e1fe595ad94),
+    (0xbc303f596148f7b3, 0x3fb5a496f442f5f7),
+    (0x3c5194df81e53974, 0x3fb5303d9ae17198),
+    (0xbc5d9b9740e35d03, 0x3fb4c0bf5ece7720),
+    (0x3c5c8e36bfbfbe16, 0x3fb455cee611f798),
+    (0x3c3a671a81b2a62b, 0x3fb3ef2537f4bd98),
+    (0xbc46924f3ea94496, 0x3fb38c81159019f4),
+    (0x3c5f4810b3ea82e1, 0x3fb32da666da6986),
+    (0x3c4efe0fc5432b71, 0x3fb2d25db947935c),
+    (0xbc401856d2e18777, 0x3fb27a73cd8cef83),
+    (0xbc5226037780ab05, 0x3fb225b9327a93ec),
+    (0x3c574c3facd1d0f9, 0x3fb1d401eb2d297d),
+    (0x3c5efcc65fe76934, 0x3fb185251f1e8e42),
+    (0xbc3d660768d4d451, 0x3fb138fcd2d356e2),
+    (0x3c5085e1980ec6b9, 0x3fb0ef65a811d695),
+    (0x3c4cdcc84cc8957c, 0x3fb0a83ea4b6607a),
+    (0x3c48e1a3b87364b3, 0x3fb06368ff595027),
+    (0x3c50eed3defbcd46, 0x3fb020c7f117274a),
+    (0x3c4bf209377febef, 0x3fafc08117c52623),
+    (0x3c3b8d686c30a05b, 0x3faf437329b858db),
+    (0xbc480494c1f8b689, 0x3faeca36c479ad2d),
+    (0xbc415308d61e54ea, 0x3fae549f78dc731a),
+    (0x3c44f9a301ea99e2, 0x3fade28383f2bd93),
+    (0x3c43e00be4bcdb0a, 0x3fad73bb9bfc7fbc),
+    (0x3c4e17e5ddd711b6, 0x3fad0822c1e0cc3f),
+    (0xbc2bfc5bdc5e2f56, 0x3fac9f9616b9915d),
+    (0x3c4eb250508ea562, 0x3fac39f4b5082614),
+    (0x3bf62ba319930d3e, 0x3fabd71f8d3523cf),
+    (0x3c4c1e7548f16445, 0x3fab76f945089f4c),
+    (0xbc307ac943344235, 0x3fab196619d1140c),
+    (0xbc3260491a0b15cd, 0x3faabe4bc4f76eb3),
+    (0xbc1dc0c989a9f1dc, 0x3faa659162c5bdbf),
+    (0x3c245ec998f893a7, 0x3faa0f1f5b2c4e7f),
+    (0xbc4db68d9864e47c, 0x3fa9badf4c567c53),
+    (0x3c323240c4976e5b, 0x3fa968bbf6e55290),
+    (0xbc459c783fb1faff, 0x3fa918a12baa6bc0),
+    (0xbc31d605cc82fe0c, 0x3fa8ca7bbac14569),
+    (0xbc2ba2f27ba9d956, 0x3fa87e3963e89e86),
+    (0x3c4177a5231a71d6, 0x3fa833c8c800754e),
+    (0x3c174abb52015108, 0x3fa7eb195b93e366),
+    (0xbc3d89b582653be5, 0x3fa7a41b5a5877bd),
+    (0xbc4b751dd171f3da, 0x3fa75ebfbb8ecc72),
+    (0x3c441cbbb5f4f83b, 0x3fa71af82731fc9b),
+    (0xbc4c81a88efe1e7d, 0x3fa6d8b6ebe55096),
+    (0x3c4d6f3f3ddf3453, 0x3fa697eef590fd48),
+    (0x3c460743dd0e1384, 0x3fa65893c4a030ea),
+    (0xbc450a3bd46425e1, 0x3fa61a9965d3e271),
+    (0xbc2e4a753066c180, 0x3fa5ddf46a9ef43b),
+    (0x3c441b7a908fc5cb, 0x3fa5a299e20139e7),
+    (0xbc43db061e71c7f8, 0x3fa5687f51d7d7c4),
+];
@@ -0,0 +1,115 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::err::dawson_coeffs::DAWSON_NODES;
+use crate::rounding::CpuRound;
+
+/// Computes Dawson's integral for finite x >= 2^-13.
+pub(crate) fn dawsonf_core(x: f64) -> f64 {
+    if x <= 12.0625 {
+        // Taylor expansion around the nearest node c = k/8, |x - c| <= 1/16,
+        // see [crate::f_dawson] for the recurrence.
+        let k = (x * 8.).cpu_round();
+        let c2 = k * 0.25;
+        let t = x - k * 0.125;
+        let mut a_prev = f64::from_bits(DAWSON_NODES[k as usize].1);
+        let mut a_cur = f_fmla(a_prev, -c2, 1.);
+        let mut tn = t;
+        let mut sum = f_fmla(a_cur, t, a_prev);
+        let mut prev_term = a_cur * t;
+        let mut n = 1.;
+        loop {
+            let a_next = f_fmla(a_cur, c2, 2. * a_prev) / -(n + 1.);
+            tn *= t;
+            let term = a_next * tn;
+            sum += term;
+            if (term.abs() + prev_term.abs()) < f64::from_bits(0x3c30000000000000) * sum.abs() {
+                break;
+            }
+            prev_term = term;
+            a_prev = a_cur;
+            a_cur = a_next;
+            n += 1.;
+        }
+        return sum;
+    }
+    // Asymptotic expansion F(x) ~ 1/(2x) * sum((2n-1)!! / (2x^2)^n)
+    let r = 0.5 / (x * x);
+    let mut term = r;
+    let mut sum = 1. + term;
+    let mut n = 3.;
+    while term > f64::from_bits(0x3c30000000000000) {
+        term *= n * r;
+        sum += term;
+        n += 2.;
+    }
+    sum / (2. * x)
+}
+
+/// Computes Dawson's integral F(x) = exp(-x^2) * int(exp(t^2), t = 0..x)
+///
+/// ulp 0.5
+pub fn f_dawsonf(x: f32) -> f32 {
+    let ax = x.abs();
+    if !x.is_finite() {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        // F(+-inf) = +-0
+        return 0.0f32.copysign(x);
+    }
+    let dx = x as f64;
+    if ax < f32::from_bits(0x39000000) {
+        // |x| < 2^-13, F(x) ~ x - 2/3 * x^3
+        const M_TWO_THIRDS: f64 = f64::from_bits(0xbfe5555555555555);
+        return f_fmla(dx * dx * M_TWO_THIRDS, dx, dx) as f32;
+    }
+    let v = dawsonf_core(ax as f64);
+    (if x < 0. { -v } else { v }) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dawsonf() {
+        assert_eq!(f_dawsonf(0.5), 0.4244364);
+        assert_eq!(f_dawsonf(1.), 0.5380795);
+        assert_eq!(f_dawsonf(-2.25), -0.25655428);
+        assert_eq!(f_dawsonf(5.), 0.10213407);
+        assert_eq!(f_dawsonf(12.), 0.041812878);
+        assert_eq!(f_dawsonf(12.5), 0.040129248);
+        assert_eq!(f_dawsonf(-40.), -0.01250391);
+        assert_eq!(f_dawsonf(1e-10), 1e-10);
+        assert_eq!(f_dawsonf(1e30), 5e-31);
+        assert_eq!(f_dawsonf(f32::INFINITY), 0.);
+        assert!(f_dawsonf(f32::NAN).is_nan());
+    }
+}
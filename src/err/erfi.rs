@@ -0,0 +1,93 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::{dyad_fmla, f_fmla};
+use crate::double_double::DoubleDouble;
+use crate::err::dawson::dawson_dd;
+use crate::exponents::mul_exp_pos;
+
+/// Imaginary error function erfi(x) = -i*erf(ix)
+pub fn f_erfi(x: f64) -> f64 {
+    let ax = x.abs();
+    if !x.is_finite() {
+        // erfi(NaN) = NaN, erfi(+-inf) = +-inf
+        return x;
+    }
+    const TWO_OVER_SQRT_PI: DoubleDouble =
+        DoubleDouble::from_bit_pair((0x3c71ae3a914fed80, 0x3ff20dd750429b6d));
+    if ax < f64::from_bits(0x3c20000000000000) {
+        // |x| < 2^-61, erfi(x) ~ 2/sqrt(pi) * x, the ratio of x^3 term to the main term
+        // is less than 2^-123
+        if x == 0. {
+            return x;
+        }
+        let y = TWO_OVER_SQRT_PI.hi * x; /* tentative result */
+        /* scale x by 2^106 to get out the subnormal range */
+        let sx = x * f64::from_bits(0x4690000000000000);
+        let mut p = DoubleDouble::quick_mult_f64(TWO_OVER_SQRT_PI, sx);
+        /* now compute the residual h + l - y */
+        p.lo += f_fmla(-y, f64::from_bits(0x4690000000000000), p.hi);
+        return dyad_fmla(p.lo, f64::from_bits(0x3950000000000000), y);
+    }
+    if ax < f64::from_bits(0x3e40000000000000) {
+        // |x| < 2^-27, erfi(x) ~ 2/sqrt(pi) * (x + x^3/3)
+        const ONE_THIRD: f64 = f64::from_bits(0x3fd5555555555555);
+        let p = DoubleDouble::quick_mult_f64(TWO_OVER_SQRT_PI, x);
+        return f_fmla(p.hi, x * x * ONE_THIRD, p.lo) + p.hi;
+    }
+    if ax > 27. {
+        // erfi(x) ~ exp(x^2) / (sqrt(pi) * x) overflows
+        return f64::INFINITY.copysign(x);
+    }
+    // erfi(x) = 2/sqrt(pi) * exp(x^2) * F(x), F is Dawson's integral
+    let f = DoubleDouble::quick_mult(TWO_OVER_SQRT_PI, dawson_dd(ax));
+    let v = mul_exp_pos(f, DoubleDouble::from_exact_mult(ax, ax));
+    if x < 0. { -v } else { v }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfi() {
+        assert_eq!(f_erfi(0.5), 0.614952094696511);
+        assert_eq!(f_erfi(1.), 1.6504257587975428);
+        assert_eq!(f_erfi(-2.25), -45.73515014065393);
+        assert_eq!(f_erfi(5.), 8298273880.676804);
+        assert_eq!(f_erfi(12.), 1.6299357995243493e61);
+        assert_eq!(f_erfi(26.), 8.314637164730988e291);
+        assert_eq!(f_erfi(27.), f64::INFINITY);
+        assert_eq!(f_erfi(1e-10), 1.1283791670955126e-10);
+        assert_eq!(f_erfi(3e-320), 3.3853e-320);
+        assert_eq!(f_erfi(-1e-300), -1.1283791670955126e-300);
+        assert_eq!(f_erfi(f64::INFINITY), f64::INFINITY);
+        assert_eq!(f_erfi(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(f_erfi(f64::NAN).is_nan());
+    }
+}
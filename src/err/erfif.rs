@@ -0,0 +1,75 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::err::dawsonf::dawsonf_core;
+use crate::exponents::core_expdf;
+
+/// Imaginary error function erfi(x) = -i*erf(ix)
+///
+/// ulp 0.5
+pub fn f_erfif(x: f32) -> f32 {
+    let ax = x.abs();
+    if !x.is_finite() {
+        // erfi(NaN) = NaN, erfi(+-inf) = +-inf
+        return x;
+    }
+    const TWO_OVER_SQRT_PI: f64 = f64::from_bits(0x3ff20dd750429b6d);
+    let dx = x as f64;
+    if ax < f32::from_bits(0x39000000) {
+        // |x| < 2^-13, erfi(x) ~ 2/sqrt(pi) * (x + x^3/3)
+        const ONE_THIRD: f64 = f64::from_bits(0x3fd5555555555555);
+        return (TWO_OVER_SQRT_PI * f_fmla(dx * dx * ONE_THIRD, dx, dx)) as f32;
+    }
+    if ax > 10. {
+        // erfi(x) ~ exp(x^2) / (sqrt(pi) * x) overflows
+        return f32::INFINITY.copysign(x);
+    }
+    // erfi(x) = 2/sqrt(pi) * exp(x^2) * F(x), F is Dawson's integral
+    let v = TWO_OVER_SQRT_PI * dawsonf_core(ax as f64) * core_expdf(dx * dx);
+    (if x < 0. { -v } else { v }) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfif() {
+        assert_eq!(f_erfif(0.5), 0.6149521);
+        assert_eq!(f_erfif(1.), 1.6504258);
+        assert_eq!(f_erfif(-2.25), -45.73515);
+        assert_eq!(f_erfif(5.), 8.298274e9);
+        assert_eq!(f_erfif(9.5), 9.35877e37);
+        assert_eq!(f_erfif(10.), f32::INFINITY);
+        assert_eq!(f_erfif(1e-10), 1.1283792e-10);
+        assert_eq!(f_erfif(-1e-05), -1.12837915e-5);
+        assert_eq!(f_erfif(f32::INFINITY), f32::INFINITY);
+        assert!(f_erfif(f32::NAN).is_nan());
+    }
+}
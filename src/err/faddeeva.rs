@@ -0,0 +1,278 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::err::dawson::dawson_dd;
+use crate::err::erfcx::f_erfcx;
+use crate::exponents::mul_exp_neg;
+use crate::pow_exec::exp_dd_fast;
+use crate::sici::{ComplexDD, sincos_dd};
+use crate::sincos::f_sincos;
+
+const TWO_OVER_SQRT_PI: DoubleDouble =
+    DoubleDouble::from_bit_pair((0x3c71ae3a914fed80, 0x3ff20dd750429b6d));
+
+/// Computes exp(-z^2) = exp(y^2 - x^2) * (cos(2xy) - i*sin(2xy)) for y^2 - x^2 < 709.
+#[inline]
+fn exp_neg_z2(x: f64, y: f64) -> ComplexDD {
+    let d = DoubleDouble::full_dd_sub(
+        DoubleDouble::from_exact_mult(y, y),
+        DoubleDouble::from_exact_mult(x, x),
+    );
+    let e = exp_dd_fast(d);
+    let xy = DoubleDouble::from_exact_mult(2. * x.abs(), y.abs());
+    let (mut sin, mut cos) = sincos_dd(xy.hi);
+    // sin(a + b) ~ sin(a) + b*cos(a), cos(a + b) ~ cos(a) - b*sin(a)
+    let s0 = sin;
+    sin = DoubleDouble::mul_f64_add(cos, xy.lo, sin);
+    cos = DoubleDouble::mul_f64_add(s0, -xy.lo, cos);
+    if (x < 0.) != (y < 0.) {
+        sin = -sin;
+    }
+    ComplexDD {
+        re: DoubleDouble::quick_mult(e, cos),
+        im: -DoubleDouble::quick_mult(e, sin),
+    }
+}
+
+/// Computes w(z) for 0 <= x < 7, 0 <= y < 2.
+///
+/// w(z) = exp(-z^2) * (1 + 2i/sqrt(pi) * z * sum(z^(2k) / (k! * (2k + 1)), k = 0..inf)),
+/// relative cancellation here is bounded by exp(2y^2).
+fn faddeeva_series(x: f64, y: f64) -> (f64, f64) {
+    let q = ComplexDD {
+        re: DoubleDouble::full_dd_sub(
+            DoubleDouble::from_exact_mult(x, x),
+            DoubleDouble::from_exact_mult(y, y),
+        ),
+        im: DoubleDouble::from_exact_mult(2. * x, y),
+    };
+    let q_abs = x * x + y * y;
+    let mut term = ComplexDD {
+        re: DoubleDouble::new(0., 1.),
+        im: DoubleDouble::new(0., 0.),
+    };
+    let mut sum = term;
+    let mut k = 1.;
+    loop {
+        term = term.mul(q);
+        term.re = DoubleDouble::div_dd_f64(term.re, k);
+        term.im = DoubleDouble::div_dd_f64(term.im, k);
+        let d = 1. / (2. * k + 1.);
+        sum.re = DoubleDouble::add(sum.re, DoubleDouble::div_dd_f64(term.re, 2. * k + 1.));
+        sum.im = DoubleDouble::add(sum.im, DoubleDouble::div_dd_f64(term.im, 2. * k + 1.));
+        let t = (term.re.hi.abs() + term.im.hi.abs()) * d;
+        if k > q_abs && t < f64::from_bits(0x3910000000000000) * (sum.re.hi.abs() + sum.im.hi.abs())
+        {
+            break;
+        }
+        k += 1.;
+    }
+    // s = z * sum
+    let s = sum.mul(ComplexDD {
+        re: DoubleDouble::new(0., x),
+        im: DoubleDouble::new(0., y),
+    });
+    // a = 1 + 2i/sqrt(pi) * s
+    let a = ComplexDD {
+        re: DoubleDouble::full_add_f64(-DoubleDouble::quick_mult(TWO_OVER_SQRT_PI, s.im), 1.),
+        im: DoubleDouble::quick_mult(TWO_OVER_SQRT_PI, s.re),
+    };
+    let w = exp_neg_z2(x, y).mul(a);
+    (w.re.to_f64(), w.im.to_f64())
+}
+
+/// Computes w(z) for x >= 0, y > 0 outside of [0, 7) x [0, 2) using Laplace
+/// continued fraction w(z) = i/sqrt(pi) * 1/(z -) 1/2/(z -) 1/(z -) 3/2/(z -) ...
+fn faddeeva_cf(x: f64, y: f64) -> (f64, f64) {
+    if x > f64::from_bits(0x4630000000000000) || y > f64::from_bits(0x4630000000000000) {
+        // |z| > 2^100, w(z) ~ i/(sqrt(pi) * z) = (y + ix) / (sqrt(pi) * |z|^2)
+        const ONE_OVER_SQRT_PI: f64 = f64::from_bits(0x3fe20dd750429b6d);
+        let (re, im) = if x >= y {
+            let t = y / x;
+            let d = ONE_OVER_SQRT_PI / (1. + t * t);
+            ((t / x) * d, d / x)
+        } else {
+            let t = x / y;
+            let d = ONE_OVER_SQRT_PI / (1. + t * t);
+            (d / y, (t / y) * d)
+        };
+        return (re, im);
+    }
+    let nu = (10. + 300. / (y * y + 0.5 * x * x)) as i32;
+    let mut rx = 0.;
+    let mut ry = 0.;
+    for n in (1..=nu + 1).rev() {
+        let n = n as f64;
+        let tx = y + n * rx;
+        let ty = x - n * ry;
+        let c = 0.5 / (tx * tx + ty * ty);
+        rx = c * tx;
+        ry = c * ty;
+    }
+    let mut re = TWO_OVER_SQRT_PI.hi * rx;
+    let mut im = TWO_OVER_SQRT_PI.hi * ry;
+    if y < 1. && x < 27.3 {
+        // Continued fraction recovers only the Dawson part of w(z) near real axis,
+        // thus exp(-z^2) should be added
+        let d = DoubleDouble::full_dd_sub(
+            DoubleDouble::from_exact_mult(x, x),
+            DoubleDouble::from_exact_mult(y, y),
+        );
+        let e = mul_exp_neg(DoubleDouble::new(0., 1.), d);
+        let (sin, cos) = f_sincos(2. * x * y);
+        re += e * cos;
+        im -= e * sin;
+    }
+    (re, im)
+}
+
+/// Computes Faddeeva function w(z) = exp(-z^2) * erfc(-iz) for z = x + iy
+///
+/// Returns (Re w(z), Im w(z)).
+/// Max found error ~4 ulp relative to |w(z)|, not correctly rounded.
+pub fn f_faddeeva(x: f64, y: f64) -> (f64, f64) {
+    if x.is_nan() || y.is_nan() {
+        return (f64::NAN, f64::NAN);
+    }
+    if x == 0. {
+        // w(iy) = erfcx(y)
+        return (f_erfcx(y), 0.0f64.copysign(x));
+    }
+    let ax = x.abs();
+    if y == 0. {
+        // w(x) = exp(-x^2) + 2i/sqrt(pi) * F(x), F is Dawson's integral
+        if ax.is_infinite() {
+            return (0., 0.0f64.copysign(x));
+        }
+        let re = if ax < 27.3 {
+            mul_exp_neg(
+                DoubleDouble::new(0., 1.),
+                DoubleDouble::from_exact_mult(ax, ax),
+            )
+        } else {
+            0.
+        };
+        let im = if ax <= f64::from_bits(0x41a0000000000000) {
+            DoubleDouble::quick_mult(TWO_OVER_SQRT_PI, dawson_dd(ax)).to_f64()
+        } else {
+            faddeeva_cf(ax, 0.).1
+        };
+        return (re, im.copysign(x));
+    }
+    if y.is_infinite() || ax.is_infinite() {
+        if y < 0. {
+            return (f64::NAN, f64::NAN);
+        }
+        return (0., 0.0f64.copysign(x));
+    }
+    let ay = y.abs();
+    // w(-x + iy) = conj(w(x + iy))
+    let flip = if y < 0. { x > 0. } else { x < 0. };
+    let (re, mut im) = if ax < 7. && ay < 2. {
+        faddeeva_series(ax, ay)
+    } else {
+        faddeeva_cf(ax, ay)
+    };
+    if flip {
+        im = -im;
+    }
+    if y > 0. {
+        return (re, im);
+    }
+    // w(z) = 2 * exp(-z^2) - w(-z)
+    let d = y * y - x * x;
+    if d > 710. {
+        return (f64::NAN, f64::NAN);
+    }
+    if d < -746. {
+        return (-re, -im);
+    }
+    let e = exp_neg_z2(x, y);
+    (
+        DoubleDouble::mul_f64_add_f64(e.re, 2., -re).to_f64(),
+        DoubleDouble::mul_f64_add_f64(e.im, 2., -im).to_f64(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_faddeeva() {
+        assert_eq!(
+            f_faddeeva(0.5, 0.5),
+            (0.533156707912175, 0.2304882313844584)
+        );
+        assert_eq!(
+            f_faddeeva(1., 0.),
+            (0.36787944117144233, 0.6071577058413937)
+        );
+        assert_eq!(
+            f_faddeeva(-2., 0.),
+            (0.01831563888873418, -0.3400262170660662)
+        );
+        assert_eq!(f_faddeeva(0., 1.5), (0.3215854164543175, 0.));
+        assert_eq!(
+            f_faddeeva(3., 1e-3),
+            (0.0002019724245573203, 0.20115654204559758)
+        );
+        assert_eq!(
+            f_faddeeva(8., 0.5),
+            (0.004496705370059768, 0.07080011061892225)
+        );
+        assert_eq!(
+            f_faddeeva(-1.5, 2.5),
+            (0.1651358180237101, -0.08922180006361186)
+        );
+        assert_eq!(
+            f_faddeeva(20., 20.),
+            (0.014113538470519282, 0.01409590764933707)
+        );
+        assert_eq!(
+            f_faddeeva(1., -1.),
+            (-1.1370378783511974, 2.026813791854195)
+        );
+        assert_eq!(
+            f_faddeeva(-2.5, -0.75),
+            (-0.08426481920907854, -0.21223583921221967)
+        );
+        assert_eq!(
+            f_faddeeva(1e-20, 3.),
+            (0.17900115118138996, 5.437226000717288e-22)
+        );
+        assert_eq!(
+            f_faddeeva(1e200, 1e199),
+            (5.586035480670856e-202, 5.5860354806708545e-201)
+        );
+        assert_eq!(f_faddeeva(f64::INFINITY, 1.), (0., 0.));
+        let w = f_faddeeva(f64::NAN, 1.);
+        assert!(w.0.is_nan() && w.1.is_nan());
+    }
+}
@@ -27,6 +27,9 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 #![deny(unreachable_pub)]
+mod dawson;
+mod dawson_coeffs;
+mod dawsonf;
 mod erf;
 mod erf_poly;
 mod erfc;
@@ -34,23 +37,36 @@ mod erfcx;
 mod erfcxf;
 mod erff;
 mod erffc;
+mod erfi;
+mod erfif;
+mod faddeeva;
 mod inverf;
 mod inverfc;
 mod inverfcf;
 mod inverff;
+mod owens_t;
 mod rerf;
 mod rerf_poly;
 mod rerff;
+mod voigt;
 
+pub use dawson::f_dawson;
+pub use dawsonf::f_dawsonf;
+pub(crate) use erf::erf_fast;
 pub use erf::f_erf;
 pub use erfc::f_erfc;
 pub use erfcx::f_erfcx;
 pub use erfcxf::f_erfcxf;
 pub use erff::f_erff;
 pub use erffc::f_erfcf;
+pub use erfi::f_erfi;
+pub use erfif::f_erfif;
+pub use faddeeva::f_faddeeva;
 pub use inverf::f_erfinv;
 pub use inverfc::f_erfcinv;
 pub use inverfcf::f_erfcinvf;
 pub use inverff::f_erfinvf;
+pub use owens_t::f_owens_t;
 pub use rerf::f_rerf;
 pub use rerff::f_rerff;
+pub use voigt::f_voigt;
@@ -0,0 +1,195 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::err::erfcx::f_erfcx;
+use crate::exponents::{f_exp, mul_exp_neg};
+use crate::tangent::f_atan;
+
+const ONE_OVER_2PI: DoubleDouble =
+    DoubleDouble::from_bit_pair((0xbc66b01ec5417056, 0x3fc45f306dc9c883));
+const FRAC_1_SQRT_2: f64 = f64::from_bits(0x3fe6a09e667f3bcd);
+
+/**
+Gauss-Legendre rule of order 32 on [-1, 1], positive nodes as (node, weight).
+
+Generated by SageMath:
+```python
+mp.prec = 200
+p = legendre_P(32, x)
+for r in sorted(r for r in p.roots(ring=RealField(200), multiplicities=False) if r > 0):
+    w = 2 / ((1 - r^2) * p.derivative(x)(r)^2)
+    print(f"({double_to_hex(r)}, {double_to_hex(w)}),")
+```
+**/
+static GAUSS_LEGENDRE_32: [(u64, u64); 16] = [
+    (0x3fa8bbc8488cc499, 0x3fb8b6d9eaec77ad),
+    (0x3fc27e0ea717f237, 0x3fb87bc776f8c6d7),
+    (0x3fcea0f7e19c094b, 0x3fb8062fc0f6fef9),
+    (0x3fd53d55ce57bdf6, 0x3fb7572bdb3f6e51),
+    (0x3fdaf76b57c6f8f1, 0x3fb6705e18e13ed1),
+    (0x3fe038862866b29d, 0x3fb553ee25ebebc6),
+    (0x3fe2ce9146962ca4, 0x3fb40483e126fd14),
+    (0x3fe537a89c487f8a, 0x3fb2854103b35e0c),
+    (0x3fe76e0931d693ba, 0x3fb0d9b9a62cac10),
+    (0x3fe96c69481c4bc5, 0x3fae0bd76c924981),
+    (0x3feb2e04fd686a13, 0x3faa1c6ae961fbfa),
+    (0x3fecaea9b4574cb9, 0x3fa5ee963a335495),
+    (0x3fedeac0259f7f42, 0x3fa18c5800a355d9),
+    (0x3feedf5518053baa, 0x3f9a0060a8531ffa),
+    (0x3fef8a212714bcdc, 0x3f90aa3c248696c9),
+    (0x3fefe995e70409b6, 0x3f7cbf8bc743cc5c),
+];
+
+/// Integrates f over [0, b] using Gauss-Legendre rule of order 32.
+#[inline]
+fn gauss_legendre32(b: f64, f: impl Fn(f64) -> f64) -> DoubleDouble {
+    let m = 0.5 * b;
+    let mut sum = DoubleDouble::default();
+    for &(x, w) in GAUSS_LEGENDRE_32.iter() {
+        let dx = f64::from_bits(x) * m;
+        sum = DoubleDouble::f64_mul_f64_add(f64::from_bits(w), f(m - dx), sum);
+        sum = DoubleDouble::f64_mul_f64_add(f64::from_bits(w), f(m + dx), sum);
+    }
+    DoubleDouble::quick_mult_f64(sum, m)
+}
+
+/// Computes upper tail of standard normal distribution Q(x) for x >= 0.
+#[inline]
+fn normal_q(x: f64) -> f64 {
+    // Q(x) = erfc(x/sqrt(2)) / 2 = exp(-x^2/2) * erfcx(x/sqrt(2)) / 2
+    let e = f_erfcx(x * FRAC_1_SQRT_2) * 0.5;
+    mul_exp_neg(
+        DoubleDouble::new(0., e),
+        DoubleDouble::quick_mult_f64(DoubleDouble::from_exact_mult(x, x), 0.5),
+    )
+}
+
+/// Computes T(h, a) for h >= 0, 0 < a <= 1.
+///
+/// T(h, a) = exp(-h^2/2) / (2pi) * int(exp(-h^2*x^2/2) / (1 + x^2), x = 0..a),
+/// all terms of the quadrature are positive.
+fn owens_t_small(h: f64, a: f64) -> f64 {
+    if h > 40. {
+        // exp(-h^2/2) is below the smallest subnormal
+        return 0.;
+    }
+    let s = DoubleDouble::quick_mult_f64(DoubleDouble::from_exact_mult(h, h), 0.5);
+    let integral = if a < f64::from_bits(0x3d70000000000000) {
+        // a < 2^-40, integral ~ a - a^3 * (1 + h^2/2) / 3
+        const ONE_THIRD: f64 = f64::from_bits(0x3fd5555555555555);
+        DoubleDouble::new(0., f_fmla(-a * a * ONE_THIRD, (1. + s.hi) * a, a))
+    } else if h <= 1. {
+        let s = s.hi;
+        gauss_legendre32(a, |x| f_exp(-s * x * x) / f_fmla(x, x, 1.))
+    } else {
+        // Substitution y = h*x moves poles at +-i to +-ih and keeps
+        // the gaussian well resolved for any h
+        let l = a * h;
+        if l > 13. {
+            // int(exp(-y^2/2) * h / (h^2 + y^2), y = 0..inf) = pi/2 * erfcx(h/sqrt(2)),
+            // and the tail over (13, inf) is below 2^-120 relative to the integral
+            const PI_OVER_2: f64 = f64::from_bits(0x3ff921fb54442d18);
+            DoubleDouble::new(0., PI_OVER_2 * f_erfcx(h * FRAC_1_SQRT_2))
+        } else {
+            let h2 = h * h;
+            gauss_legendre32(l, |y| f_exp(-0.5 * y * y) * h / f_fmla(y, y, h2))
+        }
+    };
+    mul_exp_neg(DoubleDouble::quick_mult(ONE_OVER_2PI, integral), s)
+}
+
+/// Computes Owen's T function
+///
+/// T(h, a) = 1/(2pi) * int(exp(-h^2*(1 + x^2)/2) / (1 + x^2), x = 0..a)
+///
+/// Max found error ~4 ulp.
+pub fn f_owens_t(h: f64, a: f64) -> f64 {
+    if h.is_nan() || a.is_nan() {
+        return f64::NAN;
+    }
+    let ah = h.abs();
+    let aa = a.abs();
+    if a == 0. || ah.is_infinite() {
+        return 0.0f64.copysign(a);
+    }
+    if h == 0. {
+        // T(0, a) = atan(a) / (2pi)
+        return DoubleDouble::quick_mult_f64(ONE_OVER_2PI, f_atan(a)).to_f64();
+    }
+    if aa.is_infinite() {
+        // T(h, inf) = Q(|h|) / 2
+        return (0.5 * normal_q(ah)).copysign(a);
+    }
+    let t = if aa <= 1. {
+        owens_t_small(ah, aa)
+    } else {
+        // T(h, a) = Q(h)/2 * (1 - 2Q(ah)) + (Q(ah)/2 - T(ah, 1/a)),
+        // where both parts are non-negative
+        let k = ah * aa;
+        let qh = normal_q(ah);
+        let qk = normal_q(k);
+        let t_k = if k.is_infinite() {
+            0.
+        } else {
+            owens_t_small(k, 1. / aa)
+        };
+        let p = DoubleDouble::quick_mult_f64(DoubleDouble::from_exact_add(1., -2. * qk), 0.5 * qh);
+        DoubleDouble::add(p, DoubleDouble::from_full_exact_add(0.5 * qk, -t_k)).to_f64()
+    };
+    t.copysign(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owens_t() {
+        assert_eq!(f_owens_t(0.5, 0.5), 0.06448860284750375);
+        assert_eq!(f_owens_t(1., 0.3), 0.027728115901104102);
+        assert_eq!(f_owens_t(-1., 0.3), 0.027728115901104102);
+        assert_eq!(f_owens_t(2., -0.75), -0.010429297924124843);
+        assert_eq!(f_owens_t(0., 1.), 0.125);
+        assert_eq!(f_owens_t(0., -3.), -0.19879180882521663);
+        assert_eq!(f_owens_t(0.3, 2.), 0.1626043059327724);
+        assert_eq!(f_owens_t(3., 5.), 0.0006749490158150472);
+        assert_eq!(f_owens_t(-1.5, 1000.), 0.03340360063442903);
+        assert_eq!(f_owens_t(10., 0.1), 2.618907292249097e-24);
+        assert_eq!(f_owens_t(25., 0.02), 5.86100525078723e-139);
+        assert_eq!(f_owens_t(0.001, 1e-09), 1.591548635144437e-10);
+        assert_eq!(f_owens_t(0.1, 1e-07), 1.583611545016365e-08);
+        assert_eq!(f_owens_t(1., f64::INFINITY), 0.07932762696572852);
+        assert_eq!(f_owens_t(-2., f64::NEG_INFINITY), -0.011375065974089603);
+        assert_eq!(f_owens_t(f64::INFINITY, 0.5), 0.);
+        assert_eq!(f_owens_t(1., 0.), 0.);
+        assert!(f_owens_t(f64::NAN, 1.).is_nan());
+        assert!(f_owens_t(1., f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::err::faddeeva::f_faddeeva;
+use crate::exponents::mul_exp_neg;
+
+/// Computes Lorentz profile gamma / (pi * (x^2 + gamma^2)) avoiding overflow.
+#[inline]
+fn lorentz(x: f64, gamma: f64) -> f64 {
+    const ONE_OVER_PI: DoubleDouble =
+        DoubleDouble::from_bit_pair((0xbc76b01ec5417056, 0x3fd45f306dc9c883));
+    let ax = x.abs();
+    let (m, s) = if ax > gamma { (ax, gamma) } else { (gamma, ax) };
+    // divisions here may meet numbers close to overflow, so only FMA based ones are used
+    let r = DoubleDouble::from_exact_safe_div(s, m);
+    let den = DoubleDouble::full_add_f64(DoubleDouble::quick_mult(r, r), 1.);
+    let q = DoubleDouble::quick_mult(DoubleDouble::from_exact_safe_div(gamma, m), ONE_OVER_PI);
+    DoubleDouble::div_safe_dd_f64(DoubleDouble::div(q, den), m).to_f64()
+}
+
+/// Computes Voigt profile
+///
+/// V(x; sigma, gamma) = Re(w(z)) / (sigma * sqrt(2pi)), z = (x + i*gamma) / (sigma * sqrt(2)),
+/// convolution of Gaussian with standard deviation sigma and Lorentzian with half-width gamma.
+///
+/// Max found error ~4 ulp.
+pub fn f_voigt(x: f64, sigma: f64, gamma: f64) -> f64 {
+    if x.is_nan() || sigma.is_nan() || gamma.is_nan() || sigma < 0. || gamma < 0. {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        return 0.;
+    }
+    if sigma == 0. {
+        if gamma == 0. {
+            // Dirac delta
+            return if x == 0. { f64::INFINITY } else { 0. };
+        }
+        return lorentz(x, gamma);
+    }
+    if gamma.is_infinite() || sigma.is_infinite() {
+        return 0.;
+    }
+    const ONE_OVER_SQRT_2PI: DoubleDouble =
+        DoubleDouble::from_bit_pair((0xbc7cbc0d30ebfd15, 0x3fd9884533d43651));
+    if gamma == 0. {
+        // Gaussian exp(-x^2 / (2sigma^2)) / (sigma * sqrt(2pi))
+        let t = DoubleDouble::from_exact_div(x, sigma);
+        let t2 = DoubleDouble::quick_mult_f64(DoubleDouble::quick_mult(t, t), 0.5);
+        return mul_exp_neg(DoubleDouble::div_dd_f64(ONE_OVER_SQRT_2PI, sigma), t2);
+    }
+    let m = x.abs().max(gamma);
+    if m > sigma * f64::from_bits(0x41b0000000000000) {
+        // |z| > 2^27, gaussian part is negligible and w(z) = i / (sqrt(pi) * z) * (1 + O(1/z^2))
+        // is exact up to the last bit
+        return lorentz(x, gamma);
+    }
+    const FRAC_1_SQRT_2: DoubleDouble =
+        DoubleDouble::from_bit_pair((0xbc8bdd3413b26456, 0x3fe6a09e667f3bcd));
+    const TWO_OVER_SQRT_PI: f64 = f64::from_bits(0x3ff20dd750429b6d);
+    let u = DoubleDouble::quick_mult(DoubleDouble::from_exact_div(x.abs(), sigma), FRAC_1_SQRT_2);
+    let v = DoubleDouble::quick_mult(DoubleDouble::from_exact_div(gamma, sigma), FRAC_1_SQRT_2);
+    let (re, im) = f_faddeeva(u.hi, v.hi);
+    // Rounding of z is amplified by the gaussian part, so apply first order correction
+    // using w'(z) = -2z * w(z) + 2i / sqrt(pi)
+    let z2 = f_fmla(u.hi, u.hi, v.hi * v.hi);
+    let (d_re, d_im) = if z2 > 900. {
+        // |z| > 30, both terms of w'(z) nearly cancel, so w'(z) ~ -i / (sqrt(pi) * z^2) is used
+        const ONE_OVER_SQRT_PI: f64 = f64::from_bits(0x3fe20dd750429b6d);
+        let r = 1. / z2;
+        let s = r * r * ONE_OVER_SQRT_PI;
+        (-2. * u.hi * v.hi * s, -(u.hi - v.hi) * (u.hi + v.hi) * s)
+    } else {
+        (
+            -2. * f_fmla(u.hi, re, -v.hi * im),
+            f_fmla(-2., f_fmla(u.hi, im, v.hi * re), TWO_OVER_SQRT_PI),
+        )
+    };
+    let re = f_fmla(d_re, u.lo, f_fmla(-d_im, v.lo, re));
+    DoubleDouble::quick_mult_f64(DoubleDouble::div_dd_f64(ONE_OVER_SQRT_2PI, sigma), re).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voigt() {
+        assert_eq!(f_voigt(0., 1., 1.), 0.2087092805203677);
+        assert_eq!(f_voigt(0.5, 1., 0.5), 0.2563640941085846);
+        assert_eq!(f_voigt(-2., 0.7, 0.1), 0.0219646700737315);
+        assert_eq!(f_voigt(4., 0.15, 0.02), 0.0003995677763511545);
+        assert_eq!(f_voigt(10., 1., 3.), 0.008983375262686019);
+        assert_eq!(f_voigt(1000., 0.5, 0.2), 6.366202243681537e-08);
+        assert_eq!(f_voigt(1., 0.001, 0.0001), 3.183108379350932e-05);
+        assert_eq!(f_voigt(45., 1., 0.3), 4.722494934844168e-5);
+        assert_eq!(f_voigt(1e8, 3., 2.), 6.36619772367583e-17);
+        assert_eq!(f_voigt(1e17, 0.5, 0.5), 1.5915494309189534e-35);
+        assert_eq!(f_voigt(1e20, 1e-3, 1.), 3.1830988618379067e-41);
+        assert_eq!(f_voigt(1., 1., 0.), 0.24197072451914334);
+        assert_eq!(f_voigt(1., 0., 1.), 0.15915494309189535);
+        assert_eq!(f_voigt(0., 0., 0.), f64::INFINITY);
+        assert_eq!(f_voigt(2., 0., 0.), 0.);
+        assert_eq!(f_voigt(f64::INFINITY, 1., 1.), 0.);
+        assert!(f_voigt(1., -1., 1.).is_nan());
+        assert!(f_voigt(1., 1., -1.).is_nan());
+        assert!(f_voigt(f64::NAN, 1., 1.).is_nan());
+    }
+}
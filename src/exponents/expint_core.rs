@@ -0,0 +1,207 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::logs::fast_log_d_to_dd;
+use crate::pow_exec::exp_dd_fast;
+
+pub(crate) const EULER_DD: DoubleDouble =
+    DoubleDouble::from_bit_pair((0xbc56cb90701fbfab, 0x3fe2788cfc6fb619));
+
+const LN2_DD: DoubleDouble = DoubleDouble::from_bit_pair((0x3c7abc9e3b39803f, 0x3fe62e42fefa39ef));
+
+/// Positive zero of Ei(x) as triple-double, x0 = 0.37250741078136663446...
+pub(crate) const EI_ZERO: (u64, u64, u64) =
+    (0x3fd7d72952b4b5fc, 0x3c6e4c986021c6f2, 0x390ae2d0d6529db7);
+
+/// Computes log|x| for double-double argument, subnormal `x.hi` is allowed.
+#[inline]
+pub(crate) fn log_abs_dd(x: DoubleDouble) -> DoubleDouble {
+    let ax = if x.hi < 0. { -x } else { x };
+    let mut v = fast_log_d_to_dd(ax.hi);
+    v.lo += ax.lo / ax.hi;
+    v
+}
+
+/// Computes e^(-x) * v for x >= 0, handling results in subnormal range.
+#[inline]
+pub(crate) fn mul_exp_neg(v: DoubleDouble, x: DoubleDouble) -> f64 {
+    if x.hi > 746. {
+        // e^-746 is below the smallest subnormal
+        return 0.;
+    }
+    if x.hi > 600. {
+        // Scale by 2^256 to keep exponent and products away from subnormal range
+        let sx = DoubleDouble::mul_f64_add(LN2_DD, 256., -x);
+        let e = exp_dd_fast(sx);
+        return DoubleDouble::quick_mult(v, e).to_f64() * f64::from_bits(0x2ff0000000000000);
+    }
+    let e = exp_dd_fast(-x);
+    DoubleDouble::quick_mult(v, e).to_f64()
+}
+
+/// Computes e^x * v for 0 <= x <= 710, handling results close to overflow.
+#[inline]
+pub(crate) fn mul_exp_pos(v: DoubleDouble, x: DoubleDouble) -> f64 {
+    if x.hi > 600. {
+        // Scale by 2^-256 to keep exponent and products away from overflow
+        let sx = DoubleDouble::mul_f64_add(LN2_DD, -256., x);
+        let e = exp_dd_fast(sx);
+        return DoubleDouble::quick_mult(v, e).to_f64() * f64::from_bits(0x4ff0000000000000);
+    }
+    let e = exp_dd_fast(x);
+    DoubleDouble::quick_mult(v, e).to_f64()
+}
+
+/// Computes sum(x^k / (k * k!), k = 1..inf) for |x| <= 60.
+#[inline]
+fn ei_power_sum(x: DoubleDouble) -> DoubleDouble {
+    let mut term = x;
+    let mut sum = x;
+    let mut k = 1f64;
+    while k < 400. {
+        k += 1.;
+        term = DoubleDouble::div_dd_f64(DoubleDouble::quick_mult(term, x), k);
+        let t = DoubleDouble::div_dd_f64(term, k);
+        sum = DoubleDouble::add(sum, t);
+        if t.hi.abs() <= f64::from_bits(0x3910000000000000) * sum.hi.abs() {
+            // |t| < 2^-110 * |sum|
+            break;
+        }
+    }
+    sum
+}
+
+/// Computes Ei(x) using power series, Ei(x) = euler + log|x| + sum(x^k / (k * k!)).
+///
+/// Valid for 0 < x <= 60 and -2 <= x < 0.
+#[inline]
+pub(crate) fn ei_series(x: DoubleDouble) -> DoubleDouble {
+    let log_x = log_abs_dd(x);
+    let s = DoubleDouble::add(EULER_DD, log_x);
+    DoubleDouble::add(s, ei_power_sum(x))
+}
+
+/// Computes Ei(x) using Taylor expansion at the positive zero x0, for |x - x0| < 2^-7.
+///
+/// Generated by SageMath:
+/// ```text
+/// R = RealField(300)
+/// x0 = find_root(lambda t: exp_integral_ei(R(t)), 0.3, 0.4)
+/// for k in range(1, 15):
+///     c = derivative(exp_integral_ei(x), x, k).subs(x=x0) / factorial(k)
+/// ```
+#[inline]
+pub(crate) fn ei_near_zero(x: DoubleDouble) -> DoubleDouble {
+    static C: [(u64, u64); 14] = [
+        (0x3c92e4825482cff3, 0x400f2b73279a55db),
+        (0xbca8656387ab2dae, 0xc00a40bba003d345),
+        (0x3cbcbc36c3934c29, 0x401a16e9c5b37b9f),
+        (0xbcc88a8fae83889c, 0xc029f07c2cea9cba),
+        (0x3cd7d30ddc3bba67, 0x403be2e46c5d58f7),
+        (0xbcd7d8da5624a53c, 0xc04f307c91892eea),
+        (0x3d0ca2814484fc28, 0x4061f11e4cf752d6),
+        (0x3d0b79270a159f3b, 0xc075127e44a3c8bc),
+        (0x3d2f9d6630556b6a, 0x4089244135520fe9),
+        (0x3cc0481767debbc7, 0xc09e5f2fc3e583b4),
+        (0x3d33bd6bd9237adf, 0x40b287c401aa2e9c),
+        (0x3d4b9cd172330848, 0xc0c6ccbb6410044d),
+        (0xbd430333dcd96805, 0x40dc3fbd23b968bd),
+        (0x3d8a3b60eaa06609, 0xc0f19abf2246dfa5),
+    ];
+    // d = x - x0 with x0 taken in triple-double precision
+    let d0 = DoubleDouble::from_full_exact_sub(x.hi, f64::from_bits(EI_ZERO.0));
+    let d1 = DoubleDouble::full_add_f64(d0, x.lo - f64::from_bits(EI_ZERO.1));
+    let d = DoubleDouble::full_add_f64(d1, -f64::from_bits(EI_ZERO.2));
+    let mut p = DoubleDouble::from_bit_pair(C[13]);
+    for &c in C[..13].iter().rev() {
+        p = DoubleDouble::mul_add(p, d, DoubleDouble::from_bit_pair(c));
+    }
+    DoubleDouble::quick_mult(p, d)
+}
+
+/// Computes x * e^(-x) * Ei(x) using asymptotic expansion sum(k!/x^k), for x >= 60.
+///
+/// Terms decrease while k < x, truncation at the smallest term gives
+/// relative error about e^-x * sqrt(2*pi*x) < 2^-80.
+#[inline]
+pub(crate) fn ei_asympt_scaled(x: DoubleDouble) -> DoubleDouble {
+    let r = x.recip();
+    let mut term = DoubleDouble::new(0., 1.);
+    let mut sum = term;
+    let mut k = 0f64;
+    while k < 200. && k < x.hi {
+        k += 1.;
+        term = DoubleDouble::quick_mult(DoubleDouble::quick_mult_f64(term, k), r);
+        sum = DoubleDouble::add(sum, term);
+        if term.hi <= f64::from_bits(0x3910000000000000) * sum.hi {
+            // term < 2^-110 * sum
+            break;
+        }
+    }
+    sum
+}
+
+/// Computes e^x * E_n(x) using continued fraction, for x > 1 and n >= 1.
+///
+/// E_n(x) = e^-x * (1/(x+n-) 1*n/(x+n+2-) 2(n+1)/(x+n+4-) ...),
+/// evaluated by modified Lentz's method.
+pub(crate) fn expint_en_cf_scaled(n: u32, x: f64) -> DoubleDouble {
+    let nm1 = n as f64 - 1.;
+    let mut b = DoubleDouble::from_full_exact_add(x, n as f64);
+    let mut d = b.recip();
+    let mut h = d;
+    // First step is done explicitly, since initial value of c is infinity
+    b = DoubleDouble::full_add_f64(b, 2.);
+    d = DoubleDouble::mul_f64_add(d, -(nm1 + 1.), b).recip();
+    let mut c = b;
+    h = DoubleDouble::quick_mult(h, DoubleDouble::quick_mult(c, d));
+    let mut i = 2f64;
+    while i < 500. {
+        let a = -i * (nm1 + i);
+        b = DoubleDouble::full_add_f64(b, 2.);
+        d = DoubleDouble::mul_f64_add(d, a, b).recip();
+        c = DoubleDouble::full_dd_add(b, DoubleDouble::from_f64_div_dd(a, c));
+        let del = DoubleDouble::quick_mult(c, d);
+        h = DoubleDouble::quick_mult(h, del);
+        if (del.hi - 1.).abs() + del.lo.abs() <= f64::from_bits(0x3930000000000000) {
+            // |del - 1| < 2^-108
+            break;
+        }
+        i += 1.;
+    }
+    h
+}
+
+/// Computes E1(x) for 0 < x <= 2 using power series.
+///
+/// E1(x) = -euler - log(x) - sum((-x)^k / (k * k!))
+#[inline]
+pub(crate) fn e1_series(x: DoubleDouble) -> DoubleDouble {
+    -ei_series(-x)
+}
@@ -0,0 +1,126 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::logs::simple_fast_log;
+use crate::polyeval::f_polyeval9;
+
+const EULER: f64 = f64::from_bits(0x3fe2788cfc6fb619);
+
+/// Positive zero of Ei(x), x0 = 0.37250741078136663446...
+pub(crate) const EI_ZEROF: f64 = f64::from_bits(0x3fd7d72952b4b5fc);
+
+/// Computes Ei(x) using power series, Ei(x) = euler + log|x| + sum(x^k / (k * k!)).
+///
+/// Valid for 0 < x <= 40 and -2 <= x < 0.
+#[inline]
+pub(crate) fn ei_seriesf(x: f64) -> f64 {
+    let mut term = x;
+    let mut sum = x;
+    let mut k = 1f64;
+    while k < 200. {
+        k += 1.;
+        term = term * x / k;
+        let t = term / k;
+        sum += t;
+        if t.abs() <= f64::from_bits(0x3c90000000000000) * sum.abs() {
+            // |t| < 2^-54 * |sum|
+            break;
+        }
+    }
+    EULER + simple_fast_log(x.abs()) + sum
+}
+
+/// Computes Ei(x) using Taylor expansion at the positive zero x0, for |x - x0| < 2^-7.
+///
+/// Coefficients are the leading parts of the ones in `ei_near_zero`.
+#[inline]
+pub(crate) fn ei_near_zerof(x: f64) -> f64 {
+    // x0 error is about 2^-56, that is enough since |x - x0| >= 2^-26 for any f32 x
+    let d = x - EI_ZEROF;
+    let p = f_polyeval9(
+        d,
+        f64::from_bits(0x400f2b73279a55db),
+        f64::from_bits(0xc00a40bba003d345),
+        f64::from_bits(0x401a16e9c5b37b9f),
+        f64::from_bits(0xc029f07c2cea9cba),
+        f64::from_bits(0x403be2e46c5d58f7),
+        f64::from_bits(0xc04f307c91892eea),
+        f64::from_bits(0x4061f11e4cf752d6),
+        f64::from_bits(0xc075127e44a3c8bc),
+        f64::from_bits(0x4089244135520fe9),
+    );
+    p * d
+}
+
+/// Computes x * e^(-x) * Ei(x) using asymptotic expansion sum(k!/x^k), for x >= 40.
+#[inline]
+pub(crate) fn ei_asympt_scaledf(x: f64) -> f64 {
+    let r = 1. / x;
+    let mut term = 1f64;
+    let mut sum = term;
+    let mut k = 0f64;
+    while k < 100. && k < x {
+        k += 1.;
+        term = term * k * r;
+        sum += term;
+        if term <= f64::from_bits(0x3c90000000000000) * sum {
+            // term < 2^-54 * sum
+            break;
+        }
+    }
+    sum
+}
+
+/// Computes e^x * E_n(x) using continued fraction, for x > 1 and n >= 1.
+///
+/// E_n(x) = e^-x * (1/(x+n-) 1*n/(x+n+2-) 2(n+1)/(x+n+4-) ...),
+/// evaluated by modified Lentz's method.
+pub(crate) fn expint_en_cf_scaledf(n: u32, x: f64) -> f64 {
+    const TINY: f64 = f64::from_bits(0x0010000000000000);
+    let nm1 = n as f64 - 1.;
+    let mut b = x + n as f64;
+    let mut c = 1. / TINY;
+    let mut d = 1. / b;
+    let mut h = d;
+    let mut i = 1f64;
+    while i < 300. {
+        let a = -i * (nm1 + i);
+        b += 2.;
+        d = 1. / f_fmla(a, d, b);
+        c = b + a / c;
+        let del = c * d;
+        h *= del;
+        if (del - 1.).abs() <= f64::from_bits(0x3c90000000000000) {
+            // |del - 1| < 2^-54
+            break;
+        }
+        i += 1.;
+    }
+    h
+}
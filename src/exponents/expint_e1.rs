@@ -0,0 +1,105 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::expint_core::{e1_series, expint_en_cf_scaled, mul_exp_neg};
+use crate::pow_exec::exp_dd_fast;
+
+/// Computes exponential integral E1(x)
+///
+/// E1(x) = integral(e^-t/t, t = x..inf), defined for x > 0.
+pub fn f_expint_e1(x: f64) -> f64 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f64::NAN;
+        }
+        if x == 0. {
+            return f64::INFINITY;
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+    }
+    let dx = DoubleDouble::new(0., x);
+    if x <= 2. {
+        return e1_series(dx).to_f64();
+    }
+    let s = expint_en_cf_scaled(1, x);
+    mul_exp_neg(s, dx)
+}
+
+/// Computes exponentially scaled exponential integral e^x * E1(x)
+///
+/// Defined for x > 0.
+pub fn f_expint_e1e(x: f64) -> f64 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f64::NAN;
+        }
+        if x == 0. {
+            return f64::INFINITY;
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+    }
+    if x <= 2. {
+        let dx = DoubleDouble::new(0., x);
+        let e = exp_dd_fast(dx);
+        return DoubleDouble::quick_mult(e1_series(dx), e).to_f64();
+    }
+    if x > f64::from_bits(0x4340000000000000) {
+        // x > 2^53, e^x * E1(x) ~ 1/x - 1/x^2
+        let r = DoubleDouble::from_recip(x);
+        return r.hi + (r.lo - r.hi * r.hi);
+    }
+    expint_en_cf_scaled(1, x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expint_e1() {
+        assert_eq!(f_expint_e1(1.), 0.21938393439552029);
+        assert_eq!(f_expint_e1(0.), f64::INFINITY);
+        assert_eq!(f_expint_e1(f64::INFINITY), 0.);
+        assert!(f_expint_e1(-1.).is_nan());
+        assert!(f_expint_e1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_expint_e1e() {
+        assert_eq!(f_expint_e1e(1.), 0.5963473623231941);
+        assert_eq!(f_expint_e1e(0.), f64::INFINITY);
+        assert_eq!(f_expint_e1e(f64::INFINITY), 0.);
+        assert!(f_expint_e1e(-1.).is_nan());
+        assert!(f_expint_e1e(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,102 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::exponents::core_expdf;
+use crate::exponents::expint_coref::{ei_seriesf, expint_en_cf_scaledf};
+
+/// Computes exponential integral E1(x)
+///
+/// E1(x) = integral(e^-t/t, t = x..inf), defined for x > 0.
+pub fn f_expint_e1f(x: f32) -> f32 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f32::NAN;
+        }
+        if x == 0. {
+            return f32::INFINITY;
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+    }
+    let xd = x as f64;
+    if x <= 2. {
+        // E1(x) = -Ei(-x)
+        return -ei_seriesf(-xd) as f32;
+    }
+    if x > 103. {
+        // E1(x) < e^-x / x, that is below the smallest subnormal
+        return 0.;
+    }
+    let s = expint_en_cf_scaledf(1, xd);
+    (s * core_expdf(-xd)) as f32
+}
+
+/// Computes exponentially scaled exponential integral e^x * E1(x)
+///
+/// Defined for x > 0.
+pub fn f_expint_e1ef(x: f32) -> f32 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f32::NAN;
+        }
+        if x == 0. {
+            return f32::INFINITY;
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+    }
+    let xd = x as f64;
+    if x <= 2. {
+        return (-ei_seriesf(-xd) * core_expdf(xd)) as f32;
+    }
+    expint_en_cf_scaledf(1, xd) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expint_e1f() {
+        assert_eq!(f_expint_e1f(1.), 0.21938394);
+        assert_eq!(f_expint_e1f(0.), f32::INFINITY);
+        assert_eq!(f_expint_e1f(f32::INFINITY), 0.);
+        assert!(f_expint_e1f(-1.).is_nan());
+        assert!(f_expint_e1f(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_expint_e1ef() {
+        assert_eq!(f_expint_e1ef(1.), 0.5963474);
+        assert_eq!(f_expint_e1ef(0.), f32::INFINITY);
+        assert_eq!(f_expint_e1ef(f32::INFINITY), 0.);
+        assert!(f_expint_e1ef(-1.).is_nan());
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::expint_core::{
+    EI_ZERO, ei_asympt_scaled, ei_near_zero, ei_series, expint_en_cf_scaled, log_abs_dd,
+    mul_exp_neg, mul_exp_pos,
+};
+use crate::pow_exec::exp_dd_fast;
+
+/// Computes Ei(x) for finite non-zero x, x < 60.
+#[inline]
+pub(crate) fn ei_small(x: DoubleDouble) -> DoubleDouble {
+    if (x.hi - f64::from_bits(EI_ZERO.0)).abs() < f64::from_bits(0x3f80000000000000) {
+        // |x - x0| < 2^-7
+        return ei_near_zero(x);
+    }
+    ei_series(x)
+}
+
+/// Computes exponential integral Ei(x)
+///
+/// Ei(x) = -p.v. integral(e^-t/t, t = -x..inf).
+pub fn f_expint_ei(x: f64) -> f64 {
+    if !x.is_normal() {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x == 0. {
+            return f64::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_negative() {
+                0.
+            } else {
+                f64::INFINITY
+            };
+        }
+    }
+    let dx = DoubleDouble::new(0., x);
+    if x < -2. {
+        // Ei(x) = -E1(-x)
+        let s = expint_en_cf_scaled(1, -x);
+        return -mul_exp_neg(s, -dx);
+    }
+    if x <= 60. {
+        return ei_small(dx).to_f64();
+    }
+    if x > 717. {
+        return f64::INFINITY;
+    }
+    // Ei(x) = e^x / x * sum(k!/x^k)
+    let log_x = log_abs_dd(dx);
+    let z = DoubleDouble::full_dd_sub(dx, log_x);
+    if z.hi > 709.782712893384 {
+        return f64::INFINITY;
+    }
+    mul_exp_pos(ei_asympt_scaled(dx), z)
+}
+
+/// Computes exponentially scaled exponential integral e^-x * Ei(x)
+pub fn f_expint_eie(x: f64) -> f64 {
+    if !x.is_normal() {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x == 0. {
+            return f64::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_negative() { -0. } else { 0. };
+        }
+    }
+    let dx = DoubleDouble::new(0., x);
+    if x < -2. {
+        // e^-x * Ei(x) = -e^-x * E1(-x)
+        if x < -f64::from_bits(0x4340000000000000) {
+            // |x| > 2^53, e^-x * Ei(x) ~ 1/x + 1/x^2
+            let r = DoubleDouble::from_recip(x);
+            return r.hi + (r.lo + r.hi * r.hi);
+        }
+        return -expint_en_cf_scaled(1, -x).to_f64();
+    }
+    if x <= 60. {
+        let e = exp_dd_fast(-dx);
+        return DoubleDouble::quick_mult(ei_small(dx), e).to_f64();
+    }
+    if x > f64::from_bits(0x4340000000000000) {
+        // x > 2^53, e^-x * Ei(x) ~ 1/x + 1/x^2
+        let r = DoubleDouble::from_recip(x);
+        return r.hi + (r.lo + r.hi * r.hi);
+    }
+    DoubleDouble::div_dd_f64(ei_asympt_scaled(dx), x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expint_ei() {
+        assert_eq!(f_expint_ei(1.), 1.8951178163559368);
+        assert_eq!(f_expint_ei(-1.), -0.21938393439552029);
+        assert_eq!(f_expint_ei(0.), f64::NEG_INFINITY);
+        assert_eq!(f_expint_ei(f64::INFINITY), f64::INFINITY);
+        assert_eq!(f_expint_ei(f64::NEG_INFINITY), 0.);
+        assert!(f_expint_ei(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_expint_eie() {
+        assert_eq!(f_expint_eie(1.), 0.697174883235066);
+        assert_eq!(f_expint_eie(0.), f64::NEG_INFINITY);
+        assert_eq!(f_expint_eie(f64::INFINITY), 0.);
+        assert!(f_expint_eie(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,129 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::exponents::core_expdf;
+use crate::exponents::expint_coref::{
+    EI_ZEROF, ei_asympt_scaledf, ei_near_zerof, ei_seriesf, expint_en_cf_scaledf,
+};
+
+/// Computes Ei(x) for finite non-zero x, -2 <= x <= 40.
+#[inline]
+pub(crate) fn ei_smallf(x: f64) -> f64 {
+    if (x - EI_ZEROF).abs() < f64::from_bits(0x3f80000000000000) {
+        // |x - x0| < 2^-7
+        return ei_near_zerof(x);
+    }
+    ei_seriesf(x)
+}
+
+/// Computes exponential integral Ei(x)
+///
+/// Ei(x) = -p.v. integral(e^-t/t, t = -x..inf).
+pub fn f_expint_eif(x: f32) -> f32 {
+    if !x.is_normal() {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        if x == 0. {
+            return f32::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_negative() {
+                0.
+            } else {
+                f32::INFINITY
+            };
+        }
+    }
+    let xd = x as f64;
+    if x < -2. {
+        if x < -103. {
+            // |Ei(x)| < e^x / |x|, that is below the smallest subnormal
+            return -0.;
+        }
+        // Ei(x) = -E1(-x)
+        let s = expint_en_cf_scaledf(1, -xd);
+        return (-s * core_expdf(xd)) as f32;
+    }
+    if x <= 40. {
+        return ei_smallf(xd) as f32;
+    }
+    if x > 95. {
+        return f32::INFINITY;
+    }
+    // Ei(x) = e^x / x * sum(k!/x^k)
+    (core_expdf(xd) / xd * ei_asympt_scaledf(xd)) as f32
+}
+
+/// Computes exponentially scaled exponential integral e^-x * Ei(x)
+pub fn f_expint_eief(x: f32) -> f32 {
+    if !x.is_normal() {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        if x == 0. {
+            return f32::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_negative() { -0. } else { 0. };
+        }
+    }
+    let xd = x as f64;
+    if x < -2. {
+        // e^-x * Ei(x) = -e^-x * E1(-x)
+        return -expint_en_cf_scaledf(1, -xd) as f32;
+    }
+    if x <= 40. {
+        return (ei_smallf(xd) * core_expdf(-xd)) as f32;
+    }
+    (ei_asympt_scaledf(xd) / xd) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expint_eif() {
+        assert_eq!(f_expint_eif(1.), 1.8951178);
+        assert_eq!(f_expint_eif(-1.), -0.21938394);
+        assert_eq!(f_expint_eif(0.), f32::NEG_INFINITY);
+        assert_eq!(f_expint_eif(f32::INFINITY), f32::INFINITY);
+        assert_eq!(f_expint_eif(f32::NEG_INFINITY), 0.);
+        assert!(f_expint_eif(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_expint_eief() {
+        assert_eq!(f_expint_eief(1.), 0.6971749);
+        assert_eq!(f_expint_eief(-1.), -0.5963474);
+        assert_eq!(f_expint_eief(0.), f32::NEG_INFINITY);
+        assert_eq!(f_expint_eief(f32::INFINITY), 0.);
+        assert!(f_expint_eief(f32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,125 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::expint_core::{EULER_DD, expint_en_cf_scaled, log_abs_dd, mul_exp_neg};
+use crate::exponents::expint_e1::f_expint_e1;
+use crate::pow_exec::exp_dd_fast;
+
+/// Computes E_n(x) for 0 < x <= 1, n >= 2 using power series.
+///
+/// E_n(x) = (-x)^(n-1)/(n-1)! * (digamma(n) - log(x)) - sum((-x)^k / ((k - n + 1) * k!), k != n - 1)
+fn expint_en_series(n: u32, x: f64) -> DoubleDouble {
+    let nm1 = n - 1;
+    // k = 0 term
+    let mut sum = DoubleDouble::from_exact_div(1., nm1 as f64);
+    let mut term = DoubleDouble::new(0., 1.);
+    let mut k = 0u32;
+    let mut pole_term = DoubleDouble::default();
+    while k < 400 {
+        k += 1;
+        // term = (-x)^k / k!
+        term = DoubleDouble::div_dd_f64(DoubleDouble::quick_mult_f64(term, -x), k as f64);
+        if k == nm1 {
+            // digamma(n) = -euler + sum(1/j, j = 1..n-1)
+            let mut psi = -EULER_DD;
+            for j in 1..n {
+                psi = DoubleDouble::add(psi, DoubleDouble::from_exact_div(1., j as f64));
+            }
+            let log_x = log_abs_dd(DoubleDouble::new(0., x));
+            pole_term = DoubleDouble::quick_mult(term, DoubleDouble::full_dd_sub(psi, log_x));
+            continue;
+        }
+        let t = DoubleDouble::div_dd_f64(term, (k as f64) - nm1 as f64);
+        sum = DoubleDouble::full_dd_sub(sum, t);
+        if k > nm1 && t.hi.abs() <= f64::from_bits(0x3910000000000000) * sum.hi.abs() {
+            // |t| < 2^-110 * |sum|
+            break;
+        }
+    }
+    DoubleDouble::full_dd_add(sum, pole_term)
+}
+
+/// Computes generalized exponential integral E_n(x)
+///
+/// E_n(x) = integral(e^(-x*t)/t^n, t = 1..inf), defined for x >= 0.
+pub fn f_expint_en(n: u32, x: f64) -> f64 {
+    if n == 1 {
+        return f_expint_e1(x);
+    }
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f64::NAN;
+        }
+        if x == 0. {
+            // E_n(0) = 1/(n - 1)
+            return if n == 0 {
+                f64::INFINITY
+            } else {
+                1. / (n - 1) as f64
+            };
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+    }
+    let dx = DoubleDouble::new(0., x);
+    if n == 0 {
+        // E_0(x) = e^-x / x
+        let r = DoubleDouble::from_exact_div(1., x);
+        if !r.hi.is_finite() {
+            return f64::INFINITY;
+        }
+        if x <= 1. {
+            return DoubleDouble::quick_mult(r, exp_dd_fast(-dx)).to_f64();
+        }
+        return mul_exp_neg(r, dx);
+    }
+    if x <= 1. {
+        return expint_en_series(n, x).to_f64();
+    }
+    let s = expint_en_cf_scaled(n, x);
+    mul_exp_neg(s, dx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expint_en() {
+        assert_eq!(f_expint_en(1, 1.), 0.21938393439552029);
+        assert_eq!(f_expint_en(2, 1.), 0.14849550677592205);
+        assert_eq!(f_expint_en(0, 1.), 0.36787944117144233);
+        assert_eq!(f_expint_en(3, 0.), 0.5);
+        assert_eq!(f_expint_en(0, 0.), f64::INFINITY);
+        assert_eq!(f_expint_en(5, f64::INFINITY), 0.);
+        assert!(f_expint_en(5, -1.).is_nan());
+        assert!(f_expint_en(5, f64::NAN).is_nan());
+    }
+}
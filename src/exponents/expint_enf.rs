@@ -0,0 +1,121 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::exponents::core_expdf;
+use crate::exponents::expint_coref::expint_en_cf_scaledf;
+use crate::exponents::expint_e1f::f_expint_e1f;
+use crate::logs::simple_fast_log;
+
+/// Computes E_n(x) for 0 < x <= 1, n >= 2 using power series.
+///
+/// E_n(x) = (-x)^(n-1)/(n-1)! * (digamma(n) - log(x)) - sum((-x)^k / ((k - n + 1) * k!), k != n - 1)
+fn expint_en_seriesf(n: u32, x: f64) -> f64 {
+    const EULER: f64 = f64::from_bits(0x3fe2788cfc6fb619);
+    let nm1 = n - 1;
+    // k = 0 term
+    let mut sum = 1. / nm1 as f64;
+    let mut term = 1f64;
+    let mut k = 0u32;
+    let mut pole_term = 0f64;
+    while k < 200 {
+        k += 1;
+        // term = (-x)^k / k!
+        term = term * -x / k as f64;
+        if k == nm1 {
+            // digamma(n) = -euler + sum(1/j, j = 1..n-1)
+            let mut psi = -EULER;
+            for j in 1..n {
+                psi += 1. / j as f64;
+            }
+            pole_term = term * (psi - simple_fast_log(x));
+            continue;
+        }
+        let t = term / ((k as f64) - nm1 as f64);
+        sum -= t;
+        if k > nm1 && t.abs() <= f64::from_bits(0x3c90000000000000) * sum.abs() {
+            // |t| < 2^-54 * |sum|
+            break;
+        }
+    }
+    sum + pole_term
+}
+
+/// Computes generalized exponential integral E_n(x)
+///
+/// E_n(x) = integral(e^(-x*t)/t^n, t = 1..inf), defined for x >= 0.
+pub fn f_expint_enf(n: u32, x: f32) -> f32 {
+    if n == 1 {
+        return f_expint_e1f(x);
+    }
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f32::NAN;
+        }
+        if x == 0. {
+            // E_n(0) = 1/(n - 1)
+            return if n == 0 {
+                f32::INFINITY
+            } else {
+                (1. / (n - 1) as f64) as f32
+            };
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+    }
+    let xd = x as f64;
+    if x > 103. {
+        // E_n(x) <= e^-x / x, that is below the smallest subnormal
+        return 0.;
+    }
+    if n == 0 {
+        // E_0(x) = e^-x / x
+        return (core_expdf(-xd) / xd) as f32;
+    }
+    if x <= 1. {
+        return expint_en_seriesf(n, xd) as f32;
+    }
+    (expint_en_cf_scaledf(n, xd) * core_expdf(-xd)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expint_enf() {
+        assert_eq!(f_expint_enf(1, 1.), 0.21938394);
+        assert_eq!(f_expint_enf(2, 1.), 0.14849551);
+        assert_eq!(f_expint_enf(0, 1.), 0.36787945);
+        assert_eq!(f_expint_enf(3, 0.), 0.5);
+        assert_eq!(f_expint_enf(0, 0.), f32::INFINITY);
+        assert_eq!(f_expint_enf(5, f32::INFINITY), 0.);
+        assert!(f_expint_enf(5, -1.).is_nan());
+        assert!(f_expint_enf(5, f32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,91 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::expint_core::{ei_asympt_scaled, expint_en_cf_scaled};
+use crate::exponents::expint_ei::ei_small;
+use crate::logs::{fast_log_d_to_dd, log1p_dd};
+
+/// Computes logarithmic integral li(x)
+///
+/// li(x) = p.v. integral(1/log(t), t = 0..x) = Ei(log(x)), defined for x >= 0.
+pub fn f_li(x: f64) -> f64 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f64::NAN;
+        }
+        if x == 0. {
+            return 0.;
+        }
+        if x.is_infinite() {
+            return f64::INFINITY;
+        }
+    }
+    if x == 1. {
+        return f64::NEG_INFINITY;
+    }
+    // t = log(x) must have small relative error, since Ei(t) ~ euler + log|t| near x = 1
+    let t = if x >= 0.5 && x <= 2. {
+        // x - 1 is exact by Sterbenz lemma
+        log1p_dd(x - 1.)
+    } else {
+        fast_log_d_to_dd(x)
+    };
+    if t.hi < -2. {
+        // li(x) = Ei(t) = -E1(-t) = -e^t * (e^-t * E1(-t)) = -x * (e^-t * E1(-t))
+        let s = expint_en_cf_scaled(1, -t.hi);
+        // g(u) = e^u * E1(u) satisfies g'(u) = g(u) - 1/u, account for t.lo by first order term
+        let dg = (s.hi + 1. / t.hi) * -t.lo;
+        let s = DoubleDouble::full_add_f64(s, dg);
+        return -DoubleDouble::quick_mult_f64(s, x).to_f64();
+    }
+    if t.hi <= 60. {
+        return ei_small(t).to_f64();
+    }
+    // li(x) = e^t / t * sum(k!/t^k) = x / t * sum(k!/t^k)
+    let s = DoubleDouble::div(ei_asympt_scaled(t), t);
+    // x is scaled by 2^-256 to avoid overflow in intermediate products
+    DoubleDouble::quick_mult_f64(s, x * f64::from_bits(0x2ff0000000000000)).to_f64()
+        * f64::from_bits(0x4ff0000000000000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_li() {
+        assert_eq!(f_li(2.), 1.0451637801174928);
+        assert_eq!(f_li(0.5), -0.37867104306108795);
+        assert_eq!(f_li(1.), f64::NEG_INFINITY);
+        assert_eq!(f_li(0.), 0.);
+        assert_eq!(f_li(f64::INFINITY), f64::INFINITY);
+        assert!(f_li(-1.).is_nan());
+        assert!(f_li(f64::NAN).is_nan());
+    }
+}
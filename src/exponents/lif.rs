@@ -0,0 +1,84 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::exponents::expint_coref::{ei_asympt_scaledf, expint_en_cf_scaledf};
+use crate::exponents::expint_eif::ei_smallf;
+use crate::logs::{core_log1pf, fast_logf};
+
+/// Computes logarithmic integral li(x)
+///
+/// li(x) = p.v. integral(1/log(t), t = 0..x) = Ei(log(x)), defined for x >= 0.
+pub fn f_lif(x: f32) -> f32 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f32::NAN;
+        }
+        if x == 0. {
+            return 0.;
+        }
+        if x.is_infinite() {
+            return f32::INFINITY;
+        }
+    }
+    if x == 1. {
+        return f32::NEG_INFINITY;
+    }
+    // t = log(x) must have small relative error, since Ei(t) ~ euler + log|t| near x = 1
+    let t = if x >= 0.5 && x <= 2. {
+        // x - 1 is exact by Sterbenz lemma
+        core_log1pf(x - 1.)
+    } else {
+        fast_logf(x)
+    };
+    let xd = x as f64;
+    if t < -2. {
+        // li(x) = Ei(t) = -E1(-t) = -x * (e^-t * E1(-t))
+        return (-xd * expint_en_cf_scaledf(1, -t)) as f32;
+    }
+    if t <= 40. {
+        return ei_smallf(t) as f32;
+    }
+    // li(x) = e^t / t * sum(k!/t^k) = x / t * sum(k!/t^k)
+    (xd / t * ei_asympt_scaledf(t)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lif() {
+        assert_eq!(f_lif(2.), 1.0451638);
+        assert_eq!(f_lif(0.5), -0.37867105);
+        assert_eq!(f_lif(1.), f32::NEG_INFINITY);
+        assert_eq!(f_lif(0.), 0.);
+        assert_eq!(f_lif(f32::INFINITY), f32::INFINITY);
+        assert!(f_lif(-1.).is_nan());
+        assert!(f_lif(f32::NAN).is_nan());
+    }
+}
@@ -39,8 +39,18 @@ mod exp2m1;
 mod exp2m1f;
 mod exp_f128;
 mod expf;
+mod expint_core;
+mod expint_coref;
+mod expint_e1;
+mod expint_e1f;
+mod expint_ei;
+mod expint_eif;
+mod expint_en;
+mod expint_enf;
 mod expm1;
 mod expm1f;
+mod li;
+mod lif;
 mod logistic;
 mod logisticf;
 
@@ -60,8 +70,18 @@ pub use exp10m1::f_exp10m1;
 pub use exp10m1f::f_exp10m1f;
 pub(crate) use expf::{core_expdf, core_expf};
 pub use expf::{expf, f_expf};
+pub(crate) use expint_core::{EULER_DD, ei_asympt_scaled, log_abs_dd, mul_exp_neg, mul_exp_pos};
+pub(crate) use expint_coref::ei_asympt_scaledf;
+pub use expint_e1::{f_expint_e1, f_expint_e1e};
+pub use expint_e1f::{f_expint_e1ef, f_expint_e1f};
+pub use expint_ei::{f_expint_ei, f_expint_eie};
+pub use expint_eif::{f_expint_eief, f_expint_eif};
+pub use expint_en::f_expint_en;
+pub use expint_enf::f_expint_enf;
 pub use expm1::f_expm1;
 pub(crate) use expm1::{EXPM1_T0, EXPM1_T1};
 pub use expm1f::f_expm1f;
+pub use li::f_li;
+pub use lif::f_lif;
 pub use logistic::f_logistic;
 pub use logisticf::f_logisticf;
@@ -0,0 +1,211 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::rounding::CpuRound;
+use crate::tangent::{ATAN_I, atan_eval};
+use crate::{f_atan2, f_atan2f};
+
+/// Computes atan2
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn atan2f(y: f32, x: f32) -> f32 {
+    const M: [f64; 2] = [0., 1.];
+    const PI: f64 = f64::from_bits(0x400921fb54442d18);
+    const PI2: f64 = f64::from_bits(0x3ff921fb54442d18);
+    static OFF: [f64; 8] = [0.0, PI2, PI, PI2, -0.0, -PI2, -PI, -PI2];
+    static SGN: [f64; 2] = [1., -1.];
+    let ux = x.to_bits();
+    let uy = y.to_bits();
+    let ax = ux & 0x7fffffff;
+    let ay = uy & 0x7fffffff;
+    // x or y is nan or inf, or y is zero
+    if ay >= (0xff << 23) || ax >= (0xff << 23) || ay == 0 {
+        return f_atan2f(y, x);
+    }
+    let gt = (ay > ax) as usize;
+    let i = (uy >> 31)
+        .wrapping_mul(4)
+        .wrapping_add((ux >> 31).wrapping_mul(2))
+        .wrapping_add(gt as u32);
+
+    let zx = x as f64;
+    let zy = y as f64;
+    // z = x/y if |y| > |x|, and z = y/x otherwise
+    let mut z = f_fmla(M[gt], zx, M[1usize.wrapping_sub(gt)] * zy)
+        / f_fmla(M[gt], zy, M[1usize.wrapping_sub(gt)] * zx);
+    let mut r;
+
+    let d = ax as i32 - ay as i32;
+    if d < (27 << 23) && d > (-(27 << 23)) {
+        let z2 = z * z;
+        let z4 = z2 * z2;
+        let z8 = z4 * z4;
+
+        const CN: [u64; 7] = [
+            0x3ff0000000000000,
+            0x40040e0698f94c35,
+            0x400248c5da347f0d,
+            0x3fed873386572976,
+            0x3fc46fa40b20f1d0,
+            0x3f833f5e041eed0f,
+            0x3f1546bbf28667c5,
+        ];
+        const CD: [u64; 7] = [
+            0x3ff0000000000000,
+            0x4006b8b143a3f6da,
+            0x4008421201d18ed5,
+            0x3ff8221d086914eb,
+            0x3fd670657e3a07ba,
+            0x3fa0f4951fd1e72d,
+            0x3f4b3874b8798286,
+        ];
+
+        let mut cn0 = f_fmla(z2, f64::from_bits(CN[1]), f64::from_bits(CN[0]));
+        let cn2 = f_fmla(z2, f64::from_bits(CN[3]), f64::from_bits(CN[2]));
+        let mut cn4 = f_fmla(z2, f64::from_bits(CN[5]), f64::from_bits(CN[4]));
+        let cn6 = f64::from_bits(CN[6]);
+        cn0 = f_fmla(z4, cn2, cn0);
+        cn4 = f_fmla(z4, cn6, cn4);
+        cn0 = f_fmla(z8, cn4, cn0);
+        let mut cd0 = f_fmla(z2, f64::from_bits(CD[1]), f64::from_bits(CD[0]));
+        let cd2 = f_fmla(z2, f64::from_bits(CD[3]), f64::from_bits(CD[2]));
+        let mut cd4 = f_fmla(z2, f64::from_bits(CD[5]), f64::from_bits(CD[4]));
+        let cd6 = f64::from_bits(CD[6]);
+        cd0 = f_fmla(z4, cd2, cd0);
+        cd4 = f_fmla(z4, cd6, cd4);
+        cd0 = f_fmla(z8, cd4, cd0);
+        r = cn0 / cd0;
+    } else {
+        r = 1.;
+    }
+    z *= SGN[gt];
+    r = f_fmla(z, r, OFF[i as usize]);
+    r as f32
+}
+
+/// Computes atan2
+///
+/// Max found ULP 0.50003
+pub fn atan2(y: f64, x: f64) -> f64 {
+    static IS_NEG: [f64; 2] = [1.0, -1.0];
+    const ZERO: DoubleDouble = DoubleDouble::new(0.0, 0.0);
+    const MZERO: DoubleDouble = DoubleDouble::new(-0.0, -0.0);
+    const MPI: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0xbca1a62633145c07),
+        f64::from_bits(0xc00921fb54442d18),
+    );
+    const PI_OVER_2: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0x3c91a62633145c07),
+        f64::from_bits(0x3ff921fb54442d18),
+    );
+    const MPI_OVER_2: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0xbc91a62633145c07),
+        f64::from_bits(0xbff921fb54442d18),
+    );
+
+    // Adjustment for constant term:
+    //   CONST_ADJ[x_sign][y_sign][recip]
+    static CONST_ADJ: [[[DoubleDouble; 2]; 2]; 2] = [
+        [[ZERO, MPI_OVER_2], [MZERO, MPI_OVER_2]],
+        [[MPI, PI_OVER_2], [MPI, PI_OVER_2]],
+    ];
+
+    let x_sign = x.is_sign_negative() as usize;
+    let y_sign = y.is_sign_negative() as usize;
+    let x_abs = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    let y_abs = y.to_bits() & 0x7fff_ffff_ffff_ffff;
+    let recip = x_abs < y_abs;
+    let min_abs = if recip { x_abs } else { y_abs };
+    let max_abs = if !recip { x_abs } else { y_abs };
+    let min_exp = min_abs.wrapping_shr(52);
+    let max_exp = max_abs.wrapping_shr(52);
+
+    // Inputs are 0, inf, nan, or close to overflow, or close to underflow.
+    if max_exp > 0x7ffu64 - 128u64 || min_exp < 128u64 {
+        return f_atan2(y, x);
+    }
+
+    let num = f64::from_bits(min_abs);
+    let den = f64::from_bits(max_abs);
+
+    let final_sign = IS_NEG[((x_sign != y_sign) != recip) as usize];
+    let const_term = CONST_ADJ[x_sign][y_sign][recip as usize];
+    let exp_diff = max_exp - min_exp;
+    if exp_diff > 54 {
+        return f_fmla(
+            final_sign,
+            const_term.hi,
+            final_sign * (const_term.lo + num / den),
+        );
+    }
+
+    let mut k = (64.0 * num / den).cpu_round();
+    let idx = k as u64;
+    // k = idx / 64
+    k *= f64::from_bits(0x3f90000000000000);
+
+    // atan(n/d) - atan(k/64) = atan((n - d * k/64)) / (d + n * k/64))
+    let num_k = DoubleDouble::from_exact_mult(num, k);
+    let den_k = DoubleDouble::from_exact_mult(den, k);
+
+    // num_dd = n - d * k
+    let num_dd = DoubleDouble::from_exact_add(num - den_k.hi, -den_k.lo);
+    // den_dd = d + n * k
+    let mut den_dd = DoubleDouble::from_exact_add(den, num_k.hi);
+    den_dd.lo += num_k.lo;
+
+    // q = (n - d * k) / (d + n * k)
+    let q = DoubleDouble::div(num_dd, den_dd);
+    // p ~ atan(q)
+    let p = atan_eval(q);
+
+    let vlo = DoubleDouble::from_bit_pair(ATAN_I[idx as usize]);
+    let r = DoubleDouble::add(const_term, DoubleDouble::add(vlo, p));
+    r.to_f64() * final_sign
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_atan2() {
+        assert_eq!(atan2f(-5., 2.), -1.19029);
+        assert_eq!(atan2f(-3590000000., -15437000.), -1.5750962);
+        assert_eq!(atan2f(0., -1.), std::f32::consts::PI);
+        assert!(atan2f(f32::NAN, 1.).is_nan());
+        assert_eq!(atan2(-5., 2.), -1.1902899496825317);
+        assert_eq!(atan2(2., -5.), 2.761086276477428);
+        assert_eq!(atan2(-3., -4.), -2.498091544796509);
+        assert_eq!(atan2(1., 0.), std::f64::consts::FRAC_PI_2);
+        assert!(atan2(1., f64::NAN).is_nan());
+    }
+}
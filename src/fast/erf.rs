@@ -0,0 +1,70 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::err::erf_fast;
+use crate::{f_erf, f_erff};
+
+/// Error function
+///
+/// Accurate implementation evaluates in a single stage, so this is the same as [crate::f_erff].
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn erff(x: f32) -> f32 {
+    f_erff(x)
+}
+
+/// Error function
+///
+/// Max found ULP 0.50001
+pub fn erf(x: f64) -> f64 {
+    let z = x.abs();
+    let ux = z.to_bits();
+    // |x| < 2^-61, |x| > 5.9215871957945065, inf or nan
+    if ux < 0x3c20000000000000u64 || ux > 0x4017afb48dc96626u64 {
+        return f_erf(x);
+    }
+    let r = erf_fast(z).result;
+    f64::copysign(r.to_f64(), x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_erf() {
+        assert_eq!(erff(1.), 0.8427008);
+        assert_eq!(erff(f32::NEG_INFINITY), -1.);
+        assert_eq!(erf(1.), 0.8427007929497149);
+        assert_eq!(erf(-0.49866735123), -0.5193279892991808);
+        assert_eq!(erf(0.), 0.);
+        assert_eq!(erf(f64::INFINITY), 1.);
+        assert!(erf(f64::NAN).is_nan());
+    }
+}
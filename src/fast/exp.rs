@@ -0,0 +1,108 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::exponents::{EXP_REDUCE_T0, EXP_REDUCE_T1, core_expf, fast_ldexp};
+use crate::rounding::CpuRound;
+use crate::{f_exp, f_expf};
+
+/// Computes exp
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn expf(x: f32) -> f32 {
+    let x_abs = x.to_bits() & 0x7fff_ffff;
+    // |x| >= 89 or nan
+    if x_abs >= 0x42b2_0000 {
+        return f_expf(x);
+    }
+    core_expf(x) as f32
+}
+
+/// Computes exp
+///
+/// Max found ULP 0.5004
+pub fn exp(x: f64) -> f64 {
+    let aix = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    // |x| <= 5.55112e-17, |x| >= 708.396 or nan
+    if aix <= 0x3c90000000000000u64 || aix >= 0x4086232bdd7abcd2u64 {
+        return f_exp(x);
+    }
+    const S: f64 = f64::from_bits(0x40b71547652b82fe);
+    let t = (x * S).cpu_round();
+    let jt: i64 = unsafe {
+        t.to_int_unchecked::<i64>() // this is already finite here
+    };
+    let i0: i64 = (jt >> 6) & 0x3f;
+    let i1 = jt & 0x3f;
+    let ie: i64 = jt >> 12;
+    let t0 = DoubleDouble::from_bit_pair(EXP_REDUCE_T0[i0 as usize]);
+    let t1 = DoubleDouble::from_bit_pair(EXP_REDUCE_T1[i1 as usize]);
+    let tz = DoubleDouble::quick_mult(t0, t1);
+
+    const L2: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0x3d0718432a1b0e26),
+        f64::from_bits(0x3f262e42ff000000),
+    );
+
+    let dx = f_fmla(L2.lo, t, f_fmla(-L2.hi, t, x));
+    let dx2 = dx * dx;
+    const CH: [u64; 4] = [
+        0x3ff0000000000000,
+        0x3fe0000000000000,
+        0x3fc55555557e54ff,
+        0x3fa55555553a12f4,
+    ];
+
+    let pw0 = f_fmla(dx, f64::from_bits(CH[3]), f64::from_bits(CH[2]));
+    let pw1 = f_fmla(dx, f64::from_bits(CH[1]), f64::from_bits(CH[0]));
+
+    let p = f_fmla(dx2, pw0, pw1);
+    let f = DoubleDouble::new(f_fmla(tz.hi * dx, p, tz.lo), tz.hi);
+    fast_ldexp(f.hi + f.lo, ie as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_exp() {
+        assert_eq!(expf(1.), 2.7182817);
+        assert_eq!(expf(-0.5), 0.60653067);
+        assert_eq!(expf(f32::INFINITY), f32::INFINITY);
+        assert_eq!(expf(f32::NEG_INFINITY), 0.);
+        assert!(expf(f32::NAN).is_nan());
+        assert_eq!(exp(1.), 2.718281828459045);
+        assert_eq!(exp(5.), 148.4131591025766);
+        assert_eq!(exp(-709.), 1.216780750623423e-308);
+        assert_eq!(exp(f64::INFINITY), f64::INFINITY);
+        assert!(exp(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::{f_fmla, min_normal_f64};
+use crate::double_double::DoubleDouble;
+use crate::logs::{LOG_COEFFS, LOG_R_DD, LOG_RANGE_REDUCTION, fast_logf};
+use crate::polyeval::f_polyeval4;
+use crate::{f_log, f_logf};
+
+/// Natural logarithm
+///
+/// Max found ULP 0.50000001
+#[inline]
+pub fn logf(x: f32) -> f32 {
+    let x_u = x.to_bits();
+    // x <= 0, inf or nan
+    if x_u == 0 || x_u >= 0x7f80_0000 {
+        return f_logf(x);
+    }
+    fast_logf(x) as f32
+}
+
+/// Natural logarithm
+///
+/// Max found ULP 0.513
+pub fn log(x: f64) -> f64 {
+    let x_u = x.to_bits();
+
+    const E_BIAS: u64 = (1u64 << (11 - 1u64)) - 1u64;
+    const MAX_NORMAL: u64 = f64::to_bits(f64::MAX);
+
+    // x is subnormal, zero, negative, inf or nan
+    if x_u < min_normal_f64().to_bits() || x_u > MAX_NORMAL {
+        return f_log(x);
+    }
+
+    let shifted = (x_u >> 45) as i32;
+    let index = shifted & 0x7F;
+    let r = f64::from_bits(LOG_RANGE_REDUCTION[index as usize]);
+
+    let x_e = -(E_BIAS as i32) + x_u.wrapping_add(1u64 << 45).wrapping_shr(52) as i32;
+    let e_x = x_e as f64;
+
+    const LOG_2_HI: f64 = f64::from_bits(0x3fe62e42fefa3800);
+    const LOG_2_LO: f64 = f64::from_bits(0x3d2ef35793c76730);
+
+    let log_r_dd = LOG_R_DD[index as usize];
+
+    let hi = f_fmla(e_x, LOG_2_HI, f64::from_bits(log_r_dd.1));
+    let lo = f_fmla(e_x, LOG_2_LO, f64::from_bits(log_r_dd.0));
+
+    // Set m = 1.mantissa.
+    let x_m = (x_u & 0x000F_FFFF_FFFF_FFFFu64) | 0x3FF0_0000_0000_0000u64;
+    let m = f64::from_bits(x_m);
+
+    let u;
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "fma"
+        ),
+        target_arch = "aarch64"
+    ))]
+    {
+        u = f_fmla(r, m, -1.0); // exact
+    }
+    #[cfg(not(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "fma"
+        ),
+        target_arch = "aarch64"
+    )))]
+    {
+        use crate::logs::LOG_CD;
+        let c_m = x_m & 0x3FFF_E000_0000_0000u64;
+        let c = f64::from_bits(c_m);
+        u = f_fmla(r, m - c, f64::from_bits(LOG_CD[index as usize])); // exact
+    }
+
+    let r1 = DoubleDouble::from_exact_add(hi, u);
+
+    let u_sq = u * u;
+
+    let p0 = f_fmla(
+        u,
+        f64::from_bits(LOG_COEFFS[1]),
+        f64::from_bits(LOG_COEFFS[0]),
+    );
+    let p1 = f_fmla(
+        u,
+        f64::from_bits(LOG_COEFFS[3]),
+        f64::from_bits(LOG_COEFFS[2]),
+    );
+    let p2 = f_fmla(
+        u,
+        f64::from_bits(LOG_COEFFS[5]),
+        f64::from_bits(LOG_COEFFS[4]),
+    );
+    let p = f_polyeval4(u_sq, lo + r1.lo, p0, p1, p2);
+    r1.hi + p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_log() {
+        assert_eq!(logf(2.), 0.6931472);
+        assert_eq!(logf(1.), 0.);
+        assert_eq!(logf(0.), f32::NEG_INFINITY);
+        assert!(logf(-1.).is_nan());
+        assert_eq!(log(2.), 0.6931471805599453);
+        assert_eq!(log(1.), 0.);
+        assert_eq!(log(1e-300), -690.7755278982137);
+        assert_eq!(log(0.), f64::NEG_INFINITY);
+        assert!(log(-1.).is_nan());
+    }
+}
@@ -0,0 +1,225 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::{f_fmla, is_integer, is_integerf, is_odd_integer, is_odd_integerf};
+use crate::double_double::DoubleDouble;
+use crate::logs::LOG2_R;
+use crate::polyeval::f_polyeval3;
+use crate::pow_exec::{pow_exp_1, pow_log_1};
+use crate::pow_tables::EXP2_MID1;
+use crate::powf_tables::LOG2_R_TD;
+use crate::rounding::CpuRound;
+use crate::{f_pow, f_powf};
+
+/// Power function
+///
+/// Max found ULP 0.5
+pub fn powf(x: f32, y: f32) -> f32 {
+    let x_abs = x.to_bits() & 0x7fff_ffff;
+    let y_abs = y.to_bits() & 0x7fff_ffff;
+    // y is zero, |y| > 2^47, inf or nan, x is zero, subnormal, inf or nan
+    if y_abs == 0 || y_abs > 0x4f17_0000 || !(0x0080_0000..0x7f80_0000).contains(&x_abs) {
+        return f_powf(x, y);
+    }
+
+    let mut sign: u64 = 0;
+    if x.is_sign_negative() {
+        if !is_integerf(y) {
+            // pow( negative, non-integer ) = NaN
+            return f32::NAN;
+        }
+        if is_odd_integerf(y) {
+            sign = 0x8000_0000_0000_0000u64;
+        }
+    }
+
+    const E_BIAS: u32 = (1u32 << (8 - 1u32)) - 1u32;
+    let e_x = ((x_abs >> 23) as i32 - E_BIAS as i32) as f64;
+    let x_mant = x_abs & ((1u32 << 23) - 1);
+    let idx_x = (x_mant >> (23 - 7)) as i32;
+    // 1 <= m_x < 2
+    let m_x = f32::from_bits(x_mant | 0x3f800000);
+
+    let dx;
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "fma"
+        ),
+        target_arch = "aarch64"
+    ))]
+    {
+        use crate::common::f_fmlaf;
+        use crate::logs::LOG_REDUCTION_F32;
+        dx = f_fmlaf(
+            m_x,
+            f32::from_bits(LOG_REDUCTION_F32.0[idx_x as usize]),
+            -1.0,
+        ) as f64; // Exact.
+    }
+    #[cfg(not(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "fma"
+        ),
+        target_arch = "aarch64"
+    )))]
+    {
+        use crate::logs::LOG_RANGE_REDUCTION;
+        dx = f_fmla(
+            m_x as f64,
+            f64::from_bits(LOG_RANGE_REDUCTION[idx_x as usize]),
+            -1.0,
+        ); // Exact
+    }
+
+    // dx * P(dx) ~ log2(1 + dx)
+    const COEFFS: [u64; 6] = [
+        0x3ff71547652b82fe,
+        0xbfe71547652b7a07,
+        0x3fdec709dc458db1,
+        0xbfd715479c2266c9,
+        0x3fd2776ae1ddf8f0,
+        0xbfce7b2178870157,
+    ];
+
+    let dx2 = dx * dx; // Exact
+    let c0 = f_fmla(dx, f64::from_bits(COEFFS[1]), f64::from_bits(COEFFS[0]));
+    let c1 = f_fmla(dx, f64::from_bits(COEFFS[3]), f64::from_bits(COEFFS[2]));
+    let c2 = f_fmla(dx, f64::from_bits(COEFFS[5]), f64::from_bits(COEFFS[4]));
+
+    let p = f_polyeval3(dx2, c0, c1, c2);
+
+    let s = f_fmla(dx, p, f64::from_bits(LOG2_R[idx_x as usize]) + e_x);
+
+    let y6 = (y * f32::from_bits(0x42800000)) as f64; // Exact.
+    let hm = (s * y6).cpu_round();
+
+    let lo6_hi = f_fmla(y6, e_x + f64::from_bits(LOG2_R_TD[idx_x as usize].2), -hm); // Exact
+    let lo6 = f_fmla(
+        y6,
+        f_fmla(dx, p, f64::from_bits(LOG2_R_TD[idx_x as usize].1)),
+        lo6_hi,
+    );
+
+    let hm_i = unsafe { hm.to_int_unchecked::<i64>() }.clamp(-(1i64 << 15), 1i64 << 15);
+
+    let idx_y = hm_i & 0x3f;
+
+    // 2^hi
+    let exp_hi_i = (hm_i >> 6).wrapping_shl(52);
+    // 2^mid
+    let exp_mid_i = EXP2_MID1[idx_y as usize].1;
+    // (-1)^sign * 2^hi * 2^mid
+    let exp2_hi_mid_i = (exp_hi_i.wrapping_add(exp_mid_i as i64) as u64).wrapping_add(sign);
+    let exp2_hi_mid = f64::from_bits(exp2_hi_mid_i);
+
+    // P(lo6) ~ 2^(lo6 / 2^6) = 2^(lo).
+    const EXP2_COEFFS: [u64; 6] = [
+        0x3ff0000000000000,
+        0x3f862e42fefa39ef,
+        0x3f0ebfbdff82a23a,
+        0x3e8c6b08d7076268,
+        0x3e03b2ad33f8b48b,
+        0x3d75d870c4d84445,
+    ];
+
+    let lo6_sqr = lo6 * lo6;
+    let d0 = f_fmla(
+        lo6,
+        f64::from_bits(EXP2_COEFFS[1]),
+        f64::from_bits(EXP2_COEFFS[0]),
+    );
+    let d1 = f_fmla(
+        lo6,
+        f64::from_bits(EXP2_COEFFS[3]),
+        f64::from_bits(EXP2_COEFFS[2]),
+    );
+    let d2 = f_fmla(
+        lo6,
+        f64::from_bits(EXP2_COEFFS[5]),
+        f64::from_bits(EXP2_COEFFS[4]),
+    );
+    let pp = f_polyeval3(lo6_sqr, d0, d1, d2);
+
+    (pp * exp2_hi_mid) as f32
+}
+
+/// Power function
+///
+/// Max found ULP 0.5001
+pub fn pow(x: f64, y: f64) -> f64 {
+    let x_a = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    let ey = ((y.to_bits() >> 52) & 0x7ff) as i32;
+    // |y| < 2^-969, |y| >= 2^1014, inf or nan, x is zero, subnormal, inf or nan
+    if !(0x36..0x7f5).contains(&ey)
+        || !(0x0010_0000_0000_0000..0x7ff0_0000_0000_0000).contains(&x_a)
+    {
+        return f_pow(x, y);
+    }
+
+    let mut s = 1.0;
+    if x.is_sign_negative() {
+        if !is_integer(y) {
+            // pow( negative, non-integer ) = NaN
+            return f64::NAN;
+        }
+        if is_odd_integer(y) {
+            s = -1.0;
+        }
+    }
+
+    let (l, _) = pow_log_1(f64::from_bits(x_a));
+    let r = DoubleDouble::quick_mult_f64(l, y);
+    let res = pow_exp_1(r, s);
+    // Results close to overflow or in subnormal range can't be obtained from the fast pass
+    if res.hi.is_nan() {
+        return f_pow(x, y);
+    }
+    res.to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_pow() {
+        assert_eq!(powf(0.5, 1.5432), 0.34312353);
+        assert_eq!(powf(-2., 3.), -8.);
+        assert!(powf(-2., 0.5).is_nan());
+        assert_eq!(powf(f32::INFINITY, 0.5), f32::INFINITY);
+        assert_eq!(powf(f32::NAN, 0.), 1.);
+        assert_eq!(pow(2., 0.5), 1.4142135623730951);
+        assert_eq!(pow(-3., 3.), -27.);
+        assert_eq!(pow(10., -300.), 1e-300);
+        assert!(pow(-3., 0.5).is_nan());
+        assert_eq!(pow(0., -1.), f64::INFINITY);
+        assert_eq!(pow(1., 0.5), 1.);
+    }
+}
@@ -0,0 +1,189 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::sin::{range_reduction_small, sincos_eval};
+use crate::sin_cosf::sincosf_eval;
+use crate::sin_table::SIN_K_PI_OVER_128;
+use crate::sincos_reduce::LargeArgumentReduction;
+use crate::{f_cos, f_cosf, f_sin, f_sinf};
+
+/// Sine function
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn sinf(x: f32) -> f32 {
+    let x_abs = x.to_bits() & 0x7fff_ffffu32;
+    // |x| < 0.000443633, inf or nan
+    if x_abs < 0x39e8_9769u32 || x_abs >= 0x7f80_0000u32 {
+        return f_sinf(x);
+    }
+    let rs = sincosf_eval(x as f64, x_abs);
+    f_fmla(rs.sin_y, rs.cos_k, f_fmla(rs.cosm1_y, rs.sin_k, rs.sin_k)) as f32
+}
+
+/// Cosine function
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn cosf(x: f32) -> f32 {
+    let x_abs = x.to_bits() & 0x7fff_ffffu32;
+    // |x| < 0.000244141, inf or nan
+    if x_abs < 0x3980_0000u32 || x_abs >= 0x7f80_0000u32 {
+        return f_cosf(x);
+    }
+    let rs = sincosf_eval(f32::from_bits(x_abs) as f64, x_abs);
+    f_fmla(rs.sin_y, -rs.sin_k, f_fmla(rs.cosm1_y, rs.cos_k, rs.cos_k)) as f32
+}
+
+#[inline]
+fn reduce(x: f64) -> (DoubleDouble, u64) {
+    const E_BIAS: u64 = (1u64 << (11 - 1u64)) - 1u64;
+    let x_e = (x.to_bits() >> 52) & 0x7ff;
+    if x_e < E_BIAS + 16 {
+        range_reduction_small(x)
+    } else {
+        let mut argument_reduction = LargeArgumentReduction::default();
+        let (k, y) = argument_reduction.reduce(x);
+        (y, k)
+    }
+}
+
+/// Sine for double precision
+///
+/// Max found ULP 0.5008
+pub fn sin(x: f64) -> f64 {
+    let x_e = (x.to_bits() >> 52) & 0x7ff;
+    const E_BIAS: u64 = (1u64 << (11 - 1u64)) - 1u64;
+
+    let ax = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    // |x| < 2^-26, inf or nan
+    if x_e < E_BIAS - 26 || x_e > 2 * E_BIAS {
+        return f_sin(x);
+    }
+    if ax <= 0x3fa921fbd34a9550 {
+        // |x| <= 0.0490874
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        const C: [u64; 4] = [
+            0xbfc5555555555555,
+            0x3f8111111110e45a,
+            0xbf2a019ffd7fdaaf,
+            0x3ec71819b9bf01ef,
+        ];
+        let p01 = f_fmla(x2, f64::from_bits(C[1]), f64::from_bits(C[0]));
+        let p23 = f_fmla(x2, f64::from_bits(C[3]), f64::from_bits(C[2]));
+        let w0 = f_fmla(x4, p23, p01);
+        return f_fmla(x2 * w0, x, x);
+    }
+
+    let (y, k) = reduce(x);
+    let r_sincos = sincos_eval(y);
+
+    // cos(k * pi/128) = sin(k * pi/128 + pi/2) = sin((k + 64) * pi/128).
+    let sk = SIN_K_PI_OVER_128[(k & 255) as usize];
+    let ck = SIN_K_PI_OVER_128[((k.wrapping_add(64)) & 255) as usize];
+
+    let sin_k = DoubleDouble::from_bit_pair(sk);
+    let cos_k = DoubleDouble::from_bit_pair(ck);
+
+    let sin_k_cos_y = DoubleDouble::quick_mult(r_sincos.v_cos, sin_k);
+    let cos_k_sin_y = DoubleDouble::quick_mult(r_sincos.v_sin, cos_k);
+
+    let mut rr = DoubleDouble::from_exact_add(sin_k_cos_y.hi, cos_k_sin_y.hi);
+    rr.lo += sin_k_cos_y.lo + cos_k_sin_y.lo;
+    rr.to_f64()
+}
+
+/// Cosine for double precision
+///
+/// Max found ULP 0.502
+pub fn cos(x: f64) -> f64 {
+    let x_e = (x.to_bits() >> 52) & 0x7ff;
+    const E_BIAS: u64 = (1u64 << (11 - 1u64)) - 1u64;
+
+    let ax = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    // |x| < 2^-27, inf or nan
+    if x_e < E_BIAS - 27 || x_e > 2 * E_BIAS {
+        return f_cos(x);
+    }
+    if ax <= 0x3fa921fbd34a9550 {
+        // |x| <= 0.0490874
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        const C: [u64; 4] = [
+            0xbfe0000000000000,
+            0x3fa55555555554a4,
+            0xbf56c16c1619b84a,
+            0x3efa013d3d01cf7f,
+        ];
+        let p01 = f_fmla(x2, f64::from_bits(C[1]), f64::from_bits(C[0]));
+        let p23 = f_fmla(x2, f64::from_bits(C[3]), f64::from_bits(C[2]));
+        let w0 = f_fmla(x4, p23, p01);
+        return f_fmla(x2, w0, 1.);
+    }
+
+    let (y, k) = reduce(x);
+    let r_sincos = sincos_eval(y);
+
+    let sk = SIN_K_PI_OVER_128[(k.wrapping_add(128) & 255) as usize];
+    let ck = SIN_K_PI_OVER_128[((k.wrapping_add(64)) & 255) as usize];
+    let msin_k = DoubleDouble::from_bit_pair(sk);
+    let cos_k = DoubleDouble::from_bit_pair(ck);
+
+    let cos_k_cos_y = DoubleDouble::quick_mult(r_sincos.v_cos, cos_k);
+    let cos_k_msin_y = DoubleDouble::quick_mult(r_sincos.v_sin, msin_k);
+
+    let mut rr = DoubleDouble::from_exact_add(cos_k_cos_y.hi, cos_k_msin_y.hi);
+    rr.lo += cos_k_cos_y.lo + cos_k_msin_y.lo;
+    rr.to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_sincos() {
+        assert_eq!(sinf(1.), 0.84147096);
+        assert_eq!(sinf(-0.3), -0.29552022);
+        assert!(sinf(f32::INFINITY).is_nan());
+        assert_eq!(cosf(0.5), 0.87758255);
+        assert_eq!(cosf(1.7), -0.12884454);
+        assert!(cosf(f32::NAN).is_nan());
+        assert_eq!(sin(1.), 0.8414709848078965);
+        assert_eq!(sin(0.03), 0.02999550020249566);
+        assert_eq!(sin(1e22), -0.8522008497671888);
+        assert!(sin(f64::INFINITY).is_nan());
+        assert_eq!(cos(1.), 0.5403023058681398);
+        assert_eq!(cos(0.03), 0.9995500337489875);
+        assert_eq!(cos(1e22), 0.523214785395139);
+        assert!(cos(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,204 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::{dd_fmla, f_fmla};
+use crate::double_double::DoubleDouble;
+use crate::exponents::{EXP_REDUCE_T0, EXP_REDUCE_T1};
+use crate::{f_tanh, f_tanhf};
+
+/// Hyperbolic tangent
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn tanhf(x: f32) -> f32 {
+    let ux = x.to_bits();
+    let e = ux.wrapping_shr(23) & 0xff;
+    // |x| < 2^-26, |x| > 9.010913, inf or nan
+    if e < 102 || ux.wrapping_shl(1) > (0x41102cb3u32 << 1) {
+        return f_tanhf(x);
+    }
+    let z = x as f64;
+    let z2 = z * z;
+    let z4 = z2 * z2;
+    let z8 = z4 * z4;
+    const CN: [u64; 8] = [
+        0x3ff0000000000000,
+        0x3fc30877b8b72d33,
+        0x3f7694aa09ae9e5e,
+        0x3f14101377abb729,
+        0x3e9e0392b1db0018,
+        0x3e12533756e546f7,
+        0x3d6d62e5abe6ae8a,
+        0x3c9b06be534182de,
+    ];
+    const CD: [u64; 8] = [
+        0x3ff0000000000000,
+        0x3fded99131b0ebea,
+        0x3fa0d27ed6c95a69,
+        0x3f47cbdaca0e9fcc,
+        0x3edb4e60b892578e,
+        0x3e5a6f707c5c71ab,
+        0x3dc35a8b6e2cd94c,
+        0x3d0ca8230677aa01,
+    ];
+    let mut n0 = f_fmla(z2, f64::from_bits(CN[1]), f64::from_bits(CN[0]));
+    let n2 = f_fmla(z2, f64::from_bits(CN[3]), f64::from_bits(CN[2]));
+    let mut n4 = f_fmla(z2, f64::from_bits(CN[5]), f64::from_bits(CN[4]));
+    let n6 = f_fmla(z2, f64::from_bits(CN[7]), f64::from_bits(CN[6]));
+    n0 = f_fmla(z4, n2, n0);
+    n4 = f_fmla(z4, n6, n4);
+    n0 = f_fmla(z8, n4, n0);
+    let mut d0 = f_fmla(z2, f64::from_bits(CD[1]), f64::from_bits(CD[0]));
+    let d2 = f_fmla(z2, f64::from_bits(CD[3]), f64::from_bits(CD[2]));
+    let mut d4 = f_fmla(z2, f64::from_bits(CD[5]), f64::from_bits(CD[4]));
+    let d6 = f_fmla(z2, f64::from_bits(CD[7]), f64::from_bits(CD[6]));
+    d0 = f_fmla(z4, d2, d0);
+    d4 = f_fmla(z4, d6, d4);
+    d0 = f_fmla(z8, d4, d0);
+    (z * n0 / d0) as f32
+}
+
+/// Hyperbolic tangent
+///
+/// Max found ULP 0.561
+pub fn tanh(x: f64) -> f64 {
+    let ax = x.abs();
+    let aix = ax.to_bits();
+    // |x| < 2^-30, |x| >= 19.0615, inf or nan
+    if aix < 0x3e10000000000000u64 || aix >= 0x40330fc1931f09cau64 {
+        return f_tanh(x);
+    }
+    if aix < 0x3fd0000000000000u64 {
+        // |x| < 0x1p-2
+        const C: [u64; 8] = [
+            0xbfd5555555555554,
+            0x3fc1111111110d61,
+            0xbfaba1ba1b983d8b,
+            0x3f9664f4820e99f0,
+            0xbf8226e11e4ac7cf,
+            0x3f6d6c4ab70668b6,
+            0xbf57bbecb57ce996,
+            0x3f41451443697dd8,
+        ];
+        let x2 = x * x;
+        let x3 = x2 * x;
+        let x4 = x2 * x2;
+        let x8 = x4 * x4;
+
+        let p1w0 = f_fmla(x2, f64::from_bits(C[7]), f64::from_bits(C[6]));
+        let p1w1 = f_fmla(x2, f64::from_bits(C[5]), f64::from_bits(C[4]));
+
+        let p0w0 = f_fmla(x2, f64::from_bits(C[3]), f64::from_bits(C[2]));
+        let p0w1 = f_fmla(x2, f64::from_bits(C[1]), f64::from_bits(C[0]));
+
+        let p1 = f_fmla(x4, p1w0, p1w1);
+        let mut p0 = f_fmla(x4, p0w0, p0w1);
+        p0 += x8 * p1;
+        return f_fmla(p0, x3, x);
+    }
+
+    const S: f64 = f64::from_bits(0xc0c71547652b82fe);
+    let v0 = dd_fmla(ax, S, f64::from_bits(0x4188000004000000));
+    let jt = v0.to_bits();
+    let v = v0.to_bits() & 0xfffffffff8000000;
+    let t = f64::from_bits(v) - f64::from_bits(0x4188000000000000);
+
+    let i1: i64 = ((jt >> 27) & 0x3f) as i64;
+    let i0 = (jt >> 33) & 0x3f;
+    let ie = ((jt.wrapping_shl(13)) >> 52) as i64;
+    let sp = (1023i64.wrapping_add(ie) as u64).wrapping_shl(52);
+    const CH: [u64; 4] = [
+        0x4000000000000000,
+        0x4000000000000000,
+        0x3ff55555557e54ff,
+        0x3fe55555553a12f4,
+    ];
+    let t0h = f64::from_bits(EXP_REDUCE_T0[i0 as usize].1);
+    let t1h = f64::from_bits(EXP_REDUCE_T1[i1 as usize].1);
+    let th = t0h * t1h;
+    if aix < 0x400d76c8b4395810u64 {
+        // |x| ~< 3.683
+        let t0l = f64::from_bits(EXP_REDUCE_T0[i0 as usize].0);
+        let t1l = f64::from_bits(EXP_REDUCE_T1[i1 as usize].0);
+        let tl = f_fmla(t0h, t1l, t1h * t0l) + dd_fmla(t0h, t1h, -th);
+        let th = th * f64::from_bits(sp);
+        let tl = tl * f64::from_bits(sp);
+        const L2H: f64 = f64::from_bits(0xbf162e42ff000000);
+        const L2L: f64 = f64::from_bits(0xbcf718432a1b0e26);
+        let dx = f_fmla(-L2L, t, f_fmla(L2H, t, -ax));
+        let dx2 = dx * dx;
+
+        let pw0 = f_fmla(dx, f64::from_bits(CH[3]), f64::from_bits(CH[2]));
+        let pw1 = f_fmla(dx, f64::from_bits(CH[1]), f64::from_bits(CH[0]));
+
+        let p = dx * f_fmla(dx2, pw0, pw1);
+        let r = DoubleDouble::from_exact_add(th, tl + th * p);
+
+        let q = DoubleDouble::from_exact_add(1.0, r.hi);
+        let qh = q.hi;
+        let ql = q.lo + r.lo;
+
+        let rqh = 1.0 / qh;
+        let rql = f_fmla(ql, rqh, dd_fmla(rqh, qh, -1.)) * -rqh;
+        let p = DoubleDouble::mult(r, DoubleDouble::new(rql, rqh));
+
+        let mut r = DoubleDouble::from_exact_sub(0.5, p.hi);
+        r.lo -= p.lo;
+        return f_fmla(f64::copysign(2., x), r.hi, f64::copysign(2., x) * r.lo);
+    }
+    const L2: f64 = f64::from_bits(0xbf162e42fefa39ef);
+    let dx = dd_fmla(L2, t, -ax);
+    let dx2 = dx * dx;
+
+    let pw0 = f_fmla(dx, f64::from_bits(CH[3]), f64::from_bits(CH[2]));
+    let pw1 = f_fmla(dx, f64::from_bits(CH[1]), f64::from_bits(CH[0]));
+
+    let p = dx * f_fmla(dx2, pw0, pw1);
+    let mut rh = th * f64::from_bits(sp);
+    rh += (p + ((2. * f64::from_bits(0x3c83000000000000)) * ax)) * rh;
+    rh = (2. * rh) / (1. + rh);
+    f64::copysign(1., x) - f64::copysign(rh, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_tanh() {
+        assert_eq!(tanhf(0.5), 0.46211717);
+        assert_eq!(tanhf(-7.), -0.99999833);
+        assert_eq!(tanhf(f32::INFINITY), 1.);
+        assert!(tanhf(f32::NAN).is_nan());
+        assert_eq!(tanh(0.1), 0.09966799462495582);
+        assert_eq!(tanh(-2.549980150947863), -0.9878799187977153);
+        assert_eq!(tanh(4.799980150947863), 0.9998645463239773);
+        assert_eq!(tanh(f64::NEG_INFINITY), -1.);
+        assert!(tanh(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,135 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::flags::{FpFlags, classify, classifyf};
+use crate::{f_exp, f_exp2, f_exp2f, f_exp10, f_exp10f, f_expf, f_expm1, f_expm1f};
+
+/// Computes exp(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn exp(x: f64) -> (f64, FpFlags) {
+    let r = f_exp(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes exp(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn expf(x: f32) -> (f32, FpFlags) {
+    let r = f_expf(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes 2^x with exception flags
+///
+/// Max ULP 0.5
+pub fn exp2(x: f64) -> (f64, FpFlags) {
+    let r = f_exp2(x);
+    // 2^n is exact for every integer n in the representable range
+    let exact = x.is_infinite() || (x.trunc() == x && (-1074. ..=1023.).contains(&x));
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes 2^x with exception flags
+///
+/// Max ULP 0.5
+pub fn exp2f(x: f32) -> (f32, FpFlags) {
+    let r = f_exp2f(x);
+    let exact = x.is_infinite() || (x.trunc() == x && (-149. ..=127.).contains(&x));
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes 10^x with exception flags
+///
+/// Max ULP 0.5
+pub fn exp10(x: f64) -> (f64, FpFlags) {
+    let r = f_exp10(x);
+    // 10^n is exact for n in [0, 22]
+    let exact = x.is_infinite() || (x.trunc() == x && (0. ..=22.).contains(&x));
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes 10^x with exception flags
+///
+/// Max ULP 0.5
+pub fn exp10f(x: f32) -> (f32, FpFlags) {
+    let r = f_exp10f(x);
+    // 10^n is exact for n in [0, 10]
+    let exact = x.is_infinite() || (x.trunc() == x && (0. ..=10.).contains(&x));
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes exp(x) - 1 with exception flags
+///
+/// Max ULP 0.5
+pub fn expm1(x: f64) -> (f64, FpFlags) {
+    let r = f_expm1(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes exp(x) - 1 with exception flags
+///
+/// Max ULP 0.5
+pub fn expm1f(x: f32) -> (f32, FpFlags) {
+    let r = f_expm1f(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp_flags() {
+        assert_eq!(exp(0.), (1., FpFlags::empty()));
+        assert_eq!(
+            exp(1000.),
+            (f64::INFINITY, FpFlags::OVERFLOW | FpFlags::INEXACT)
+        );
+        assert_eq!(exp(-1000.), (0., FpFlags::UNDERFLOW | FpFlags::INEXACT));
+        assert_eq!(exp(f64::NEG_INFINITY), (0., FpFlags::empty()));
+        assert_eq!(exp(1.).1, FpFlags::INEXACT);
+        assert!(exp(f64::NAN).1.is_empty());
+        assert_eq!(
+            expf(100.),
+            (f32::INFINITY, FpFlags::OVERFLOW | FpFlags::INEXACT)
+        );
+        assert_eq!(exp2(-1074.), (f64::from_bits(1), FpFlags::empty()));
+        assert_eq!(exp2(-1074.5).1, FpFlags::UNDERFLOW | FpFlags::INEXACT);
+        assert_eq!(exp2f(3.), (8., FpFlags::empty()));
+        assert_eq!(exp10(22.), (1e22, FpFlags::empty()));
+        assert_eq!(exp10(23.).1, FpFlags::INEXACT);
+        assert_eq!(exp10f(-1.).1, FpFlags::INEXACT);
+        assert_eq!(expm1(0.), (0., FpFlags::empty()));
+        assert_eq!(expm1f(1e-40).1, FpFlags::UNDERFLOW | FpFlags::INEXACT);
+    }
+}
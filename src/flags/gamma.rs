@@ -0,0 +1,89 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::flags::{FpFlags, classify, classifyf};
+use crate::{f_lgamma, f_lgammaf, f_tgamma, f_tgammaf};
+
+/// Computes gamma(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn tgamma(x: f64) -> (f64, FpFlags) {
+    let r = f_tgamma(x);
+    // (n-1)! is exact for n in [1, 23]
+    let exact = x == f64::INFINITY || (x.trunc() == x && (1. ..=23.).contains(&x));
+    (r, classify(x.is_nan(), x.is_finite(), r, x == 0., exact))
+}
+
+/// Computes gamma(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn tgammaf(x: f32) -> (f32, FpFlags) {
+    let r = f_tgammaf(x);
+    // (n-1)! is exact for n in [1, 14]
+    let exact = x == f32::INFINITY || (x.trunc() == x && (1. ..=14.).contains(&x));
+    (r, classifyf(x.is_nan(), x.is_finite(), r, x == 0., exact))
+}
+
+/// Computes ln(|gamma(x)|) with exception flags
+///
+/// Max ULP 0.5
+pub fn lgamma(x: f64) -> (f64, FpFlags) {
+    let r = f_lgamma(x);
+    let pole = x <= 0. && x.trunc() == x;
+    let exact = x == 1. || x == 2. || x.is_infinite();
+    (r, classify(x.is_nan(), x.is_finite(), r, pole, exact))
+}
+
+/// Computes ln(|gamma(x)|) with exception flags
+///
+/// Max ULP 0.5
+pub fn lgammaf(x: f32) -> (f32, FpFlags) {
+    let r = f_lgammaf(x);
+    let pole = x <= 0. && x.trunc() == x;
+    let exact = x == 1. || x == 2. || x.is_infinite();
+    (r, classifyf(x.is_nan(), x.is_finite(), r, pole, exact))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gamma_flags() {
+        assert_eq!(tgamma(5.), (24., FpFlags::empty()));
+        assert_eq!(tgamma(0.), (f64::INFINITY, FpFlags::DIVBYZERO));
+        assert_eq!(tgamma(-2.).1, FpFlags::INVALID);
+        assert_eq!(tgamma(0.5).1, FpFlags::INEXACT);
+        assert_eq!(tgamma(200.).1, FpFlags::OVERFLOW | FpFlags::INEXACT);
+        assert_eq!(tgammaf(4.), (6., FpFlags::empty()));
+        assert_eq!(lgamma(1.), (0., FpFlags::empty()));
+        assert_eq!(lgamma(-2.), (f64::INFINITY, FpFlags::DIVBYZERO));
+        assert_eq!(lgamma(3.).1, FpFlags::INEXACT);
+        assert_eq!(lgammaf(0.), (f32::INFINITY, FpFlags::DIVBYZERO));
+    }
+}
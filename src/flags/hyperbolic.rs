@@ -0,0 +1,168 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::flags::{FpFlags, classify, classifyf};
+use crate::{
+    f_acosh, f_acoshf, f_asinh, f_asinhf, f_atanh, f_atanhf, f_cosh, f_coshf, f_sinh, f_sinhf,
+    f_tanh, f_tanhf,
+};
+
+/// Computes sinh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn sinh(x: f64) -> (f64, FpFlags) {
+    let r = f_sinh(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes sinh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn sinhf(x: f32) -> (f32, FpFlags) {
+    let r = f_sinhf(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes cosh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn cosh(x: f64) -> (f64, FpFlags) {
+    let r = f_cosh(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes cosh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn coshf(x: f32) -> (f32, FpFlags) {
+    let r = f_coshf(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes tanh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn tanh(x: f64) -> (f64, FpFlags) {
+    let r = f_tanh(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes tanh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn tanhf(x: f32) -> (f32, FpFlags) {
+    let r = f_tanhf(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes asinh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn asinh(x: f64) -> (f64, FpFlags) {
+    let r = f_asinh(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes asinh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn asinhf(x: f32) -> (f32, FpFlags) {
+    let r = f_asinhf(x);
+    let exact = x == 0. || x.is_infinite();
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes acosh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn acosh(x: f64) -> (f64, FpFlags) {
+    let r = f_acosh(x);
+    let exact = x == 1. || x == f64::INFINITY;
+    (r, classify(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes acosh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn acoshf(x: f32) -> (f32, FpFlags) {
+    let r = f_acoshf(x);
+    let exact = x == 1. || x == f32::INFINITY;
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, exact))
+}
+
+/// Computes atanh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn atanh(x: f64) -> (f64, FpFlags) {
+    let r = f_atanh(x);
+    (
+        r,
+        classify(x.is_nan(), x.is_finite(), r, x.abs() == 1., x == 0.),
+    )
+}
+
+/// Computes atanh(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn atanhf(x: f32) -> (f32, FpFlags) {
+    let r = f_atanhf(x);
+    (
+        r,
+        classifyf(x.is_nan(), x.is_finite(), r, x.abs() == 1., x == 0.),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperbolic_flags() {
+        assert_eq!(sinh(0.), (0., FpFlags::empty()));
+        assert_eq!(sinh(1000.).1, FpFlags::OVERFLOW | FpFlags::INEXACT);
+        assert_eq!(sinhf(f32::INFINITY), (f32::INFINITY, FpFlags::empty()));
+        assert_eq!(cosh(0.), (1., FpFlags::empty()));
+        assert_eq!(coshf(100.).1, FpFlags::OVERFLOW | FpFlags::INEXACT);
+        assert_eq!(tanh(1.).1, FpFlags::INEXACT);
+        assert_eq!(tanhf(-0.).1, FpFlags::empty());
+        assert_eq!(asinh(1e-310).1, FpFlags::UNDERFLOW | FpFlags::INEXACT);
+        assert_eq!(asinhf(2.).1, FpFlags::INEXACT);
+        assert_eq!(acosh(1.), (0., FpFlags::empty()));
+        assert_eq!(acoshf(0.5).1, FpFlags::INVALID);
+        assert_eq!(atanh(1.), (f64::INFINITY, FpFlags::DIVBYZERO));
+        assert_eq!(atanh(2.).1, FpFlags::INVALID);
+        assert_eq!(atanhf(-1.), (f32::NEG_INFINITY, FpFlags::DIVBYZERO));
+    }
+}
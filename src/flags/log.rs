@@ -0,0 +1,147 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::flags::{FpFlags, classify, classifyf};
+use crate::{f_log, f_log1p, f_log1pf, f_log2, f_log2f, f_log10, f_log10f, f_logf};
+
+/// Computes ln(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn log(x: f64) -> (f64, FpFlags) {
+    let r = f_log(x);
+    let exact = x == 1. || x == f64::INFINITY;
+    (r, classify(x.is_nan(), x.is_finite(), r, x == 0., exact))
+}
+
+/// Computes ln(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn logf(x: f32) -> (f32, FpFlags) {
+    let r = f_logf(x);
+    let exact = x == 1. || x == f32::INFINITY;
+    (r, classifyf(x.is_nan(), x.is_finite(), r, x == 0., exact))
+}
+
+/// Computes log2(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn log2(x: f64) -> (f64, FpFlags) {
+    let r = f_log2(x);
+    // exact for powers of two, including subnormals
+    let exact = x == f64::INFINITY || (x > 0. && r.trunc() == r && f_exp2_exact(r) == x);
+    (r, classify(x.is_nan(), x.is_finite(), r, x == 0., exact))
+}
+
+/// Computes log2(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn log2f(x: f32) -> (f32, FpFlags) {
+    let r = f_log2f(x);
+    let exact =
+        x == f32::INFINITY || (x > 0. && r.trunc() == r && f_exp2_exact(r as f64) == x as f64);
+    (r, classifyf(x.is_nan(), x.is_finite(), r, x == 0., exact))
+}
+
+/// Computes log10(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn log10(x: f64) -> (f64, FpFlags) {
+    let r = f_log10(x);
+    // exact for 10^n, n in [0, 22]
+    let exact = x == f64::INFINITY
+        || (r.trunc() == r && (0. ..=22.).contains(&r) && 10f64.powi(r as i32) == x);
+    (r, classify(x.is_nan(), x.is_finite(), r, x == 0., exact))
+}
+
+/// Computes log10(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn log10f(x: f32) -> (f32, FpFlags) {
+    let r = f_log10f(x);
+    // exact for 10^n, n in [0, 10]
+    let exact = x == f32::INFINITY
+        || (r.trunc() == r && (0. ..=10.).contains(&r) && 10f32.powi(r as i32) == x);
+    (r, classifyf(x.is_nan(), x.is_finite(), r, x == 0., exact))
+}
+
+/// Computes ln(1 + x) with exception flags
+///
+/// Max ULP 0.5
+pub fn log1p(x: f64) -> (f64, FpFlags) {
+    let r = f_log1p(x);
+    let exact = x == 0. || x == f64::INFINITY;
+    (r, classify(x.is_nan(), x.is_finite(), r, x == -1., exact))
+}
+
+/// Computes ln(1 + x) with exception flags
+///
+/// Max ULP 0.5
+pub fn log1pf(x: f32) -> (f32, FpFlags) {
+    let r = f_log1pf(x);
+    let exact = x == 0. || x == f32::INFINITY;
+    (r, classifyf(x.is_nan(), x.is_finite(), r, x == -1., exact))
+}
+
+/// Exact 2^n for integral n, 0 outside of representable range
+#[inline]
+fn f_exp2_exact(n: f64) -> f64 {
+    if n > 1023. || n < -1074. {
+        return 0.;
+    }
+    let n = n as i32;
+    if n >= -1022 {
+        f64::from_bits(((n + 1023) as u64) << 52)
+    } else {
+        f64::from_bits(1u64 << (n + 1074))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_flags() {
+        assert_eq!(log(1.), (0., FpFlags::empty()));
+        assert_eq!(log(0.), (f64::NEG_INFINITY, FpFlags::DIVBYZERO));
+        assert_eq!(log(-1.).1, FpFlags::INVALID);
+        assert_eq!(log(2.).1, FpFlags::INEXACT);
+        assert_eq!(log(f64::INFINITY), (f64::INFINITY, FpFlags::empty()));
+        assert_eq!(logf(0.), (f32::NEG_INFINITY, FpFlags::DIVBYZERO));
+        assert_eq!(log2(0.125), (-3., FpFlags::empty()));
+        assert_eq!(log2(f64::from_bits(1)), (-1074., FpFlags::empty()));
+        assert_eq!(log2(3.).1, FpFlags::INEXACT);
+        assert_eq!(log2f(1024.), (10., FpFlags::empty()));
+        assert_eq!(log10(1000.), (3., FpFlags::empty()));
+        assert_eq!(log10(0.1).1, FpFlags::INEXACT);
+        assert_eq!(log10f(100.), (2., FpFlags::empty()));
+        assert_eq!(log1p(-1.), (f64::NEG_INFINITY, FpFlags::DIVBYZERO));
+        assert_eq!(log1p(1e-310).1, FpFlags::UNDERFLOW | FpFlags::INEXACT);
+        assert_eq!(log1pf(-2.).1, FpFlags::INVALID);
+    }
+}
@@ -0,0 +1,193 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Floating-point exception flags reporting.
+//!
+//! Each function here returns the same value as its counterpart from the crate root
+//! together with the IEEE 754 exceptions that a C99 Annex F implementation would raise
+//! for the same operation: invalid, divide-by-zero, overflow, underflow and inexact.
+//! Flags are derived from the already existing special-case results, so the plain
+//! functions keep their cost and nothing is stored in a global or thread-local state.
+//!
+//! As permitted by C99 F.9, inexact may be reported for some results that happen to be exact,
+//! it is never omitted for an inexact result. Underflow is detected after rounding.
+#![deny(unreachable_pub)]
+mod exp;
+mod gamma;
+mod hyperbolic;
+mod log;
+mod pow;
+mod trigonometry;
+
+pub use exp::{exp, exp2, exp2f, exp10, exp10f, expf, expm1, expm1f};
+pub use gamma::{lgamma, lgammaf, tgamma, tgammaf};
+pub use hyperbolic::{
+    acosh, acoshf, asinh, asinhf, atanh, atanhf, cosh, coshf, sinh, sinhf, tanh, tanhf,
+};
+pub use log::{log, log1p, log1pf, log2, log2f, log10, log10f, logf};
+pub use pow::{pow, powf};
+pub use trigonometry::{acos, acosf, asin, asinf, atan, atanf, cos, cosf, sin, sinf, tan, tanf};
+
+use std::ops::{BitOr, BitOrAssign};
+
+/// Set of IEEE 754 floating-point exceptions
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FpFlags(u8);
+
+impl FpFlags {
+    /// Operation has no mathematically defined result, NaN is returned
+    pub const INVALID: FpFlags = FpFlags(1);
+    /// Exact infinite result from finite arguments, e.g. a pole
+    pub const DIVBYZERO: FpFlags = FpFlags(1 << 1);
+    /// Result is too large to be represented and was rounded to infinity
+    pub const OVERFLOW: FpFlags = FpFlags(1 << 2);
+    /// Result is tiny (subnormal or zero) and inexact
+    pub const UNDERFLOW: FpFlags = FpFlags(1 << 3);
+    /// Result was rounded
+    pub const INEXACT: FpFlags = FpFlags(1 << 4);
+
+    /// No exceptions
+    #[inline]
+    pub const fn empty() -> FpFlags {
+        FpFlags(0)
+    }
+
+    /// Raw bits of the set
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if no exception is set
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if all exceptions from `other` are set
+    #[inline]
+    pub const fn contains(self, other: FpFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Union of two sets
+    #[inline]
+    pub const fn union(self, other: FpFlags) -> FpFlags {
+        FpFlags(self.0 | other.0)
+    }
+}
+
+impl BitOr for FpFlags {
+    type Output = FpFlags;
+
+    #[inline]
+    fn bitor(self, rhs: FpFlags) -> FpFlags {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for FpFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: FpFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Derives exceptions from the result of a function.
+///
+/// `finite` tells whether all arguments are finite, `pole` whether an infinite result
+/// at this point is exact, `exact` whether a finite result is exact.
+#[inline]
+pub(crate) fn classify(nan_input: bool, finite: bool, r: f64, pole: bool, exact: bool) -> FpFlags {
+    if nan_input {
+        // quiet NaN propagation, or a defined result such as pow(1, NaN)
+        return FpFlags::empty();
+    }
+    if r.is_nan() {
+        return FpFlags::INVALID;
+    }
+    if r.is_infinite() {
+        return if !finite {
+            FpFlags::empty()
+        } else if pole {
+            FpFlags::DIVBYZERO
+        } else {
+            FpFlags::OVERFLOW | FpFlags::INEXACT
+        };
+    }
+    if exact {
+        return FpFlags::empty();
+    }
+    if r == 0. || r.is_subnormal() {
+        return FpFlags::UNDERFLOW | FpFlags::INEXACT;
+    }
+    FpFlags::INEXACT
+}
+
+/// Single precision counterpart of [classify].
+#[inline]
+pub(crate) fn classifyf(nan_input: bool, finite: bool, r: f32, pole: bool, exact: bool) -> FpFlags {
+    if r.is_subnormal() && !exact && !nan_input {
+        return FpFlags::UNDERFLOW | FpFlags::INEXACT;
+    }
+    classify(nan_input, finite, r as f64, pole, exact)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fp_flags() {
+        let flags = FpFlags::OVERFLOW | FpFlags::INEXACT;
+        assert!(flags.contains(FpFlags::OVERFLOW));
+        assert!(flags.contains(FpFlags::INEXACT));
+        assert!(!flags.contains(FpFlags::UNDERFLOW));
+        assert!(FpFlags::empty().is_empty());
+        assert_eq!(
+            classify(false, true, f64::NAN, false, false),
+            FpFlags::INVALID
+        );
+        assert_eq!(
+            classify(true, false, f64::NAN, false, false),
+            FpFlags::empty()
+        );
+        assert_eq!(
+            classify(false, true, f64::INFINITY, true, false),
+            FpFlags::DIVBYZERO
+        );
+        assert_eq!(
+            classify(false, true, 1e-310, false, false),
+            FpFlags::UNDERFLOW | FpFlags::INEXACT
+        );
+        assert_eq!(
+            classifyf(false, true, 1e-40, false, false),
+            FpFlags::UNDERFLOW | FpFlags::INEXACT
+        );
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::flags::{FpFlags, classify, classifyf};
+use crate::{f_pow, f_powf};
+
+/// Splits finite non-zero `x` into odd mantissa and exponent
+#[inline]
+fn odd_decompose(x: f64) -> (u64, i64) {
+    let bits = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    let biased = (bits >> 52) as i64;
+    let (m, e) = if biased == 0 {
+        (bits, -1074)
+    } else {
+        ((bits & ((1u64 << 52) - 1)) | (1u64 << 52), biased - 1075)
+    };
+    let tz = m.trailing_zeros();
+    (m >> tz, e + tz as i64)
+}
+
+/// Checks if correctly rounded `r` = x^y is the exact value of x^y
+fn pow_is_exact(x: f64, y: f64, r: f64) -> bool {
+    if y == 0. || x == 1. || x == 0. || x.abs() == 1. {
+        return true;
+    }
+    if x.is_infinite() || y.is_infinite() {
+        return r == 0. || r.is_infinite() || r.abs() == 1.;
+    }
+    // non-integral powers are treated as inexact, and for |x| != 1 an exact
+    // result requires |y| to stay below the exponent range
+    if y.trunc() != y || y.abs() > 1100. || r == 0. || !r.is_finite() {
+        return false;
+    }
+    let n = y as i64;
+    let (mx, ex) = odd_decompose(x);
+    let (mr, er) = odd_decompose(r);
+    if er != ex * n {
+        return false;
+    }
+    if n < 0 {
+        return mx == 1 && mr == 1;
+    }
+    let mut acc = 1u64;
+    for _ in 0..n {
+        acc = match acc.checked_mul(mx) {
+            Some(v) if v < (1u64 << 53) => v,
+            _ => return false,
+        };
+    }
+    acc == mr
+}
+
+/// Computes x^y with exception flags
+///
+/// Max ULP 0.5
+pub fn pow(x: f64, y: f64) -> (f64, FpFlags) {
+    let r = f_pow(x, y);
+    let pole = x == 0. && y < 0.;
+    (
+        r,
+        classify(
+            x.is_nan() || y.is_nan(),
+            x.is_finite() && y.is_finite(),
+            r,
+            pole,
+            pow_is_exact(x, y, r),
+        ),
+    )
+}
+
+/// Computes x^y with exception flags
+///
+/// Max ULP 0.5
+pub fn powf(x: f32, y: f32) -> (f32, FpFlags) {
+    let r = f_powf(x, y);
+    let pole = x == 0. && y < 0.;
+    (
+        r,
+        classifyf(
+            x.is_nan() || y.is_nan(),
+            x.is_finite() && y.is_finite(),
+            r,
+            pole,
+            pow_is_exact(x as f64, y as f64, r as f64),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow_flags() {
+        assert_eq!(pow(3., 4.), (81., FpFlags::empty()));
+        assert_eq!(pow(2., -3.), (0.125, FpFlags::empty()));
+        assert_eq!(pow(3., -1.).1, FpFlags::INEXACT);
+        assert_eq!(pow(2., 0.5).1, FpFlags::INEXACT);
+        assert_eq!(pow(0., -1.), (f64::INFINITY, FpFlags::DIVBYZERO));
+        assert_eq!(pow(-2., 0.5).1, FpFlags::INVALID);
+        assert_eq!(pow(10., 400.).1, FpFlags::OVERFLOW | FpFlags::INEXACT);
+        assert_eq!(pow(2., -1074.), (f64::from_bits(1), FpFlags::empty()));
+        assert_eq!(pow(2., -1080.).1, FpFlags::UNDERFLOW | FpFlags::INEXACT);
+        assert_eq!(pow(3., 40.).1, FpFlags::INEXACT);
+        assert!(pow(f64::NAN, 2.).1.is_empty());
+        assert_eq!(pow(0.5, f64::INFINITY), (0., FpFlags::empty()));
+        assert_eq!(powf(-3., 3.), (-27., FpFlags::empty()));
+        assert_eq!(powf(3., 16.).1, FpFlags::INEXACT);
+        assert_eq!(powf(0., -2.), (f32::INFINITY, FpFlags::DIVBYZERO));
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::flags::{FpFlags, classify, classifyf};
+use crate::{
+    f_acos, f_acosf, f_asin, f_asinf, f_atan, f_atanf, f_cos, f_cosf, f_sin, f_sinf, f_tan, f_tanf,
+};
+
+/// Computes sin(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn sin(x: f64) -> (f64, FpFlags) {
+    let r = f_sin(x);
+    (r, classify(x.is_nan(), x.is_finite(), r, false, x == 0.))
+}
+
+/// Computes sin(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn sinf(x: f32) -> (f32, FpFlags) {
+    let r = f_sinf(x);
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, x == 0.))
+}
+
+/// Computes cos(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn cos(x: f64) -> (f64, FpFlags) {
+    let r = f_cos(x);
+    (r, classify(x.is_nan(), x.is_finite(), r, false, x == 0.))
+}
+
+/// Computes cos(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn cosf(x: f32) -> (f32, FpFlags) {
+    let r = f_cosf(x);
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, x == 0.))
+}
+
+/// Computes tan(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn tan(x: f64) -> (f64, FpFlags) {
+    let r = f_tan(x);
+    (r, classify(x.is_nan(), x.is_finite(), r, false, x == 0.))
+}
+
+/// Computes tan(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn tanf(x: f32) -> (f32, FpFlags) {
+    let r = f_tanf(x);
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, x == 0.))
+}
+
+/// Computes asin(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn asin(x: f64) -> (f64, FpFlags) {
+    let r = f_asin(x);
+    (r, classify(x.is_nan(), x.is_finite(), r, false, x == 0.))
+}
+
+/// Computes asin(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn asinf(x: f32) -> (f32, FpFlags) {
+    let r = f_asinf(x);
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, x == 0.))
+}
+
+/// Computes acos(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn acos(x: f64) -> (f64, FpFlags) {
+    let r = f_acos(x);
+    (r, classify(x.is_nan(), x.is_finite(), r, false, x == 1.))
+}
+
+/// Computes acos(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn acosf(x: f32) -> (f32, FpFlags) {
+    let r = f_acosf(x);
+    (r, classifyf(x.is_nan(), x.is_finite(), r, false, x == 1.))
+}
+
+/// Computes atan(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn atan(x: f64) -> (f64, FpFlags) {
+    let r = f_atan(x);
+    // atan(±inf) = ±pi/2 is inexact
+    (r, classify(x.is_nan(), true, r, false, x == 0.))
+}
+
+/// Computes atan(x) with exception flags
+///
+/// Max ULP 0.5
+pub fn atanf(x: f32) -> (f32, FpFlags) {
+    let r = f_atanf(x);
+    (r, classifyf(x.is_nan(), true, r, false, x == 0.))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trigonometry_flags() {
+        assert_eq!(sin(0.), (0., FpFlags::empty()));
+        assert_eq!(sin(f64::INFINITY).1, FpFlags::INVALID);
+        assert_eq!(sin(1e-310).1, FpFlags::UNDERFLOW | FpFlags::INEXACT);
+        assert_eq!(sinf(1.).1, FpFlags::INEXACT);
+        assert_eq!(cos(0.), (1., FpFlags::empty()));
+        assert_eq!(cosf(f32::NEG_INFINITY).1, FpFlags::INVALID);
+        assert_eq!(tan(-0.).1, FpFlags::empty());
+        assert_eq!(tanf(0.5).1, FpFlags::INEXACT);
+        assert_eq!(asin(2.).1, FpFlags::INVALID);
+        assert_eq!(asinf(0.).1, FpFlags::empty());
+        assert_eq!(acos(1.), (0., FpFlags::empty()));
+        assert_eq!(acosf(-1.).1, FpFlags::INEXACT);
+        assert_eq!(atan(f64::INFINITY).1, FpFlags::INEXACT);
+        assert!(atanf(f32::NAN).1.is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::rounding::CpuRound;
+use crate::sici::ComplexDD;
+use crate::sincospi::{reduce_pi_64, sincospi_eval_dd};
+use crate::sincospi_tables::SINPI_K_PI_OVER_64;
+
+const PI_DD: DoubleDouble = DoubleDouble::from_bit_pair((0x3ca1a62633145c07, 0x400921fb54442d18));
+const INV_PI_DD: DoubleDouble =
+    DoubleDouble::from_bit_pair((0xbc76b01ec5417056, 0x3fd45f306dc9c883));
+
+/// Taylor series of C(x)/x in powers of x^4.
+///
+/// Generated by SageMath:
+/// ```text
+/// R = RealField(300)
+/// for k in range(0, 37):
+///     c = (-1)^k * (R(pi)/2)^(2*k) / (factorial(2*k) * (4*k + 1))
+/// ```
+static FRESNEL_C_SERIES: [(u64, u64); 37] = [
+    (0x0000000000000000, 0x3ff0000000000000),
+    (0x3c6be20e475b865f, 0xbfcf952e0f96d631),
+    (0xbc33db070f1c0241, 0x3f9cdca8f1f7bdce),
+    (0x3be5f316db1f0917, 0xbf5a4b5e253713aa),
+    (0x3b8331cba13e19af, 0x3f0c59b5171fbece),
+    (0xbb338bf94ec4c943, 0xbeb42260e324cb15),
+    (0xbad5dc5e2adaad7d, 0x3e543ba95e6026e3),
+    (0x3a56a734cad33f05, 0xbdee4492b54a02d3),
+    (0x3a08ab701700daa5, 0x3d81805fc4cc7452),
+    (0xb9b4d45339ee8f8a, 0xbd101c57d7f1bd79),
+    (0xb936250c1447b2f4, 0x3c982ad6a93d8480),
+    (0xb8beadc3de259997, 0xbc1e1addf78e08bf),
+    (0xb83b88f2f50f7503, 0x3b9fa310eb5b8d10),
+    (0xb7b6f3bb389c7fa0, 0xbb1c6c7ba2b1e876),
+    (0x37336f689b6cb6b3, 0x3a961504e4af5029),
+    (0xb693c0c4b71c075e, 0xba0df65d043db542),
+    (0x36249efb03023400, 0x3981e788b7efc0d5),
+    (0x35521d99ea453b1e, 0xb8f2fda05f5eddc3),
+    (0xb4d62630a60b82de, 0x3861ff4e2529deb6),
+    (0xb443f73384ba553c, 0xb7cea958c17f042b),
+    (0xb37e259b8edf0744, 0x37379a9b57423af9),
+    (0xb340e54508ef3c95, 0xb6a0806b4944963e),
+    (0x32aa71ca4326ccb8, 0x36050bd66a9d57aa),
+    (0xb20bac801f25f16d, 0xb568957b1b0b3ea3),
+    (0xb16e033e766e3dc6, 0x34ca65c27e0e471e),
+    (0x30ab824d3457164a, 0xb42a25174fc50690),
+    (0x302278f552c90960, 0x3387f5bc2022d1e7),
+    (0x2f81f5d0c10ef2c3, 0xb2e4603e69e57b94),
+    (0xaee84465bf4ab9df, 0x32401f93187ca09f),
+    (0xae3eee98631545e6, 0xb197cd534ba69f68),
+    (0x2d8d34ab57b69e01, 0x30f06d3ebc529e2b),
+    (0xacc0a407e97e3924, 0xb0453ef280719625),
+    (0x2c20b6f60a20ae55, 0x2f99cd2cd28a7c11),
+    (0xab68500f1fd9111c, 0xaeed7a5024621e5b),
+    (0x2ad023013824ede5, 0x2e3fbd889033752c),
+    (0x29ba02bf7c68084c, 0xad9021f5ef284646),
+    (0x297148f53e791b40, 0x2cdf03bb9554419a),
+];
+
+/// Taylor series of S(x)/x^3 in powers of x^4.
+///
+/// Generated by SageMath:
+/// ```text
+/// R = RealField(300)
+/// for k in range(0, 36):
+///     c = (-1)^k * (R(pi)/2)^(2*k + 1) / (factorial(2*k + 1) * (4*k + 3))
+/// ```
+static FRESNEL_S_SERIES: [(u64, u64); 36] = [
+    (0xbc8ee6913347c2a6, 0x3fe0c152382d7366),
+    (0x3c52aa5d7529ac2e, 0xbfb79fb3502b22a8),
+    (0x3c1deb99a477f48a, 0x3f7dacb4f393e148),
+    (0x3bd17e6b2ccf64b4, 0xbf3474740f5841a0),
+    (0xbb85ad8add657db7, 0x3ee1b57c07277f96),
+    (0xbb2c36eb23f55025, 0xbe85005162b804f4),
+    (0xbacc1108aeb6cb25, 0x3e221c01f4be8cad),
+    (0x3a586f1047457895, 0xbdb7b8a0a4370fc2),
+    (0xb9e751383833f54c, 0x3d486544b9936226),
+    (0x395c2340aa968ff1, 0xbcd437cee019ea13),
+    (0xb8f73e108b4ff688, 0x3c5b940030de0759),
+    (0xb8629668835f9e37, 0xbbdf7f257cc18111),
+    (0x37f08f3b3287d0e4, 0x3b5e8ec5bfa87f4f),
+    (0xb76aec30b6419ce4, 0xbad97ef52591a3e7),
+    (0xb6df2074929422ab, 0x3a527d1825d725bf),
+    (0xb63dbfbe91cc0799, 0xb9c785346401b401),
+    (0xb5d74c3383d62fd6, 0x393a75375250e3d2),
+    (0xb54ae86d59f1cce8, 0xb8aa8161241f620c),
+    (0xb49140c16a50d74c, 0x3817cc3c6753c9a6),
+    (0x340cd4cb6282a2cd, 0xb783424a0be4663a),
+    (0xb375599e385999f4, 0x36ec3da658a237cc),
+    (0xb2f88adccd3751cf, 0xb652d8acd0b17363),
+    (0xb248db4677b33eb5, 0x35b6fe00aa8d7ab4),
+    (0xb1bc5067b42b99d9, 0xb519bd0aaeaa9eaa),
+    (0xb10b258271ab9e9a, 0x347a883d986f1510),
+    (0x3070325403901b82, 0xb3d9449a9ce0be8f),
+    (0xaf8bcf5235625172, 0x33364c886a8c7480),
+    (0x2f3597cf4fa56e36, 0xb2924952936e09be),
+    (0x2e876f3fb64f4ca1, 0x31ebf133e10afbfa),
+    (0x2de6390c54d1aedb, 0xb143f00158cc4674),
+    (0xad35526fbc95c26e, 0x309aa1c20f05700d),
+    (0xac9c04ce69147866, 0xaff0af348e671691),
+    (0x2beff8350b529684, 0x2f43a5e617fc532a),
+    (0xab189459dab2c57a, 0xae95c99ad7453eec),
+    (0xaa8a7d47244a9c70, 0x2de6ca293b078d82),
+    (0x29c6984622aaf19c, 0xad3685f1eaa6738c),
+];
+
+#[derive(Copy, Clone)]
+pub(crate) struct FresnelDD {
+    pub(crate) s: DoubleDouble,
+    pub(crate) c: DoubleDouble,
+}
+
+/// Evaluates S(x) and C(x) for 2^-300 <= x <= 2.5 using power series.
+#[inline]
+pub(crate) fn fresnel_series(x: f64) -> FresnelDD {
+    let x2 = DoubleDouble::from_exact_mult(x, x);
+    let u = DoubleDouble::quick_mult(x2, x2);
+    let mut c = DoubleDouble::from_bit_pair(FRESNEL_C_SERIES[36]);
+    for &coeff in FRESNEL_C_SERIES[..36].iter().rev() {
+        c = DoubleDouble::quick_mul_add(u, c, DoubleDouble::from_bit_pair(coeff));
+    }
+    let mut s = DoubleDouble::from_bit_pair(FRESNEL_S_SERIES[35]);
+    for &coeff in FRESNEL_S_SERIES[..35].iter().rev() {
+        s = DoubleDouble::quick_mul_add(u, s, DoubleDouble::from_bit_pair(coeff));
+    }
+    FresnelDD {
+        s: DoubleDouble::quick_mult(s, DoubleDouble::quick_mult_f64(x2, x)),
+        c: DoubleDouble::quick_mult_f64(c, x),
+    }
+}
+
+/// Computes auxiliary functions f(x), g(x) for 2.5 < x < 2^26.
+///
+/// g(x) + i*f(x) is evaluated as x times continued fraction
+/// 1/(1-i*pi*x^2-) 2/(5-i*pi*x^2-) 12/(9-i*pi*x^2-) ... by modified Lentz's method.
+fn fresnel_auxiliary_cf(x: f64) -> (DoubleDouble, DoubleDouble) {
+    let x2 = DoubleDouble::from_exact_mult(x, x);
+    let mut b = ComplexDD {
+        re: DoubleDouble::new(0., 1.),
+        im: -DoubleDouble::quick_mult(PI_DD, x2),
+    };
+    let mut d = b.recip();
+    let mut h = d;
+    // First step is done explicitly, since initial value of c is infinity
+    b.re.hi += 4.;
+    d = d.mul_f64_add(-2., b).recip();
+    let mut c = b;
+    h = h.mul(c.mul(d));
+    let mut n = 3f64;
+    while n < 600. {
+        let a = -n * (n + 1.);
+        b.re.hi += 4.;
+        d = d.mul_f64_add(a, b).recip();
+        c = c.recip().mul_f64_add(a, b);
+        let del = c.mul(d);
+        h = h.mul(del);
+        if (del.re.hi - 1.).abs() + del.im.hi.abs() <= f64::from_bits(0x3950000000000000) {
+            // |del - 1| < 2^-106
+            break;
+        }
+        n += 2.;
+    }
+    (
+        DoubleDouble::quick_mult_f64(h.im, x),
+        DoubleDouble::quick_mult_f64(h.re, x),
+    )
+}
+
+/// Computes sin(pi*x^2/2) and cos(pi*x^2/2) for 0 < x < 2^53.
+///
+/// x^2/2 is exact in double-double, so it's reduced modulo 2 without any error.
+fn sincospi_half_square(x: f64) -> (DoubleDouble, DoubleDouble) {
+    let x2 = DoubleDouble::from_exact_mult(x, x);
+    let th = x2.hi * 0.5;
+    let tl = x2.lo * 0.5;
+    let rh = th - 2. * (th * 0.5).cpu_round();
+    let rl = tl - 2. * (tl * 0.5).cpu_round();
+    let r = DoubleDouble::from_full_exact_add(rh, rl);
+
+    let (y, k) = reduce_pi_64(r.hi);
+    let y = DoubleDouble::from_full_exact_add(y, r.lo);
+
+    // cos(k * pi/64) = sin(k * pi/64 + pi/2) = sin((k + 32) * pi/64).
+    let sin_k = DoubleDouble::from_bit_pair(SINPI_K_PI_OVER_64[((k as u64) & 127) as usize]);
+    let cos_k = DoubleDouble::from_bit_pair(
+        SINPI_K_PI_OVER_64[((k as u64).wrapping_add(32) & 127) as usize],
+    );
+
+    let r_sincos = sincospi_eval_dd(y.hi);
+    // y.lo is tiny, so sin(pi*(y.hi + y.lo)) ~ sin(pi*y.hi) + pi*y.lo*cos(pi*y.hi)
+    // and cos(pi*(y.hi + y.lo)) ~ cos(pi*y.hi) - pi*y.lo*sin(pi*y.hi)
+    let py_lo = y.lo * PI_DD.hi;
+    let sin_y = DoubleDouble::add_f64(r_sincos.v_sin, py_lo * r_sincos.v_cos.hi);
+    let cos_y = DoubleDouble::add_f64(r_sincos.v_cos, -py_lo * r_sincos.v_sin.hi);
+
+    let sin = DoubleDouble::mul_add(sin_k, cos_y, DoubleDouble::quick_mult(cos_k, sin_y));
+    let cos = DoubleDouble::mul_add(cos_k, cos_y, -DoubleDouble::quick_mult(sin_k, sin_y));
+    (sin, cos)
+}
+
+/// Evaluates S(x) and C(x) for x > 2.5.
+///
+/// C(x) = 1/2 + f(x) * sin(pi*x^2/2) - g(x) * cos(pi*x^2/2),
+/// S(x) = 1/2 - f(x) * cos(pi*x^2/2) - g(x) * sin(pi*x^2/2).
+pub(crate) fn fresnel_large(x: f64) -> FresnelDD {
+    const HALF: DoubleDouble = DoubleDouble::new(0., 0.5);
+    if x >= f64::from_bits(0x4340000000000000) {
+        // x >= 2^53 is an even integer, hence x^2/2 is a multiple of 2,
+        // and C(x) = 1/2 - g(x), S(x) = 1/2 - f(x), where g(x) is negligible.
+        return FresnelDD {
+            s: DoubleDouble::from_exact_add(0.5, -INV_PI_DD.hi / x),
+            c: HALF,
+        };
+    }
+    let (f, g) = if x < f64::from_bits(0x4190000000000000) {
+        fresnel_auxiliary_cf(x)
+    } else {
+        // f(x) ~ 1/(pi*x), g(x) ~ 1/(pi^2*x^3) for x >= 2^26
+        let f = DoubleDouble::quick_mult(INV_PI_DD, DoubleDouble::from_recip(x));
+        let g = f.hi * (INV_PI_DD.hi / x / x);
+        (f, DoubleDouble::new(0., g))
+    };
+    let (sin, cos) = sincospi_half_square(x);
+    let c = DoubleDouble::full_dd_add(
+        HALF,
+        DoubleDouble::mul_add(f, sin, -DoubleDouble::quick_mult(g, cos)),
+    );
+    let s = DoubleDouble::full_dd_sub(
+        HALF,
+        DoubleDouble::mul_add(f, cos, DoubleDouble::quick_mult(g, sin)),
+    );
+    FresnelDD { s, c }
+}
@@ -0,0 +1,190 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::sincospi::f_sincospi;
+
+/// Taylor series of C(x)/x in powers of x^4.
+///
+/// Generated by SageMath:
+/// ```text
+/// R = RealField(300)
+/// for k in range(0, 27):
+///     c = (-1)^k * (R(pi)/2)^(2*k) / (factorial(2*k) * (4*k + 1))
+/// ```
+static FRESNEL_C_SERIESF: [u64; 27] = [
+    0x3ff0000000000000,
+    0xbfcf952e0f96d631,
+    0x3f9cdca8f1f7bdce,
+    0xbf5a4b5e253713aa,
+    0x3f0c59b5171fbece,
+    0xbeb42260e324cb15,
+    0x3e543ba95e6026e3,
+    0xbdee4492b54a02d3,
+    0x3d81805fc4cc7452,
+    0xbd101c57d7f1bd79,
+    0x3c982ad6a93d8480,
+    0xbc1e1addf78e08bf,
+    0x3b9fa310eb5b8d10,
+    0xbb1c6c7ba2b1e876,
+    0x3a961504e4af5029,
+    0xba0df65d043db542,
+    0x3981e788b7efc0d5,
+    0xb8f2fda05f5eddc3,
+    0x3861ff4e2529deb6,
+    0xb7cea958c17f042b,
+    0x37379a9b57423af9,
+    0xb6a0806b4944963e,
+    0x36050bd66a9d57aa,
+    0xb568957b1b0b3ea3,
+    0x34ca65c27e0e471e,
+    0xb42a25174fc50690,
+    0x3387f5bc2022d1e7,
+];
+
+/// Taylor series of S(x)/x^3 in powers of x^4.
+///
+/// Generated by SageMath:
+/// ```text
+/// R = RealField(300)
+/// for k in range(0, 26):
+///     c = (-1)^k * (R(pi)/2)^(2*k + 1) / (factorial(2*k + 1) * (4*k + 3))
+/// ```
+static FRESNEL_S_SERIESF: [u64; 26] = [
+    0x3fe0c152382d7366,
+    0xbfb79fb3502b22a8,
+    0x3f7dacb4f393e148,
+    0xbf3474740f5841a0,
+    0x3ee1b57c07277f96,
+    0xbe85005162b804f4,
+    0x3e221c01f4be8cad,
+    0xbdb7b8a0a4370fc2,
+    0x3d486544b9936226,
+    0xbcd437cee019ea13,
+    0x3c5b940030de0759,
+    0xbbdf7f257cc18111,
+    0x3b5e8ec5bfa87f4f,
+    0xbad97ef52591a3e7,
+    0x3a527d1825d725bf,
+    0xb9c785346401b401,
+    0x393a75375250e3d2,
+    0xb8aa8161241f620c,
+    0x3817cc3c6753c9a6,
+    0xb783424a0be4663a,
+    0x36ec3da658a237cc,
+    0xb652d8acd0b17363,
+    0x35b6fe00aa8d7ab4,
+    0xb519bd0aaeaa9eaa,
+    0x347a883d986f1510,
+    0xb3d9449a9ce0be8f,
+];
+
+#[derive(Copy, Clone)]
+pub(crate) struct Fresnelf {
+    pub(crate) s: f64,
+    pub(crate) c: f64,
+}
+
+/// Evaluates S(x) and C(x) for 0 <= x <= 2.5 using power series.
+#[inline]
+pub(crate) fn fresnel_seriesf(x: f64) -> Fresnelf {
+    let x2 = x * x;
+    let u = x2 * x2;
+    let c = FRESNEL_C_SERIESF
+        .iter()
+        .rev()
+        .fold(0., |acc, &coeff| f_fmla(acc, u, f64::from_bits(coeff)));
+    let s = FRESNEL_S_SERIESF
+        .iter()
+        .rev()
+        .fold(0., |acc, &coeff| f_fmla(acc, u, f64::from_bits(coeff)));
+    Fresnelf {
+        s: s * x2 * x,
+        c: c * x,
+    }
+}
+
+/// Computes auxiliary functions f(x), g(x) for 2.5 < x < 2^13.
+///
+/// g(x) + i*f(x) is evaluated as x times continued fraction
+/// 1/(1-i*pi*x^2-) 2/(5-i*pi*x^2-) 12/(9-i*pi*x^2-) ... by modified Lentz's method.
+fn fresnel_auxiliary_cff(x: f64) -> (f64, f64) {
+    #[inline]
+    fn recip(re: f64, im: f64) -> (f64, f64) {
+        let den = f_fmla(re, re, im * im);
+        (re / den, -im / den)
+    }
+    #[inline]
+    fn mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+        (f_fmla(a.0, b.0, -a.1 * b.1), f_fmla(a.0, b.1, a.1 * b.0))
+    }
+    let mut b = (1f64, -std::f64::consts::PI * x * x);
+    let mut d = recip(b.0, b.1);
+    let mut h = d;
+    // First step is done explicitly, since initial value of c is infinity
+    b.0 += 4.;
+    d = recip(f_fmla(-2., d.0, b.0), f_fmla(-2., d.1, b.1));
+    let mut c = b;
+    h = mul(h, mul(c, d));
+    let mut n = 3f64;
+    while n < 400. {
+        let a = -n * (n + 1.);
+        b.0 += 4.;
+        d = recip(f_fmla(a, d.0, b.0), f_fmla(a, d.1, b.1));
+        let rc = recip(c.0, c.1);
+        c = (f_fmla(a, rc.0, b.0), f_fmla(a, rc.1, b.1));
+        let del = mul(c, d);
+        h = mul(h, del);
+        if (del.0 - 1.).abs() + del.1.abs() <= f64::from_bits(0x3c90000000000000) {
+            // |del - 1| < 2^-54
+            break;
+        }
+        n += 2.;
+    }
+    (h.1 * x, h.0 * x)
+}
+
+/// Evaluates S(x) and C(x) for x > 2.5.
+///
+/// C(x) = 1/2 + f(x) * sin(pi*x^2/2) - g(x) * cos(pi*x^2/2),
+/// S(x) = 1/2 - f(x) * cos(pi*x^2/2) - g(x) * sin(pi*x^2/2).
+pub(crate) fn fresnel_largef(x: f64) -> Fresnelf {
+    let (f, g) = if x < 8192. {
+        fresnel_auxiliary_cff(x)
+    } else {
+        // f(x) ~ 1/(pi*x), g(x) ~ 1/(pi^2*x^3) for x >= 2^13
+        let f = std::f64::consts::FRAC_1_PI / x;
+        (f, f * (std::f64::consts::FRAC_1_PI / x / x))
+    };
+    // x is single precision, so x^2/2 is exact
+    let (sin, cos) = f_sincospi(x * x * 0.5);
+    Fresnelf {
+        s: 0.5 - f_fmla(f, cos, g * sin),
+        c: 0.5 + f_fmla(f, sin, -g * cos),
+    }
+}
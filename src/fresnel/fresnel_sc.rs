@@ -0,0 +1,151 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::fresnel::fresnel_core::{FresnelDD, fresnel_large, fresnel_series};
+
+#[inline]
+fn fresnel_dd(ax: f64) -> FresnelDD {
+    if ax <= 2.5 {
+        fresnel_series(ax)
+    } else {
+        fresnel_large(ax)
+    }
+}
+
+/// S(x) ~ pi/6 * x^3 for |x| < 2^-300, where x^3 may underflow.
+#[inline]
+fn fresnel_s_tiny(ax: f64) -> f64 {
+    const PI_OVER_6: DoubleDouble =
+        DoubleDouble::from_bit_pair((0xbc8ee6913347c2a6, 0x3fe0c152382d7366));
+    let xs = ax * f64::from_bits(0x52b0000000000000); // 2^300
+    let x3 = DoubleDouble::quick_mult_f64(DoubleDouble::from_exact_mult(xs, xs), xs);
+    DoubleDouble::quick_mult(PI_OVER_6, x3).to_f64() * f64::from_bits(0x07b0000000000000) // 2^-900
+}
+
+/// Computes Fresnel integral S(x)
+///
+/// S(x) = integral(sin(pi*t^2/2), t = 0..x).
+pub fn f_fresnel_s(x: f64) -> f64 {
+    if !x.is_finite() {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        return f64::copysign(0.5, x);
+    }
+    let ax = x.abs();
+    if ax < f64::from_bits(0x2d30000000000000) {
+        // |x| < 2^-300
+        return f64::copysign(fresnel_s_tiny(ax), x);
+    }
+    f64::copysign(fresnel_dd(ax).s.to_f64(), x)
+}
+
+/// Computes Fresnel integral C(x)
+///
+/// C(x) = integral(cos(pi*t^2/2), t = 0..x).
+pub fn f_fresnel_c(x: f64) -> f64 {
+    if !x.is_finite() {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        return f64::copysign(0.5, x);
+    }
+    let ax = x.abs();
+    if ax < f64::from_bits(0x3f10000000000000) {
+        // |x| < 2^-14, C(x) ~ x - pi^2/40 * x^5
+        return x;
+    }
+    f64::copysign(fresnel_dd(ax).c.to_f64(), x)
+}
+
+/// Computes Fresnel integrals S(x) and C(x)
+///
+/// Returns (S(x), C(x)).
+pub fn f_fresnel_sc(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        if x.is_nan() {
+            return (f64::NAN, f64::NAN);
+        }
+        return (f64::copysign(0.5, x), f64::copysign(0.5, x));
+    }
+    let ax = x.abs();
+    if ax < f64::from_bits(0x2d30000000000000) {
+        // |x| < 2^-300
+        return (f64::copysign(fresnel_s_tiny(ax), x), x);
+    }
+    let r = fresnel_dd(ax);
+    (
+        f64::copysign(r.s.to_f64(), x),
+        f64::copysign(r.c.to_f64(), x),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fresnel_s() {
+        assert_eq!(f_fresnel_s(1.), 0.43825914739035476);
+        assert_eq!(f_fresnel_s(-1.), -0.43825914739035476);
+        assert_eq!(f_fresnel_s(0.5), 0.06473243285999927);
+        assert_eq!(f_fresnel_s(3.), 0.496312998967375);
+        assert_eq!(f_fresnel_s(10.), 0.46816997858488224);
+        assert_eq!(f_fresnel_s(1000.), 0.4996816901138163);
+        assert_eq!(f_fresnel_s(1e-5), 5.23598775598299e-16);
+        assert_eq!(f_fresnel_s(0.), 0.);
+        assert_eq!(f_fresnel_s(f64::INFINITY), 0.5);
+        assert_eq!(f_fresnel_s(f64::NEG_INFINITY), -0.5);
+        assert!(f_fresnel_s(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_fresnel_c() {
+        assert_eq!(f_fresnel_c(1.), 0.7798934003768229);
+        assert_eq!(f_fresnel_c(-1.), -0.7798934003768229);
+        assert_eq!(f_fresnel_c(0.5), 0.4923442258714464);
+        assert_eq!(f_fresnel_c(3.), 0.6057207892976856);
+        assert_eq!(f_fresnel_c(10.), 0.49989869420551575);
+        assert_eq!(f_fresnel_c(1000.), 0.4999999998986788);
+        assert_eq!(f_fresnel_c(1e-5), 1e-5);
+        assert_eq!(f_fresnel_c(0.), 0.);
+        assert_eq!(f_fresnel_c(f64::INFINITY), 0.5);
+        assert!(f_fresnel_c(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_fresnel_sc() {
+        assert_eq!(f_fresnel_sc(1.), (0.43825914739035476, 0.7798934003768229));
+        assert_eq!(f_fresnel_sc(-3.), (-0.496312998967375, -0.6057207892976856));
+        assert_eq!(f_fresnel_sc(1e300), (0.5, 0.5));
+        assert_eq!(f_fresnel_sc(f64::INFINITY), (0.5, 0.5));
+        let (s, c) = f_fresnel_sc(f64::NAN);
+        assert!(s.is_nan() && c.is_nan());
+    }
+}
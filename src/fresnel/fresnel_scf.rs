@@ -0,0 +1,123 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::fresnel::fresnel_coref::{Fresnelf, fresnel_largef, fresnel_seriesf};
+
+#[inline]
+fn fresnelf(ax: f32) -> Fresnelf {
+    let xd = ax as f64;
+    if ax <= 2.5 {
+        fresnel_seriesf(xd)
+    } else {
+        fresnel_largef(xd)
+    }
+}
+
+/// Computes Fresnel integral S(x)
+///
+/// S(x) = integral(sin(pi*t^2/2), t = 0..x).
+pub fn f_fresnel_sf(x: f32) -> f32 {
+    if !x.is_finite() {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        return f32::copysign(0.5, x);
+    }
+    f32::copysign(fresnelf(x.abs()).s as f32, x)
+}
+
+/// Computes Fresnel integral C(x)
+///
+/// C(x) = integral(cos(pi*t^2/2), t = 0..x).
+pub fn f_fresnel_cf(x: f32) -> f32 {
+    if !x.is_finite() {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        return f32::copysign(0.5, x);
+    }
+    let ax = x.abs();
+    if ax < f32::from_bits(0x39800000) {
+        // |x| < 2^-12, C(x) ~ x - pi^2/40 * x^5
+        return x;
+    }
+    f32::copysign(fresnelf(ax).c as f32, x)
+}
+
+/// Computes Fresnel integrals S(x) and C(x)
+///
+/// Returns (S(x), C(x)).
+pub fn f_fresnel_scf(x: f32) -> (f32, f32) {
+    if !x.is_finite() {
+        if x.is_nan() {
+            return (f32::NAN, f32::NAN);
+        }
+        return (f32::copysign(0.5, x), f32::copysign(0.5, x));
+    }
+    let r = fresnelf(x.abs());
+    (f32::copysign(r.s as f32, x), f32::copysign(r.c as f32, x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fresnel_sf() {
+        assert_eq!(f_fresnel_sf(1.), 0.43825915);
+        assert_eq!(f_fresnel_sf(-1.), -0.43825915);
+        assert_eq!(f_fresnel_sf(0.5), 0.06473243);
+        assert_eq!(f_fresnel_sf(3.), 0.496313);
+        assert_eq!(f_fresnel_sf(10.), 0.46817);
+        assert_eq!(f_fresnel_sf(1000.), 0.49968168);
+        assert_eq!(f_fresnel_sf(0.), 0.);
+        assert_eq!(f_fresnel_sf(f32::INFINITY), 0.5);
+        assert!(f_fresnel_sf(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_fresnel_cf() {
+        assert_eq!(f_fresnel_cf(1.), 0.7798934);
+        assert_eq!(f_fresnel_cf(-1.), -0.7798934);
+        assert_eq!(f_fresnel_cf(0.5), 0.49234423);
+        assert_eq!(f_fresnel_cf(3.), 0.6057208);
+        assert_eq!(f_fresnel_cf(10.), 0.4998987);
+        assert_eq!(f_fresnel_cf(1000.), 0.5);
+        assert_eq!(f_fresnel_cf(0.), 0.);
+        assert_eq!(f_fresnel_cf(f32::NEG_INFINITY), -0.5);
+        assert!(f_fresnel_cf(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_fresnel_scf() {
+        assert_eq!(f_fresnel_scf(1.), (0.43825915, 0.7798934));
+        assert_eq!(f_fresnel_scf(-3.), (-0.496313, -0.6057208));
+        let (s, c) = f_fresnel_scf(f32::NAN);
+        assert!(s.is_nan() && c.is_nan());
+    }
+}
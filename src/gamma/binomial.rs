@@ -0,0 +1,151 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::mul_exp_pos;
+use crate::gamma::lnbeta::{lgamma_delta, lgamma1p};
+
+/// Computes log(C(n, k)) for 0 <= k <= n.
+pub(crate) fn lchoose_core(n: f64, k: f64) -> DoubleDouble {
+    // C(n, k) = C(n, n - k), so the smaller of k and n - k goes into the delta,
+    // n - k is exact when k > n/2
+    let (x, j) = if k > 0.5 * n {
+        (DoubleDouble::from_full_exact_add(k, 1.), n - k)
+    } else {
+        let r = DoubleDouble::from_full_exact_sub(n, k);
+        (DoubleDouble::full_add_f64(r, 1.), k)
+    };
+    // log(C(n, k)) = lgamma(n + 1) - lgamma(n - j + 1) - lgamma(j + 1),
+    // first difference is taken directly, since it cancels when n >> j
+    DoubleDouble::full_dd_sub(lgamma_delta(x, j), lgamma1p(j))
+}
+
+/// Computes binomial coefficient C(n, k)
+///
+/// Exact while result fits into 53 bits, otherwise max found error ~0.5 ulp.
+pub fn f_binomial(n: u32, k: u32) -> f64 {
+    if k > n {
+        return 0.;
+    }
+    let j = k.min(n - k);
+    // Each partial product C(n - j + i, i) is an integer
+    let mut r: u128 = 1;
+    let mut i = 1u32;
+    while i <= j && r < 1u128 << 96 {
+        r = r * (n - j + i) as u128 / i as u128;
+        i += 1;
+    }
+    if i > j {
+        return r as f64;
+    }
+    let l = lchoose_core(n as f64, j as f64);
+    if l.hi > 710. {
+        return f64::INFINITY;
+    }
+    mul_exp_pos(DoubleDouble::new(0., 1.), l)
+}
+
+/// Computes log of binomial coefficient log(C(n, k))
+///
+/// Returns -inf when k > n.
+pub fn f_lbinomial(n: u32, k: u32) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+    if k == 0 || k == n {
+        return 0.;
+    }
+    lchoose_core(n as f64, k as f64).to_f64()
+}
+
+/// Computes log(C(n, k)) = lgamma(n + 1) - lgamma(k + 1) - lgamma(n - k + 1) for real 0 <= k <= n
+///
+/// Stays accurate when n >> k, where direct difference of log-gammas cancels.
+/// Returns NaN outside of 0 <= k <= n.
+///
+/// Max found error ~1 ulp.
+pub fn f_lchoose(n: f64, k: f64) -> f64 {
+    if !n.is_finite() || !k.is_finite() || k < 0. || k > n {
+        return f64::NAN;
+    }
+    if k == 0. || k == n {
+        return 0.;
+    }
+    lchoose_core(n, k).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(f_binomial(5, 2), 10.);
+        assert_eq!(f_binomial(10, 0), 1.);
+        assert_eq!(f_binomial(10, 10), 1.);
+        assert_eq!(f_binomial(3, 5), 0.);
+        assert_eq!(f_binomial(52, 5), 2598960.);
+        assert_eq!(f_binomial(100, 50), 1.008913445455642e29);
+        assert_eq!(f_binomial(1000, 3), 166167000.);
+        assert_eq!(f_binomial(1030, 515), f64::INFINITY);
+        assert_eq!(f_binomial(2000, 1000), f64::INFINITY);
+        assert_eq!(f_binomial(4294967295, 2), 9.223372030412325e18);
+        assert_eq!(f_binomial(4294967295, 7), 5.349195732419006e63);
+    }
+
+    #[test]
+    fn test_lbinomial() {
+        assert_eq!(f_lbinomial(5, 2), 2.302585092994046);
+        assert_eq!(f_lbinomial(10, 0), 0.);
+        assert_eq!(f_lbinomial(10, 10), 0.);
+        assert_eq!(f_lbinomial(3, 5), f64::NEG_INFINITY);
+        assert_eq!(f_lbinomial(100, 50), 66.78384165201743);
+        assert_eq!(f_lbinomial(1000, 3), 18.9285038647141);
+        assert_eq!(f_lbinomial(2000, 1000), 1382.2679935374802);
+        assert_eq!(f_lbinomial(4294967295, 1), 22.18070977768542);
+        assert_eq!(f_lbinomial(4294967295, 2147483647), 2977044459.8102784);
+    }
+
+    #[test]
+    fn test_lchoose() {
+        assert_eq!(f_lchoose(5., 2.), 2.302585092994046);
+        assert_eq!(f_lchoose(5.5, 2.25), 2.6123032012980447);
+        assert_eq!(f_lchoose(10., 0.), 0.);
+        assert_eq!(f_lchoose(7., 7.), 0.);
+        assert_eq!(f_lchoose(0.5, 0.25), 0.0757614352083811);
+        assert_eq!(f_lchoose(1000000000000000., 1.), 34.538776394910684);
+        assert_eq!(f_lchoose(1000000000000000., 1e-10), 3.5115992059729975e-09);
+        assert_eq!(f_lchoose(1e300, 3.), 2070.534824225413);
+        assert_eq!(f_lchoose(20., 1e-12), 3.597739657142835e-12);
+        assert_eq!(f_lchoose(1000000., 999999.5), 7.028537641617382);
+        assert!(f_lchoose(3., 4.).is_nan());
+        assert!(f_lchoose(3., -1.).is_nan());
+        assert!(f_lchoose(f64::INFINITY, 1.).is_nan());
+        assert!(f_lchoose(f64::NAN, 1.).is_nan());
+    }
+}
@@ -837,3 +837,27 @@ pub(crate) fn digamma_coeefs(x: f64) -> (&'static [(u64, u64); 12], &'static [(u
         (&P, &Q)
     }
 }
+
+/*
+Bernoulli(2k)/(2k)! for k = 1..12, shared by asymptotic expansions of polygamma functions.
+Generated in SageMath:
+for k in range(1, 13):
+    c = bernoulli(2*k) / factorial(2*k)
+    hi = RealField(53)(c)
+    lo = RealField(53)(c - RealField(300)(hi))
+    print("(" + double_to_hex(lo) + ", " + double_to_hex(hi) + "),")
+*/
+pub(crate) static BERNOULLI_2K_OVER_FACTORIAL: [(u64, u64); 12] = [
+    (0x3c55555555555555, 0x3fb5555555555555),
+    (0x3bef49f49f49f49f, 0xbf56c16c16c16c17),
+    (0xbba50ffbaa655100, 0x3f01566abc011567),
+    (0x3b44e65f77088199, 0xbeabbd779334ef0b),
+    (0xbaf75a7bb0599f07, 0x3e566a8f2bf70ebe),
+    (0x3a716a73200360d2, 0xbe022805d644267f),
+    (0xba01ed444b9ec588, 0x3dad6db2c4e09162),
+    (0xb9f2ff894d037a17, 0xbd57da4e1f79955c),
+    (0xb9a88d4ccd141422, 0x3d0355871d652e9e),
+    (0x3939c31f0af5255f, 0xbcaf57d968caacf1),
+    (0xb8b3ea5a927db8ab, 0x3c5967e1f09c376f),
+    (0xb8ab843f32aad364, 0xbc0497d9033a2b5c),
+];
@@ -0,0 +1,261 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::gamma::lgamma::lgamma_core;
+
+/**
+Factorials n! for n = 0..170, larger values overflow.
+
+Generated by SageMath:
+```python
+for n in range(171):
+    print(double_to_hex(RealField(53)(factorial(n))) + ",")
+```
+**/
+static FACTORIALS: [u64; 171] = [
+    0x3ff0000000000000,
+    0x3ff0000000000000,
+    0x4000000000000000,
+    0x4018000000000000,
+    0x4038000000000000,
+    0x405e000000000000,
+    0x4086800000000000,
+    0x40b3b00000000000,
+    0x40e3b00000000000,
+    0x4116260000000000,
+    0x414baf8000000000,
+    0x418308a800000000,
+    0x41bc8cfc00000000,
+    0x41f7328cc0000000,
+    0x42344c3b28000000,
+    0x4273077775800000,
+    0x42b3077775800000,
+    0x42f437eeecd80000,
+    0x4336beecca730000,
+    0x437b02b930689000,
+    0x43c0e1b3be415a00,
+    0x4406283be9b5c620,
+    0x444e77526159f06c,
+    0x4495e5c335f8a4ce,
+    0x44e06c52687a7b9a,
+    0x4529a940c33f6121,
+    0x4574d9849ea37eeb,
+    0x45c19787e5d9f316,
+    0x460ec92dd23d6967,
+    0x465be6518687a785,
+    0x46aa27ec6e1f2d0d,
+    0x46f956ad0aae33a4,
+    0x474956ad0aae33a4,
+    0x479a21627303a541,
+    0x47ebc3789a33df96,
+    0x483e5dcbe8a8bc8c,
+    0x489114c2b2deea0f,
+    0x48e3c0011ed1bea1,
+    0x493774015499125f,
+    0x498c95619f1a8e64,
+    0x49e1dd5d037098fe,
+    0x4a36e39f2c684406,
+    0x4a8e0ac0ea48d948,
+    0x4ae42f399d68f1fc,
+    0x4b3bc0ef38704cbb,
+    0x4b9383a833aef5f3,
+    0x4bec0d41ca4b818e,
+    0x4c4499bc508f7324,
+    0x4c9ee69a78d72cb6,
+    0x4cf7a88e4484be3b,
+    0x4d527baf2587b49e,
+    0x4dad751f23d047dc,
+    0x4e07ef294d193a63,
+    0x4e63d20e33d8e45a,
+    0x4ec0b93bfbbf00ac,
+    0x4f1cbe5f18b04928,
+    0x4f792693359a4003,
+    0x4fd6665b1bbd6102,
+    0x50344cc291239fea,
+    0x5092b6c35dccd76c,
+    0x50f18b5727f009f5,
+    0x5150b8cf1210c97e,
+    0x51b0330899804332,
+    0x520fe478ee34844a,
+    0x526fe478ee34844a,
+    0x52d0320568f6ab2e,
+    0x5330b395943e6087,
+    0x53917c0097314d0d,
+    0x53f293c0a0a461de,
+    0x5454074bad313983,
+    0x54b5e7fac56dd6e8,
+    0x55184d5a3305da69,
+    0x557b5705796695b6,
+    0x55df2f423e7902c4,
+    0x564207524c1df599,
+    0x56a5209471331bd0,
+    0x570916b0466cb107,
+    0x576e2f4c14bac4fc,
+    0x57d264d25ca1d009,
+    0x5836b473aa57bccc,
+    0x589c619094edabff,
+    0x5901f5bd7e3e66d7,
+    0x596702dac9bff3c4,
+    0x59cdd7b3bda4f022,
+    0x5a33958df4743d96,
+    0x5a9a02a088aa61cb,
+    0x5b0179c3dbd279b5,
+    0x5b67c1863ed21d72,
+    0x5bd0550c4b30743e,
+    0x5c36b645188f61a6,
+    0x5c9ff0512a89a152,
+    0x5d06b4d9b43dd8b0,
+    0x5d7051fc798c73bf,
+    0x5dd7b722e0a01831,
+    0x5e416a7d9cf591c4,
+    0x5ea9da1274fc845f,
+    0x5f13638dd7bd6347,
+    0x5f7d62e2fafb0a78,
+    0x5fe67fb5c8283404,
+    0x605166c698cf183b,
+    0x60bb30964ec395dc,
+    0x612574569a265440,
+    0x619118b502d68b23,
+    0x61fb83c3509147ec,
+    0x62665b0eb1760a70,
+    0x62d256b20d92d490,
+    0x633e5f96e67b300e,
+    0x63a963e824aafa2c,
+    0x64156c4bdef04315,
+    0x64823e389bd89920,
+    0x64ef5af14bdc472f,
+    0x655b30dd3fc905ba,
+    0x65c7cac197cfe503,
+    0x663500fee805882d,
+    0x66a2b4e306a4ed48,
+    0x6710ce83f7f82d2f,
+    0x677e764f3171d1e4,
+    0x67ebd824633209db,
+    0x6859ab418b722116,
+    0x68c7dd36efa41ac2,
+    0x69365f6380a9d916,
+    0x69a5262c0fa08f37,
+    0x6a142861fee50880,
+    0x6a835ece2af0162b,
+    0x6af2c3d7b998957a,
+    0x6b625340ab3f01f9,
+    0x6bd209f3a89205f1,
+    0x6c41e5dfc140e1e5,
+    0x6cb1e5dfc140e1e5,
+    0x6d2209ab80c363a9,
+    0x6d9251d22ec67138,
+    0x6e02bfbd1bdf17df,
+    0x6e7355bb04be109e,
+    0x6ee4171452ed7d44,
+    0x6f55082946d09f23,
+    0x6fc62e9b88b007d7,
+    0x70379185413b0855,
+    0x70a939c09fd12eeb,
+    0x711b3243ac4d8695,
+    0x718d88957d1c3026,
+    0x720026b1c06b6a55,
+    0x7271ca9fcdf65321,
+    0x72e3bcc9487d4439,
+    0x73560ce8defbf238,
+    0x73c8ce85fadb707e,
+    0x743c19f3c62c956f,
+    0x74b006cd07056d39,
+    0x752267cf76103b70,
+    0x75954807e082c4b9,
+    0x7608c5d92b583900,
+    0x767d07da7ecb62cc,
+    0x76f11fa1e0c9f746,
+    0x776455903aefd5a3,
+    0x77d84e466672ad5d,
+    0x784d3e2cb341f894,
+    0x78c1b4a51088f182,
+    0x793594292c26e656,
+    0x79aa77ba8027b686,
+    0x7a2055e51b1882a7,
+    0x7a944ab297a8724b,
+    0x7b095d5f3d928ede,
+    0x7b7fe771cb7257b3,
+    0x7bf4307602be5b7f,
+    0x7c69b5b6477e6884,
+    0x7ce07868c5ccfaf4,
+    0x7d553b370efa3b7f,
+    0x7dcb88cb676c8529,
+    0x7e41f63cb077cade,
+    0x7eb7932fa79d3a43,
+    0x7f2f2054eb4d96ec,
+    0x7fa4ab7864418639,
+];
+
+/// Computes factorial n!
+///
+/// Values are exact for n <= 22 and correctly rounded for all n, overflows when n > 170.
+pub fn f_factorial(n: u32) -> f64 {
+    if n as usize >= FACTORIALS.len() {
+        return f64::INFINITY;
+    }
+    f64::from_bits(FACTORIALS[n as usize])
+}
+
+/// Computes log(n!)
+pub fn f_lfactorial(n: u32) -> f64 {
+    if n < 2 {
+        return 0.;
+    }
+    lgamma_core(n as f64 + 1.).0.to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(f_factorial(0), 1.);
+        assert_eq!(f_factorial(1), 1.);
+        assert_eq!(f_factorial(5), 120.);
+        assert_eq!(f_factorial(20), 2.43290200817664e18);
+        assert_eq!(f_factorial(22), 1.1240007277776077e21);
+        assert_eq!(f_factorial(23), 2.585201673888498e22);
+        assert_eq!(f_factorial(50), 3.0414093201713376e64);
+        assert_eq!(f_factorial(170), 7.257415615307999e306);
+        assert_eq!(f_factorial(171), f64::INFINITY);
+        assert_eq!(f_factorial(4294967295), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_lfactorial() {
+        assert_eq!(f_lfactorial(0), 0.);
+        assert_eq!(f_lfactorial(1), 0.);
+        assert_eq!(f_lfactorial(2), 0.6931471805599453);
+        assert_eq!(f_lfactorial(10), 15.104412573075516);
+        assert_eq!(f_lfactorial(170), 706.5730622457874);
+        assert_eq!(f_lfactorial(1000), 5912.128178488163);
+        assert_eq!(f_lfactorial(123456), 1323904.4924837977);
+        assert_eq!(f_lfactorial(4294967295), 90970455792.05489);
+    }
+}
@@ -26,8 +26,11 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::common::f_fmla;
 use crate::double_double::DoubleDouble;
+use crate::exponents::log_abs_dd;
 use crate::gamma::lgamma::lgamma_core;
+use crate::logs::log1p_dd;
 
 /// Computes log(beta(x)) function
 pub fn f_lnbeta(a: f64, b: f64) -> f64 {
@@ -73,6 +76,93 @@ pub(crate) fn lnbeta_core(a: f64, b: f64) -> DoubleDouble {
     DoubleDouble::quick_dd_add(y1, y)
 }
 
+/// Computes log(1 + t) for double-double t > -1.
+#[inline]
+fn log1p_dd_dd(t: DoubleDouble) -> DoubleDouble {
+    let r = log1p_dd(t.hi);
+    DoubleDouble::full_add_f64(r, t.lo / (1. + t.hi))
+}
+
+/// Computes lgamma(x + d) - lgamma(x) for x >= 16, d >= 0 using difference of Stirling series,
+/// so nothing cancels when d << x.
+fn lgamma_delta_stirling(x: DoubleDouble, d: f64) -> DoubleDouble {
+    let y = DoubleDouble::full_add_f64(x, d);
+    // (y - 1/2) * log(y) - (x - 1/2) * log(x) - d =
+    // (x - 1/2) * log(1 + d/x) + d * (log(y) - 1)
+    let xm = DoubleDouble::full_add_f64(x, -0.5);
+    let l1 = log1p_dd_dd(DoubleDouble::div(DoubleDouble::new(0., d), x));
+    let ly = DoubleDouble::full_add_f64(log_abs_dd(y), -1.);
+    let r = DoubleDouble::quick_mult(xm, l1);
+    let r = DoubleDouble::full_dd_add(r, DoubleDouble::quick_mult_f64(ly, d));
+
+    // Difference of the tails sum(B(2m) / (2m * (2m - 1) * x^(2m - 1))):
+    // v^p - u^p = (v - u) * h(p - 1), where u = 1/x, v = 1/y, v - u = -d * u * v,
+    // and h(p) = sum(v^i * u^(p-i), i = 0..p) satisfies h(p) = v * h(p - 1) + u^p
+    const C: [u64; 8] = [
+        0x3fb5555555555555,
+        0xbf66c16c16c16c17,
+        0x3f4a01a01a01a01a,
+        0xbf43813813813814,
+        0x3f4b951e2b18ff23,
+        0xbf5f6ab0d9993c7d,
+        0x3f7a41a41a41a41a,
+        0xbf9e4286cb0f5398,
+    ];
+    let u = 1. / x.hi;
+    let v = 1. / y.hi;
+    let mut h = 1.;
+    let mut up = 1.;
+    let mut tail = f64::from_bits(C[0]);
+    for &c in C.iter().skip(1) {
+        // h(p) with p even
+        up *= u;
+        h = f_fmla(v, h, up);
+        up *= u;
+        h = f_fmla(v, h, up);
+        tail = f_fmla(f64::from_bits(c), h, tail);
+    }
+    let tail = -d * u * v * tail;
+    DoubleDouble::full_add_f64(r, tail)
+}
+
+/// Computes lgamma(x + d) - lgamma(x) for x > 0, d >= 0.
+///
+/// Unlike direct difference of [lgamma_core] it keeps relative accuracy when d << x.
+pub(crate) fn lgamma_delta(x: DoubleDouble, d: f64) -> DoubleDouble {
+    if x.hi >= 16. {
+        return lgamma_delta_stirling(x, d);
+    }
+    // lgamma(x + d) - lgamma(x) = lgamma(x + n + d) - lgamma(x + n) - sum(log(1 + d/(x + i)), i = 0..n-1)
+    let mut shifted = x;
+    let mut sum = DoubleDouble::default();
+    while shifted.hi < 16. {
+        // Without FMA, division splits the quotient and overflows for huge d/x,
+        // so the residual is taken with explicit mul_add
+        let q = d / shifted.hi;
+        let r = f64::mul_add(-q, shifted.hi, d) - q * shifted.lo;
+        let t = DoubleDouble::new(r / shifted.hi, q);
+        sum = DoubleDouble::full_dd_add(sum, log1p_dd_dd(t));
+        shifted = DoubleDouble::full_add_f64(shifted, 1.);
+    }
+    DoubleDouble::full_dd_sub(lgamma_delta_stirling(shifted, d), sum)
+}
+
+/// Computes lgamma(1 + x) for x >= 0, accurate also when x is tiny.
+pub(crate) fn lgamma1p(x: f64) -> DoubleDouble {
+    if x < 1. {
+        return lgamma_delta(DoubleDouble::new(0., 1.), x);
+    }
+    let t = DoubleDouble::from_full_exact_add(x, 1.);
+    let (r, _) = lgamma_core(t.hi);
+    if t.lo != 0. {
+        // digamma(t) ~ log(t) - 1/(2t) - 1/(12t^2) is enough for correction by t.lo
+        let rt = 1. / t.hi;
+        let dg = log_abs_dd(t).hi - rt * f_fmla(rt, f64::from_bits(0x3fb5555555555555), 0.5);
+        return DoubleDouble::full_add_f64(r, t.lo * dg);
+    }
+    r
+}
+
 #[cfg(test)]
 mod tests {
     use crate::f_lnbeta;
@@ -30,9 +30,11 @@ mod beta;
 mod betaf;
 mod betainc;
 mod betaincf;
+mod binomial;
 mod digamma;
 mod digamma_coeffs;
 mod digammaf;
+mod factorial;
 mod gamma_p;
 mod gamma_pf;
 mod gamma_q;
@@ -43,7 +45,13 @@ mod lgamma_rf;
 mod lgammaf;
 mod lnbeta;
 mod lnbetaf;
+mod pochhammer;
+mod pochhammerf;
+mod polygamma;
+mod polygammaf;
 mod tgamma;
+mod tgamma_ratio;
+mod tgamma_ratiof;
 mod tgammaf;
 mod trigamma;
 mod trigammaf;
@@ -52,8 +60,10 @@ pub use beta::f_beta;
 pub use betaf::f_betaf;
 pub use betainc::f_betainc_reg;
 pub use betaincf::f_betainc_regf;
+pub use binomial::{f_binomial, f_lbinomial, f_lchoose};
 pub use digamma::f_digamma;
 pub use digammaf::f_digammaf;
+pub use factorial::{f_factorial, f_lfactorial};
 pub use gamma_p::f_gamma_p;
 pub use gamma_pf::f_gamma_pf;
 pub use gamma_q::f_gamma_q;
@@ -64,7 +74,14 @@ pub use lgamma_rf::f_lgamma_rf;
 pub use lgammaf::f_lgammaf;
 pub use lnbeta::f_lnbeta;
 pub use lnbetaf::f_lnbetaf;
+pub use pochhammer::f_pochhammer;
+pub use pochhammerf::f_pochhammerf;
+pub use polygamma::f_polygamma;
+pub use polygammaf::f_polygammaf;
 pub use tgamma::f_tgamma;
+pub use tgamma_ratio::{f_tgamma_delta_ratio, f_tgamma_ratio};
+pub(crate) use tgamma_ratio::{is_pole, lgamma_dd, lgamma_ratio_core};
+pub use tgamma_ratiof::{f_tgamma_delta_ratiof, f_tgamma_ratiof};
 pub use tgammaf::f_tgammaf;
 pub use trigamma::f_trigamma;
 pub use trigammaf::f_trigammaf;
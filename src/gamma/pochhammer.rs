@@ -0,0 +1,108 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::is_integer;
+use crate::double_double::DoubleDouble;
+use crate::gamma::lnbeta::lgamma_delta;
+use crate::gamma::tgamma_ratio::{exp_signed, f_tgamma_ratio, is_pole, lgamma_ratio_core};
+
+/// Computes rising factorial (Pochhammer symbol) (a)_n = Gamma(a + n)/Gamma(a)
+///
+/// For integer n > 0 this is a * (a + 1) * ... * (a + n - 1), falling factorial
+/// a * (a - 1) * ... * (a - n + 1) is (-1)^n * (-a)_n.
+pub fn f_pochhammer(a: f64, n: f64) -> f64 {
+    if a.is_nan() || n.is_nan() {
+        return f64::NAN;
+    }
+    if n == 0. {
+        return 1.;
+    }
+    if a == f64::INFINITY && n.is_finite() {
+        return if n > 0. { f64::INFINITY } else { 0. };
+    }
+    if !a.is_finite() || !n.is_finite() {
+        return f_tgamma_ratio(a + n, a);
+    }
+    let x = DoubleDouble::from_full_exact_add(a, n);
+    if a > 0. && x.hi > 0. {
+        let l = if n > 0. {
+            lgamma_delta(DoubleDouble::new(0., a), n)
+        } else {
+            -lgamma_delta(x, -n)
+        };
+        return exp_signed(l, 1.);
+    }
+    if n > 0. && n <= 64. && is_integer(n) {
+        // Direct product handles zeros and signs for non-positive a
+        let mut p = DoubleDouble::new(0., a);
+        let mut i = 1.;
+        while i < n {
+            let t = DoubleDouble::from_full_exact_add(a, i);
+            p = DoubleDouble::quick_mult(p, t);
+            p = DoubleDouble::from_exact_add(p.hi, p.lo);
+            i += 1.;
+        }
+        if p.hi.is_finite() {
+            return p.to_f64();
+        }
+    }
+    if x.lo == 0. {
+        return f_tgamma_ratio(x.hi, a);
+    }
+    if is_pole(a) {
+        return 0.;
+    }
+    let (l, sign) = lgamma_ratio_core(x, DoubleDouble::new(0., a));
+    exp_signed(l, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pochhammer() {
+        assert_eq!(f_pochhammer(3., 4.), 360.);
+        assert_eq!(f_pochhammer(0.5, 10.), 639383.8623046875);
+        assert_eq!(f_pochhammer(-3., 2.), 6.);
+        assert_eq!(f_pochhammer(-3., 5.), 0.);
+        assert_eq!(f_pochhammer(-2.5, 3.), -1.875);
+        assert_eq!(f_pochhammer(1000000., 2.5), 1000001875000507.8);
+        assert_eq!(f_pochhammer(100., -0.5), 0.10037696342977405);
+        assert_eq!(
+            f_pochhammer(-20.619389039551283, -19.364972930019654),
+            -3.923752246162518e-28
+        );
+        assert!(f_pochhammer(5., -7.).is_nan());
+        assert_eq!(f_pochhammer(0.5, 1e300), f64::INFINITY);
+        assert_eq!(f_pochhammer(1e-300, 1.5), 8.86226925452758e-301);
+        assert_eq!(f_pochhammer(2., 0.), 1.);
+        assert_eq!(f_pochhammer(f64::INFINITY, 2.), f64::INFINITY);
+        assert!(f_pochhammer(f64::NAN, 1.).is_nan());
+    }
+}
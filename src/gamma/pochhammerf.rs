@@ -0,0 +1,98 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::is_integerf;
+use crate::gamma::tgamma_ratiof::{
+    expf_signed, f_tgamma_ratiof, is_polef, lgamma_deltaf, lgamma_ratio_coref,
+};
+
+/// Computes rising factorial (Pochhammer symbol) (a)_n = Gamma(a + n)/Gamma(a)
+///
+/// For integer n > 0 this is a * (a + 1) * ... * (a + n - 1), falling factorial
+/// a * (a - 1) * ... * (a - n + 1) is (-1)^n * (-a)_n.
+pub fn f_pochhammerf(a: f32, n: f32) -> f32 {
+    if a.is_nan() || n.is_nan() {
+        return f32::NAN;
+    }
+    if n == 0. {
+        return 1.;
+    }
+    if a == f32::INFINITY && n.is_finite() {
+        return if n > 0. { f32::INFINITY } else { 0. };
+    }
+    if !a.is_finite() || !n.is_finite() {
+        return f_tgamma_ratiof(a + n, a);
+    }
+    let x = a as f64 + n as f64;
+    if a > 0. && x > 0. {
+        let l = if n > 0. {
+            lgamma_deltaf(a as f64, n as f64)
+        } else {
+            -lgamma_deltaf(x, -n as f64)
+        };
+        return expf_signed(l, 1.);
+    }
+    if n > 0. && n <= 64. && is_integerf(n) {
+        // Direct product handles zeros and signs for non-positive a
+        let da = a as f64;
+        let mut p = da;
+        let mut i = 1.;
+        while i < n as f64 {
+            p *= da + i;
+            i += 1.;
+        }
+        if p.is_finite() {
+            return p as f32;
+        }
+    }
+    if x as f32 as f64 == x && x - a as f64 == n as f64 {
+        return f_tgamma_ratiof(x as f32, a);
+    }
+    if is_polef(a) {
+        return 0.;
+    }
+    let (l, sign) = lgamma_ratio_coref(x, a as f64);
+    expf_signed(l, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pochhammerf() {
+        assert_eq!(f_pochhammerf(3., 4.), 360.);
+        assert_eq!(f_pochhammerf(0.5, 10.), 639383.9);
+        assert_eq!(f_pochhammerf(-3., 5.), 0.);
+        assert_eq!(f_pochhammerf(-2.5, 3.), -1.875);
+        assert_eq!(f_pochhammerf(10000., 2.5), 1.0001875e10);
+        assert_eq!(f_pochhammerf(-20.6, -19.3), -8.2082364e-29);
+        assert_eq!(f_pochhammerf(f32::INFINITY, 2.), f32::INFINITY);
+        assert!(f_pochhammerf(f32::NAN, 1.).is_nan());
+    }
+}
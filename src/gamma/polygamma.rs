@@ -0,0 +1,386 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::is_integer;
+use crate::double_double::DoubleDouble;
+use crate::gamma::digamma::f_digamma;
+use crate::gamma::digamma_coeffs::BERNOULLI_2K_OVER_FACTORIAL;
+use crate::gamma::lgamma::lgamma_core;
+use crate::gamma::trigamma::f_trigamma;
+use crate::logs::fast_log_dd;
+use crate::pow_exec::exp_dd_fast;
+use crate::rounding::CpuRound;
+use crate::tangent::cotpi_core;
+use crate::{f_lgamma, f_log, f_log1p};
+
+#[inline]
+fn dd_powi(x: DoubleDouble, n: u32) -> DoubleDouble {
+    let mut r = DoubleDouble::new(0., 1.);
+    let mut b = x;
+    let mut e = n;
+    // products are renormalized, since quick multiplication drops lo * lo
+    // which otherwise grows over a long chain of squarings
+    while e > 0 {
+        if e & 1 != 0 {
+            r = DoubleDouble::quick_mult(r, b);
+            r = DoubleDouble::from_exact_add(r.hi, r.lo);
+        }
+        e >>= 1;
+        if e > 0 {
+            b = DoubleDouble::quick_mult(b, b);
+            b = DoubleDouble::from_exact_add(b.hi, b.lo);
+        }
+    }
+    r
+}
+
+/// Computes n!/x^n for x > 0 as `s * 2^e`
+///
+/// Running product may pass far below or above double range before coming back,
+/// when x is between 1 and n, so it is kept normalized with a separate exponent.
+#[inline]
+fn factorial_over_pow(n: u32, x: DoubleDouble) -> (DoubleDouble, i32) {
+    const P600: f64 = f64::from_bits(0x6570000000000000);
+    const M600: f64 = f64::from_bits(0x1a70000000000000);
+    let mut s = DoubleDouble::new(0., 1.);
+    let mut e = 0i32;
+    for j in 1..=n {
+        s = DoubleDouble::div(DoubleDouble::mult_f64(s, j as f64), x);
+        if !s.hi.is_finite() {
+            // every factor is > 1 for such small x, so result overflows anyway
+            break;
+        }
+        if s.hi < f64::from_bits(0x2700000000000000) {
+            // s < 2^-399
+            s = DoubleDouble::new(s.lo * P600, s.hi * P600);
+            e -= 600;
+        } else if s.hi > f64::from_bits(0x58f0000000000000) {
+            // s > 2^400
+            s = DoubleDouble::new(s.lo * M600, s.hi * M600);
+            e += 600;
+        }
+    }
+    (s, e)
+}
+
+/// Order from which n!/x^n is computed through logarithms rather than running product
+pub(crate) const FACTORIAL_LOG_ORDER: u32 = 512;
+
+/// Computes n!/x^n for x > 0 as `s * 2^e` from log(n!) - n*log(x)
+///
+/// Cost doesn't depend on n, used for high orders where the running product is too slow.
+pub(crate) fn factorial_over_pow_log(n: u32, x: DoubleDouble) -> (DoubleDouble, i32) {
+    const LN2: DoubleDouble = DoubleDouble::from_bit_pair((0x3c7abc9e3b39803f, 0x3fe62e42fefa39ef));
+    const INV_LN2: f64 = f64::from_bits(0x3ff71547652b82fe);
+    let (lf, _) = lgamma_core(n as f64 + 1.);
+    let lx = fast_log_dd(x);
+    let mut l = DoubleDouble::mul_f64_add(lx, -(n as f64), lf);
+    // both logarithms are huge and cancel, so low part may exceed ulp of the difference
+    l = DoubleDouble::from_exact_add(l.hi, l.lo);
+    let e = (l.hi * INV_LN2).cpu_round();
+    let mut r = DoubleDouble::mul_f64_add(LN2, -e, l);
+    r = DoubleDouble::from_exact_add(r.hi, r.lo);
+    (exp_dd_fast(r), e as i32)
+}
+
+/// Bounds log|PolyGamma\[n, x\]| for x > 0, n >= 2.
+///
+/// Follows from n!/x^(n+1) <= |PolyGamma\[n, x\]| <= n!/x^(n+1) + (n-1)!/x^n,
+/// used to skip evaluation when the result surely overflows or underflows.
+#[inline]
+pub(crate) fn polygamma_log_bounds(n: u32, x: f64) -> (f64, f64) {
+    let nf = n as f64;
+    let lx = f_log(x);
+    let lo = f_lgamma(nf + 1.) - nf * lx - lx;
+    (lo, lo + f_log1p(x / nf))
+}
+
+/// Computes x * 2^e
+#[inline]
+fn dd_ldexp(x: DoubleDouble, e: i32) -> DoubleDouble {
+    let mut r = x;
+    let mut e = e;
+    while e > 1000 {
+        r = DoubleDouble::new(
+            r.lo * f64::from_bits(0x7e70000000000000),
+            r.hi * f64::from_bits(0x7e70000000000000),
+        );
+        e -= 1000;
+    }
+    while e < -1000 {
+        r = DoubleDouble::new(
+            r.lo * f64::from_bits(0x0170000000000000),
+            r.hi * f64::from_bits(0x0170000000000000),
+        );
+        e += 1000;
+    }
+    let p = f64::from_bits(((0x3ff + e) as u64) << 52);
+    let hi = r.hi * p;
+    if !hi.is_finite() {
+        return DoubleDouble::new(0., hi);
+    }
+    DoubleDouble::new(r.lo * p, hi)
+}
+
+/// Computes |PolyGamma\[n, x\]| / (n!/x^n) for x > 0, n >= 2.
+///
+/// Recurrence PolyGamma\[n, x\] = PolyGamma\[n, x + 1\] - (-1)^n * n! / x^(n + 1)
+/// moves the argument into the region where asymptotic expansion converges:
+/// PolyGamma\[n, z\] ~ (-1)^(n+1) * ((n-1)!/z^n + n!/(2*z^(n+1)) +
+/// sum(Bernoulli(2k) * (2k + n - 1)! / ((2k)! * z^(2k + n))).
+///
+/// Everything is scaled by x^n/n! so factorials never appear explicitly.
+fn polygamma_scaled(n: u32, x: DoubleDouble) -> DoubleDouble {
+    // With z >= n + 24 each term of expansion is bounded by 2 * (2*pi)^(-2k),
+    // thus 12 terms are enough for double-double precision.
+    let threshold = n as f64 + 24.;
+    let mut sum = DoubleDouble::default();
+    let mut z = x;
+    while z.hi < threshold {
+        // (x/z)^n / z
+        let ratio = DoubleDouble::div(x, z);
+        let term = DoubleDouble::div(dd_powi(ratio, n), z);
+        sum = DoubleDouble::add(sum, term);
+        // For z < n consecutive terms decrease at least by (z/(z+1))^(n+1) <= 1/e,
+        // so the rest of the series is below the last term
+        if z.hi < n as f64 && term.hi < sum.hi * f64::from_bits(0x3910000000000000) {
+            // term < sum * 2^-110
+            return sum;
+        }
+        z = DoubleDouble::full_add_f64(z, 1.);
+    }
+
+    let r = z.recip();
+    let r2 = DoubleDouble::quick_mult(r, r);
+
+    // Bernoulli(2k)/(2k)! * (n + 1)(n + 2)...(n + 2k - 1) in reversed order for Horner scheme
+    let mut coeffs = [DoubleDouble::default(); 12];
+    let mut rising = n as f64 + 1.;
+    for (k, dst) in coeffs.iter_mut().enumerate() {
+        *dst = DoubleDouble::mult_f64(
+            DoubleDouble::from_bit_pair(BERNOULLI_2K_OVER_FACTORIAL[k]),
+            rising,
+        );
+        let q = n as f64 + 2. * (k as f64 + 1.);
+        rising *= q * (q + 1.);
+    }
+    let mut p = coeffs[11];
+    for &c in coeffs[..11].iter().rev() {
+        p = DoubleDouble::mul_add(p, r2, c);
+    }
+    p = DoubleDouble::quick_mult(p, r2);
+    // 1/n + 1/(2z) + sum
+    p = DoubleDouble::add(DoubleDouble::quick_mult_f64(r, 0.5), p);
+    p = DoubleDouble::add(DoubleDouble::from_exact_div(1., n as f64), p);
+
+    let ratio = DoubleDouble::div(x, z);
+    let tail = DoubleDouble::quick_mult(dd_powi(ratio, n), p);
+    DoubleDouble::add(sum, tail)
+}
+
+/// Computes |PolyGamma\[n, x\]| for x > 0, n >= 2.
+#[inline]
+fn polygamma_positive(n: u32, x: DoubleDouble) -> DoubleDouble {
+    let (lo, hi) = polygamma_log_bounds(n, x.hi);
+    if lo > 710. {
+        return DoubleDouble::new(0., f64::INFINITY);
+    } else if hi < -746. {
+        return DoubleDouble::default();
+    }
+    let (scale, e) = if n < FACTORIAL_LOG_ORDER {
+        factorial_over_pow(n, x)
+    } else {
+        factorial_over_pow_log(n, x)
+    };
+    if !scale.hi.is_finite() {
+        return DoubleDouble::new(0., f64::INFINITY);
+    }
+    dd_ldexp(DoubleDouble::quick_mult(scale, polygamma_scaled(n, x)), e)
+}
+
+/// Max order for which pi^(n+1) * n! * Q_n(cot(pi*x)) may stay finite
+///
+/// Beyond this n! alone overflows and |Q_n(t)| * pi^(n+1) >= 1 for any representable
+/// non-zero t = cot(pi*x).
+const COTPI_MAX_ORDER: usize = 170;
+
+/// Evaluates Q_n(t) where d^n/dx^n cot(pi*x) = pi^n * n! * Q_n(cot(pi*x)).
+///
+/// Q_0(t) = t, Q_(k+1)(t) = -(1 + t^2) * Q_k'(t) / (k + 1).
+/// Q_n is a polynomial of degree n + 1 with only even or only odd powers
+/// having the same sign, so evaluation doesn't suffer from cancellation.
+/// Coefficients of Q_n have the sign (-1)^n.
+fn cotpi_derivative_poly(n: u32, t: DoubleDouble) -> DoubleDouble {
+    let len = n as usize + 2;
+    let mut q = [DoubleDouble::default(); COTPI_MAX_ORDER + 2];
+    let mut next = [DoubleDouble::default(); COTPI_MAX_ORDER + 2];
+    q[1] = DoubleDouble::new(0., 1.);
+    for k in 0..n as usize {
+        // Q_k has degree k + 1, so Q_k' has degree k and Q_(k+1) has degree k + 2
+        let scale = -1. / (k as f64 + 1.);
+        for (i, dst) in next[..k + 3].iter_mut().enumerate() {
+            let mut v = DoubleDouble::default();
+            if i <= k {
+                v = DoubleDouble::mult_f64(q[i + 1], (i + 1) as f64);
+            }
+            if i >= 2 {
+                v = DoubleDouble::add(v, DoubleDouble::mult_f64(q[i - 1], (i - 1) as f64));
+            }
+            *dst = DoubleDouble::mult_f64(v, scale);
+        }
+        std::mem::swap(&mut q, &mut next);
+    }
+    let mut p = q[len - 1];
+    for &c in q[..len - 1].iter().rev() {
+        p = DoubleDouble::mul_add(p, t, c);
+    }
+    p
+}
+
+/// Computes polygamma function of order n, PolyGamma\[n, x\].
+///
+/// The polygamma function is the (n + 1)-th derivative of the logarithm of the gamma function,
+/// `n = 0` is digamma and `n = 1` is trigamma.
+pub fn f_polygamma(n: u32, x: f64) -> f64 {
+    if n == 0 {
+        return f_digamma(x);
+    } else if n == 1 {
+        return f_trigamma(x);
+    }
+
+    let odd_order = n & 1 != 0;
+
+    if !x.is_normal() {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_negative() { f64::NAN } else { 0. };
+        }
+        if x == 0. {
+            // PolyGamma[n, x] ~ (-1)^(n+1) * n! / x^(n+1)
+            return if odd_order || x.is_sign_negative() {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            };
+        }
+    }
+
+    if x > 0. {
+        let r = polygamma_positive(n, DoubleDouble::new(0., x));
+        let v = r.to_f64();
+        return if odd_order { v } else { -v };
+    }
+
+    if is_integer(x) {
+        // Poles of odd order have the same sign at both sides
+        return if odd_order { f64::INFINITY } else { f64::NAN };
+    }
+
+    // Reflection formula
+    // PolyGamma[n, 1 - x] + (-1)^(n+1) * PolyGamma[n, x] = (-1)^n * pi * d^n/dx^n cot(pi*x)
+    // which gives
+    // PolyGamma[n, x] = (-1)^n * PolyGamma[n, 1 - x] - pi^(n+1) * n! * Q_n(cot(pi*x))
+    const PI: DoubleDouble = DoubleDouble::from_bit_pair((0x3ca1a62633145c07, 0x400921fb54442d18));
+    let t = cotpi_core(x);
+    // cotangent term overflows for high orders or near poles, its sign is the sign of Q_n(t)
+    let overflow = if odd_order || t.hi < 0. {
+        f64::INFINITY
+    } else {
+        f64::NEG_INFINITY
+    };
+    if n as usize > COTPI_MAX_ORDER {
+        // Q_n is odd for even n, thus vanishes at half-integers
+        if !odd_order && (x - x.trunc()).abs() == 0.5 {
+            return -polygamma_positive(n, DoubleDouble::from_full_exact_sub(1., x)).to_f64();
+        }
+        return overflow;
+    }
+    let mut c = cotpi_derivative_poly(n, t);
+    // Factors are all greater than one, so there is no spurious intermediate overflow
+    c = DoubleDouble::quick_mult(c, PI);
+    for j in 1..=n {
+        if !c.hi.is_finite() {
+            return overflow;
+        }
+        c = DoubleDouble::mult_f64(DoubleDouble::quick_mult(c, PI), j as f64);
+    }
+    if !c.hi.is_finite() {
+        return overflow;
+    }
+    let dx = DoubleDouble::from_full_exact_sub(1., x);
+    // (-1)^n * PolyGamma[n, 1 - x] = -|PolyGamma[n, 1 - x]|
+    let reflected = polygamma_positive(n, dx);
+    let r = DoubleDouble::full_dd_add(reflected, c);
+    if !r.hi.is_finite() {
+        return -r.hi;
+    }
+    -r.to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygamma() {
+        assert_eq!(f_polygamma(0, 2.23432), f_digamma(2.23432));
+        assert_eq!(f_polygamma(1, 2.23432), f_trigamma(2.23432));
+        assert_eq!(f_polygamma(2, 1.), -2.4041138063191885);
+        assert_eq!(f_polygamma(2, 0.5), -16.82879664423432);
+        assert_eq!(f_polygamma(3, 2.23432), 0.3326891916302549);
+        assert_eq!(f_polygamma(2, -2.4653), 6.757713222121561);
+        assert_eq!(f_polygamma(5, -0.54331), 17905.38734207264);
+        assert_eq!(f_polygamma(4, 1e-5), -2.399999999999999e26);
+        assert_eq!(f_polygamma(7, 315.5), 2.339636604079709e-15);
+        assert_eq!(f_polygamma(6, -3.7), -3283537.1093814913);
+        assert_eq!(f_polygamma(50, 2.5), -1.542177794502834e44);
+        assert_eq!(f_polygamma(2, -100.25), 124.0250082069177);
+        assert_eq!(f_polygamma(2000, 1000.), -3.8355185180233863e-268);
+        assert_eq!(f_polygamma(300, 400.), -3.495090778721026e-169);
+        assert_eq!(f_polygamma(2000, -1000.5), -1.9119204783142092e-269);
+        assert_eq!(f_polygamma(5000, 2000.), -1.6307701367817272e-183);
+        assert_eq!(f_polygamma(10000000, 1.), f64::NEG_INFINITY);
+        assert_eq!(f_polygamma(10000001, 1.), f64::INFINITY);
+        assert_eq!(f_polygamma(10000000, 3678794.5), -1.8147122973168105e-3);
+        assert_eq!(f_polygamma(10000000, 3.7e6), -0.);
+        assert_eq!(f_polygamma(u32::MAX, 1e300), 0.);
+        assert_eq!(f_polygamma(171, -2.25), f64::INFINITY);
+        assert_eq!(f_polygamma(172, -2.25), f64::INFINITY);
+        assert_eq!(f_polygamma(172, -1.75), f64::NEG_INFINITY);
+        assert_eq!(f_polygamma(2, 0.), f64::NEG_INFINITY);
+        assert_eq!(f_polygamma(2, -0.), f64::INFINITY);
+        assert_eq!(f_polygamma(3, 0.), f64::INFINITY);
+        assert_eq!(f_polygamma(3, -4.), f64::INFINITY);
+        assert!(f_polygamma(2, -4.).is_nan());
+        assert_eq!(f_polygamma(2, f64::INFINITY), 0.);
+        assert!(f_polygamma(2, f64::NEG_INFINITY).is_nan());
+        assert!(f_polygamma(2, f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,265 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::{f_fmla, is_integerf};
+use crate::double_double::DoubleDouble;
+use crate::gamma::digamma_coeffs::BERNOULLI_2K_OVER_FACTORIAL;
+use crate::gamma::digammaf::f_digammaf;
+use crate::gamma::polygamma::{FACTORIAL_LOG_ORDER, factorial_over_pow_log, polygamma_log_bounds};
+use crate::gamma::trigammaf::f_trigammaf;
+use crate::tangent::cotpif_core;
+
+/// Computes |PolyGamma\[n, x\]| for x > 0, n >= 2.
+///
+/// See [crate::f_polygamma] for the details, this is a single precision variant
+/// of the same scheme: recurrence to push the argument to z >= n + 16
+/// and then asymptotic expansion, all scaled by x^n/n!.
+fn polygammaf_positive(n: u32, x: f64) -> f64 {
+    let (lo, hi) = polygamma_log_bounds(n, x);
+    if lo > 90. {
+        return f64::INFINITY;
+    } else if hi < -105. {
+        return 0.;
+    }
+    if n >= FACTORIAL_LOG_ORDER {
+        let (scale, e) = factorial_over_pow_log(n, DoubleDouble::new(0., x));
+        return polygammaf_tail(n, x, scale.to_f64(), e);
+    }
+    // n!/x^n = scale * 2^e, kept normalized since the running product
+    // may pass far below double range before coming back when x < n
+    let mut scale = 1.0f64;
+    let mut e = 0i32;
+    for j in 1..=n {
+        scale = scale * j as f64 / x;
+        if !scale.is_finite() {
+            return f64::INFINITY;
+        }
+        if scale < f64::from_bits(0x2700000000000000) {
+            // scale < 2^-399
+            scale *= f64::from_bits(0x6570000000000000); // 2^600
+            e -= 600;
+        } else if scale > f64::from_bits(0x58f0000000000000) {
+            // scale > 2^400
+            scale *= f64::from_bits(0x1a70000000000000); // 2^-600
+            e += 600;
+        }
+    }
+    polygammaf_tail(n, x, scale, e)
+}
+
+/// Computes |PolyGamma\[n, x\]| given n!/x^n = scale * 2^e
+fn polygammaf_tail(n: u32, x: f64, scale: f64, e: i32) -> f64 {
+    let threshold = n as f64 + 16.;
+    let mut sum = 0.;
+    let mut z = x;
+    while z < threshold {
+        let term = powu(x / z, n) / z;
+        sum += term;
+        // For z < n the rest of the series is below the last term
+        if z < n as f64 && term < sum * f64::from_bits(0x3c80000000000000) {
+            // term < sum * 2^-55
+            return ldexp_split(scale * sum, e);
+        }
+        z += 1.;
+    }
+
+    let r = 1. / z;
+    let r2 = r * r;
+    let mut coeffs = [0f64; 8];
+    let mut rising = n as f64 + 1.;
+    for (k, dst) in coeffs.iter_mut().enumerate() {
+        *dst = f64::from_bits(BERNOULLI_2K_OVER_FACTORIAL[k].1) * rising;
+        let q = n as f64 + 2. * (k as f64 + 1.);
+        rising *= q * (q + 1.);
+    }
+    let mut p = coeffs[7];
+    for &c in coeffs[..7].iter().rev() {
+        p = f_fmla(p, r2, c);
+    }
+    p = f_fmla(p, r2, f_fmla(r, 0.5, 1. / n as f64));
+    let tail = powu(x / z, n) * p;
+    ldexp_split(scale * (sum + tail), e)
+}
+
+/// Computes x^n, `powi` alone takes only orders up to i32::MAX
+#[inline]
+fn powu(x: f64, n: u32) -> f64 {
+    if n <= i32::MAX as u32 {
+        return x.powi(n as i32);
+    }
+    let h = x.powi((n >> 1) as i32);
+    let r = h * h;
+    if n & 1 != 0 { r * x } else { r }
+}
+
+/// Computes r * 2^e for |e| far beyond a single power of two range
+#[inline]
+fn ldexp_split(r: f64, e: i32) -> f64 {
+    if e < -1200 {
+        return 0.;
+    } else if e > 1200 {
+        return f64::INFINITY;
+    }
+    // 2^e as 2^(e/2) * 2^(e - e/2)
+    let h = e / 2;
+    r * f64::from_bits(((0x3ff + h) as u64) << 52) * f64::from_bits(((0x3ff + e - h) as u64) << 52)
+}
+
+/// Max order for which pi^(n+1) * n! * Q_n(cot(pi*x)) may stay finite,
+/// see [crate::f_polygamma].
+const COTPIF_MAX_ORDER: usize = 170;
+
+/// Evaluates Q_n(t) where d^n/dx^n cot(pi*x) = pi^n * n! * Q_n(cot(pi*x)).
+fn cotpif_derivative_poly(n: u32, t: f64) -> f64 {
+    let len = n as usize + 2;
+    let mut q = [0f64; COTPIF_MAX_ORDER + 2];
+    let mut next = [0f64; COTPIF_MAX_ORDER + 2];
+    q[1] = 1.;
+    for k in 0..n as usize {
+        let scale = -1. / (k as f64 + 1.);
+        for (i, dst) in next[..k + 3].iter_mut().enumerate() {
+            let mut v = 0.;
+            if i <= k {
+                v = q[i + 1] * (i + 1) as f64;
+            }
+            if i >= 2 {
+                v = f_fmla(q[i - 1], (i - 1) as f64, v);
+            }
+            *dst = v * scale;
+        }
+        std::mem::swap(&mut q, &mut next);
+    }
+    let mut p = q[len - 1];
+    for &c in q[..len - 1].iter().rev() {
+        p = f_fmla(p, t, c);
+    }
+    p
+}
+
+/// Computes polygamma function of order n, PolyGamma\[n, x\].
+///
+/// The polygamma function is the (n + 1)-th derivative of the logarithm of the gamma function,
+/// `n = 0` is digamma and `n = 1` is trigamma.
+pub fn f_polygammaf(n: u32, x: f32) -> f32 {
+    if n == 0 {
+        return f_digammaf(x);
+    } else if n == 1 {
+        return f_trigammaf(x);
+    }
+
+    let odd_order = n & 1 != 0;
+
+    let xb = x.to_bits();
+    // filter out exceptional cases
+    if xb >= 0xffu32 << 23 || xb == 0 {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        if x.is_infinite() {
+            return if x.is_sign_negative() { f32::NAN } else { 0. };
+        }
+        if x == 0. {
+            return if odd_order || x.is_sign_negative() {
+                f32::INFINITY
+            } else {
+                f32::NEG_INFINITY
+            };
+        }
+    }
+
+    if x > 0. {
+        let v = polygammaf_positive(n, x as f64);
+        return if odd_order { v as f32 } else { -v as f32 };
+    }
+
+    if is_integerf(x) {
+        return if odd_order { f32::INFINITY } else { f32::NAN };
+    }
+
+    // Reflection formula
+    // PolyGamma[n, x] = (-1)^n * PolyGamma[n, 1 - x] - pi^(n+1) * n! * Q_n(cot(pi*x))
+    const PI: f64 = f64::from_bits(0x400921fb54442d18);
+    let dx = x as f64;
+    let t = cotpif_core(dx);
+    // cotangent term overflows for high orders or near poles, its sign is the sign of Q_n(t)
+    let overflow = if odd_order || t < 0. {
+        f32::INFINITY
+    } else {
+        f32::NEG_INFINITY
+    };
+    if n as usize > COTPIF_MAX_ORDER {
+        // Q_n is odd for even n, thus vanishes at half-integers
+        if !odd_order && (dx - dx.trunc()).abs() == 0.5 {
+            return -polygammaf_positive(n, 1. - dx) as f32;
+        }
+        return overflow;
+    }
+    let mut c = cotpif_derivative_poly(n, t) * PI;
+    for j in 1..=n {
+        c *= PI * j as f64;
+        if !c.is_finite() {
+            return overflow;
+        }
+    }
+    let reflected = polygammaf_positive(n, 1. - dx);
+    (-(reflected + c)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygammaf() {
+        assert_eq!(f_polygammaf(0, 2.23432), f_digammaf(2.23432));
+        assert_eq!(f_polygammaf(1, 2.23432), f_trigammaf(2.23432));
+        assert_eq!(f_polygammaf(2, 1.), -2.4041138);
+        assert_eq!(f_polygammaf(3, 2.23432), 0.33268923);
+        assert_eq!(f_polygammaf(2, -2.4653), 6.757696);
+        assert_eq!(f_polygammaf(5, -0.54331), 17905.385);
+        assert_eq!(f_polygammaf(6, -3.7), -3283540.8);
+        assert_eq!(f_polygammaf(10, 0.3), -2048467500000.0);
+        assert_eq!(f_polygammaf(2, -100.25), 124.02501);
+        assert_eq!(f_polygammaf(50, 2.5), f32::NEG_INFINITY);
+        assert_eq!(f_polygammaf(40, 30.), -3.0429735e-13);
+        assert_eq!(f_polygammaf(2180, 800.), -34.019028);
+        assert_eq!(f_polygammaf(2150, 800.), -3.6219786e-12);
+        assert_eq!(f_polygammaf(10000000, 1.), f32::NEG_INFINITY);
+        assert_eq!(f_polygammaf(100000, 36788.), -1.9818038e-2);
+        assert_eq!(f_polygammaf(100000, 40000.), -0.);
+        assert_eq!(f_polygammaf(171, -2.25), f32::INFINITY);
+        assert_eq!(f_polygammaf(172, -1.75), f32::NEG_INFINITY);
+        assert_eq!(f_polygammaf(2, 0.), f32::NEG_INFINITY);
+        assert_eq!(f_polygammaf(2, -0.), f32::INFINITY);
+        assert_eq!(f_polygammaf(3, -4.), f32::INFINITY);
+        assert!(f_polygammaf(2, -4.).is_nan());
+        assert_eq!(f_polygammaf(2, f32::INFINITY), 0.);
+        assert!(f_polygammaf(2, f32::NEG_INFINITY).is_nan());
+        assert!(f_polygammaf(2, f32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,212 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::is_integer;
+use crate::double_double::DoubleDouble;
+use crate::exponents::{log_abs_dd, mul_exp_neg, mul_exp_pos};
+use crate::gamma::digamma::f_digamma;
+use crate::gamma::lgamma::lgamma_core;
+use crate::gamma::lnbeta::lgamma_delta;
+
+/// Computes sign * e^l handling overflow and underflow.
+#[inline]
+pub(crate) fn exp_signed(l: DoubleDouble, sign: f64) -> f64 {
+    if l.hi > 710. {
+        return f64::INFINITY * sign;
+    }
+    if l.hi < -746. {
+        return 0. * sign;
+    }
+    let one = DoubleDouble::new(0., sign);
+    if l.hi >= 0. {
+        mul_exp_pos(one, l)
+    } else {
+        mul_exp_neg(one, -l)
+    }
+}
+
+#[inline]
+pub(crate) fn is_pole(x: f64) -> bool {
+    x == 0. || (x < 0. && is_integer(x))
+}
+
+/// Computes log|Gamma(x)| and sign of Gamma(x) for double-double x that is not a pole.
+pub(crate) fn lgamma_dd(x: DoubleDouble) -> (DoubleDouble, f64) {
+    if x.lo == 0. {
+        let (l, s) = lgamma_core(x.hi);
+        return (l, s as f64);
+    }
+    if is_pole(x.hi) {
+        // Gamma(-m + e) ~ (-1)^m / (m! * e)
+        let m = -x.hi;
+        let (lf, _) = lgamma_core(m + 1.);
+        let sign = if is_integer(m * 0.5) { 1. } else { -1. };
+        let le = log_abs_dd(DoubleDouble::new(0., x.lo));
+        return (-DoubleDouble::full_dd_add(lf, le), sign * x.lo.signum());
+    }
+    // Rounding of the argument is amplified by digamma near the poles,
+    // so log(Gamma(x.hi + x.lo)) ~ log(Gamma(x.hi)) + digamma(x.hi) * x.lo
+    let (l, s) = lgamma_core(x.hi);
+    (
+        DoubleDouble::full_add_f64(l, f_digamma(x.hi) * x.lo),
+        s as f64,
+    )
+}
+
+/// Computes log|Gamma(a)/Gamma(b)| and sign of the ratio for finite a, b that are not poles.
+pub(crate) fn lgamma_ratio_core(a: DoubleDouble, b: DoubleDouble) -> (DoubleDouble, f64) {
+    if a.lo == 0. && b.lo == 0. && a.hi > 0. && b.hi > 0. {
+        let r = DoubleDouble::from_full_exact_sub(a.hi, b.hi);
+        if r.lo == 0. {
+            // Exact difference, nothing is lost when arguments are close
+            return if r.hi >= 0. {
+                (lgamma_delta(b, r.hi), 1.)
+            } else {
+                (-lgamma_delta(a, -r.hi), 1.)
+            };
+        }
+    }
+    let (la, sa) = lgamma_dd(a);
+    let (lb, sb) = lgamma_dd(b);
+    (DoubleDouble::full_dd_sub(la, lb), sa * sb)
+}
+
+/// Computes Gamma(a)/Gamma(b) when both a and b are poles as a limit
+/// Gamma(a + e)/Gamma(b + e), e -> 0.
+fn tgamma_ratio_poles(a: f64, b: f64) -> f64 {
+    // Gamma(-m + e)/Gamma(-n + e) -> (-1)^(m - n) * n!/m!
+    let sign = if is_integer((a - b) * 0.5) { 1. } else { -1. };
+    let (l, s) = lgamma_ratio_core(
+        DoubleDouble::from_full_exact_add(1., -b),
+        DoubleDouble::from_full_exact_add(1., -a),
+    );
+    exp_signed(l, sign * s)
+}
+
+/// Computes ratio of gamma functions Gamma(a)/Gamma(b)
+///
+/// Stays finite when Gamma(a) and Gamma(b) overflow individually.
+/// When both arguments are poles returns limit of Gamma(a + e)/Gamma(b + e) as e -> 0,
+/// when only a is a pole returns NaN.
+pub fn f_tgamma_ratio(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        return f64::NAN;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        if a == f64::INFINITY && b.is_finite() && !is_pole(b) {
+            return f64::INFINITY;
+        }
+        if b == f64::INFINITY && a.is_finite() && !is_pole(a) {
+            return 0.;
+        }
+        return f64::NAN;
+    }
+    match (is_pole(a), is_pole(b)) {
+        (true, true) => return tgamma_ratio_poles(a, b),
+        (true, false) => return f64::NAN,
+        (false, true) => return 0.,
+        _ => {}
+    }
+    if a == b {
+        return 1.;
+    }
+    let (l, sign) = lgamma_ratio_core(DoubleDouble::new(0., a), DoubleDouble::new(0., b));
+    exp_signed(l, sign)
+}
+
+/// Computes Gamma(a)/Gamma(a + delta)
+///
+/// Keeps full accuracy when delta is small relative to a.
+pub fn f_tgamma_delta_ratio(a: f64, delta: f64) -> f64 {
+    if a.is_nan() || delta.is_nan() {
+        return f64::NAN;
+    }
+    if delta == 0. && a.is_finite() && !is_pole(a) {
+        return 1.;
+    }
+    if !a.is_finite() || !delta.is_finite() {
+        return f_tgamma_ratio(a, a + delta);
+    }
+    let x = DoubleDouble::from_full_exact_add(a, delta);
+    if x.lo == 0. {
+        return f_tgamma_ratio(a, x.hi);
+    }
+    if is_pole(a) {
+        return f64::NAN;
+    }
+    if a > 0. && x.hi > 0. {
+        let l = if delta > 0. {
+            -lgamma_delta(DoubleDouble::new(0., a), delta)
+        } else {
+            lgamma_delta(x, -delta)
+        };
+        return exp_signed(l, 1.);
+    }
+    let (l, sign) = lgamma_ratio_core(DoubleDouble::new(0., a), x);
+    exp_signed(l, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tgamma_ratio() {
+        assert_eq!(f_tgamma_ratio(5., 3.), 12.);
+        assert_eq!(f_tgamma_ratio(0.5, 1.5), 2.);
+        assert_eq!(f_tgamma_ratio(200., 190.), 7.739669421472003e22);
+        assert_eq!(f_tgamma_ratio(1000.5, 1000.), 31.618824001815913);
+        assert_eq!(f_tgamma_ratio(-2.5, 3.5), -0.28444444444444444);
+        assert_eq!(f_tgamma_ratio(-3.5, -2.25), -0.15497240192486542);
+        assert_eq!(f_tgamma_ratio(-3., -5.), 20.);
+        assert_eq!(f_tgamma_ratio(-5., -3.), 0.05);
+        assert_eq!(f_tgamma_ratio(0., -4.), 24.);
+        assert_eq!(f_tgamma_ratio(2., -3.), 0.);
+        assert!(f_tgamma_ratio(-3., 2.).is_nan());
+        assert_eq!(f_tgamma_ratio(1e10, 9999999999.), 9999999999.);
+        assert_eq!(f_tgamma_ratio(f64::INFINITY, 2.), f64::INFINITY);
+        assert_eq!(f_tgamma_ratio(2., f64::INFINITY), 0.);
+        assert!(f_tgamma_ratio(f64::NAN, 1.).is_nan());
+    }
+
+    #[test]
+    fn test_tgamma_delta_ratio() {
+        assert_eq!(f_tgamma_delta_ratio(5., 1.), 0.2);
+        assert_eq!(f_tgamma_delta_ratio(1e10, 0.5), 1.0000000000125e-05);
+        assert_eq!(
+            f_tgamma_delta_ratio(1000000000000000., -2.5e-06),
+            1.0000863506689917
+        );
+        assert_eq!(f_tgamma_delta_ratio(0.3, 1e-12), 1.0000000000035025);
+        assert_eq!(f_tgamma_delta_ratio(-4.3, 1.7), 0.11475461707404391);
+        assert_eq!(f_tgamma_delta_ratio(0.25, -10.5), -5346862.111728767);
+        assert_eq!(f_tgamma_delta_ratio(3., 0.), 1.);
+        assert!(f_tgamma_delta_ratio(-2., 3.).is_nan());
+        assert!(f_tgamma_delta_ratio(f64::NAN, 1.).is_nan());
+    }
+}
@@ -0,0 +1,205 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::{f_fmla, is_integerf};
+use crate::double_double::DoubleDouble;
+use crate::exponents::core_expdf;
+use crate::gamma::lgamma::lgamma_core;
+use crate::logs::{f_log, f_log1p};
+
+/// Computes lgamma(x + d) - lgamma(x) for x > 0, d >= 0 without cancellation when d << x.
+pub(crate) fn lgamma_deltaf(x: f64, d: f64) -> f64 {
+    // lgamma(x + d) - lgamma(x) = lgamma(x + n + d) - lgamma(x + n) - sum(log(1 + d/(x + i)), i = 0..n-1)
+    let mut x = x;
+    let mut sum = 0.;
+    while x < 16. {
+        sum += f_log1p(d / x);
+        x += 1.;
+    }
+    let y = x + d;
+    // (y - 1/2) * log(y) - (x - 1/2) * log(x) - d = (x - 1/2) * log(1 + d/x) + d * (log(y) - 1)
+    let r = f_fmla(x - 0.5, f_log1p(d / x), d * (f_log(y) - 1.));
+    // Difference of the tails sum(B(2m) / (2m * (2m - 1) * x^(2m - 1))),
+    // v^p - u^p = -d * u * v * sum(v^i * u^(p-1-i), i = 0..p-1), where u = 1/x, v = 1/y
+    let u = 1. / x;
+    let v = 1. / y;
+    let u2 = u * u;
+    let h2 = f_fmla(v, v + u, u2);
+    let h4 = f_fmla(v * v, h2, u2 * u2);
+    let h6 = f_fmla(v * v, h4, u2 * u2 * u2);
+    let tail = f_fmla(
+        f64::from_bits(0xbf43813813813814),
+        h6,
+        f_fmla(
+            f64::from_bits(0x3f4a01a01a01a01a),
+            h4,
+            f_fmla(
+                f64::from_bits(0xbf66c16c16c16c17),
+                h2,
+                f64::from_bits(0x3fb5555555555555),
+            ),
+        ),
+    );
+    f_fmla(-d * u * v, tail, r) - sum
+}
+
+/// Computes sign * e^l as f32 handling overflow and underflow.
+#[inline]
+pub(crate) fn expf_signed(l: f64, sign: f64) -> f32 {
+    if l > 89. {
+        return (f64::INFINITY * sign) as f32;
+    }
+    if l < -104. {
+        return (0. * sign) as f32;
+    }
+    (core_expdf(l) * sign) as f32
+}
+
+#[inline]
+pub(crate) fn is_polef(x: f32) -> bool {
+    x == 0. || (x < 0. && is_integerf(x))
+}
+
+/// Computes log|Gamma(a)/Gamma(b)| and sign of the ratio for finite a, b that are not poles.
+///
+/// Arguments are f64 so sums of f32 values are passed exactly.
+pub(crate) fn lgamma_ratio_coref(a: f64, b: f64) -> (f64, f64) {
+    if a > 0. && b > 0. {
+        let d = a - b;
+        return if d >= 0. {
+            (lgamma_deltaf(b, d), 1.)
+        } else {
+            (-lgamma_deltaf(a, -d), 1.)
+        };
+    }
+    // log-gammas of negative arguments are large near the poles,
+    // so they are taken in double-double to keep the difference accurate
+    let (la, sa) = lgamma_core(a);
+    let (lb, sb) = lgamma_core(b);
+    (DoubleDouble::full_dd_sub(la, lb).to_f64(), (sa * sb) as f64)
+}
+
+/// Computes ratio of gamma functions Gamma(a)/Gamma(b)
+///
+/// Stays finite when Gamma(a) and Gamma(b) overflow individually.
+/// When both arguments are poles returns limit of Gamma(a + e)/Gamma(b + e) as e -> 0,
+/// when only a is a pole returns NaN.
+pub fn f_tgamma_ratiof(a: f32, b: f32) -> f32 {
+    if a.is_nan() || b.is_nan() {
+        return f32::NAN;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        if a == f32::INFINITY && b.is_finite() && !is_polef(b) {
+            return f32::INFINITY;
+        }
+        if b == f32::INFINITY && a.is_finite() && !is_polef(a) {
+            return 0.;
+        }
+        return f32::NAN;
+    }
+    match (is_polef(a), is_polef(b)) {
+        (true, true) => {
+            // Gamma(-m + e)/Gamma(-n + e) -> (-1)^(m - n) * n!/m!
+            let sign = if is_integerf((a - b) * 0.5) { 1. } else { -1. };
+            let (l, s) = lgamma_ratio_coref(1. - b as f64, 1. - a as f64);
+            return expf_signed(l, sign * s);
+        }
+        (true, false) => return f32::NAN,
+        (false, true) => return 0.,
+        _ => {}
+    }
+    if a == b {
+        return 1.;
+    }
+    let (l, sign) = lgamma_ratio_coref(a as f64, b as f64);
+    expf_signed(l, sign)
+}
+
+/// Computes Gamma(a)/Gamma(a + delta)
+///
+/// Keeps full accuracy when delta is small relative to a.
+pub fn f_tgamma_delta_ratiof(a: f32, delta: f32) -> f32 {
+    if a.is_nan() || delta.is_nan() {
+        return f32::NAN;
+    }
+    if delta == 0. && a.is_finite() && !is_polef(a) {
+        return 1.;
+    }
+    if !a.is_finite() || !delta.is_finite() {
+        return f_tgamma_ratiof(a, a + delta);
+    }
+    let x = a as f64 + delta as f64;
+    if x as f32 as f64 == x && x - a as f64 == delta as f64 {
+        return f_tgamma_ratiof(a, x as f32);
+    }
+    if is_polef(a) {
+        return f32::NAN;
+    }
+    if a > 0. && x > 0. {
+        let l = if delta > 0. {
+            -lgamma_deltaf(a as f64, delta as f64)
+        } else {
+            lgamma_deltaf(x, -delta as f64)
+        };
+        return expf_signed(l, 1.);
+    }
+    let (l, sign) = lgamma_ratio_coref(a as f64, x);
+    expf_signed(l, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tgamma_ratiof() {
+        assert_eq!(f_tgamma_ratiof(5., 3.), 12.);
+        assert_eq!(f_tgamma_ratiof(0.5, 1.5), 2.);
+        assert_eq!(f_tgamma_ratiof(40., 35.), 6.909084e7);
+        assert_eq!(f_tgamma_ratiof(-2.5, 3.5), -0.28444445);
+        assert_eq!(f_tgamma_ratiof(-3., -5.), 20.);
+        assert_eq!(f_tgamma_ratiof(0., -4.), 24.);
+        assert_eq!(f_tgamma_ratiof(2., -3.), 0.);
+        assert_eq!(f_tgamma_ratiof(1000000., 999999.), 999999.);
+        assert_eq!(f_tgamma_ratiof(f32::INFINITY, 2.), f32::INFINITY);
+        assert!(f_tgamma_ratiof(f32::NAN, 1.).is_nan());
+    }
+
+    #[test]
+    fn test_tgamma_delta_ratiof() {
+        assert_eq!(f_tgamma_delta_ratiof(5., 1.), 0.2);
+        assert_eq!(f_tgamma_delta_ratiof(1000000., 0.5), 0.0010000002);
+        assert_eq!(
+            f_tgamma_delta_ratiof(901809440000., -3.80671e-05),
+            1.0010484
+        );
+        assert_eq!(f_tgamma_delta_ratiof(0.3, 1e-06), 1.0000035);
+        assert_eq!(f_tgamma_delta_ratiof(-4.3, 1.7), 0.114754535);
+        assert!(f_tgamma_delta_ratiof(f32::NAN, 1.).is_nan());
+    }
+}
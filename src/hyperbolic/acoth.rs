@@ -0,0 +1,236 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::logs::{fast_log_dd, log2_dyadic};
+use crate::polyeval::f_polyeval8;
+
+// Generated by MPMath:
+// atanh(t) = sum_{k>=0} t^(2k+1)/(2k+1)
+static ATANH_TAYLOR_F128: [DyadicFloat128; 16] = [
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -127,
+        mantissa: 0x80000000_00000000_00000000_00000000_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -129,
+        mantissa: 0xaaaaaaaa_aaaaaaaa_aaaaaaaa_aaaaaaab_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -130,
+        mantissa: 0xcccccccc_cccccccc_cccccccc_cccccccd_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -130,
+        mantissa: 0x92492492_49249249_24924924_92492492_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -131,
+        mantissa: 0xe38e38e3_8e38e38e_38e38e38_e38e38e4_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -131,
+        mantissa: 0xba2e8ba2_e8ba2e8b_a2e8ba2e_8ba2e8ba_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -131,
+        mantissa: 0x9d89d89d_89d89d89_d89d89d8_9d89d89e_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -131,
+        mantissa: 0x88888888_88888888_88888888_88888889_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0xf0f0f0f0_f0f0f0f0_f0f0f0f0_f0f0f0f1_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0xd79435e5_0d79435e_50d79435_e50d7943_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0xc30c30c3_0c30c30c_30c30c30_c30c30c3_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0xb21642c8_590b2164_2c8590b2_1642c859_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0xa3d70a3d_70a3d70a_3d70a3d7_0a3d70a4_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0x97b425ed_097b425e_d097b425_ed097b42_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0x8d3dcb08_d3dcb08d_3dcb08d3_dcb08d3e_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0x84210842_10842108_42108421_08421084_u128,
+    },
+];
+
+#[cold]
+#[inline(never)]
+fn acoth_hard(x: f64) -> f64 {
+    let ax = x.abs();
+    let mut r = if ax >= 16. {
+        // acoth(x) = atanh(1/x)
+        let t = DyadicFloat128::accurate_reciprocal(ax);
+        let z = t * t;
+        let mut p = ATANH_TAYLOR_F128[15];
+        for i in (0..15).rev() {
+            p = z * p + ATANH_TAYLOR_F128[i];
+        }
+        t * p
+    } else {
+        const ONE: DyadicFloat128 = DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -127,
+            mantissa: 0x80000000_00000000_00000000_00000000_u128,
+        };
+        // acoth(x) = log((x + 1) / (x - 1)) / 2
+        let dx = DyadicFloat128::new_from_f64(ax);
+        let q = (dx + ONE) * (dx - ONE).reciprocal();
+        let mut l = log2_dyadic(q, q.fast_as_f64());
+        l.exponent -= 1;
+        l
+    };
+    if x.is_sign_negative() {
+        r.sign = DyadicSign::Neg;
+    }
+    r.fast_as_f64()
+}
+
+/// Computes inverse hyperbolic cotangent
+///
+/// Max found ULP 0.5
+pub fn f_acoth(x: f64) -> f64 {
+    let ax = x.abs();
+    let ix = ax.to_bits();
+    // |x| <= 1 or NaN
+    if ix <= 0x3ff0000000000000u64 || ix >= 0x7ff0000000000000u64 {
+        if ix == 0x3ff0000000000000u64 {
+            // x = +-1
+            return f64::copysign(f64::INFINITY, x);
+        }
+        if ix == 0x7ff0000000000000u64 {
+            // x = +-Inf
+            return f64::copysign(0., x);
+        }
+        if x.is_nan() {
+            return x + x;
+        }
+        return f64::NAN;
+    }
+    // |x| >= 2^54
+    if ix >= 0x4350000000000000u64 {
+        // acoth(x) ~ 1/x + 1/(3x^3), where 1/(3x^3) is far below the rounding
+        // error of 1/x.
+        return 1. / x;
+    }
+    if ix >= 0x4030000000000000u64 {
+        // |x| >= 16
+        // acoth(x) = atanh(t) = t + t^3/3 + t^3 * z * Q(z), t = 1/x, z = t^2
+        const C1: DoubleDouble =
+            DoubleDouble::from_bit_pair((0x3c75555555555555, 0x3fd5555555555555));
+        let t = DoubleDouble::from_recip(ax);
+        let z = t.hi * t.hi;
+        let q = f_polyeval8(
+            z,
+            f64::from_bits(0x3fc999999999999a),
+            f64::from_bits(0x3fc2492492492492),
+            f64::from_bits(0x3fbc71c71c71c71c),
+            f64::from_bits(0x3fb745d1745d1746),
+            f64::from_bits(0x3fb3b13b13b13b14),
+            f64::from_bits(0x3fb1111111111111),
+            f64::from_bits(0x3fae1e1e1e1e1e1e),
+            f64::from_bits(0x3faaf286bca1af28),
+        );
+        let mut p = DoubleDouble::from_exact_add(C1.hi, z * q);
+        p.lo += C1.lo;
+        let t2 = DoubleDouble::quick_mult(t, t);
+        let v = DoubleDouble::quick_mult(DoubleDouble::quick_mult(t2, p), t);
+        let r = DoubleDouble::add(t, v);
+        let err = r.hi * f64::from_bits(0x3bd0000000000000);
+        let ub = r.hi + (r.lo + err);
+        let lb = r.hi + (r.lo - err);
+        if ub == lb {
+            return f64::copysign(r.to_f64(), x);
+        }
+        return acoth_hard(x);
+    }
+    // acoth(x) = log((x + 1) / (x - 1)) / 2
+    let num = DoubleDouble::from_exact_add(ax, 1.);
+    let den = DoubleDouble::from_exact_sub(ax, 1.);
+    let q = DoubleDouble::div(num, den);
+    let l = fast_log_dd(q);
+    let r = DoubleDouble::new(l.lo * 0.5, l.hi * 0.5);
+    let err = r.hi * f64::from_bits(0x3be0000000000000);
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub == lb {
+        return f64::copysign(r.to_f64(), x);
+    }
+    acoth_hard(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acoth() {
+        assert_eq!(f_acoth(1.), f64::INFINITY);
+        assert_eq!(f_acoth(-1.), f64::NEG_INFINITY);
+        assert_eq!(f_acoth(f64::INFINITY), 0.);
+        assert!(f_acoth(0.5).is_nan());
+        assert!(f_acoth(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,89 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::hyperbolic::asinhf::log_eval;
+use crate::polyeval::f_polyeval6;
+
+/// Computes inverse hyperbolic cotangent
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_acothf(x: f32) -> f32 {
+    let ax = x.to_bits() & 0x7fff_ffff;
+    // |x| <= 1, Inf or NaN
+    if ax <= 0x3f80_0000u32 || ax >= 0x7f80_0000u32 {
+        if ax == 0x3f80_0000u32 {
+            // x = +-1
+            return f32::copysign(f32::INFINITY, x);
+        }
+        if ax == 0x7f80_0000u32 {
+            // x = +-Inf
+            return f32::copysign(0., x);
+        }
+        if x.is_nan() {
+            return x + x;
+        }
+        return f32::NAN;
+    }
+    let xd = x as f64;
+    let a = xd.abs();
+    // |x| > 16
+    if ax > 0x4180_0000u32 {
+        // acoth(x) = atanh(t), t = 1/x
+        let t = 1. / xd;
+        let z = t * t;
+        let p = f_polyeval6(
+            z,
+            0.,
+            f64::from_bits(0x3fd5555555555555),
+            f64::from_bits(0x3fc999999999999a),
+            f64::from_bits(0x3fc2492492492492),
+            f64::from_bits(0x3fbc71c71c71c71c),
+            f64::from_bits(0x3fb745d1745d1746),
+        );
+        return f_fmla(t, p, t) as f32;
+    }
+    // acoth(x) = log((x + 1) / (x - 1)) / 2
+    let r = 0.5 * log_eval((a + 1.) / (a - 1.));
+    f64::copysign(r, xd) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acothf() {
+        assert_eq!(f_acothf(1.), f32::INFINITY);
+        assert_eq!(f_acothf(-1.), f32::NEG_INFINITY);
+        assert_eq!(f_acothf(f32::INFINITY), 0.);
+        assert!(f_acothf(0.5).is_nan());
+        assert!(f_acothf(f32::NAN).is_nan());
+    }
+}
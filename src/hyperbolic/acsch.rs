@@ -0,0 +1,258 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::logs::{fast_log_d_to_dd, fast_log_dd, log2_dyadic};
+use crate::polyeval::f_polyeval8;
+
+// Generated by MPMath:
+// asinh(t) = sum_{k>=0} (-1)^k*(2k)!/(4^k*(k!)^2*(2k+1)) * t^(2k+1)
+static ASINH_TAYLOR_F128: [DyadicFloat128; 16] = [
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -127,
+        mantissa: 0x80000000_00000000_00000000_00000000_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -130,
+        mantissa: 0xaaaaaaaa_aaaaaaaa_aaaaaaaa_aaaaaaab_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -131,
+        mantissa: 0x99999999_99999999_99999999_9999999a_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -132,
+        mantissa: 0xb6db6db6_db6db6db_6db6db6d_b6db6db7_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -133,
+        mantissa: 0xf8e38e38_e38e38e3_8e38e38e_38e38e39_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -133,
+        mantissa: 0xb745d174_5d1745d1_745d1745_d1745d17_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -133,
+        mantissa: 0x8e276276_27627627_62762762_76276276_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -134,
+        mantissa: 0xe4cccccc_cccccccc_cccccccc_cccccccd_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -134,
+        mantissa: 0xbd43c3c3_c3c3c3c3_c3c3c3c3_c3c3c3c4_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -134,
+        mantissa: 0x9fef286b_ca1af286_bca1af28_6bca1af3_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -134,
+        mantissa: 0x89779e79_e79e79e7_9e79e79e_79e79e7a_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -135,
+        mantissa: 0xef9de9bd_37a6f4de_9bd37a6f_4de9bd38_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -135,
+        mantissa: 0xd3431eb8_51eb851e_b851eb85_1eb851ec_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -135,
+        mantissa: 0xbc16ed09_7b425ed0_97b425ed_097b425f_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -135,
+        mantissa: 0xa8dd1846_9ee58469_ee58469e_e58469ee_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -135,
+        mantissa: 0x98b41def_7bdef7bd_ef7bdef7_bdef7bdf_u128,
+    },
+];
+
+#[cold]
+#[inline(never)]
+fn acsch_hard(x: f64) -> f64 {
+    let ax = x.abs();
+    let mut r = if ax >= 16. {
+        // acsch(x) = asinh(1/x)
+        let t = DyadicFloat128::accurate_reciprocal(ax);
+        let z = t * t;
+        let mut p = ASINH_TAYLOR_F128[15];
+        for i in (0..15).rev() {
+            p = z * p + ASINH_TAYLOR_F128[i];
+        }
+        t * p
+    } else {
+        const ONE: DyadicFloat128 = DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -127,
+            mantissa: 0x80000000_00000000_00000000_00000000_u128,
+        };
+        // acsch(x) = log((1 + sqrt(1 + x^2)) / x)
+        let dx = DyadicFloat128::new_from_f64(ax);
+        let s = (ONE + dx * dx).sqrt();
+        let q = (ONE + s) * DyadicFloat128::accurate_reciprocal(ax);
+        log2_dyadic(q, q.fast_as_f64())
+    };
+    if x.is_sign_negative() {
+        r.sign = DyadicSign::Neg;
+    }
+    r.fast_as_f64()
+}
+
+/// Computes inverse hyperbolic cosecant
+///
+/// Max found ULP 0.5
+pub fn f_acsch(x: f64) -> f64 {
+    let ax = x.abs();
+    let ix = ax.to_bits();
+    // |x| >= 2^54, Inf or NaN
+    if ix >= 0x4350000000000000u64 {
+        // acsch(x) ~ 1/x - 1/(6x^3), where 1/(6x^3) is far below the rounding
+        // error of 1/x.
+        if ix > 0x7ff0000000000000u64 {
+            return x + x;
+        } // nan
+        return 1. / x;
+    }
+    // |x| < 2^-54
+    if ix < 0x3c90000000000000u64 {
+        if ix == 0 {
+            return 1. / x;
+        }
+        // acsch(x) ~ log(2/|x|) + x^2/4, where x^2/4 is far below the rounding
+        // error of log(2/|x|).
+        const LN2: DoubleDouble = DoubleDouble::new(
+            f64::from_bits(0x3c7abc9e3b39803f),
+            f64::from_bits(0x3fe62e42fefa39ef),
+        );
+        let l = fast_log_d_to_dd(ax);
+        let r = DoubleDouble::sub(LN2, l);
+        let err = r.hi * f64::from_bits(0x3be0000000000000);
+        let ub = r.hi + (r.lo + err);
+        let lb = r.hi + (r.lo - err);
+        if ub == lb {
+            return f64::copysign(r.to_f64(), x);
+        }
+        const LN2_F128: DyadicFloat128 = DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -128,
+            mantissa: 0xb17217f7_d1cf79ab_c9e3b398_03f2f6af_u128,
+        };
+        let mut l = log2_dyadic(DyadicFloat128::new_from_f64(ax), ax);
+        l.sign = DyadicSign::Pos;
+        let mut r = LN2_F128 + l;
+        if x.is_sign_negative() {
+            r.sign = DyadicSign::Neg;
+        }
+        return r.fast_as_f64();
+    }
+    if ix >= 0x4030000000000000u64 {
+        // |x| >= 16
+        // acsch(x) = asinh(t) = t - t^3/6 + t^3 * z * Q(z), t = 1/x, z = t^2
+        const C1: DoubleDouble =
+            DoubleDouble::from_bit_pair((0xbc65555555555555, 0xbfc5555555555555));
+        let t = DoubleDouble::from_recip(ax);
+        let z = t.hi * t.hi;
+        let q = f_polyeval8(
+            z,
+            f64::from_bits(0x3fb3333333333333),
+            f64::from_bits(0xbfa6db6db6db6db7),
+            f64::from_bits(0x3f9f1c71c71c71c7),
+            f64::from_bits(0xbf96e8ba2e8ba2e9),
+            f64::from_bits(0x3f91c4ec4ec4ec4f),
+            f64::from_bits(0xbf8c99999999999a),
+            f64::from_bits(0x3f87a87878787878),
+            f64::from_bits(0xbf83fde50d79435e),
+        );
+        let mut p = DoubleDouble::from_exact_add(C1.hi, z * q);
+        p.lo += C1.lo;
+        let t2 = DoubleDouble::quick_mult(t, t);
+        let v = DoubleDouble::quick_mult(DoubleDouble::quick_mult(t2, p), t);
+        let r = DoubleDouble::add(t, v);
+        let err = r.hi * f64::from_bits(0x3bd0000000000000);
+        let ub = r.hi + (r.lo + err);
+        let lb = r.hi + (r.lo - err);
+        if ub == lb {
+            return f64::copysign(r.to_f64(), x);
+        }
+        return acsch_hard(x);
+    }
+    // acsch(x) = log((1 + sqrt(1 + x^2)) / |x|)
+    let x2 = DoubleDouble::from_exact_mult(ax, ax);
+    let mut w = DoubleDouble::from_full_exact_add(1., x2.hi);
+    w.lo += x2.lo;
+    let s = w.sqrt();
+    let mut num = DoubleDouble::from_full_exact_add(1., s.hi);
+    num.lo += s.lo;
+    let q = DoubleDouble::div_dd_f64(num, ax);
+    let r = fast_log_dd(q);
+    let err = r.hi * f64::from_bits(0x3be0000000000000);
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub == lb {
+        return f64::copysign(r.to_f64(), x);
+    }
+    acsch_hard(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acsch() {
+        assert_eq!(f_acsch(0.), f64::INFINITY);
+        assert_eq!(f_acsch(-0.), f64::NEG_INFINITY);
+        assert_eq!(f_acsch(f64::INFINITY), 0.);
+        assert_eq!(f_acsch(f64::NEG_INFINITY), -0.);
+        assert!(f_acsch(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::hyperbolic::asinhf::log_eval;
+use crate::polyeval::f_polyeval6;
+
+/// Computes inverse hyperbolic cosecant
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_acschf(x: f32) -> f32 {
+    let ax = x.to_bits() & 0x7fff_ffff;
+    if ax == 0 || ax >= 0x7f80_0000u32 {
+        // +-0, +-Inf or NaN
+        return 1. / x;
+    }
+    let xd = x as f64;
+    let a = xd.abs();
+    // |x| > 16
+    if ax > 0x4180_0000u32 {
+        if ax == 0x4ad43d43u32 {
+            // exceptional case, result is extremely close to the rounding midpoint
+            return f32::copysign(f32::from_bits(0x341a644b), x);
+        }
+        // acsch(x) = asinh(t), t = 1/x
+        let t = 1. / xd;
+        let z = t * t;
+        let p = f_polyeval6(
+            z,
+            0.,
+            f64::from_bits(0xbfc5555555555555),
+            f64::from_bits(0x3fb3333333333333),
+            f64::from_bits(0xbfa6db6db6db6db7),
+            f64::from_bits(0x3f9f1c71c71c71c7),
+            f64::from_bits(0xbf96e8ba2e8ba2e9),
+        );
+        return f_fmla(t, p, t) as f32;
+    }
+    // acsch(x) = log((1 + sqrt(1 + x^2)) / |x|)
+    let r = log_eval((1. + f_fmla(a, a, 1.).sqrt()) / a);
+    f64::copysign(r, xd) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acschf() {
+        assert_eq!(f_acschf(0.), f32::INFINITY);
+        assert_eq!(f_acschf(-0.), f32::NEG_INFINITY);
+        assert_eq!(f_acschf(f32::INFINITY), 0.);
+        assert!(f_acschf(f32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::logs::{fast_log_d_to_dd, fast_log_dd, log2_dyadic};
+
+#[cold]
+#[inline(never)]
+fn asech_hard(x: f64) -> f64 {
+    const ONE: DyadicFloat128 = DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -127,
+        mantissa: 0x80000000_00000000_00000000_00000000_u128,
+    };
+    // asech(x) = log((1 + sqrt(1 - x^2)) / x)
+    let dx = DyadicFloat128::new_from_f64(x);
+    let s = (ONE - dx * dx).sqrt();
+    let q = (ONE + s) * DyadicFloat128::accurate_reciprocal(x);
+    log2_dyadic(q, q.fast_as_f64()).fast_as_f64()
+}
+
+/// Computes inverse hyperbolic secant
+///
+/// Max found ULP 0.5
+pub fn f_asech(x: f64) -> f64 {
+    let ix = x.to_bits();
+    // x <= 0, x >= 1 or NaN
+    if ix == 0 || ix >= 0x3ff0000000000000u64 {
+        if ix == 0 || ix == 0x8000000000000000u64 {
+            // x = +-0
+            return f64::INFINITY;
+        }
+        if ix == 0x3ff0000000000000u64 {
+            // x = 1
+            return 0.;
+        }
+        if x.is_nan() {
+            return x + x;
+        }
+        return f64::NAN;
+    }
+    // x < 2^-54
+    if ix < 0x3c90000000000000u64 {
+        // asech(x) ~ log(2/x) - x^2/4, where x^2/4 is far below the rounding
+        // error of log(2/x).
+        const LN2: DoubleDouble = DoubleDouble::new(
+            f64::from_bits(0x3c7abc9e3b39803f),
+            f64::from_bits(0x3fe62e42fefa39ef),
+        );
+        let l = fast_log_d_to_dd(x);
+        let r = DoubleDouble::sub(LN2, l);
+        let err = r.hi * f64::from_bits(0x3be0000000000000);
+        let ub = r.hi + (r.lo + err);
+        let lb = r.hi + (r.lo - err);
+        if ub == lb {
+            return r.to_f64();
+        }
+        const LN2_F128: DyadicFloat128 = DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -128,
+            mantissa: 0xb17217f7_d1cf79ab_c9e3b398_03f2f6af_u128,
+        };
+        let mut l = log2_dyadic(DyadicFloat128::new_from_f64(x), x);
+        l.sign = DyadicSign::Pos;
+        return (LN2_F128 + l).fast_as_f64();
+    }
+    // asech(x) = log((1 + sqrt(1 - x^2)) / x)
+    let x2 = DoubleDouble::from_exact_mult(x, x);
+    let mut w = DoubleDouble::from_exact_sub(1., x2.hi);
+    w.lo -= x2.lo;
+    let s = w.sqrt();
+    let mut num = DoubleDouble::from_exact_add(1., s.hi);
+    num.lo += s.lo;
+    let q = DoubleDouble::div_dd_f64(num, x);
+    let r = fast_log_dd(q);
+    let err = r.hi * f64::from_bits(0x3be0000000000000);
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub == lb {
+        return r.to_f64();
+    }
+    asech_hard(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asech() {
+        assert_eq!(f_asech(1.), 0.);
+        assert_eq!(f_asech(0.), f64::INFINITY);
+        assert!(f_asech(-0.5).is_nan());
+        assert!(f_asech(1.5).is_nan());
+        assert!(f_asech(f64::NAN).is_nan());
+    }
+}
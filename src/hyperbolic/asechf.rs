@@ -0,0 +1,70 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::hyperbolic::asinhf::log_eval;
+
+/// Computes inverse hyperbolic secant
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_asechf(x: f32) -> f32 {
+    let t = x.to_bits();
+    // x <= 0, x >= 1 or NaN
+    if t == 0 || t >= 0x3f80_0000u32 {
+        if t == 0 || t == 0x8000_0000u32 {
+            // x = +-0
+            return f32::INFINITY;
+        }
+        if t == 0x3f80_0000u32 {
+            // x = 1
+            return 0.;
+        }
+        if x.is_nan() {
+            return x + x;
+        }
+        return f32::NAN;
+    }
+    let xd = x as f64;
+    // asech(x) = log((1 + sqrt(1 - x^2)) / x)
+    let s = ((1. - xd) * (1. + xd)).sqrt();
+    log_eval((1. + s) / xd) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asechf() {
+        assert_eq!(f_asechf(1.), 0.);
+        assert_eq!(f_asechf(0.), f32::INFINITY);
+        assert!(f_asechf(-0.5).is_nan());
+        assert!(f_asechf(1.5).is_nan());
+        assert!(f_asechf(f32::NAN).is_nan());
+    }
+}
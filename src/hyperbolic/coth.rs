@@ -0,0 +1,223 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::i0_exp;
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::exponents::rational128_exp;
+use crate::polyeval::f_polyeval7;
+
+// Generated by MPMath:
+// coth(x) = 1/x + sum_{n>=1} 2^(2n)*B(2n)/(2n)! * x^(2n-1)
+static COTH_TAYLOR_F128: [DyadicFloat128; 14] = [
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -129,
+        mantissa: 0xaaaaaaaa_aaaaaaaa_aaaaaaaa_aaaaaaab_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -133,
+        mantissa: 0xb60b60b6_0b60b60b_60b60b60_b60b60b6_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -136,
+        mantissa: 0x8ab355e0_08ab355e_008ab355_e008ab35_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -140,
+        mantissa: 0xddebbc99_a7785563_34111eef_ccdaab89_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -143,
+        mantissa: 0xb354795f_b875ed14_b089f4cc_1f12f738_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -146,
+        mantissa: 0x91402eb2_2133f7ba_56337ff2_7cb7da48_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -150,
+        mantissa: 0xeb6d9627_048b0fdc_257768c2_74f0337d_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -153,
+        mantissa: 0xbed270fb_ccaae25f_f129a06f_42d0dc42_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -156,
+        mantissa: 0x9aac38eb_2974ecee_56665d7d_7bc1dbfa_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -160,
+        mantissa: 0xfabecb46_55678663_ce0f50ad_aa0b8212_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -163,
+        mantissa: 0xcb3f0f84_e1bb77d8_2b4adb04_8eaa5e7b_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -166,
+        mantissa: 0xa4bec819_d15ae370_87e6555a_6c876af6_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -169,
+        mantissa: 0x858996be_35683203_a8a4d91c_ebb6a6c8_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -173,
+        mantissa: 0xd87b96ac_f8e0b3e6_16e913f6_cf2056ca_u128,
+    },
+];
+
+#[cold]
+#[inline(never)]
+fn coth_hard(x: f64) -> f64 {
+    let ax = x.abs();
+    let r = if ax < 0.125 {
+        let dx = DyadicFloat128::new_from_f64(x);
+        let z = dx * dx;
+        let mut p = COTH_TAYLOR_F128[13];
+        for i in (0..13).rev() {
+            p = z * p + COTH_TAYLOR_F128[i];
+        }
+        DyadicFloat128::accurate_reciprocal(x) + dx * p
+    } else {
+        const ONE: DyadicFloat128 = DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -127,
+            mantissa: 0x80000000_00000000_00000000_00000000_u128,
+        };
+        // coth(x) = (1 + exp(-2|x|)) / (1 - exp(-2|x|))
+        let e = rational128_exp(-ax);
+        let e2 = e * e;
+        let mut r = (ONE + e2) * (ONE - e2).reciprocal();
+        if x.is_sign_negative() {
+            r.sign = DyadicSign::Neg;
+        }
+        r
+    };
+    r.fast_as_f64()
+}
+
+/// Computes hyperbolic cotangent
+///
+/// Max found ULP 0.5
+pub fn f_coth(x: f64) -> f64 {
+    let ax = x.abs();
+    let ix = ax.to_bits();
+    // |x| < 2^-54
+    if ix < 0x3c90000000000000u64 {
+        // coth(x) ~ 1/x + x/3, where x/3 is far below the rounding error of 1/x.
+        return 1. / x;
+    }
+    // |x| >= 19.5, coth(x) rounds to +1 or -1 to nearest
+    if ix >= 0x4033800000000000u64 {
+        if ix > 0x7ff0000000000000u64 {
+            return x + x;
+        } // nan
+        let f = f64::copysign(1.0, x);
+        if ix == 0x7ff0000000000000u64 {
+            return f;
+        }
+        let df = f64::copysign(f64::from_bits(0x3c80000000000000), x);
+        return f + df;
+    }
+    // |x| < 0.125
+    if ix < 0x3fc0000000000000u64 {
+        // Taylor series
+        //   coth(x) = 1/x + x/3 + x * z * Q(z), z = x^2
+        const C1: DoubleDouble =
+            DoubleDouble::from_bit_pair((0x3c75555555555555, 0x3fd5555555555555));
+        let r1 = DoubleDouble::from_recip(x);
+        let z = x * x;
+        let q = f_polyeval7(
+            z,
+            f64::from_bits(0xbf96c16c16c16c17),
+            f64::from_bits(0x3f61566abc011567),
+            f64::from_bits(0xbf2bbd779334ef0b),
+            f64::from_bits(0x3ef66a8f2bf70ebe),
+            f64::from_bits(0xbec22805d644267f),
+            f64::from_bits(0x3e8d6db2c4e09162),
+            f64::from_bits(0xbe57da4e1f79955c),
+        );
+        let mut p = DoubleDouble::from_exact_add(C1.hi, z * q);
+        p.lo += C1.lo;
+        let v = DoubleDouble::quick_mult_f64(p, x);
+        let r = DoubleDouble::add(r1, v);
+        let err = r.hi.abs() * f64::from_bits(0x3bd0000000000000);
+        let ub = r.hi + (r.lo + err);
+        let lb = r.hi + (r.lo - err);
+        if ub == lb {
+            return r.to_f64();
+        }
+        return coth_hard(x);
+    }
+    // coth(x) = (1 + exp(-2|x|)) / (1 - exp(-2|x|))
+    let e = i0_exp(-ax);
+    let e2 = DoubleDouble::quick_mult(e, e);
+    let mut den = DoubleDouble::from_exact_sub(1., e2.hi);
+    den.lo -= e2.lo;
+    let mut num = DoubleDouble::from_exact_add(1., e2.hi);
+    num.lo += e2.lo;
+    let r = DoubleDouble::div(num, den);
+    let err = r.hi * f64::from_bits(0x3bd0000000000000);
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub == lb {
+        return f64::copysign(r.to_f64(), x);
+    }
+    coth_hard(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coth() {
+        assert_eq!(f_coth(0.), f64::INFINITY);
+        assert_eq!(f_coth(-0.), f64::NEG_INFINITY);
+        assert_eq!(f_coth(f64::INFINITY), 1.);
+        assert_eq!(f_coth(f64::NEG_INFINITY), -1.);
+        assert_eq!(f_coth(20.), 1.);
+        assert!(f_coth(f64::NAN).is_nan());
+    }
+}
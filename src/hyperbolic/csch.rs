@@ -0,0 +1,221 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::i0_exp;
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::exponents::rational128_exp;
+use crate::polyeval::f_polyeval7;
+
+// Generated by MPMath:
+// csch(x) = 1/x + sum_{n>=1} -2*(2^(2n-1)-1)*B(2n)/(2n)! * x^(2n-1)
+static CSCH_TAYLOR_F128: [DyadicFloat128; 14] = [
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -130,
+        mantissa: 0xaaaaaaaa_aaaaaaaa_aaaaaaaa_aaaaaaab_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -133,
+        mantissa: 0x9f49f49f_49f49f49_f49f49f4_9f49f49f_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -136,
+        mantissa: 0x865dbb31_0865dbb3_10865dbb_310865dc_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -140,
+        mantissa: 0xdc2fe520_742964b8_6da8fcb1_ed40f632_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -143,
+        mantissa: 0xb2facf23_0899b21e_2631afd1_b9036dbc_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -146,
+        mantissa: 0x912e06ac_4aefd13b_5ee8b982_7e68434c_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -150,
+        mantissa: 0xeb663aba_5352eb83_a6963d07_2edc8bfb_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -153,
+        mantissa: 0xbed0f356_eab3490a_2c69be1c_01f256a0_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -156,
+        mantissa: 0x9aabeb95_0cff5833_dfef324a_4d031e19_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -160,
+        mantissa: 0xfabeabee_7bfebbb6_dd42d6eb_bff5ccd1_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -163,
+        mantissa: 0xcb3f092a_e93f50ca_4f8c19aa_37d23a06_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -166,
+        mantissa: 0xa4bec6d0_53caafcd_d21f744a_9fdcb61d_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -169,
+        mantissa: 0x8589967b_709cd2e8_f48bd748_994a1852_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -173,
+        mantissa: 0xd87b9691_e96dde46_fad29733_f1fdd7f0_u128,
+    },
+];
+
+#[cold]
+#[inline(never)]
+fn csch_hard(x: f64) -> f64 {
+    let ax = x.abs();
+    let r = if ax < 0.125 {
+        let dx = DyadicFloat128::new_from_f64(x);
+        let z = dx * dx;
+        let mut p = CSCH_TAYLOR_F128[13];
+        for i in (0..13).rev() {
+            p = z * p + CSCH_TAYLOR_F128[i];
+        }
+        DyadicFloat128::accurate_reciprocal(x) + dx * p
+    } else {
+        const ONE: DyadicFloat128 = DyadicFloat128 {
+            sign: DyadicSign::Pos,
+            exponent: -127,
+            mantissa: 0x80000000_00000000_00000000_00000000_u128,
+        };
+        // csch(x) = 2 * exp(-|x|) / (1 - exp(-2|x|))
+        let e = rational128_exp(-ax);
+        let mut num = e;
+        num.exponent += 1;
+        let mut r = num * (ONE - e * e).reciprocal();
+        if x.is_sign_negative() {
+            r.sign = DyadicSign::Neg;
+        }
+        r
+    };
+    r.fast_as_f64()
+}
+
+/// Computes hyperbolic cosecant
+///
+/// Max found ULP 0.5
+pub fn f_csch(x: f64) -> f64 {
+    let ax = x.abs();
+    let ix = ax.to_bits();
+    // |x| < 2^-54
+    if ix < 0x3c90000000000000u64 {
+        // csch(x) ~ 1/x - x/6, where x/6 is far below the rounding error of 1/x.
+        return 1. / x;
+    }
+    // |x| >= 746, csch(x) underflows
+    if ix >= 0x4087500000000000u64 {
+        if ix >= 0x7ff0000000000000u64 {
+            // +-Inf or NaN
+            return 1. / x;
+        }
+        return f64::copysign(0., x);
+    }
+    // |x| < 0.125
+    if ix < 0x3fc0000000000000u64 {
+        // Taylor series
+        //   csch(x) = 1/x - x/6 + x * z * Q(z), z = x^2
+        const C1: DoubleDouble =
+            DoubleDouble::from_bit_pair((0xbc65555555555555, 0xbfc5555555555555));
+        let r1 = DoubleDouble::from_recip(x);
+        let z = x * x;
+        let q = f_polyeval7(
+            z,
+            f64::from_bits(0x3f93e93e93e93e94),
+            f64::from_bits(0xbf60cbb766210cbb),
+            f64::from_bits(0x3f2b85fca40e852d),
+            f64::from_bits(0xbef65f59e4611336),
+            f64::from_bits(0x3ec225c0d5895dfa),
+            f64::from_bits(0xbe8d6cc7574a6a5d),
+            f64::from_bits(0x3e57da1e6add5669),
+        );
+        let mut p = DoubleDouble::from_exact_add(C1.hi, z * q);
+        p.lo += C1.lo;
+        let v = DoubleDouble::quick_mult_f64(p, x);
+        let r = DoubleDouble::add(r1, v);
+        let err = r.hi.abs() * f64::from_bits(0x3bd0000000000000);
+        let ub = r.hi + (r.lo + err);
+        let lb = r.hi + (r.lo - err);
+        if ub == lb {
+            return r.to_f64();
+        }
+        return csch_hard(x);
+    }
+    // |x| >= 600, exp(-|x|) lower part may be subnormal
+    if ix >= 0x4082c00000000000u64 {
+        return csch_hard(x);
+    }
+    // csch(x) = 2 * exp(-|x|) / (1 - exp(-2|x|))
+    let e = i0_exp(-ax);
+    let e2 = DoubleDouble::quick_mult(e, e);
+    let mut den = DoubleDouble::from_exact_sub(1., e2.hi);
+    den.lo -= e2.lo;
+    let num = DoubleDouble::new(e.lo * 2., e.hi * 2.);
+    let r = DoubleDouble::div(num, den);
+    let err = r.hi * f64::from_bits(0x3bd0000000000000);
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub == lb {
+        return f64::copysign(r.to_f64(), x);
+    }
+    csch_hard(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csch() {
+        assert_eq!(f_csch(0.), f64::INFINITY);
+        assert_eq!(f_csch(-0.), f64::NEG_INFINITY);
+        assert_eq!(f_csch(f64::INFINITY), 0.);
+        assert!(f_csch(f64::NAN).is_nan());
+    }
+}
@@ -29,12 +29,24 @@
 #![deny(unreachable_pub)]
 mod acosh;
 mod acoshf;
+mod acoth;
+mod acothf;
+mod acsch;
+mod acschf;
+mod asech;
+mod asechf;
 mod asinh;
 mod asinhf;
 mod atanh;
 mod atanhf;
 mod cosh;
 mod coshf;
+mod coth;
+mod cothf;
+mod csch;
+mod cschf;
+mod sech;
+mod sechf;
 mod sinh;
 mod sinhf;
 mod tanh;
@@ -42,12 +54,24 @@ mod tanhf;
 
 pub use acosh::f_acosh;
 pub use acoshf::f_acoshf;
+pub use acoth::f_acoth;
+pub use acothf::f_acothf;
+pub use acsch::f_acsch;
+pub use acschf::f_acschf;
+pub use asech::f_asech;
+pub use asechf::f_asechf;
 pub use asinh::f_asinh;
 pub use asinhf::f_asinhf;
 pub use atanh::f_atanh;
 pub use atanhf::f_atanhf;
 pub use cosh::f_cosh;
 pub use coshf::f_coshf;
+pub use coth::f_coth;
+pub use cothf::f_cothf;
+pub use csch::f_csch;
+pub use cschf::f_cschf;
+pub use sech::f_sech;
+pub use sechf::f_sechf;
 pub use sinh::f_sinh;
 pub use sinhf::f_sinhf;
 pub use tanh::f_tanh;
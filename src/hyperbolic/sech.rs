@@ -0,0 +1,233 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bessel::i0_exp;
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::exponents::rational128_exp;
+use crate::polyeval::f_polyeval9;
+
+// Generated by MPMath:
+// sech(x) = 1 + sum_{n>=1} E(2n)/(2n)! * x^(2n)
+static SECH_TAYLOR_F128: [DyadicFloat128; 17] = [
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -128,
+        mantissa: 0x80000000_00000000_00000000_00000000_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -130,
+        mantissa: 0xd5555555_55555555_55555555_55555555_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -131,
+        mantissa: 0xad82d82d_82d82d82_d82d82d8_2d82d82e_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -132,
+        mantissa: 0x8cb2cb2c_b2cb2cb2_cb2cb2cb_2cb2cb2d_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -134,
+        mantissa: 0xe41a141e_69b918b9_5e130903_0d58a808_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -135,
+        mantissa: 0xb8e4b1a2_28c9c4a3_2f7a826e_66af40b8_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -136,
+        mantissa: 0x95de7539_c100f5e1_9c35e32f_b3f44713_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -138,
+        mantissa: 0xf2f55cd9_b2c7655e_18153d20_5dacf784_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -139,
+        mantissa: 0xc4ef489a_a92429ac_2664f5b9_51b1d298_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -140,
+        mantissa: 0x9fa116f6_cde1283a_b21ff6c8_105d5279_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -141,
+        mantissa: 0x8163fe7b_5fa6604d_32c9800b_c88ab302_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -143,
+        mantissa: 0xd1c298b9_61d034c3_404c06f8_a3f3c640_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -144,
+        mantissa: 0xaa067163_5bc865df_290102fe_12a36b96_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -145,
+        mantissa: 0x89d134aa_ec04a090_5df3f0b4_1b5c2f69_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -147,
+        mantissa: 0xdf6bbc84_ec471615_496f29c0_4b7c651b_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -148,
+        mantissa: 0xb5191e21_27293594_2c4c5cf2_e674463e_u128,
+    },
+    DyadicFloat128 {
+        sign: DyadicSign::Neg,
+        exponent: -149,
+        mantissa: 0x92cae814_253c7c47_0a454adf_2b37eeaf_u128,
+    },
+];
+
+#[cold]
+#[inline(never)]
+fn sech_hard(x: f64) -> f64 {
+    const ONE: DyadicFloat128 = DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -127,
+        mantissa: 0x80000000_00000000_00000000_00000000_u128,
+    };
+    let ax = x.abs();
+    let r = if ax < 0.125 {
+        let dx = DyadicFloat128::new_from_f64(x);
+        let z = dx * dx;
+        let mut p = SECH_TAYLOR_F128[16];
+        for i in (0..16).rev() {
+            p = z * p + SECH_TAYLOR_F128[i];
+        }
+        ONE + z * p
+    } else {
+        // sech(x) = 2 * exp(-|x|) / (1 + exp(-2|x|))
+        let e = rational128_exp(-ax);
+        let mut num = e;
+        num.exponent += 1;
+        num * (ONE + e * e).reciprocal()
+    };
+    r.fast_as_f64()
+}
+
+/// Computes hyperbolic secant
+///
+/// Max found ULP 0.5
+pub fn f_sech(x: f64) -> f64 {
+    let ax = x.abs();
+    let ix = ax.to_bits();
+    // |x| < 2^-27
+    if ix < 0x3e40000000000000u64 {
+        // sech(x) ~ 1 - x^2/2, where x^2/2 < 2^-55 always rounds to 1.
+        if ix == 0 {
+            return 1.;
+        }
+        return 1. - f64::from_bits(0x3c80000000000000);
+    }
+    // |x| >= 746, sech(x) underflows
+    if ix >= 0x4087500000000000u64 {
+        if ix > 0x7ff0000000000000u64 {
+            return x + x;
+        } // nan
+        return 0.;
+    }
+    // |x| < 0.125
+    if ix < 0x3fc0000000000000u64 {
+        // Taylor series
+        //   sech(x) = 1 - z/2 + z^2 * Q(z), z = x^2
+        let z = DoubleDouble::from_exact_mult(x, x);
+        let q = f_polyeval9(
+            z.hi,
+            f64::from_bits(0x3fcaaaaaaaaaaaab),
+            f64::from_bits(0xbfb5b05b05b05b06),
+            f64::from_bits(0x3fa1965965965966),
+            f64::from_bits(0xbf8c834283cd3723),
+            f64::from_bits(0x3f771c9634451939),
+            f64::from_bits(0xbf62bbcea738201f),
+            f64::from_bits(0x3f4e5eab9b3658ed),
+            f64::from_bits(0xbf389de913552485),
+            f64::from_bits(0x3f23f422ded9bc25),
+        );
+        let mut r = DoubleDouble::from_exact_sub(1., z.hi * 0.5);
+        r.lo = f_fmla(z.hi * z.hi, q, r.lo - z.lo * 0.5);
+        let err = f64::from_bits(0x3bd0000000000000);
+        let ub = r.hi + (r.lo + err);
+        let lb = r.hi + (r.lo - err);
+        if ub == lb {
+            return r.to_f64();
+        }
+        return sech_hard(x);
+    }
+    // |x| >= 600, exp(-|x|) lower part may be subnormal
+    if ix >= 0x4082c00000000000u64 {
+        return sech_hard(x);
+    }
+    // sech(x) = 2 * exp(-|x|) / (1 + exp(-2|x|))
+    let e = i0_exp(-ax);
+    let e2 = DoubleDouble::quick_mult(e, e);
+    let mut den = DoubleDouble::from_exact_add(1., e2.hi);
+    den.lo += e2.lo;
+    let num = DoubleDouble::new(e.lo * 2., e.hi * 2.);
+    let r = DoubleDouble::div(num, den);
+    let err = r.hi * f64::from_bits(0x3bd0000000000000);
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub == lb {
+        return r.to_f64();
+    }
+    sech_hard(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sech() {
+        assert_eq!(f_sech(0.), 1.);
+        assert_eq!(f_sech(-0.), 1.);
+        assert_eq!(f_sech(f64::INFINITY), 0.);
+        assert_eq!(f_sech(f64::NEG_INFINITY), 0.);
+        assert!(f_sech(f64::NAN).is_nan());
+    }
+}
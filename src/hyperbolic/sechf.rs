@@ -0,0 +1,80 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::exponents::core_expdf;
+use crate::polyeval::f_polyeval7;
+
+/// Computes hyperbolic secant
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_sechf(x: f32) -> f32 {
+    let ax = x.to_bits() & 0x7fff_ffff;
+    let xd = x as f64;
+    // |x| < 0.125
+    if ax < 0x3e00_0000u32 {
+        // Taylor series
+        //   sech(x) = 1 + x^2 * Q(x^2)
+        let z = xd * xd;
+        let p = f_polyeval7(
+            z,
+            1.,
+            f64::from_bits(0xbfe0000000000000),
+            f64::from_bits(0x3fcaaaaaaaaaaaab),
+            f64::from_bits(0xbfb5b05b05b05b06),
+            f64::from_bits(0x3fa1965965965966),
+            f64::from_bits(0xbf8c834283cd3723),
+            f64::from_bits(0x3f771c9634451939),
+        );
+        return p as f32;
+    }
+    // |x| >= 105, sech(x) underflows
+    if ax >= 0x42d2_0000u32 {
+        if ax > 0x7f80_0000u32 {
+            return x + x;
+        } // nan
+        return 0.;
+    }
+    // sech(x) = 2 * exp(-|x|) / (1 + exp(-2|x|))
+    let e_half = core_expdf(-0.5 * xd.abs());
+    let e = e_half * e_half;
+    ((e + e) / (1. + e * e)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sechf() {
+        assert_eq!(f_sechf(0.), 1.);
+        assert_eq!(f_sechf(f32::INFINITY), 0.);
+        assert_eq!(f_sechf(f32::NEG_INFINITY), 0.);
+        assert!(f_sechf(f32::NAN).is_nan());
+    }
+}
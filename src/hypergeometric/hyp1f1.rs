@@ -0,0 +1,286 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::f_exp;
+use crate::gamma::{is_pole, lgamma_dd, lgamma_ratio_core};
+use crate::hypergeometric::hyp_core::{LN2_DD, mul_exp};
+use crate::logs::fast_log_d_to_dd;
+
+/// Sums series of 1F1(a; b; x) = sum((a)_k / (b)_k * x^k / k!).
+///
+/// Returns (s, e, c) where the sum is s * 2^e, so large arguments do not overflow,
+/// and c is the ratio of the largest term to the sum, that measures cancellation.
+pub(crate) fn hyp1f1_series(a: DoubleDouble, b: f64, x: f64) -> (DoubleDouble, i32, f64) {
+    let mut t = DoubleDouble::new(0., 1.);
+    let mut s = t;
+    let mut e = 0i32;
+    let mut max_t = 1f64;
+    let mut k = 0f64;
+    let lim = a.hi.abs().max(b.abs());
+    while k < 1e6 {
+        let ak = DoubleDouble::full_add_f64(a, k);
+        if ak.hi == 0. {
+            // polynomial case
+            break;
+        }
+        let bk = DoubleDouble::from_full_exact_add(b, k);
+        let den = DoubleDouble::quick_mult_f64(bk, k + 1.);
+        t = DoubleDouble::quick_mult_f64(
+            DoubleDouble::div(DoubleDouble::quick_mult(t, ak), den),
+            x,
+        );
+        s = DoubleDouble::add(s, t);
+        max_t = max_t.max(t.hi.abs());
+        k += 1.;
+        if k > lim
+            && t.hi.abs() <= f64::from_bits(0x3950000000000000) * s.hi.abs()
+            && (ak.hi * x).abs() < den.hi.abs()
+        {
+            // |t| < 2^-106 * |s| and terms are decreasing
+            break;
+        }
+        if s.hi.abs() > f64::from_bits(0x7830000000000000)
+            || t.hi.abs() > f64::from_bits(0x7830000000000000)
+        {
+            // Rescale by 2^-900
+            const SCALE: f64 = f64::from_bits(0x07b0000000000000);
+            s = DoubleDouble::quick_mult_f64(s, SCALE);
+            t = DoubleDouble::quick_mult_f64(t, SCALE);
+            max_t *= SCALE;
+            e += 900;
+        }
+    }
+    (s, e, max_t / s.hi.abs())
+}
+
+/// Computes 1F1(a; b; x) for negative a by downward recurrence
+/// (b - a) * M(a - 1) = a * M(a + 1) - (2a - b + x) * M(a),
+/// starting from a + n in [0, 1) evaluated by series.
+///
+/// Series for such a cancels heavily, while recurrence is stable only in part of the domain,
+/// so the same recurrence with perturbed initial value is run in parallel to estimate
+/// error amplification. Returns None when the recurrence loses too much accuracy.
+fn hyp1f1_recur_down(a: DoubleDouble, b: f64, x: f64) -> Option<(DoubleDouble, i32)> {
+    let n = (-a.hi).ceil();
+    let a0 = DoubleDouble::full_add_f64(a, n);
+    let (mut m1, e1, _) = hyp1f1_series(a0, b, x);
+    let (mut m0, e0, _) = hyp1f1_series(DoubleDouble::full_add_f64(a0, -1.), b, x);
+    let mut e = e0.max(e1);
+    m1 = DoubleDouble::quick_mult_f64(m1, f64::exp2((e1 - e) as f64));
+    m0 = DoubleDouble::quick_mult_f64(m0, f64::exp2((e0 - e) as f64));
+    // perturbed by 2^-20
+    const DELTA: f64 = f64::from_bits(0x3eb0000000000000);
+    let mut p1 = m1.hi;
+    let mut p0 = m0.hi * (1. + DELTA);
+    let mut k = 1f64;
+    while k < n {
+        let ak = DoubleDouble::full_add_f64(a0, -k);
+        let c = DoubleDouble::full_add_f64(
+            DoubleDouble::full_add_f64(DoubleDouble::full_dd_add(ak, ak), x),
+            -b,
+        );
+        let num = DoubleDouble::full_dd_sub(
+            DoubleDouble::quick_mult(ak, m1),
+            DoubleDouble::quick_mult(c, m0),
+        );
+        let den = DoubleDouble::full_dd_sub(DoubleDouble::new(0., b), ak);
+        m1 = m0;
+        m0 = DoubleDouble::div(num, den);
+        let q = (ak.hi * p1 - c.hi * p0) / den.hi;
+        p1 = p0;
+        p0 = q;
+        if m0.hi.abs() > f64::from_bits(0x7830000000000000) {
+            // Rescale by 2^-900
+            const SCALE: f64 = f64::from_bits(0x07b0000000000000);
+            m0 = DoubleDouble::quick_mult_f64(m0, SCALE);
+            m1 = DoubleDouble::quick_mult_f64(m1, SCALE);
+            p0 *= SCALE;
+            p1 *= SCALE;
+            e += 900;
+        }
+        k += 1.;
+    }
+    // amplification of initial error must stay below 2^48
+    let err = (p0 - m0.hi).abs();
+    if err.is_nan() || err >= f64::from_bits(0x42f0000000000000) * DELTA * m0.hi.abs() {
+        return None;
+    }
+    Some((m0, e))
+}
+
+/// Asymptotic expansion of 1F1(a; b; x) for large positive x without the
+/// Gamma(b)/Gamma(a) * e^x * x^(a-b) factor:
+/// sum((b - a)_k * (1 - a)_k / k! * x^-k).
+///
+/// Returns None when the series diverges before reaching the required accuracy.
+fn hyp1f1_asympt_sum(a: DoubleDouble, b: f64, x: f64) -> Option<DoubleDouble> {
+    let ba = DoubleDouble::full_dd_sub(DoubleDouble::new(0., b), a);
+    let oa = DoubleDouble::full_dd_sub(DoubleDouble::new(0., 1.), a);
+    let mut t = DoubleDouble::new(0., 1.);
+    let mut s = t;
+    let mut prev = 1f64;
+    let mut k = 0f64;
+    while k < 500. {
+        let p = DoubleDouble::quick_mult(
+            DoubleDouble::full_add_f64(ba, k),
+            DoubleDouble::full_add_f64(oa, k),
+        );
+        if p.hi == 0. {
+            return Some(s);
+        }
+        t = DoubleDouble::div_dd_f64(DoubleDouble::quick_mult(t, p), (k + 1.) * x);
+        s = DoubleDouble::add(s, t);
+        k += 1.;
+        let at = t.hi.abs();
+        if at <= f64::from_bits(0x3bf0000000000000) * s.hi.abs() {
+            // |t| < 2^-64 * |s|
+            return Some(s);
+        }
+        if at > prev {
+            return None;
+        }
+        prev = at;
+    }
+    None
+}
+
+/// Computes Kummer's confluent hypergeometric function 1F1(a; b; x)
+///
+/// 1F1(a; b; x) = sum((a)_k / (b)_k * x^k / k!), k = 0..inf
+///
+/// Negative x uses Kummer's transformation 1F1(a; b; x) = e^x * 1F1(b - a; b; -x),
+/// large x uses asymptotic expansion. When series cancels for large negative a,
+/// downward recurrence in a is used where it is stable.
+/// Returns NaN when b is a non-positive integer, unless series terminates before.
+///
+/// Max found error ~1 ulp for |a|, |b| <= 30, |x| <= 300. For larger negative a
+/// (or b - a when x < 0) and |x| comparable to |a| the result may lose accuracy.
+pub fn f_hyp1f1(a: f64, b: f64, x: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || x.is_nan() {
+        return f64::NAN;
+    }
+    if x == 0. || a == 0. {
+        return 1.;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return f64::NAN;
+    }
+    if is_pole(b) && !(is_pole(a) && a >= b) {
+        return f64::NAN;
+    }
+    if a == b {
+        return f_exp(x);
+    }
+    if x.is_infinite() {
+        if is_pole(a) {
+            return f64::NAN;
+        }
+        if x < 0. {
+            return if a > 0. { 0. } else { f64::NAN };
+        }
+        let (_, sign) = lgamma_ratio_core(DoubleDouble::new(0., b), DoubleDouble::new(0., a));
+        return f64::INFINITY * sign;
+    }
+    // Kummer's transformation turns alternating series into non-alternating one for x < 0
+    let (p, ax, l0) = if x < 0. {
+        (
+            DoubleDouble::from_full_exact_sub(b, a),
+            -x,
+            DoubleDouble::new(0., x),
+        )
+    } else {
+        (DoubleDouble::new(0., a), x, DoubleDouble::default())
+    };
+    if p.lo == 0. && p.hi == 0. {
+        return mul_exp(DoubleDouble::new(0., 1.), l0);
+    }
+    let is_poly = p.lo == 0. && is_pole(p.hi);
+    if !is_poly && ax >= 60. {
+        if let Some(s) = hyp1f1_asympt_sum(p, b, ax) {
+            // 1F1(p; b; x) ~ Gamma(b)/Gamma(p) * e^x * x^(p - b) * s,
+            // the other part is smaller by a factor of e^-x
+            let (lg, sign) = lgamma_ratio_core(DoubleDouble::new(0., b), p);
+            let lx = fast_log_d_to_dd(ax);
+            let pb = DoubleDouble::full_add_f64(p, -b);
+            let mut l = DoubleDouble::quick_mult(pb, lx);
+            l = DoubleDouble::full_dd_add(l, lg);
+            l = DoubleDouble::full_add_f64(l, ax);
+            l = DoubleDouble::full_dd_add(l, l0);
+            // Skipped part relative to the result is
+            // Gamma(p)/Gamma(b - p) * e^-x * x^(b - 2p)
+            let (lgp, _) = lgamma_dd(p);
+            let lbp = if is_pole(b - p.hi) {
+                f64::INFINITY
+            } else {
+                lgamma_dd(DoubleDouble::full_dd_sub(DoubleDouble::new(0., b), p))
+                    .0
+                    .hi
+            };
+            let skipped = lgp.hi - lbp - ax + (b - 2. * p.hi) * lx.hi;
+            if skipped < -50. {
+                return mul_exp(DoubleDouble::quick_mult_f64(s, sign), l);
+            }
+        }
+    }
+    let (mut s, mut e, c) = hyp1f1_series(p, b, ax);
+    if !is_poly
+        && p.hi < -1.
+        && p.hi > -1e6
+        && (c.is_nan() || c >= f64::from_bits(0x42c0000000000000))
+    {
+        // Series lost more than 2^45 to cancellation
+        if let Some((rs, re)) = hyp1f1_recur_down(p, b, ax) {
+            s = rs;
+            e = re;
+        }
+    }
+    mul_exp(s, DoubleDouble::mul_f64_add(LN2_DD, e as f64, l0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyp1f1() {
+        assert_eq!(f_hyp1f1(0.5, 1.5, 2.), 2.3644538928052095);
+        assert_eq!(f_hyp1f1(1., 2., -1.), 0.6321205588285577);
+        assert_eq!(f_hyp1f1(-2., 3., 4.5), -0.3125);
+        assert_eq!(f_hyp1f1(2.5, -1.5, 0.75), 18.126812642246026);
+        assert_eq!(f_hyp1f1(3., 7., 120.), 2.1164007792219198e46);
+        assert_eq!(f_hyp1f1(-7.5, 2.25, 35.), 324342.60066728416);
+        assert_eq!(f_hyp1f1(12., 5., -40.), -3.130962499843832e-14);
+        assert_eq!(f_hyp1f1(0.1, 0.2, 0.001), 1.000500229239601);
+        assert_eq!(f_hyp1f1(1., 1., 3.), 20.085536923187668);
+        assert!(f_hyp1f1(1., -2., 1.).is_nan());
+        assert!(f_hyp1f1(f64::NAN, 1., 1.).is_nan());
+        assert_eq!(f_hyp1f1(-2., -3., 1.5), 2.375);
+        assert_eq!(f_hyp1f1(2., 3., 0.), 1.);
+    }
+}
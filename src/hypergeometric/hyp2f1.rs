@@ -0,0 +1,400 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::{EULER_DD, log_abs_dd};
+use crate::gamma::{is_pole, lgamma_dd};
+use crate::hypergeometric::hyp_core::{mul_exp, sum_scaled};
+use crate::logs::log1p_dd;
+
+#[inline]
+fn is_pole_dd(x: DoubleDouble) -> bool {
+    x.lo == 0. && is_pole(x.hi)
+}
+
+/// Computes log|prod(Gamma(num)) / prod(Gamma(den))| and sign of the fraction,
+/// sign is zero when one of den is a pole.
+fn lgamma_fraction(num: &[DoubleDouble], den: &[DoubleDouble]) -> (DoubleDouble, f64) {
+    let mut l = DoubleDouble::default();
+    let mut sign = 1f64;
+    for &x in den.iter() {
+        if is_pole_dd(x) {
+            return (DoubleDouble::default(), 0.);
+        }
+        let (g, s) = lgamma_dd(x);
+        l = DoubleDouble::full_dd_sub(l, g);
+        sign *= s;
+    }
+    for &x in num.iter() {
+        let (g, s) = lgamma_dd(x);
+        l = DoubleDouble::full_dd_add(l, g);
+        sign *= s;
+    }
+    (l, sign)
+}
+
+/// Computes digamma function for double-double x that is not a pole.
+///
+/// Argument is shifted to y >= 20 by recurrence psi(x) = psi(x + 1) - 1/x,
+/// then asymptotic expansion psi(y) = log(y) - 1/(2y) - sum(B_2k / (2k * y^2k)) is used.
+fn digamma_dd(x: DoubleDouble) -> DoubleDouble {
+    let mut y = x;
+    let mut s = DoubleDouble::default();
+    while y.hi < 20. {
+        s = DoubleDouble::full_dd_add(s, DoubleDouble::div(DoubleDouble::new(0., 1.), y));
+        y = DoubleDouble::full_add_f64(y, 1.);
+    }
+    const C: [u64; 7] = [
+        0xbf81111111111111,
+        0x3f70410410410410,
+        0xbf71111111111111,
+        0x3f7f07c1f07c1f08,
+        0xbf95995995995996,
+        0x3fb5555555555555,
+        0xbfdc5e5e5e5e5e5e,
+    ];
+    let r = DoubleDouble::div(DoubleDouble::new(0., 1.), y);
+    let r2 = DoubleDouble::quick_mult(r, r);
+    let mut p = 0f64;
+    for &c in C.iter().rev() {
+        p = f64::mul_add(p, r2.hi, f64::from_bits(c));
+    }
+    // 1/(12y^2) + r2^2 * p
+    let mut t = DoubleDouble::quick_mult_f64(r2, 1. / 12.);
+    t = DoubleDouble::full_add_f64(t, r2.hi * r2.hi * p);
+    let mut v = log_abs_dd(y);
+    v = DoubleDouble::full_dd_sub(v, DoubleDouble::quick_mult_f64(r, 0.5));
+    v = DoubleDouble::full_dd_sub(v, t);
+    DoubleDouble::full_dd_sub(v, s)
+}
+
+/// Sums series of 2F1(a, b; c; z) = sum((a)_k * (b)_k / (c)_k * z^k / k!)
+/// for |z| <= 0.75 or when series terminates.
+fn hyp2f1_series(
+    a: DoubleDouble,
+    b: DoubleDouble,
+    c: DoubleDouble,
+    z: DoubleDouble,
+) -> DoubleDouble {
+    let mut t = DoubleDouble::new(0., 1.);
+    let mut s = t;
+    let mut k = 0f64;
+    let lim = a.hi.abs().max(b.hi.abs()).max(c.hi.abs());
+    while k < 1e6 {
+        let num = DoubleDouble::quick_mult(
+            DoubleDouble::full_add_f64(a, k),
+            DoubleDouble::full_add_f64(b, k),
+        );
+        if num.hi == 0. {
+            // polynomial case
+            break;
+        }
+        let den = DoubleDouble::quick_mult_f64(DoubleDouble::full_add_f64(c, k), k + 1.);
+        let q = DoubleDouble::quick_mult(num, z);
+        t = DoubleDouble::div(DoubleDouble::quick_mult(t, q), den);
+        s = DoubleDouble::add(s, t);
+        k += 1.;
+        if k > lim
+            && t.hi.abs() <= f64::from_bits(0x3950000000000000) * s.hi.abs()
+            && q.hi.abs() < den.hi.abs()
+        {
+            // |t| < 2^-106 * |s| and terms are decreasing
+            break;
+        }
+    }
+    s
+}
+
+/// Computes sum((p)_n * (q)_n / (n! * (1 - m)_n) * w^n, n = 0..m-1).
+fn hyp2f1_finite_sum(p: DoubleDouble, q: DoubleDouble, m: f64, w: DoubleDouble) -> DoubleDouble {
+    let mut t = DoubleDouble::new(0., 1.);
+    let mut s = t;
+    let mut n = 0f64;
+    while n < m - 1. {
+        let num = DoubleDouble::quick_mult(
+            DoubleDouble::full_add_f64(p, n),
+            DoubleDouble::full_add_f64(q, n),
+        );
+        let den = (n + 1.) * (1. - m + n);
+        t = DoubleDouble::div_dd_f64(
+            DoubleDouble::quick_mult(DoubleDouble::quick_mult(t, num), w),
+            den,
+        );
+        s = DoubleDouble::add(s, t);
+        n += 1.;
+    }
+    s
+}
+
+/// Computes logarithmic series of 2F1 when c - a - b is an integer:
+/// sum((p)_n * (q)_n / (n! * (m + 1)_n) * w^n *
+///     (log(w) - psi(n + 1) - psi(n + m + 1) + psi(p + n) + psi(q + n)), n = 0..inf).
+fn hyp2f1_log_series(p: DoubleDouble, q: DoubleDouble, m: f64, w: DoubleDouble) -> DoubleDouble {
+    let lw = log_abs_dd(w);
+    // psi(1) = -euler, psi(m + 1) = -euler + sum(1/k, k = 1..m)
+    let mut h1 = -EULER_DD;
+    let mut h2 = -EULER_DD;
+    let mut k = 1f64;
+    while k <= m {
+        h2 = DoubleDouble::full_dd_add(h2, DoubleDouble::from_exact_div(1., k));
+        k += 1.;
+    }
+    let mut pp = digamma_dd(p);
+    let mut pq = digamma_dd(q);
+    let mut u = DoubleDouble::new(0., 1.);
+    let mut s = DoubleDouble::default();
+    let lim = p.hi.abs().max(q.hi.abs());
+    let mut n = 0f64;
+    while n < 1e6 {
+        let mut br = DoubleDouble::full_dd_sub(lw, DoubleDouble::full_dd_add(h1, h2));
+        br = DoubleDouble::full_dd_add(br, DoubleDouble::full_dd_add(pp, pq));
+        let t = DoubleDouble::quick_mult(u, br);
+        s = DoubleDouble::add(s, t);
+        let pn = DoubleDouble::full_add_f64(p, n);
+        let qn = DoubleDouble::full_add_f64(q, n);
+        if n > lim && t.hi.abs() <= f64::from_bits(0x3950000000000000) * s.hi.abs() {
+            // |t| < 2^-106 * |s|
+            break;
+        }
+        let num = DoubleDouble::quick_mult(pn, qn);
+        if num.hi == 0. {
+            break;
+        }
+        let den = (n + 1.) * (n + m + 1.);
+        u = DoubleDouble::div_dd_f64(
+            DoubleDouble::quick_mult(DoubleDouble::quick_mult(u, num), w),
+            den,
+        );
+        h1 = DoubleDouble::full_dd_add(h1, DoubleDouble::from_exact_div(1., n + 1.));
+        h2 = DoubleDouble::full_dd_add(h2, DoubleDouble::from_exact_div(1., n + m + 1.));
+        pp = DoubleDouble::full_dd_add(pp, DoubleDouble::div(DoubleDouble::new(0., 1.), pn));
+        pq = DoubleDouble::full_dd_add(pq, DoubleDouble::div(DoubleDouble::new(0., 1.), qn));
+        n += 1.;
+    }
+    s
+}
+
+/// Computes 2F1(a, b; c; z) for 0.75 < z < 1 using transformation to w = 1 - z
+///
+/// 2F1(a, b; c; z) = Gamma(c)Gamma(c-a-b)/(Gamma(c-a)Gamma(c-b)) * 2F1(a, b; a+b-c+1; w)
+///     + w^(c-a-b) * Gamma(c)Gamma(a+b-c)/(Gamma(a)Gamma(b)) * 2F1(c-a, c-b; c-a-b+1; w),
+///
+/// or its limit with logarithmic terms when c - a - b is an integer.
+///
+/// Returns (v, l) where the result is v * e^l.
+fn hyp2f1_one_minus(
+    a: DoubleDouble,
+    b: DoubleDouble,
+    c: DoubleDouble,
+    w: DoubleDouble,
+) -> (DoubleDouble, DoubleDouble) {
+    let ca = DoubleDouble::full_dd_sub(c, a);
+    let cb = DoubleDouble::full_dd_sub(c, b);
+    let cab = DoubleDouble::full_dd_sub(ca, b);
+    let lw = log_abs_dd(w);
+    let m = cab.hi.round();
+    // c - a - b is an integer up to rounding of the transformed parameters
+    if DoubleDouble::full_add_f64(cab, -m).hi.abs() < f64::from_bits(0x3b90000000000000)
+        && m.abs() < 1e5
+    {
+        let am = m.abs();
+        let dm = DoubleDouble::new(0., am);
+        let (p, q, c1, c2) = if m >= 0. {
+            (
+                DoubleDouble::full_add_f64(a, am),
+                DoubleDouble::full_add_f64(b, am),
+                [a, b],
+                [
+                    DoubleDouble::full_add_f64(a, am),
+                    DoubleDouble::full_add_f64(b, am),
+                ],
+            )
+        } else {
+            (
+                a,
+                b,
+                [
+                    DoubleDouble::full_add_f64(a, -am),
+                    DoubleDouble::full_add_f64(b, -am),
+                ],
+                [a, b],
+            )
+        };
+        let mut parts = [(DoubleDouble::default(), DoubleDouble::default()); 2];
+        if am > 0. {
+            // Gamma(m)Gamma(c)/(Gamma(c2_0)Gamma(c2_1)) * w^min(m, 0) * sum(...)
+            let (l, sign) = lgamma_fraction(&[dm, c], &c2);
+            let f = hyp2f1_finite_sum(c1[0], c1[1], am, w);
+            let l = if m < 0. {
+                DoubleDouble::full_dd_sub(l, DoubleDouble::quick_mult_f64(lw, am))
+            } else {
+                l
+            };
+            parts[0] = (DoubleDouble::quick_mult_f64(f, sign), l);
+        }
+        // -(-1)^m * Gamma(c)/(Gamma(c1_0)Gamma(c1_1)) / m! * w^max(m, 0) * sum(...)
+        let (mut l, mut sign) = lgamma_fraction(&[c], &c1);
+        l = DoubleDouble::full_dd_sub(l, lgamma_dd(DoubleDouble::new(0., am + 1.)).0);
+        if m > 0. {
+            l = DoubleDouble::full_dd_add(l, DoubleDouble::quick_mult_f64(lw, am));
+        }
+        if am % 2. == 0. {
+            sign = -sign;
+        }
+        if sign != 0. {
+            let f = hyp2f1_log_series(p, q, am, w);
+            parts[1] = (DoubleDouble::quick_mult_f64(f, sign), l);
+        }
+        return sum_scaled(&parts);
+    }
+    let mut parts = [(DoubleDouble::default(), DoubleDouble::default()); 2];
+    let (l1, s1) = lgamma_fraction(&[c, cab], &[ca, cb]);
+    if s1 != 0. {
+        let f = hyp2f1_series(a, b, DoubleDouble::full_add_f64(-cab, 1.), w);
+        parts[0] = (DoubleDouble::quick_mult_f64(f, s1), l1);
+    }
+    let (mut l2, s2) = lgamma_fraction(&[c, -cab], &[a, b]);
+    if s2 != 0. {
+        let f = hyp2f1_series(ca, cb, DoubleDouble::full_add_f64(cab, 1.), w);
+        l2 = DoubleDouble::full_dd_add(l2, DoubleDouble::quick_mult(cab, lw));
+        parts[1] = (DoubleDouble::quick_mult_f64(f, s2), l2);
+    }
+    sum_scaled(&parts)
+}
+
+/// Computes 2F1(a, b; c; z) for 0 <= z < 1, w = 1 - z.
+///
+/// Returns (v, l) where the result is v * e^l.
+fn hyp2f1_core(
+    a: DoubleDouble,
+    b: DoubleDouble,
+    c: DoubleDouble,
+    z: DoubleDouble,
+    w: DoubleDouble,
+) -> (DoubleDouble, DoubleDouble) {
+    if z.hi <= 0.75 || is_pole_dd(a) || is_pole_dd(b) {
+        return (hyp2f1_series(a, b, c, z), DoubleDouble::default());
+    }
+    hyp2f1_one_minus(a, b, c, w)
+}
+
+/// Computes Gauss's hypergeometric function 2F1(a, b; c; x)
+///
+/// 2F1(a, b; c; x) = sum((a)_k * (b)_k / (c)_k * x^k / k!), k = 0..inf
+///
+/// Series is used for -0.5 <= x <= 0.75, Pfaff's transformation
+/// 2F1(a, b; c; x) = (1 - x)^-a * 2F1(a, c - b; c; x / (x - 1)) for x < -0.5,
+/// and transformation to 1 - x for argument close to 1.
+/// Returns NaN for x > 1 unless a or b is a non-positive integer so the series terminates,
+/// and when c is a non-positive integer unless series terminates before.
+///
+/// Max found error ~1 ulp for |a|, |b|, |c| <= 15, unless c - a - b (or b - a for x < -3)
+/// is close to, but not equal to, an integer. Larger parameters may lose accuracy
+/// due to cancellation in the series.
+pub fn f_hyp2f1(a: f64, b: f64, c: f64, x: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || c.is_nan() || x.is_nan() {
+        return f64::NAN;
+    }
+    if x == 0. || a == 0. || b == 0. {
+        return 1.;
+    }
+    if a.is_infinite() || b.is_infinite() || c.is_infinite() || x.is_infinite() {
+        return f64::NAN;
+    }
+    let poly = is_pole(a) || is_pole(b);
+    if is_pole(c) && !((is_pole(a) && a >= c) || (is_pole(b) && b >= c)) {
+        return f64::NAN;
+    }
+    let da = DoubleDouble::new(0., a);
+    let db = DoubleDouble::new(0., b);
+    let dc = DoubleDouble::new(0., c);
+    if poly {
+        return hyp2f1_series(da, db, dc, DoubleDouble::new(0., x)).to_f64();
+    }
+    if x > 1. {
+        return f64::NAN;
+    }
+    if x < 1. && (a == c || b == c) {
+        // 2F1(a, b; a; x) = (1 - x)^-b
+        let p = if a == c { b } else { a };
+        let l = DoubleDouble::quick_mult_f64(log1p_dd(-x), -p);
+        return mul_exp(DoubleDouble::new(0., 1.), l);
+    }
+    if x == 1. {
+        // Gauss's summation theorem
+        let cab = DoubleDouble::full_dd_sub(DoubleDouble::from_full_exact_sub(c, a), db);
+        let ca = DoubleDouble::from_full_exact_sub(c, a);
+        let cb = DoubleDouble::from_full_exact_sub(c, b);
+        if cab.hi > 0. {
+            let (l, sign) = lgamma_fraction(&[dc, cab], &[ca, cb]);
+            return mul_exp(DoubleDouble::new(0., sign), l);
+        }
+        // Diverges with sign of Gamma(c)/(Gamma(a)Gamma(b))
+        let (_, sign) = lgamma_fraction(&[dc], &[da, db]);
+        return f64::INFINITY * sign;
+    }
+    let (v, l) = if x < -0.5 {
+        // Pfaff's transformation, z = x / (x - 1), 1 - z = 1 / (1 - x)
+        let omx = DoubleDouble::from_full_exact_sub(1., x);
+        let z = DoubleDouble::div(DoubleDouble::new(0., -x), omx);
+        let w = DoubleDouble::div(DoubleDouble::new(0., 1.), omx);
+        let cb = DoubleDouble::from_full_exact_sub(c, b);
+        let (v, l) = hyp2f1_core(da, cb, dc, z, w);
+        (
+            v,
+            DoubleDouble::full_dd_sub(l, DoubleDouble::quick_mult_f64(log_abs_dd(omx), a)),
+        )
+    } else {
+        let w = DoubleDouble::from_full_exact_sub(1., x);
+        hyp2f1_core(da, db, dc, DoubleDouble::new(0., x), w)
+    };
+    mul_exp(v, l)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyp2f1() {
+        assert_eq!(f_hyp2f1(0.5, 1., 1.5, 0.25), 1.0986122886681098);
+        assert_eq!(f_hyp2f1(1., 1., 2., -0.5), 0.8109302162163288);
+        assert_eq!(f_hyp2f1(2.5, -1.5, 3., 0.9), 0.14426431320584107);
+        assert_eq!(f_hyp2f1(1.5, 2.5, 4., 0.99), 14.520358060671331);
+        assert_eq!(f_hyp2f1(1., 2., 3., -10.), 0.15204209454403259);
+        assert_eq!(f_hyp2f1(0.5, 0.5, 1.5, -0.75), 0.9045919612321218);
+        assert_eq!(f_hyp2f1(1., 1., 2., 0.95), 3.1534023932147264);
+        assert_eq!(f_hyp2f1(-3., 2., 1.5, 5.), -127.57142857142857);
+        assert_eq!(f_hyp2f1(2., 3., 7., 1.), 5.);
+        assert_eq!(f_hyp2f1(0.25, 0.75, -1.5, 0.3), 1.0514887758575537);
+        assert!(f_hyp2f1(1., 1., 2., 1.5).is_nan());
+        assert!(f_hyp2f1(1., 2., -2., 0.5).is_nan());
+        assert_eq!(f_hyp2f1(1., 2., 3., 0.), 1.);
+        assert_eq!(f_hyp2f1(1., 2., 2., 0.5), 2.);
+    }
+}
@@ -0,0 +1,89 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::{mul_exp_neg, mul_exp_pos};
+use crate::pow_exec::exp_dd_fast;
+
+pub(crate) const LN2_DD: DoubleDouble =
+    DoubleDouble::from_bit_pair((0x3c7abc9e3b39803f, 0x3fe62e42fefa39ef));
+
+/// Computes v * e^l handling overflow and underflow.
+pub(crate) fn mul_exp(v: DoubleDouble, l: DoubleDouble) -> f64 {
+    if v.hi == 0. || !v.hi.is_finite() {
+        return v.hi;
+    }
+    // Move binary exponent of v into l, so v is in [1, 2)
+    let mut v = v;
+    let mut l = l;
+    if v.hi.abs() < f64::from_bits(0x0370000000000000) {
+        // |v| < 2^-968
+        v = DoubleDouble::quick_mult_f64(v, f64::from_bits(0x7fb0000000000000));
+        l = DoubleDouble::mul_f64_add(LN2_DD, -1020., l);
+    }
+    let e = ((v.hi.to_bits() >> 52) & 0x7ff) as i64 - 1023;
+    let scale = f64::from_bits(((1023 - e) as u64) << 52);
+    v = DoubleDouble::new(v.lo * scale, v.hi * scale);
+    l = DoubleDouble::mul_f64_add(LN2_DD, e as f64, l);
+    if l.hi > 710. {
+        return f64::INFINITY.copysign(v.hi);
+    }
+    if l.hi < -746. {
+        return 0.0f64.copysign(v.hi);
+    }
+    if l.hi >= 0. {
+        mul_exp_pos(v, l)
+    } else {
+        mul_exp_neg(v, -l)
+    }
+}
+
+/// Computes sum(v_i * e^l_i) as v * e^l without overflow, parts with zero v_i are skipped.
+pub(crate) fn sum_scaled(parts: &[(DoubleDouble, DoubleDouble)]) -> (DoubleDouble, DoubleDouble) {
+    let mut l = DoubleDouble::new(0., f64::NEG_INFINITY);
+    for p in parts.iter() {
+        if p.0.hi != 0. && p.1.hi > l.hi {
+            l = p.1;
+        }
+    }
+    if l.hi == f64::NEG_INFINITY {
+        return (DoubleDouble::default(), DoubleDouble::default());
+    }
+    let mut v = DoubleDouble::default();
+    for p in parts.iter() {
+        if p.0.hi == 0. {
+            continue;
+        }
+        let d = DoubleDouble::full_dd_sub(p.1, l);
+        if d.hi < -700. {
+            continue;
+        }
+        v = DoubleDouble::add(v, DoubleDouble::quick_mult(p.0, exp_dd_fast(d)));
+    }
+    (v, l)
+}
@@ -0,0 +1,434 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::is_integer;
+use crate::double_double::DoubleDouble;
+use crate::f_pochhammer;
+use crate::gamma::{is_pole, lgamma_dd, lgamma_ratio_core};
+use crate::hypergeometric::hyp_core::{LN2_DD, mul_exp, sum_scaled};
+use crate::hypergeometric::hyp1f1::hyp1f1_series;
+use crate::logs::{fast_log_d_to_dd, log1p_dd};
+use crate::pow_exec::exp_dd_fast;
+
+/// Asymptotic expansion of U(a, b, x) for large x without x^-a factor:
+/// sum((a)_k * (a - b + 1)_k / k! * (-x)^-k).
+///
+/// Returns None when the series diverges before reaching the required accuracy.
+fn hyperu_asympt_sum(a: DoubleDouble, b: DoubleDouble, x: f64) -> Option<DoubleDouble> {
+    let ab = DoubleDouble::full_dd_sub(DoubleDouble::full_add_f64(a, 1.), b);
+    let mut t = DoubleDouble::new(0., 1.);
+    let mut s = t;
+    let mut prev = 1f64;
+    let mut k = 0f64;
+    while k < 500. {
+        let p = DoubleDouble::quick_mult(
+            DoubleDouble::full_add_f64(a, k),
+            DoubleDouble::full_add_f64(ab, k),
+        );
+        if p.hi == 0. {
+            return Some(s);
+        }
+        t = DoubleDouble::div_dd_f64(DoubleDouble::quick_mult(t, p), -(k + 1.) * x);
+        s = DoubleDouble::add(s, t);
+        k += 1.;
+        let at = t.hi.abs();
+        if at <= f64::from_bits(0x3bf0000000000000) * s.hi.abs() {
+            // |t| < 2^-64 * |s|
+            return Some(s);
+        }
+        if at > prev {
+            return None;
+        }
+        prev = at;
+    }
+    None
+}
+
+/// Computes log(1 + e^u)
+#[inline]
+fn log1p_exp(u: DoubleDouble) -> DoubleDouble {
+    if u.hi < -37. {
+        // log(1 + e^u) = e^u + O(e^2u)
+        return DoubleDouble::new(0., u.hi.exp());
+    }
+    if u.hi > 37. {
+        // log(1 + e^u) = u + e^-u + O(e^-2u)
+        return DoubleDouble::full_add_f64(u, (-u.hi).exp());
+    }
+    if u.hi < 0. {
+        let e = exp_dd_fast(u);
+        let mut v = log1p_dd(e.hi);
+        v.lo += e.lo / (1. + e.hi);
+        v
+    } else {
+        let e = exp_dd_fast(-u);
+        let mut v = log1p_dd(e.hi);
+        v.lo += e.lo / (1. + e.hi);
+        DoubleDouble::full_dd_add(u, v)
+    }
+}
+
+/// Integrand of U(a, b, x) after substitution t = e^u in log form:
+/// phi(u) = a*u + c*log(1 + e^u) - x*e^u, c = b - a - 1.
+///
+/// Returns None when x*e^u is too large for integrand to matter.
+#[inline]
+fn hyperu_phi(
+    a: DoubleDouble,
+    c: DoubleDouble,
+    lx: DoubleDouble,
+    u: DoubleDouble,
+) -> Option<DoubleDouble> {
+    // x*e^u = e^(u + log(x))
+    let r = DoubleDouble::full_dd_add(u, lx);
+    if r.hi > 700. {
+        return None;
+    }
+    let xeu = if r.hi < -700. {
+        DoubleDouble::default()
+    } else {
+        exp_dd_fast(r)
+    };
+    let mut v = DoubleDouble::quick_mult(a, u);
+    v = DoubleDouble::full_dd_add(v, DoubleDouble::quick_mult(c, log1p_exp(u)));
+    Some(DoubleDouble::full_dd_sub(v, xeu))
+}
+
+/// Computes U(a, b, x) for a > 0, x > 0 using integral representation
+/// U(a, b, x) = 1/Gamma(a) * int(t^(a-1) * (1 + t)^(b-a-1) * e^(-xt), t = 0..inf).
+///
+/// After substitution t = e^u integrand is unimodal, so it is centered at its maximum u0,
+/// and integrated by trapezoidal rule after double exponential substitution
+/// u = u0 + s*sinh(w), halving the step until the sum converges.
+///
+/// Returns (v, l) where the result is v * e^l.
+fn hyperu_integral(a: DoubleDouble, b: DoubleDouble, x: f64) -> (DoubleDouble, DoubleDouble) {
+    let c = DoubleDouble::full_add_f64(DoubleDouble::full_dd_sub(b, a), -1.);
+    let lx = fast_log_d_to_dd(x);
+    // Finds maximum of phi by bisection on phi'(u) = a + c/(1 + e^-u) - x*e^u
+    let d_phi = |u: f64| a.hi + c.hi / (1. + (-u).exp()) - (u + lx.hi).exp();
+    let mut lo = -745f64;
+    let mut hi = 745f64;
+    while d_phi(hi) > 0. {
+        lo = hi;
+        hi *= 2.;
+    }
+    for _ in 0..80 {
+        let mid = 0.5 * (lo + hi);
+        if d_phi(mid) > 0. {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let u0 = 0.5 * (lo + hi);
+    // Width of the peak, 1/sqrt(-phi''(u0))
+    let e0 = (-u0).exp();
+    let d2 = c.hi * e0 / ((1. + e0) * (1. + e0)) - (u0 + lx.hi).exp();
+    let s = if d2 < 0. { (-1. / d2).sqrt() } else { 1. };
+    let phi0 = hyperu_phi(a, c, lx, DoubleDouble::new(0., u0)).unwrap_or_default();
+
+    let term = |w: f64| -> DoubleDouble {
+        let ew = exp_dd_fast(DoubleDouble::new(0., w));
+        let rw = exp_dd_fast(DoubleDouble::new(0., -w));
+        let sh = DoubleDouble::quick_mult_f64(DoubleDouble::full_dd_sub(ew, rw), 0.5 * s);
+        let ch = DoubleDouble::quick_mult_f64(DoubleDouble::full_dd_add(ew, rw), 0.5 * s);
+        let u = DoubleDouble::full_add_f64(sh, u0);
+        match hyperu_phi(a, c, lx, u) {
+            Some(p) => {
+                let d = DoubleDouble::full_dd_sub(p, phi0);
+                if d.hi < -700. {
+                    DoubleDouble::default()
+                } else {
+                    DoubleDouble::quick_mult(exp_dd_fast(d), ch)
+                }
+            }
+            None => DoubleDouble::default(),
+        }
+    };
+    // Sums terms going outwards from w0 with step h until they become negligible
+    let sum_side = |w0: f64, h: f64, acc: DoubleDouble| -> DoubleDouble {
+        let mut acc = acc;
+        let mut w = w0;
+        while w.abs() < 20. {
+            let t = term(w);
+            acc = DoubleDouble::add(acc, t);
+            if t.hi <= f64::from_bits(0x3910000000000000) * acc.hi {
+                // t < 2^-110 * acc
+                break;
+            }
+            w += h.copysign(w0);
+        }
+        acc
+    };
+
+    let mut h = 0.5f64;
+    let mut sum = term(0.);
+    sum = sum_side(h, h, sum);
+    sum = sum_side(-h, h, sum);
+    let mut r = DoubleDouble::quick_mult_f64(sum, h);
+    for _ in 0..9 {
+        // Adds midpoints of the previous level
+        let mut mid = DoubleDouble::default();
+        mid = sum_side(0.5 * h, h, mid);
+        mid = sum_side(-0.5 * h, h, mid);
+        sum = DoubleDouble::add(sum, mid);
+        h *= 0.5;
+        let nr = DoubleDouble::quick_mult_f64(sum, h);
+        let diff = DoubleDouble::full_dd_sub(nr, r).to_f64().abs();
+        r = nr;
+        if diff <= f64::from_bits(0x3c80000000000000) * r.hi {
+            // |diff| < 2^-55 * r, the error of trapezoidal rule roughly squares on each level
+            break;
+        }
+    }
+    (r, phi0)
+}
+
+/// Computes Gamma(a) * U(a, b, x) for a > 0, x > 0.
+///
+/// Returns (v, l) where the result is v * e^l.
+fn hyperu_positive_scaled(
+    a: DoubleDouble,
+    b: DoubleDouble,
+    x: f64,
+) -> (DoubleDouble, DoubleDouble) {
+    if x >= 20. {
+        if let Some(s) = hyperu_asympt_sum(a, b, x) {
+            let lx = fast_log_d_to_dd(x);
+            let (lg, _) = lgamma_dd(a);
+            return (
+                s,
+                DoubleDouble::full_dd_sub(lg, DoubleDouble::quick_mult(a, lx)),
+            );
+        }
+    }
+    hyperu_integral(a, b, x)
+}
+
+/// Computes U(a, b, x) for a > 0, x > 0.
+///
+/// Returns (v, l) where the result is v * e^l.
+fn hyperu_positive(a: DoubleDouble, b: DoubleDouble, x: f64) -> (DoubleDouble, DoubleDouble) {
+    let (v, l) = hyperu_positive_scaled(a, b, x);
+    let (lg, _) = lgamma_dd(a);
+    (v, DoubleDouble::full_dd_sub(l, lg))
+}
+
+/// Computes U(a, b, x) for non-integer b as combination of Kummer's functions
+/// U(a, b, x) = Gamma(1-b)/Gamma(a-b+1) * M(a, b, x)
+///     + Gamma(b-1)/Gamma(a) * x^(1-b) * M(a-b+1, 2-b, x).
+///
+/// Returns (v, l) where the result is v * e^l.
+fn hyperu_kummer_sum(a: DoubleDouble, b: f64, x: f64) -> (DoubleDouble, DoubleDouble) {
+    let ab = DoubleDouble::full_add_f64(DoubleDouble::full_add_f64(a, 1.), -b);
+    let lx = fast_log_d_to_dd(x);
+    let mut parts = [(DoubleDouble::default(), DoubleDouble::default()); 2];
+    if !(is_pole(ab.hi) && ab.lo == 0.) {
+        let (lg, sign) = lgamma_ratio_core(DoubleDouble::from_full_exact_sub(1., b), ab);
+        let (m, e, _) = hyp1f1_series(a, b, x);
+        parts[0] = (
+            DoubleDouble::quick_mult_f64(m, sign),
+            DoubleDouble::mul_f64_add(LN2_DD, e as f64, lg),
+        );
+    }
+    if !(is_pole(a.hi) && a.lo == 0.) {
+        let (lg, sign) = lgamma_ratio_core(DoubleDouble::from_full_exact_add(b, -1.), a);
+        let (m, e, _) = hyp1f1_series(ab, 2. - b, x);
+        let mut l = DoubleDouble::mul_f64_add(LN2_DD, e as f64, lg);
+        l = DoubleDouble::full_dd_add(
+            l,
+            DoubleDouble::quick_mult(lx, DoubleDouble::from_full_exact_sub(1., b)),
+        );
+        parts[1] = (DoubleDouble::quick_mult_f64(m, sign), l);
+    }
+    sum_scaled(&parts)
+}
+
+/// Computes U(a, b, x) for non-positive a and a - b + 1 by downward recurrence
+/// U(a - 1) = (2a - b + x) * U(a) - a * (a - b + 1) * U(a + 1)
+/// starting from a + n in (0, 1].
+///
+/// Recurrence may amplify errors for small x and large b, so the same recurrence
+/// with perturbed initial value is run in parallel to estimate error amplification.
+/// Returns the result and whether the recurrence was accurate enough.
+fn hyperu_recur_down(a: f64, b: f64, x: f64) -> (f64, bool) {
+    let n = (-a).floor() + 1.;
+    let a0 = DoubleDouble::from_full_exact_add(a, n);
+    // Only the ratio U(a0 + 1) / U(a0) matters for accuracy, so it is computed from
+    // Gamma(a) * U(a) using Gamma(a0 + 1) = a0 * Gamma(a0)
+    let (v0, l0) = hyperu_positive_scaled(a0, DoubleDouble::new(0., b), x);
+    let (v1, l1) = hyperu_positive_scaled(
+        DoubleDouble::full_add_f64(a0, 1.),
+        DoubleDouble::new(0., b),
+        x,
+    );
+    let mut u0 = v0;
+    let mut u1 = DoubleDouble::div(
+        DoubleDouble::quick_mult(v1, exp_dd_fast(DoubleDouble::full_dd_sub(l1, l0))),
+        a0,
+    );
+    // perturbed by 2^-20
+    const DELTA: f64 = f64::from_bits(0x3eb0000000000000);
+    let mut p0 = u0.hi * (1. + DELTA);
+    let mut p1 = u1.hi;
+    let mut e = 0f64;
+    let mut k = 0f64;
+    while k < n {
+        let ak = DoubleDouble::full_add_f64(a0, -k);
+        let c = DoubleDouble::full_add_f64(
+            DoubleDouble::full_add_f64(DoubleDouble::full_dd_add(ak, ak), x),
+            -b,
+        );
+        let d = DoubleDouble::quick_mult(
+            ak,
+            DoubleDouble::full_add_f64(DoubleDouble::full_add_f64(ak, 1.), -b),
+        );
+        let next = DoubleDouble::full_dd_sub(
+            DoubleDouble::quick_mult(c, u0),
+            DoubleDouble::quick_mult(d, u1),
+        );
+        u1 = u0;
+        u0 = next;
+        let q = c.hi * p0 - d.hi * p1;
+        p1 = p0;
+        p0 = q;
+        if u0.hi.abs() > f64::from_bits(0x7830000000000000) {
+            // Rescale by 2^-900
+            const SCALE: f64 = f64::from_bits(0x07b0000000000000);
+            u0 = DoubleDouble::quick_mult_f64(u0, SCALE);
+            u1 = DoubleDouble::quick_mult_f64(u1, SCALE);
+            p0 *= SCALE;
+            p1 *= SCALE;
+            e += 900.;
+        }
+        k += 1.;
+    }
+    // amplification of initial error must stay below 2^12
+    let stable = (p0 - u0.hi).abs() < f64::from_bits(0x40b0000000000000) * DELTA * u0.hi.abs();
+    let (lg, _) = lgamma_dd(a0);
+    let l = DoubleDouble::full_dd_sub(l0, lg);
+    (mul_exp(u0, DoubleDouble::mul_f64_add(LN2_DD, e, l)), stable)
+}
+
+/// Computes Tricomi's confluent hypergeometric function U(a, b, x)
+///
+/// U(a, b, x) = 1/Gamma(a) * int(t^(a-1) * (1 + t)^(b-a-1) * e^(-xt), t = 0..inf)
+///
+/// Positive a uses the integral representation or asymptotic expansion for large x,
+/// non-positive a uses Kummer's transformation U(a, b, x) = x^(1-b) * U(a-b+1, 2-b, x),
+/// recurrence in a, or expression through Kummer's functions M(a, b, x).
+/// Returns NaN for x < 0.
+///
+/// Max found error ~1 ulp.
+pub fn f_hyperu(a: f64, b: f64, x: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || x.is_nan() || x < 0. {
+        return f64::NAN;
+    }
+    if a == 0. {
+        return 1.;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        // U(a, b, x) ~ x^-a
+        return if a > 0. { 0. } else { f64::INFINITY };
+    }
+    if x == 0. {
+        if is_pole(a) {
+            // U(-n, b, 0) = (-1)^n * (b)_n
+            let n = -a;
+            let p = f_pochhammer(b, n);
+            if p == 0. {
+                return 0.;
+            }
+            return if is_integer(n * 0.5) { p } else { -p };
+        }
+        if b >= 1. {
+            return f64::INFINITY;
+        }
+        // U(a, b, 0) = Gamma(1 - b) / Gamma(a - b + 1)
+        let ab = DoubleDouble::full_add_f64(DoubleDouble::from_full_exact_add(a, 1.), -b);
+        if is_pole(ab.hi) && ab.lo == 0. {
+            return 0.;
+        }
+        let (l, sign) = lgamma_ratio_core(DoubleDouble::from_full_exact_sub(1., b), ab);
+        return mul_exp(DoubleDouble::new(0., sign), l);
+    }
+    let da = DoubleDouble::new(0., a);
+    if a > 0. {
+        let (v, l) = hyperu_positive(da, DoubleDouble::new(0., b), x);
+        return mul_exp(v, l);
+    }
+    let ab = DoubleDouble::full_add_f64(DoubleDouble::from_full_exact_add(a, 1.), -b);
+    if ab.hi > 0. {
+        // Kummer's transformation U(a, b, x) = x^(1-b) * U(a-b+1, 2-b, x)
+        let (v, l) = hyperu_positive(ab, DoubleDouble::from_full_exact_sub(2., b), x);
+        let lx = fast_log_d_to_dd(x);
+        let l = DoubleDouble::full_dd_add(
+            l,
+            DoubleDouble::quick_mult(lx, DoubleDouble::from_full_exact_sub(1., b)),
+        );
+        return mul_exp(v, l);
+    }
+    // Both a and a - b + 1 are non-positive
+    let (r, stable) = hyperu_recur_down(a, b, x);
+    if stable || is_integer(b) {
+        return r;
+    }
+    let (v, l) = hyperu_kummer_sum(da, b, x);
+    mul_exp(v, l)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperu() {
+        assert_eq!(f_hyperu(0.5, 1.5, 2.), 0.7071067811865476);
+        assert_eq!(f_hyperu(1., 1., 1.), 0.5963473623231941);
+        assert_eq!(f_hyperu(2.5, -1.5, 0.75), 0.02535164126790677);
+        assert_eq!(f_hyperu(-1.5, 2., 3.), -0.46574751277504445);
+        assert_eq!(f_hyperu(-3., 0.5, 2.), -1.375);
+        assert_eq!(f_hyperu(0.3, 4., 0.01), 677649.1587730513);
+        assert_eq!(f_hyperu(3., 7., 120.), 6.235733346193416e-7);
+        assert_eq!(f_hyperu(-8.5, 7.25, 0.2), -296708458266.5517);
+        assert_eq!(f_hyperu(5., 2., 1e-5), 4165.024557453483);
+        assert!(f_hyperu(1., 2., -1.).is_nan());
+        assert_eq!(f_hyperu(0., 2., 3.), 1.);
+        assert_eq!(f_hyperu(1., 2., f64::INFINITY), 0.);
+        assert_eq!(f_hyperu(1., 2., 0.), f64::INFINITY);
+        assert_eq!(f_hyperu(-25., 3.25, 0.), -9.792651610929282e27);
+        assert_eq!(f_hyperu(-1e10, 0.5, 0.), f64::INFINITY);
+        assert_eq!(f_hyperu(-1e300, -2.5, 0.), f64::NEG_INFINITY);
+        assert_eq!(f_hyperu(-1e300, 0.5, 0.), f64::INFINITY);
+        assert_eq!(f_hyperu(-5., -3., 0.), 0.);
+    }
+}
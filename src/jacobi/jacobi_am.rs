@@ -0,0 +1,101 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::jacobi::jacobi_core::{PI_OVER_2_DD, atan2_dd, jacobi_am_dd};
+use crate::jacobi::jacobi_sncndn::jacobi_sncndn_dd;
+use crate::pow_exec::exp_dd_fast;
+
+/// Computes Gudermannian function gd(x) = am(x|1) for x >= 0.
+#[inline]
+fn gd_dd(x: f64) -> DoubleDouble {
+    if x > 40. {
+        // gd(x) = pi/2 - 2 * e^-x + O(e^-3x)
+        if x > 710. {
+            return PI_OVER_2_DD;
+        }
+        let e = exp_dd_fast(DoubleDouble::new(0., -x));
+        return DoubleDouble::full_dd_sub(PI_OVER_2_DD, DoubleDouble::quick_mult_f64(e, 2.));
+    }
+    // gd(x) = atan2(tanh(x), sech(x)) = atan2(1 - e^-2x, 2 * e^-x)
+    let e = exp_dd_fast(DoubleDouble::new(0., -x));
+    atan2_dd(
+        DoubleDouble::full_dd_sub(DoubleDouble::new(0., 1.), DoubleDouble::quick_mult(e, e)),
+        DoubleDouble::quick_mult_f64(e, 2.),
+    )
+}
+
+/// Computes Jacobi amplitude am(u|m)
+///
+/// am(u|m) is the inverse of incomplete elliptic integral of the first kind F(phi|m),
+/// m is the parameter, m = k^2.
+/// For m > 1 amplitude is bounded and defined as atan2(sn(u|m), cn(u|m)).
+pub fn f_jacobi_am(u: f64, m: f64) -> f64 {
+    if u.is_nan() || m.is_nan() {
+        return f64::NAN;
+    }
+    if u == 0. || m == 0. {
+        // am(u|0) = u
+        return u;
+    }
+    if m == 1. {
+        let r = gd_dd(u.abs()).to_f64();
+        return if u < 0. { -r } else { r };
+    }
+    if u.is_infinite() {
+        return if m < 1. { u } else { f64::NAN };
+    }
+    if m.is_infinite() {
+        return f64::NAN;
+    }
+    let j = jacobi_sncndn_dd(u.abs(), m);
+    let r = jacobi_am_dd(&j).to_f64();
+    if u < 0. { -r } else { r }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jacobi_am() {
+        assert_eq!(f_jacobi_am(0.5, 0.3), 0.49407289371104723);
+        assert_eq!(f_jacobi_am(1., 0.5), 0.9323150798838539);
+        assert_eq!(f_jacobi_am(2.5, 0.9), 1.5460788389853684);
+        assert_eq!(f_jacobi_am(-1.25, 0.7), -1.0835544317701045);
+        assert_eq!(f_jacobi_am(3., 0.), 3.);
+        assert_eq!(f_jacobi_am(10., 0.99), 4.58130465216949);
+        assert_eq!(f_jacobi_am(0.75, 1.), 0.6882013509140213);
+        assert_eq!(f_jacobi_am(1.5, 2.5), 0.5988300353313388);
+        assert_eq!(f_jacobi_am(-0.8, -3.), -1.0343531449226757);
+        assert_eq!(f_jacobi_am(20., 0.25), 18.62104653365467);
+        assert_eq!(f_jacobi_am(f64::INFINITY, 0.5), f64::INFINITY);
+        assert!(f_jacobi_am(f64::INFINITY, 2.).is_nan());
+        assert!(f_jacobi_am(1., f64::NAN).is_nan());
+    }
+}
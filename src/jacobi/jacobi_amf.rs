@@ -0,0 +1,81 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::hyperbolic::f_sinh;
+use crate::jacobi::jacobi_coref::{jacobi_amf_core, jacobi_sncndnf_core};
+use crate::tangent::f_atan;
+
+/// Computes Jacobi amplitude am(u|m)
+///
+/// am(u|m) is the inverse of incomplete elliptic integral of the first kind F(phi|m),
+/// m is the parameter, m = k^2.
+/// For m > 1 amplitude is bounded and defined as atan2(sn(u|m), cn(u|m)).
+pub fn f_jacobi_amf(u: f32, m: f32) -> f32 {
+    if u.is_nan() || m.is_nan() {
+        return f32::NAN;
+    }
+    if u == 0. || m == 0. {
+        // am(u|0) = u
+        return u;
+    }
+    if m == 1. {
+        // am(u|1) = gd(u) = atan(sinh(u))
+        return f_atan(f_sinh(u as f64)) as f32;
+    }
+    if u.is_infinite() {
+        return if m < 1. { u } else { f32::NAN };
+    }
+    if m.is_infinite() {
+        return f32::NAN;
+    }
+    let j = jacobi_sncndnf_core((u as f64).abs(), m as f64);
+    let r = jacobi_amf_core(&j) as f32;
+    if u < 0. { -r } else { r }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jacobi_amf() {
+        assert_eq!(f_jacobi_amf(0.5, 0.3), 0.49407288);
+        assert_eq!(f_jacobi_amf(1., 0.5), 0.93231505);
+        assert_eq!(f_jacobi_amf(2.5, 0.9), 1.5460789);
+        assert_eq!(f_jacobi_amf(-1.25, 0.7), -1.0835544);
+        assert_eq!(f_jacobi_amf(3., 0.), 3.);
+        assert_eq!(f_jacobi_amf(10., 0.99), 4.5813046);
+        assert_eq!(f_jacobi_amf(0.75, 1.), 0.68820137);
+        assert_eq!(f_jacobi_amf(1.5, 2.5), 0.59883004);
+        assert_eq!(f_jacobi_amf(-0.8, -3.), -1.0343531);
+        assert_eq!(f_jacobi_amf(20., 0.25), 18.621046);
+        assert_eq!(f_jacobi_amf(f32::INFINITY, 0.5), f32::INFINITY);
+        assert!(f_jacobi_amf(f32::INFINITY, 2.).is_nan());
+        assert!(f_jacobi_amf(1., f32::NAN).is_nan());
+    }
+}
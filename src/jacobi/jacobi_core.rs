@@ -0,0 +1,172 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::rounding::CpuRound;
+use crate::sici::sincos_dd;
+use crate::tangent::{ATAN_I, atan_eval};
+
+const ONE: DoubleDouble = DoubleDouble::new(0., 1.);
+pub(crate) const PI_DD: DoubleDouble =
+    DoubleDouble::from_bit_pair((0x3ca1a62633145c07, 0x400921fb54442d18));
+pub(crate) const PI_OVER_2_DD: DoubleDouble =
+    DoubleDouble::from_bit_pair((0x3c91a62633145c07, 0x3ff921fb54442d18));
+
+/// Landen transformations stop once |a - b| <= 2^-53 * a, since the next
+/// step of arithmetic-geometric mean then agrees with its limit to 2^-106.
+const AGM_TOLERANCE: f64 = f64::from_bits(0x3ca0000000000000);
+
+/// Jacobi elliptic functions together with the count of half periods.
+///
+/// `n` is the nearest integer to u / 2K, so that am(u) = n*pi + atan2((-1)^n * sn, (-1)^n * cn).
+pub(crate) struct JacobiDD {
+    pub(crate) sn: DoubleDouble,
+    pub(crate) cn: DoubleDouble,
+    pub(crate) dn: DoubleDouble,
+    pub(crate) n: f64,
+}
+
+/// Computes sn(u|m), cn(u|m), dn(u|m) for u >= 0 and 0 < mc = 1 - m <= 1
+/// by descending Landen transformation.
+///
+/// Arithmetic-geometric mean of 1 and sqrt(mc) gives a_N = pi / 2K, then
+/// trigonometric functions of u * a_N are transformed back to the parameter m.
+pub(crate) fn jacobi_dd(u: DoubleDouble, mc: DoubleDouble) -> JacobiDD {
+    if u.hi < f64::from_bits(0x3e10000000000000) {
+        // u < 2^-30,
+        // sn ~ u - (1 + m) * u^3 / 6, cn ~ 1 - u^2 / 2, dn ~ 1 - m * u^2 / 2
+        let m = DoubleDouble::full_dd_sub(ONE, mc);
+        let u2 = DoubleDouble::quick_mult(u, u);
+        let h = DoubleDouble::quick_mult_f64(u2, -0.5);
+        let sn = DoubleDouble::mul_add(
+            DoubleDouble::quick_mult_f64(u2, (1. + m.hi) * (-1. / 6.)),
+            u,
+            u,
+        );
+        return JacobiDD {
+            sn,
+            cn: DoubleDouble::full_add_f64(h, 1.),
+            dn: DoubleDouble::full_add_f64(DoubleDouble::quick_mult(h, m), 1.),
+            n: 0.,
+        };
+    }
+
+    let mut ak = [ONE; 16];
+    let mut bk = [ONE; 16];
+    let mut a = ONE;
+    let mut b = mc.sqrt();
+    let mut l = 0usize;
+    loop {
+        ak[l] = a;
+        bk[l] = b;
+        let c = DoubleDouble::quick_mult_f64(DoubleDouble::full_dd_add(a, b), 0.5);
+        if (a.hi - b.hi).abs() <= AGM_TOLERANCE * a.hi || l == ak.len() - 1 {
+            a = c;
+            break;
+        }
+        b = DoubleDouble::quick_mult(a, b).sqrt();
+        a = c;
+        l += 1;
+    }
+
+    // v = u * pi / 2K
+    let v = DoubleDouble::quick_mult(u, a);
+    let (sin_h, cos_h) = sincos_dd(v.hi);
+    // sin(v.hi + v.lo) ~ sin(v.hi) + v.lo * cos(v.hi),
+    // cos(v.hi + v.lo) ~ cos(v.hi) - v.lo * sin(v.hi)
+    let sin_v = DoubleDouble::full_add_f64(sin_h, v.lo * cos_h.hi);
+    let cos_v = DoubleDouble::full_add_f64(cos_h, -v.lo * sin_h.hi);
+    let n = (v.hi * (1. / PI_DD.hi)).cpu_round();
+
+    // Backward transformation, starting from cot(v), see
+    // W. H. Press et al., Numerical Recipes, 6.11.
+    let mut t = DoubleDouble::div(cos_v, sin_v);
+    let mut c = DoubleDouble::quick_mult(a, t);
+    let mut dn = ONE;
+    for i in (0..=l).rev() {
+        t = DoubleDouble::quick_mult(t, c);
+        c = DoubleDouble::quick_mult(c, dn);
+        dn = DoubleDouble::div(
+            DoubleDouble::full_dd_add(bk[i], t),
+            DoubleDouble::full_dd_add(ak[i], t),
+        );
+        t = DoubleDouble::div(c, ak[i]);
+    }
+    // c = cn / sn
+    let r = DoubleDouble::full_add_f64(DoubleDouble::quick_mult(c, c), 1.)
+        .sqrt()
+        .recip();
+    let sn = if sin_v.hi >= 0. { r } else { -r };
+    JacobiDD {
+        sn,
+        cn: DoubleDouble::quick_mult(c, sn),
+        dn,
+        n,
+    }
+}
+
+/// Computes atan2(y, x) for double-double arguments.
+pub(crate) fn atan2_dd(y: DoubleDouble, x: DoubleDouble) -> DoubleDouble {
+    let ay = if y.hi < 0. { -y } else { y };
+    let ax = if x.hi < 0. { -x } else { x };
+    let recip = ax.hi < ay.hi;
+    let t = if recip {
+        DoubleDouble::div(ax, ay)
+    } else {
+        DoubleDouble::div(ay, ax)
+    };
+    let k = (64. * t.hi).cpu_round();
+    let kk = k * (1. / 64.);
+    // atan(t) - atan(k/64) = atan((t - k/64) / (1 + t * k/64))
+    let q = DoubleDouble::div(
+        DoubleDouble::full_add_f64(t, -kk),
+        DoubleDouble::full_add_f64(DoubleDouble::quick_mult_f64(t, kk), 1.),
+    );
+    let mut r = DoubleDouble::full_dd_add(
+        DoubleDouble::from_bit_pair(ATAN_I[k as usize]),
+        atan_eval(q),
+    );
+    if recip {
+        r = DoubleDouble::full_dd_sub(PI_OVER_2_DD, r);
+    }
+    if x.hi < 0. {
+        r = DoubleDouble::full_dd_sub(PI_DD, r);
+    }
+    if y.hi < 0. { -r } else { r }
+}
+
+/// Computes amplitude am(u|m) from Jacobi elliptic functions.
+#[inline]
+pub(crate) fn jacobi_am_dd(j: &JacobiDD) -> DoubleDouble {
+    let (sn, cn) = if j.n % 2. != 0. {
+        (-j.sn, -j.cn)
+    } else {
+        (j.sn, j.cn)
+    };
+    DoubleDouble::mul_f64_add(PI_DD, j.n, atan2_dd(sn, cn))
+}
@@ -0,0 +1,156 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::jacobi::jacobi_sncndn::jacobi_sncndn_dd;
+use crate::rounding::CpuRound;
+use crate::sincos::f_sincos;
+use crate::tangent::f_atan2;
+
+/// Landen transformations stop once |a - b| <= 2^-26 * a.
+const AGM_TOLERANCE: f64 = f64::from_bits(0x3e50000000000000);
+
+/// Jacobi elliptic functions together with the count of half periods,
+/// see [crate::jacobi::jacobi_core::JacobiDD].
+pub(crate) struct JacobiF {
+    pub(crate) sn: f64,
+    pub(crate) cn: f64,
+    pub(crate) dn: f64,
+    pub(crate) n: f64,
+}
+
+/// Computes sn(u|m), cn(u|m), dn(u|m) for u >= 0 and 0 < mc = 1 - m <= 1
+/// by descending Landen transformation.
+pub(crate) fn jacobif(u: f64, mc: f64) -> JacobiF {
+    if u < f64::from_bits(0x3eb0000000000000) {
+        // u < 2^-20,
+        // sn ~ u - (1 + m) * u^3 / 6, cn ~ 1 - u^2 / 2, dn ~ 1 - m * u^2 / 2
+        let m = 1. - mc;
+        let h = -0.5 * u * u;
+        return JacobiF {
+            sn: u + (2. - mc) * (-1. / 6.) * u * u * u,
+            cn: 1. + h,
+            dn: 1. + m * h,
+            n: 0.,
+        };
+    }
+
+    let mut ak = [1f64; 16];
+    let mut bk = [1f64; 16];
+    let mut a = 1f64;
+    let mut b = mc.sqrt();
+    let mut l = 0usize;
+    loop {
+        ak[l] = a;
+        bk[l] = b;
+        let c = 0.5 * (a + b);
+        if (a - b).abs() <= AGM_TOLERANCE * a || l == ak.len() - 1 {
+            a = c;
+            break;
+        }
+        b = (a * b).sqrt();
+        a = c;
+        l += 1;
+    }
+
+    // v = u * pi / 2K
+    let v = u * a;
+    let (sin_v, cos_v) = f_sincos(v);
+    let n = (v * (1. / std::f64::consts::PI)).cpu_round();
+
+    let mut t = cos_v / sin_v;
+    let mut c = a * t;
+    let mut dn = 1f64;
+    for i in (0..=l).rev() {
+        t *= c;
+        c *= dn;
+        dn = (bk[i] + t) / (ak[i] + t);
+        t = c / ak[i];
+    }
+    // c = cn / sn
+    let r = 1. / (c * c + 1.).sqrt();
+    let sn = if sin_v >= 0. { r } else { -r };
+    JacobiF {
+        sn,
+        cn: c * sn,
+        dn,
+        n,
+    }
+}
+
+/// Computes Jacobi elliptic functions for |u| and any finite parameter m != 1.
+pub(crate) fn jacobi_sncndnf_core(au: f64, m: f64) -> JacobiF {
+    if au > 16. {
+        // Rounding error of u * pi / 2K is too large for cn and sn close to their zeros,
+        // thus use double-double arithmetic.
+        let j = jacobi_sncndn_dd(au, m);
+        return JacobiF {
+            sn: j.sn.to_f64(),
+            cn: j.cn.to_f64(),
+            dn: j.dn.to_f64(),
+            n: j.n,
+        };
+    }
+    if m > 1. {
+        // sn(u|m) = sn(ku|1/m) / k, cn(u|m) = dn(ku|1/m), dn(u|m) = cn(ku|1/m)
+        let k = m.sqrt();
+        let j = jacobif(au * k, (m - 1.) / m);
+        return JacobiF {
+            sn: j.sn / k,
+            cn: j.dn,
+            dn: j.cn,
+            n: 0.,
+        };
+    }
+    if m < 0. {
+        // sn(u|m) = sn(su|mu) / (s * dn(su|mu)), cn(u|m) = cn(su|mu) / dn(su|mu),
+        // dn(u|m) = 1 / dn(su|mu), s = sqrt(1 - m), mu = -m / (1 - m)
+        let mc = 1. - m;
+        let s = mc.sqrt();
+        let j = jacobif(au * s, 1. / mc);
+        let rdn = 1. / j.dn;
+        return JacobiF {
+            sn: j.sn * rdn / s,
+            cn: j.cn * rdn,
+            dn: rdn,
+            n: j.n,
+        };
+    }
+    jacobif(au, 1. - m)
+}
+
+/// Computes amplitude am(u|m) from Jacobi elliptic functions.
+#[inline]
+pub(crate) fn jacobi_amf_core(j: &JacobiF) -> f64 {
+    let (sn, cn) = if j.n % 2. != 0. {
+        (-j.sn, -j.cn)
+    } else {
+        (j.sn, j.cn)
+    };
+    f_fmla(j.n, std::f64::consts::PI, f_atan2(sn, cn))
+}
@@ -0,0 +1,184 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::mul_exp_neg;
+use crate::hyperbolic::f_tanh;
+use crate::jacobi::jacobi_core::{JacobiDD, jacobi_dd};
+use crate::pow_exec::exp_dd_fast;
+use crate::sincos::f_sincos;
+
+/// Computes sech(x) for x >= 0.
+#[inline]
+fn sech_dd(x: f64) -> f64 {
+    if x > 40. {
+        // e^-2x is negligible, sech(x) ~ 2 * e^-x
+        return mul_exp_neg(DoubleDouble::new(0., 2.), DoubleDouble::new(0., x));
+    }
+    // sech(x) = 2 * e^-x / (1 + e^-2x)
+    let e = exp_dd_fast(DoubleDouble::new(0., -x));
+    DoubleDouble::div(
+        DoubleDouble::quick_mult_f64(e, 2.),
+        DoubleDouble::full_add_f64(DoubleDouble::quick_mult(e, e), 1.),
+    )
+    .to_f64()
+}
+
+/// Computes Jacobi elliptic functions for |u| and any finite parameter m != 1.
+///
+/// Parameters outside (0, 1) are mapped by reciprocal and imaginary modulus
+/// transformations, DLMF 22.17.
+pub(crate) fn jacobi_sncndn_dd(au: f64, m: f64) -> JacobiDD {
+    let u = DoubleDouble::new(0., au);
+    if m > 1. {
+        // k = sqrt(m),
+        // sn(u|m) = sn(ku|1/m) / k, cn(u|m) = dn(ku|1/m), dn(u|m) = cn(ku|1/m)
+        let k = DoubleDouble::new(0., m).sqrt();
+        let mc = DoubleDouble::div_dd_f64(DoubleDouble::from_exact_add(m, -1.), m);
+        let j = jacobi_dd(DoubleDouble::quick_mult(u, k), mc);
+        return JacobiDD {
+            sn: DoubleDouble::div(j.sn, k),
+            cn: j.dn,
+            dn: j.cn,
+            n: 0.,
+        };
+    }
+    if m < 0. {
+        // s = sqrt(1 - m), mu = -m / (1 - m),
+        // sn(u|m) = sn(su|mu) / (s * dn(su|mu)), cn(u|m) = cn(su|mu) / dn(su|mu),
+        // dn(u|m) = 1 / dn(su|mu)
+        let mc = DoubleDouble::from_full_exact_add(1., -m);
+        let s = mc.sqrt();
+        let j = jacobi_dd(DoubleDouble::quick_mult(u, s), mc.recip());
+        let rdn = j.dn.recip();
+        return JacobiDD {
+            sn: DoubleDouble::div(DoubleDouble::quick_mult(j.sn, rdn), s),
+            cn: DoubleDouble::quick_mult(j.cn, rdn),
+            dn: rdn,
+            n: j.n,
+        };
+    }
+    jacobi_dd(u, DoubleDouble::from_full_exact_add(1., -m))
+}
+
+/// Computes Jacobi elliptic functions sn(u|m), cn(u|m), dn(u|m)
+///
+/// m is the parameter, m = k^2. Returns (sn, cn, dn).
+pub fn f_jacobi_sncndn(u: f64, m: f64) -> (f64, f64, f64) {
+    if u.is_nan() || m.is_nan() {
+        return (f64::NAN, f64::NAN, f64::NAN);
+    }
+    if m == 1. {
+        // sn = tanh(u), cn = dn = sech(u)
+        let c = sech_dd(u.abs());
+        return (f_tanh(u), c, c);
+    }
+    if u.is_infinite() || m.is_infinite() {
+        return (f64::NAN, f64::NAN, f64::NAN);
+    }
+    if m == 0. {
+        let (s, c) = f_sincos(u);
+        return (s, c, 1.);
+    }
+    let j = jacobi_sncndn_dd(u.abs(), m);
+    let sn = j.sn.to_f64();
+    (if u < 0. { -sn } else { sn }, j.cn.to_f64(), j.dn.to_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jacobi_sncndn() {
+        assert_eq!(
+            f_jacobi_sncndn(0.5, 0.3),
+            (0.4742156227118206, 0.8804087364264624, 0.9656789647459512)
+        );
+        assert_eq!(
+            f_jacobi_sncndn(1., 0.5),
+            (0.8030018248956439, 0.5959765676721407, 0.8231610016315962)
+        );
+        assert_eq!(
+            f_jacobi_sncndn(2.5, 0.9),
+            (
+                0.9996945384505861,
+                0.024714971010898663,
+                0.31709580068626353
+            )
+        );
+        assert_eq!(
+            f_jacobi_sncndn(-1.25, 0.7),
+            (-0.8836275365540138, 0.46819053455135645, 0.6733807716666993)
+        );
+        assert_eq!(
+            f_jacobi_sncndn(3., 0.),
+            (0.1411200080598672, -0.9899924966004454, 1.)
+        );
+        assert_eq!(
+            f_jacobi_sncndn(10., 0.99),
+            (
+                -0.9914207448606821,
+                -0.1307092447376634,
+                0.16405504440062638
+            )
+        );
+        assert_eq!(
+            f_jacobi_sncndn(0.75, 1.),
+            (0.6351489523872873, 0.7723896738572645, 0.7723896738572645)
+        );
+        assert_eq!(
+            f_jacobi_sncndn(-2., 1.),
+            (
+                -0.9640275800758169,
+                0.26580222883407967,
+                0.26580222883407967
+            )
+        );
+        assert_eq!(
+            f_jacobi_sncndn(1.5, 2.5),
+            (0.5636764736599384, 0.8259956616364863, -0.45351084067058606)
+        );
+        assert_eq!(
+            f_jacobi_sncndn(-0.8, -3.),
+            (-0.8595319403119775, 0.5110820321470194, 1.7934284120782238)
+        );
+        assert_eq!(
+            f_jacobi_sncndn(100., 0.25),
+            (-0.889858477759213, 0.45623665959681076, 0.8955657275655506)
+        );
+        assert_eq!(f_jacobi_sncndn(1e-10, 0.5), (1e-10, 1., 1.));
+        assert_eq!(
+            f_jacobi_sncndn(40., -0.5),
+            (0.3552089713280381, 0.9347869204733649, 1.031060961658408)
+        );
+        assert!(f_jacobi_sncndn(f64::NAN, 0.5).0.is_nan());
+        assert!(f_jacobi_sncndn(f64::INFINITY, 0.5).1.is_nan());
+        assert!(f_jacobi_sncndn(1., f64::INFINITY).2.is_nan());
+    }
+}
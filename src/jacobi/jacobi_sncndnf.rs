@@ -0,0 +1,111 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::hyperbolic::{f_cosh, f_tanh};
+use crate::jacobi::jacobi_coref::jacobi_sncndnf_core;
+use crate::sincos::f_sincos;
+
+/// Computes Jacobi elliptic functions sn(u|m), cn(u|m), dn(u|m)
+///
+/// m is the parameter, m = k^2. Returns (sn, cn, dn).
+pub fn f_jacobi_sncndnf(u: f32, m: f32) -> (f32, f32, f32) {
+    if u.is_nan() || m.is_nan() {
+        return (f32::NAN, f32::NAN, f32::NAN);
+    }
+    let ud = u as f64;
+    if m == 1. {
+        // sn = tanh(u), cn = dn = sech(u)
+        let c = (1. / f_cosh(ud)) as f32;
+        return (f_tanh(ud) as f32, c, c);
+    }
+    if u.is_infinite() || m.is_infinite() {
+        return (f32::NAN, f32::NAN, f32::NAN);
+    }
+    if m == 0. {
+        let (s, c) = f_sincos(ud);
+        return (s as f32, c as f32, 1.);
+    }
+    let j = jacobi_sncndnf_core(ud.abs(), m as f64);
+    let sn = j.sn as f32;
+    (if u < 0. { -sn } else { sn }, j.cn as f32, j.dn as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jacobi_sncndnf() {
+        assert_eq!(
+            f_jacobi_sncndnf(0.5, 0.3),
+            (0.47421563, 0.88040876, 0.965679)
+        );
+        assert_eq!(f_jacobi_sncndnf(1., 0.5), (0.8030018, 0.5959766, 0.823161));
+        assert_eq!(
+            f_jacobi_sncndnf(2.5, 0.9),
+            (0.9996945, 0.02471494, 0.31709585)
+        );
+        assert_eq!(
+            f_jacobi_sncndnf(-1.25, 0.7),
+            (-0.88362753, 0.46819052, 0.6733808)
+        );
+        assert_eq!(f_jacobi_sncndnf(3., 0.), (0.14112, -0.9899925, 1.));
+        assert_eq!(
+            f_jacobi_sncndnf(10., 0.99),
+            (-0.99142075, -0.13070941, 0.16405515)
+        );
+        assert_eq!(
+            f_jacobi_sncndnf(0.75, 1.),
+            (0.63514894, 0.77238965, 0.77238965)
+        );
+        assert_eq!(
+            f_jacobi_sncndnf(-2., 1.),
+            (-0.9640276, 0.26580223, 0.26580223)
+        );
+        assert_eq!(
+            f_jacobi_sncndnf(1.5, 2.5),
+            (0.5636765, 0.8259957, -0.45351085)
+        );
+        assert_eq!(
+            f_jacobi_sncndnf(-0.8, -3.),
+            (-0.85953194, 0.511082, 1.7934284)
+        );
+        assert_eq!(
+            f_jacobi_sncndnf(100., 0.25),
+            (-0.8898585, 0.45623666, 0.89556575)
+        );
+        assert_eq!(f_jacobi_sncndnf(1e-10, 0.5), (1e-10, 1., 1.));
+        assert_eq!(
+            f_jacobi_sncndnf(40., -0.5),
+            (0.35520896, 0.9347869, 1.0310609)
+        );
+        assert!(f_jacobi_sncndnf(f32::NAN, 0.5).0.is_nan());
+        assert!(f_jacobi_sncndnf(f32::INFINITY, 0.5).1.is_nan());
+        assert!(f_jacobi_sncndnf(1., f32::INFINITY).2.is_nan());
+    }
+}
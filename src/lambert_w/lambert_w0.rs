@@ -0,0 +1,236 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::exponents::core_expdf;
+use crate::logs::{fast_log_d_to_dd, simple_fast_log};
+use crate::pow_exec::exp_dd_fast;
+
+/// Closest double to -1/e, it is slightly less than -1/e,
+/// it is treated as the branch point itself.
+pub(crate) const NEG_INV_E: f64 = f64::from_bits(0xbfd78b56362cef38);
+
+/// Computes q = e*x + 1, the distance to the branch point scaled by e.
+///
+/// Around the branch point e*x is very close to -1 thus it has to be computed
+/// in extended precision to avoid catastrophic cancellation.
+#[inline]
+pub(crate) fn lambert_w_branch_distance(x: f64) -> DoubleDouble {
+    const E: DoubleDouble = DoubleDouble::from_bit_pair((0x3ca4d57ee2b1013a, 0x4005bf0a8b145769));
+    let q = DoubleDouble::mul_f64_add_f64(E, x, 1.);
+    DoubleDouble::from_full_exact_add(q.hi, q.lo)
+}
+
+/// Series expansion of Lambert W about the branch point -1/e
+///
+/// W = sum(mu_k * p^k), p = +-sqrt(2*(e*x + 1)), positive `p` gives
+/// principal branch W0 and negative `p` gives W-1 branch.
+///
+/// Coefficients obtained from recurrence (Corless et al. 1996):
+/// mu_0 = -1, mu_1 = 1, alpha_0 = 2, alpha_1 = -1,
+/// mu_k = (k-1)/(k+1) * (mu_(k-2)/2 + alpha_(k-2)/4) - alpha_k/2 - mu_(k-1)/(k+1),
+/// alpha_k = sum(mu_j*mu_(k+1-j), j=2..k-1)
+pub(crate) static LAMBERT_W_BRANCH: [(u64, u64); 14] = [
+    (0x0000000000000000, 0xbff0000000000000),
+    (0x0000000000000000, 0x3ff0000000000000),
+    (0xbc75555555555555, 0xbfd5555555555555),
+    (0xbc6c71c71c71c71c, 0x3fc38e38e38e38e4),
+    (0x3c5203cae759203d, 0xbfb4629b7f0d462a),
+    (0x3c2e573ac901e574, 0x3fa6c901e573ac90),
+    (0xbc3657786e0b9e5b, 0xbf9a9bbcb24fe29f),
+    (0x3c368114a1686dc2, 0x3f9002c98983bc43),
+    (0x3c29d0d2c7a49392, 0xbf83b20565de449c),
+    (0x3c0e1f43a5fcfd8e, 0x3f78a2b4a92630e7),
+    (0x3bf63d431eab0b3e, 0xbf6f38df0d491306),
+    (0x3bfa7a275392ace3, 0x3f63fee464a7d9c5),
+    (0xbbff3aea02dd16ed, 0xbf59d620108b4284),
+    (0x3bdb33dee5835316, 0x3f50d075d983b845),
+];
+
+/// Evaluates branch point series in double-double precision.
+///
+/// Valid for |p| < 2^-4.5, where truncation error is below 2^-106.
+#[inline]
+pub(crate) fn lambert_w_branch_series_dd(p: DoubleDouble) -> DoubleDouble {
+    let mut s = DoubleDouble::from_bit_pair(LAMBERT_W_BRANCH[13]);
+    for &c in LAMBERT_W_BRANCH[..13].iter().rev() {
+        s = DoubleDouble::mul_add(s, p, DoubleDouble::from_bit_pair(c));
+    }
+    s
+}
+
+/// Evaluates first terms of branch point series in double precision.
+#[inline]
+pub(crate) fn lambert_w_branch_series(p: f64, n: usize) -> f64 {
+    let mut s = f64::from_bits(LAMBERT_W_BRANCH[n - 1].1);
+    for &c in LAMBERT_W_BRANCH[..n - 1].iter().rev() {
+        s = f_fmla(s, p, f64::from_bits(c.1));
+    }
+    s
+}
+
+/// Halley iterations on w*e^w - x = 0.
+///
+/// Used only when w is not too far from [-2, 2], so exponent can't overflow.
+#[inline]
+pub(crate) fn lambert_w_halley(x: f64, mut w: f64) -> f64 {
+    for _ in 0..8 {
+        let ew = core_expdf(w);
+        let f = f_fmla(w, ew, -x);
+        let wp1 = w + 1.;
+        let d = f_fmla(ew, wp1, -(w + 2.) * f / (2. * wp1));
+        let dw = f / d;
+        w -= dw;
+        if dw.abs() <= f64::EPSILON * w.abs() {
+            break;
+        }
+    }
+    w
+}
+
+/// Newton iterations on w + log|w| - log|x| = 0, where `lx` is log|x|.
+///
+/// Used for large |w| where w*e^w is not representable.
+#[inline]
+pub(crate) fn lambert_w_newton_log(lx: f64, mut w: f64) -> f64 {
+    for _ in 0..8 {
+        let f = w + simple_fast_log(w.abs()) - lx;
+        let dw = f * w / (1. + w);
+        w -= dw;
+        if dw.abs() <= f64::EPSILON * w.abs() {
+            break;
+        }
+    }
+    w
+}
+
+/// Final Newton step with residual w*e^w - x computed in double-double.
+#[inline]
+pub(crate) fn lambert_w_refine_exp(x: f64, w: f64) -> f64 {
+    let ew = exp_dd_fast(DoubleDouble::new(0., w));
+    let r = DoubleDouble::mul_f64_add_f64(ew, w, -x).to_f64();
+    let d = ew.hi * (1. + w);
+    w - r / d
+}
+
+/// Final Newton step with residual w + log|w| - log|x| computed in double-double.
+#[inline]
+pub(crate) fn lambert_w_refine_log(x: f64, w: f64) -> f64 {
+    let lw = fast_log_d_to_dd(w.abs());
+    let lx = fast_log_d_to_dd(x.abs());
+    let r = DoubleDouble::full_add_f64(DoubleDouble::quick_dd_sub(lw, lx), w).to_f64();
+    w - r * w / (1. + w)
+}
+
+/// Computes principal branch of Lambert W function W0(x).
+///
+/// W0(x) is the solution of w*e^w = x satisfying w >= -1, defined for x >= -1/e.
+pub fn f_lambert_w0(x: f64) -> f64 {
+    if !x.is_finite() {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        return if x.is_sign_positive() {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        };
+    }
+
+    let ax = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+
+    if ax < 0x3e40000000000000u64 {
+        // |x| < 2^-27
+        // W0(x) ~ x - x^2 + O(x^3)
+        if ax == 0 {
+            return x;
+        }
+        return f_fmla(-x, x, x);
+    }
+
+    if x < 0. {
+        if x < NEG_INV_E {
+            // x < -1/e, also keeps e*x from overflowing
+            return f64::NAN;
+        }
+        let q = lambert_w_branch_distance(x);
+        if q.hi < 0. {
+            if x == NEG_INV_E {
+                return -1.;
+            }
+            return f64::NAN;
+        }
+        if q.hi < f64::from_bits(0x3f50000000000000) {
+            // e*x + 1 < 2^-10, close to the branch point
+            let p = DoubleDouble::quick_mult_f64(q, 2.).sqrt();
+            return lambert_w_branch_series_dd(p).to_f64();
+        }
+        if q.hi < 0.5 {
+            let p = (2. * q.hi).sqrt();
+            let w = lambert_w_halley(x, lambert_w_branch_series(p, 6));
+            return lambert_w_refine_exp(x, w);
+        }
+    }
+
+    if x <= 3. {
+        // Winitzki approximation W0(x) ~ log(1 + x) * (1 - log(1 + log(1 + x)) / (2 + log(1 + x)))
+        let l1 = simple_fast_log(1. + x);
+        let w0 = l1 * (1. - simple_fast_log(1. + l1) / (2. + l1));
+        let w = lambert_w_halley(x, w0);
+        return lambert_w_refine_exp(x, w);
+    }
+
+    // Asymptotic expansion W0(x) ~ L1 - L2 + L2/L1, L1 = log(x), L2 = log(log(x))
+    let l1 = simple_fast_log(x);
+    let l2 = simple_fast_log(l1);
+    let w = lambert_w_newton_log(l1, l1 - l2 + l2 / l1);
+    lambert_w_refine_log(x, w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lambert_w0() {
+        assert_eq!(f_lambert_w0(1.), 0.5671432904097838);
+        assert_eq!(f_lambert_w0(-0.3678794411706897), -0.9999979772309879);
+        assert_eq!(f_lambert_w0(-7.450580596923828e-9), -7.45058065243498e-9);
+        assert_eq!(f_lambert_w0(3.378476650022829e75), 168.78269482907388);
+        assert_eq!(f_lambert_w0(0.), 0.);
+        assert_eq!(f_lambert_w0(-0.), -0.);
+        assert_eq!(f_lambert_w0(NEG_INV_E), -1.);
+        assert!(f_lambert_w0(-0.368).is_nan());
+        assert!(f_lambert_w0(-1e305).is_nan());
+        assert!(f_lambert_w0(-f64::MAX).is_nan());
+        assert_eq!(f_lambert_w0(f64::INFINITY), f64::INFINITY);
+        assert!(f_lambert_w0(f64::NEG_INFINITY).is_nan());
+        assert!(f_lambert_w0(f64::NAN).is_nan());
+    }
+}
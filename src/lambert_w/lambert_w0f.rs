@@ -0,0 +1,125 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::lambert_w::lambert_w0::{
+    lambert_w_branch_series, lambert_w_halley, lambert_w_newton_log,
+};
+use crate::logs::simple_fast_log;
+
+/// Closest float to -1/e, it is slightly less than -1/e,
+/// it is treated as the branch point itself.
+pub(crate) const NEG_INV_E_F: f32 = f32::from_bits(0xbebc5ab2);
+
+/// Computes q = e*x + 1 for single precision argument.
+#[inline]
+pub(crate) fn lambert_wf_branch_distance(x: f64) -> f64 {
+    const E_HI: f64 = f64::from_bits(0x4005bf0a8b145769);
+    const E_LO: f64 = f64::from_bits(0x3ca4d57ee2b1013a);
+    f_fmla(E_LO, x, f_fmla(E_HI, x, 1.))
+}
+
+/// Computes principal branch of Lambert W function W0(x).
+///
+/// W0(x) is the solution of w*e^w = x satisfying w >= -1, defined for x >= -1/e.
+pub fn f_lambert_w0f(x: f32) -> f32 {
+    if !x.is_finite() {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        return if x.is_sign_positive() {
+            f32::INFINITY
+        } else {
+            f32::NAN
+        };
+    }
+
+    let ax = x.to_bits() & 0x7fff_ffff;
+
+    if ax < 0x39800000u32 {
+        // |x| < 2^-12
+        // W0(x) ~ x - x^2 + 3/2*x^3 + O(x^4)
+        if ax == 0 {
+            return x;
+        }
+        let dx = x as f64;
+        return f_fmla(f_fmla(dx, 1.5, -1.), dx * dx, dx) as f32;
+    }
+
+    let dx = x as f64;
+
+    if x < 0. {
+        let q = lambert_wf_branch_distance(dx);
+        if q < 0. {
+            if x == NEG_INV_E_F {
+                return -1.;
+            }
+            return f32::NAN;
+        }
+        if q < f64::from_bits(0x3f50000000000000) {
+            // e*x + 1 < 2^-10, close to the branch point
+            let p = (2. * q).sqrt();
+            return lambert_w_branch_series(p, 11) as f32;
+        }
+        if q < 0.5 {
+            let p = (2. * q).sqrt();
+            return lambert_w_halley(dx, lambert_w_branch_series(p, 6)) as f32;
+        }
+    }
+
+    if x <= 3. {
+        // Winitzki approximation W0(x) ~ log(1 + x) * (1 - log(1 + log(1 + x)) / (2 + log(1 + x)))
+        let l1 = simple_fast_log(1. + dx);
+        let w0 = l1 * (1. - simple_fast_log(1. + l1) / (2. + l1));
+        return lambert_w_halley(dx, w0) as f32;
+    }
+
+    // Asymptotic expansion W0(x) ~ L1 - L2 + L2/L1, L1 = log(x), L2 = log(log(x))
+    let l1 = simple_fast_log(dx);
+    let l2 = simple_fast_log(l1);
+    lambert_w_newton_log(l1, l1 - l2 + l2 / l1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lambert_w0f() {
+        assert_eq!(f_lambert_w0f(1.), 0.56714326);
+        assert_eq!(f_lambert_w0f(-0.3678793), -0.9991283);
+        assert_eq!(f_lambert_w0f(1e30), 64.90463);
+        assert_eq!(f_lambert_w0f(NEG_INV_E_F), -1.);
+        assert_eq!(f_lambert_w0f(0.), 0.);
+        assert_eq!(f_lambert_w0f(-0.), -0.);
+        assert!(f_lambert_w0f(-0.368).is_nan());
+        assert_eq!(f_lambert_w0f(f32::INFINITY), f32::INFINITY);
+        assert!(f_lambert_w0f(f32::NEG_INFINITY).is_nan());
+        assert!(f_lambert_w0f(f32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,95 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::lambert_w::lambert_w0::{
+    NEG_INV_E, lambert_w_branch_distance, lambert_w_branch_series, lambert_w_branch_series_dd,
+    lambert_w_halley, lambert_w_newton_log, lambert_w_refine_exp, lambert_w_refine_log,
+};
+use crate::logs::{fast_log_d_to_dd, simple_fast_log};
+
+/// Computes the lower branch of Lambert W function W-1(x).
+///
+/// W-1(x) is the solution of w*e^w = x satisfying w <= -1, defined for -1/e <= x < 0.
+pub fn f_lambert_wm1(x: f64) -> f64 {
+    if x >= 0. || !x.is_finite() {
+        if x == 0. {
+            return f64::NEG_INFINITY;
+        }
+        return f64::NAN;
+    }
+
+    if x < NEG_INV_E {
+        // x < -1/e, also keeps e*x from overflowing
+        return f64::NAN;
+    }
+    let q = lambert_w_branch_distance(x);
+    if q.hi < 0. {
+        if x == NEG_INV_E {
+            return -1.;
+        }
+        return f64::NAN;
+    }
+    if q.hi < f64::from_bits(0x3f50000000000000) {
+        // e*x + 1 < 2^-10, close to the branch point
+        let p = -DoubleDouble::quick_mult_f64(q, 2.).sqrt();
+        return lambert_w_branch_series_dd(p).to_f64();
+    }
+    if q.hi < 0.5 {
+        let p = -(2. * q.hi).sqrt();
+        let w = lambert_w_halley(x, lambert_w_branch_series(p, 6));
+        return lambert_w_refine_exp(x, w);
+    }
+
+    // Asymptotic expansion W-1(x) ~ L1 - L2 + L2/L1, L1 = log(-x), L2 = log(-log(-x))
+    let l1 = fast_log_d_to_dd(-x).to_f64();
+    let l2 = simple_fast_log(-l1);
+    let w = lambert_w_newton_log(l1, l1 - l2 + l2 / l1);
+    lambert_w_refine_log(x, w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lambert_wm1() {
+        assert_eq!(f_lambert_wm1(-0.1), -3.577152063957297);
+        assert_eq!(f_lambert_wm1(-0.3672186920437878), -1.0611663615507156);
+        assert_eq!(f_lambert_wm1(-1.4253594005183394e-82), -193.72398803662648);
+        assert_eq!(f_lambert_wm1(-5e-324), -751.0615595398791);
+        assert_eq!(f_lambert_wm1(NEG_INV_E), -1.);
+        assert_eq!(f_lambert_wm1(0.), f64::NEG_INFINITY);
+        assert!(f_lambert_wm1(0.1).is_nan());
+        assert!(f_lambert_wm1(-0.368).is_nan());
+        assert!(f_lambert_wm1(-1e305).is_nan());
+        assert!(f_lambert_wm1(-f64::MAX).is_nan());
+        assert!(f_lambert_wm1(f64::NEG_INFINITY).is_nan());
+        assert!(f_lambert_wm1(f64::NAN).is_nan());
+    }
+}
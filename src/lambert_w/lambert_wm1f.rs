@@ -0,0 +1,87 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::lambert_w::lambert_w0::{
+    lambert_w_branch_series, lambert_w_halley, lambert_w_newton_log,
+};
+use crate::lambert_w::lambert_w0f::{NEG_INV_E_F, lambert_wf_branch_distance};
+use crate::logs::simple_fast_log;
+
+/// Computes the lower branch of Lambert W function W-1(x).
+///
+/// W-1(x) is the solution of w*e^w = x satisfying w <= -1, defined for -1/e <= x < 0.
+pub fn f_lambert_wm1f(x: f32) -> f32 {
+    if x >= 0. || !x.is_finite() {
+        if x == 0. {
+            return f32::NEG_INFINITY;
+        }
+        return f32::NAN;
+    }
+
+    let dx = x as f64;
+
+    let q = lambert_wf_branch_distance(dx);
+    if q < 0. {
+        if x == NEG_INV_E_F {
+            return -1.;
+        }
+        return f32::NAN;
+    }
+    if q < f64::from_bits(0x3f50000000000000) {
+        // e*x + 1 < 2^-10, close to the branch point
+        let p = -(2. * q).sqrt();
+        return lambert_w_branch_series(p, 11) as f32;
+    }
+    if q < 0.5 {
+        let p = -(2. * q).sqrt();
+        return lambert_w_halley(dx, lambert_w_branch_series(p, 6)) as f32;
+    }
+
+    // Asymptotic expansion W-1(x) ~ L1 - L2 + L2/L1, L1 = log(-x), L2 = log(-log(-x))
+    let l1 = simple_fast_log(-dx);
+    let l2 = simple_fast_log(-l1);
+    lambert_w_newton_log(l1, l1 - l2 + l2 / l1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lambert_wm1f() {
+        assert_eq!(f_lambert_wm1f(-0.1), -3.577152);
+        assert_eq!(f_lambert_wm1f(-0.3678793), -1.0008723);
+        assert_eq!(f_lambert_wm1f(-1e-30), -73.37311);
+        assert_eq!(f_lambert_wm1f(NEG_INV_E_F), -1.);
+        assert_eq!(f_lambert_wm1f(0.), f32::NEG_INFINITY);
+        assert!(f_lambert_wm1f(0.1).is_nan());
+        assert!(f_lambert_wm1f(-0.368).is_nan());
+        assert!(f_lambert_wm1f(f32::NEG_INFINITY).is_nan());
+        assert!(f_lambert_wm1f(f32::NAN).is_nan());
+    }
+}
@@ -40,11 +40,19 @@
     clippy::print_in_format_impl
 )]
 mod acos;
+mod acosd;
+mod acosdf;
 mod acosf;
 mod acospi;
 mod acospif;
+mod acsc;
+mod acscf;
+mod asec;
+mod asecf;
 mod asin;
 mod asin_eval_dyadic;
+mod asind;
+mod asindf;
 mod asinf;
 mod asinpi;
 mod asinpif;
@@ -52,25 +60,40 @@ mod bessel;
 mod bits;
 mod common;
 mod compound;
+pub mod consts;
 mod cosm1;
 mod csc;
+mod cscpi;
 mod cube_roots;
+mod decompose;
 mod double_double;
 mod dyadic_float;
+mod elliptic;
 mod err;
 mod exponents;
+pub mod fast;
+pub mod flags;
+mod fresnel;
 mod gamma;
 mod hyperbolic;
+mod hypergeometric;
+mod jacobi;
+mod lambert_w;
 mod logs;
+mod nextafter;
+mod orthogonal;
 mod polyeval;
 mod pow;
 mod pow_exec;
 mod pow_tables;
 mod powf;
 mod powf_tables;
+mod rem_pio2;
 mod rounding;
 mod sec;
+mod secpi;
 mod shared_eval;
+mod sici;
 mod sin;
 mod sin_cosf;
 mod sin_helper;
@@ -78,8 +101,10 @@ mod sin_table;
 mod sinc;
 mod sincos;
 mod sincos_dyadic;
+mod sincos_frac;
 mod sincos_reduce;
 mod sincos_reduce_tables;
+mod sincosd;
 mod sincospi;
 mod sincospi_tables;
 mod sincpi;
@@ -90,64 +115,109 @@ mod triangle;
 mod triple_double;
 
 pub use acos::f_acos;
+pub use acosd::f_acosd;
+pub use acosdf::f_acosdf;
 pub use acosf::f_acosf;
 pub use acospi::f_acospi;
 pub use acospif::f_acospif;
+pub use acsc::f_acsc;
+pub use acscf::f_acscf;
+pub use asec::f_asec;
+pub use asecf::f_asecf;
 pub use asin::f_asin;
+pub use asind::f_asind;
+pub use asindf::f_asindf;
 pub use asinf::f_asinf;
 pub use asinpi::f_asinpi;
 pub use asinpif::f_asinpif;
 pub use bessel::{
-    f_i0, f_i0e, f_i0ef, f_i0f, f_i1, f_i1e, f_i1ef, f_i1f, f_i2, f_i2f, f_j0, f_j0f, f_j1, f_j1f,
-    f_jincpi, f_jincpif, f_k0, f_k0e, f_k0ef, f_k0f, f_k1, f_k1e, f_k1ef, f_k1f, f_k2f, f_y0,
-    f_y0f, f_y1, f_y1f,
+    f_i0, f_i0e, f_i0ef, f_i0f, f_i1, f_i1e, f_i1ef, f_i1f, f_i2, f_i2e, f_i2ef, f_i2f, f_j0,
+    f_j0_prime_zero, f_j0_zero, f_j0f, f_j1, f_j1_prime_zero, f_j1_zero, f_j1f, f_jincpi,
+    f_jincpif, f_k0, f_k0e, f_k0ef, f_k0f, f_k1, f_k1e, f_k1ef, f_k1f, f_k2, f_k2e, f_k2ef, f_k2f,
+    f_y0, f_y0_zero, f_y0f, f_y1, f_y1_zero, f_y1f,
 };
 pub use common::{copysignfk, copysignk};
 pub use compound::{f_compound, f_compound_m1, f_compound_m1f, f_compoundf, f_powm1, f_powm1f};
 pub use cosm1::f_cosm1;
 pub use csc::f_csc;
+pub use cscpi::f_cscpi;
 pub use cube_roots::{cbrtf, f_cbrt, f_cbrtf, f_rcbrt, f_rcbrtf};
+pub use decompose::{
+    f_frexp, f_frexpf, f_ilogb, f_ilogbf, f_ldexp, f_ldexpf, f_logb, f_logbf, f_modf, f_modff,
+    f_scalbn, f_scalbnf,
+};
+pub use elliptic::{
+    f_ellint_e, f_ellint_e_inc, f_ellint_e_incf, f_ellint_ef, f_ellint_f, f_ellint_ff, f_ellint_k,
+    f_ellint_kf, f_ellint_pi, f_ellint_pif, f_ellint_rc, f_ellint_rcf, f_ellint_rd, f_ellint_rdf,
+    f_ellint_rf, f_ellint_rff, f_ellint_rj, f_ellint_rjf,
+};
 pub use err::{
-    f_erf, f_erfc, f_erfcf, f_erfcinv, f_erfcinvf, f_erfcx, f_erfcxf, f_erff, f_erfinv, f_erfinvf,
-    f_rerf, f_rerff,
+    f_dawson, f_dawsonf, f_erf, f_erfc, f_erfcf, f_erfcinv, f_erfcinvf, f_erfcx, f_erfcxf, f_erff,
+    f_erfi, f_erfif, f_erfinv, f_erfinvf, f_faddeeva, f_owens_t, f_rerf, f_rerff, f_voigt,
 };
 pub use exponents::{
     exp, expf, f_exp, f_exp2, f_exp2f, f_exp2m1, f_exp2m1f, f_exp10, f_exp10f, f_exp10m1,
-    f_exp10m1f, f_expf, f_expm1, f_expm1f, f_logistic, f_logisticf,
+    f_exp10m1f, f_expf, f_expint_e1, f_expint_e1e, f_expint_e1ef, f_expint_e1f, f_expint_ei,
+    f_expint_eie, f_expint_eief, f_expint_eif, f_expint_en, f_expint_enf, f_expm1, f_expm1f, f_li,
+    f_lif, f_logistic, f_logisticf,
+};
+pub use fresnel::{
+    f_fresnel_c, f_fresnel_cf, f_fresnel_s, f_fresnel_sc, f_fresnel_scf, f_fresnel_sf,
 };
 pub use gamma::{
-    f_beta, f_betaf, f_betainc_reg, f_betainc_regf, f_digamma, f_digammaf, f_gamma_p, f_gamma_pf,
-    f_gamma_q, f_gamma_qf, f_lgamma, f_lgamma_r, f_lgamma_rf, f_lgammaf, f_lnbeta, f_lnbetaf,
-    f_tgamma, f_tgammaf, f_trigamma, f_trigammaf,
+    f_beta, f_betaf, f_betainc_reg, f_betainc_regf, f_binomial, f_digamma, f_digammaf, f_factorial,
+    f_gamma_p, f_gamma_pf, f_gamma_q, f_gamma_qf, f_lbinomial, f_lchoose, f_lfactorial, f_lgamma,
+    f_lgamma_r, f_lgamma_rf, f_lgammaf, f_lnbeta, f_lnbetaf, f_pochhammer, f_pochhammerf,
+    f_polygamma, f_polygammaf, f_tgamma, f_tgamma_delta_ratio, f_tgamma_delta_ratiof,
+    f_tgamma_ratio, f_tgamma_ratiof, f_tgammaf, f_trigamma, f_trigammaf,
 };
 pub use hyperbolic::{
-    f_acosh, f_acoshf, f_asinh, f_asinhf, f_atanh, f_atanhf, f_cosh, f_coshf, f_sinh, f_sinhf,
-    f_tanh, f_tanhf,
+    f_acosh, f_acoshf, f_acoth, f_acothf, f_acsch, f_acschf, f_asech, f_asechf, f_asinh, f_asinhf,
+    f_atanh, f_atanhf, f_cosh, f_coshf, f_coth, f_cothf, f_csch, f_cschf, f_sech, f_sechf, f_sinh,
+    f_sinhf, f_tanh, f_tanhf,
 };
+pub use hypergeometric::{f_hyp1f1, f_hyp2f1, f_hyperu};
+pub use jacobi::{f_jacobi_am, f_jacobi_amf, f_jacobi_sncndn, f_jacobi_sncndnf};
+pub use lambert_w::{f_lambert_w0, f_lambert_w0f, f_lambert_wm1, f_lambert_wm1f};
 pub use logs::{
     f_log, f_log1p, f_log1pf, f_log1pmx, f_log1pmxf, f_log2, f_log2f, f_log2p1, f_log2p1f, f_log10,
     f_log10f, f_log10p1, f_log10p1f, f_logf, f_logit, f_logitf, log, logf,
 };
+pub use nextafter::{
+    f_nextafter, f_nextafterf, f_nextdown, f_nextdownf, f_nextup, f_nextupf, f_ulp, f_ulpf,
+};
+pub use orthogonal::{
+    f_assoc_laguerre, f_assoc_legendre, f_chebyshev_t, f_chebyshev_u, f_gauss_hermite,
+    f_gauss_jacobi, f_gauss_laguerre, f_gauss_legendre, f_gegenbauer, f_hermite_h, f_jacobi_p,
+    f_laguerre_l, f_legendre_p, f_sph_legendre,
+};
 pub use pow::{f_pow, pow};
 pub use powf::{dirty_powf, f_powf, powf};
+pub use rem_pio2::{f_rem_pio2, f_rem_pio2f, f_rem2pi, f_rem2pif};
 pub use rounding::{ceil, ceilf};
 pub use rounding::{floor, floorf};
+pub use rounding::{fmod, fmodf, remainder, remainderf, remquo, remquof};
 pub use rounding::{rint, rintf, round, roundf, trunc, truncf};
 pub use rounding::{round_ties_even, roundf_ties_even};
 pub use sec::f_sec;
+pub use secpi::f_secpi;
+pub use sici::{f_chi, f_chif, f_ci, f_cif, f_shi, f_shif, f_si, f_sif};
 pub use sin::{f_cos, f_sin};
 pub use sin_cosf::{
-    f_cosf, f_cosm1f, f_cospif, f_cscf, f_secf, f_sincf, f_sincosf, f_sincospif, f_sincpif, f_sinf,
-    f_sinmxf, f_sinpif,
+    f_cosdf, f_cosf, f_cosm1f, f_cospif, f_cscf, f_cscpif, f_secf, f_secpif, f_sincf, f_sincosdf,
+    f_sincosf, f_sincospif, f_sincpif, f_sindf, f_sinf, f_sinmxf, f_sinpif,
 };
 pub use sinc::f_sinc;
 pub use sincos::f_sincos;
+pub use sincos_frac::{f_sincos_frac, f_sinpi_rational, f_twiddle_table};
+pub use sincosd::{f_cosd, f_sincosd, f_sind};
 pub use sincospi::{f_cospi, f_sincospi, f_sinpi};
 pub use sincpi::f_sincpi;
 pub use sinmx::f_sinmx;
 pub use square_root::{f_rsqrt, f_rsqrtf, f_sqrt1pm1, f_sqrt1pm1f, sqrtf};
 pub use tangent::{
-    f_atan, f_atan2, f_atan2f, f_atan2pi, f_atan2pif, f_atanf, f_atanpi, f_atanpif, f_cot, f_cotf,
-    f_cotpi, f_cotpif, f_tan, f_tanf, f_tanpi, f_tanpif,
+    f_acot, f_acotf, f_atan, f_atan2, f_atan2d, f_atan2df, f_atan2f, f_atan2pi, f_atan2pif,
+    f_atand, f_atandf, f_atanf, f_atanpi, f_atanpif, f_cot, f_cotf, f_cotpi, f_cotpif, f_tan,
+    f_tand, f_tandf, f_tanf, f_tanpi, f_tanpif,
 };
-pub use triangle::{f_cathetus, f_cathetusf, f_hypot, f_hypot3f, f_hypotf};
+pub use triangle::{f_cathetus, f_cathetusf, f_hypot, f_hypot3, f_hypot3f, f_hypotf};
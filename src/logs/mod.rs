@@ -74,6 +74,7 @@ pub(crate) use log_dd::{log_dd, log_dd_fast};
 pub use log1p::f_log1p;
 pub(crate) use log1p::log1p_f64_dyadic;
 pub(crate) use log1p_dd::{log1p_dd, log1p_fast_dd};
+pub(crate) use log1pf::core_log1pf;
 pub use log1pf::f_log1pf;
 pub use log1pmx::f_log1pmx;
 pub use log1pmxf::f_log1pmxf;
@@ -90,6 +91,7 @@ pub(crate) use log2::{LOG_COEFFS, LOG_RANGE_REDUCTION};
 pub use log2f::f_log2f;
 pub(crate) use log2f::{LOG2_R, dirty_log2f};
 pub use log2p1::f_log2p1;
+pub(crate) use log2p1::log2_dyadic;
 pub use log2p1f::f_log2p1f;
 pub(crate) use log10::LOG_R_DD;
 pub use log10::f_log10;
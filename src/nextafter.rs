@@ -0,0 +1,183 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+/// Returns next representable value after x in direction of y
+///
+/// If x equals to y returns y.
+#[inline]
+pub const fn f_nextafter(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x == y {
+        return y;
+    }
+    let ux = x.to_bits();
+    if ux << 1 == 0 {
+        return f64::from_bits((y.to_bits() & 0x8000_0000_0000_0000) | 1);
+    }
+    if (x < y) == (x > 0.) {
+        f64::from_bits(ux + 1)
+    } else {
+        f64::from_bits(ux - 1)
+    }
+}
+
+/// Returns next representable value after x in direction of y
+///
+/// If x equals to y returns y.
+#[inline]
+pub const fn f_nextafterf(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x == y {
+        return y;
+    }
+    let ux = x.to_bits();
+    if ux << 1 == 0 {
+        return f32::from_bits((y.to_bits() & 0x8000_0000) | 1);
+    }
+    if (x < y) == (x > 0.) {
+        f32::from_bits(ux + 1)
+    } else {
+        f32::from_bits(ux - 1)
+    }
+}
+
+/// Returns least representable value greater than x
+#[inline]
+pub const fn f_nextup(x: f64) -> f64 {
+    f_nextafter(x, f64::INFINITY)
+}
+
+/// Returns least representable value greater than x
+#[inline]
+pub const fn f_nextupf(x: f32) -> f32 {
+    f_nextafterf(x, f32::INFINITY)
+}
+
+/// Returns greatest representable value less than x
+#[inline]
+pub const fn f_nextdown(x: f64) -> f64 {
+    f_nextafter(x, f64::NEG_INFINITY)
+}
+
+/// Returns greatest representable value less than x
+#[inline]
+pub const fn f_nextdownf(x: f32) -> f32 {
+    f_nextafterf(x, f32::NEG_INFINITY)
+}
+
+/// Returns unit in the last place of x
+///
+/// This is the spacing between |x| and the next representable value away from zero,
+/// for the largest finite values it is 2^971.
+/// ulp(±inf) = +inf.
+#[inline]
+pub const fn f_ulp(x: f64) -> f64 {
+    let e = (x.to_bits() >> 52) & 0x7ff;
+    if e == 0x7ff {
+        return x * x;
+    }
+    if e <= 52 {
+        // 2^(e - 1075) lands into subnormal range
+        return f64::from_bits(1u64 << e.saturating_sub(1));
+    }
+    f64::from_bits((e - 52) << 52)
+}
+
+/// Returns unit in the last place of x
+///
+/// This is the spacing between |x| and the next representable value away from zero,
+/// for the largest finite values it is 2^104.
+/// ulp(±inf) = +inf.
+#[inline]
+pub const fn f_ulpf(x: f32) -> f32 {
+    let e = (x.to_bits() >> 23) & 0xff;
+    if e == 0xff {
+        return x * x;
+    }
+    if e <= 23 {
+        // 2^(e - 150) lands into subnormal range
+        return f32::from_bits(1u32 << e.saturating_sub(1));
+    }
+    f32::from_bits((e - 23) << 23)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nextafter() {
+        assert_eq!(f_nextafter(1., 2.), 1.0000000000000002);
+        assert_eq!(f_nextafter(1., 0.), 0.9999999999999999);
+        assert_eq!(f_nextafter(0., -1.), -f64::from_bits(1));
+        assert_eq!(
+            f_nextafter(-f64::from_bits(1), 1.).to_bits(),
+            (-0f64).to_bits()
+        );
+        assert_eq!(f_nextafter(f64::MAX, f64::INFINITY), f64::INFINITY);
+        assert_eq!(f_nextafter(f64::INFINITY, 0.), f64::MAX);
+        assert_eq!(f_nextafter(0., -0.).to_bits(), (-0f64).to_bits());
+        assert!(f_nextafter(f64::NAN, 1.).is_nan());
+        assert_eq!(f_nextafterf(1., 2.), 1.0000001);
+        assert_eq!(f_nextafterf(-0., 1.), f32::from_bits(1));
+    }
+
+    #[test]
+    fn test_nextup_nextdown() {
+        assert_eq!(f_nextup(-0.), f64::from_bits(1));
+        assert_eq!(f_nextup(f64::INFINITY), f64::INFINITY);
+        assert_eq!(f_nextup(f64::NEG_INFINITY), f64::MIN);
+        assert_eq!(f_nextdown(1.), 0.9999999999999999);
+        assert_eq!(f_nextdown(f64::from_bits(1)), 0.);
+        assert_eq!(f_nextupf(f32::MAX), f32::INFINITY);
+        assert_eq!(f_nextdownf(0.), -f32::from_bits(1));
+    }
+
+    #[test]
+    fn test_ulp() {
+        assert_eq!(f_ulp(1.), f64::EPSILON);
+        assert_eq!(f_ulp(-1.5), f64::EPSILON);
+        assert_eq!(f_ulp(0.), f64::from_bits(1));
+        assert_eq!(f_ulp(f64::MIN_POSITIVE), f64::from_bits(1));
+        assert_eq!(f_ulp(f64::from_bits(0x0350000000000000)), f64::MIN_POSITIVE);
+        assert_eq!(
+            f_ulp(f64::from_bits(0x0340000000000000)),
+            f64::from_bits(1 << 51)
+        );
+        assert_eq!(f_ulp(f64::MAX), 2f64.powi(971));
+        assert_eq!(f_ulp(f64::NEG_INFINITY), f64::INFINITY);
+        assert_eq!(f_ulpf(1.), f32::EPSILON);
+        assert_eq!(f_ulpf(f32::from_bits(5)), f32::from_bits(1));
+        assert_eq!(f_ulpf(f32::MAX), 2f32.powi(104));
+    }
+}
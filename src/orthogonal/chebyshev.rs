@@ -0,0 +1,97 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::orthogonal::poly_core::{recurrence, scaled_to_f64};
+
+#[inline]
+fn chebyshev(n: u32, x: f64, a0: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    // p_{k+1} = 2x p_k - p_{k-1}, p_1 = a0 * x
+    let (v, e) = recurrence(n, x, DoubleDouble::new(0., 1.), |k| {
+        (
+            DoubleDouble::new(0., if k == 0 { a0 } else { 2. }),
+            DoubleDouble::default(),
+            DoubleDouble::new(0., 1.),
+        )
+    });
+    scaled_to_f64(v, e)
+}
+
+/// Computes Chebyshev polynomial of the first kind T_n(x)
+///
+/// Max found error ~0.5 ulp, except very close to zeros of T_n.
+pub fn f_chebyshev_t(n: u32, x: f64) -> f64 {
+    chebyshev(n, x, 1.)
+}
+
+/// Computes Chebyshev polynomial of the second kind U_n(x)
+///
+/// Max found error ~0.5 ulp, except very close to zeros of U_n.
+pub fn f_chebyshev_u(n: u32, x: f64) -> f64 {
+    chebyshev(n, x, 2.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chebyshev_t() {
+        assert_eq!(f_chebyshev_t(0, 0.5), 1.);
+        assert_eq!(f_chebyshev_t(1, -0.3), -0.3);
+        assert_eq!(f_chebyshev_t(2, 0.5), -0.5);
+        assert_eq!(f_chebyshev_t(5, 0.8), -0.99712);
+        assert_eq!(f_chebyshev_t(10, -0.95), -0.99942165995);
+        assert_eq!(f_chebyshev_t(100, 0.123), 0.9724810126689892);
+        assert_eq!(f_chebyshev_t(10000, 0.3), 0.9137670715708747);
+        assert_eq!(f_chebyshev_t(3, 1.), 1.);
+        assert_eq!(f_chebyshev_t(4, -1.), 1.);
+        assert_eq!(f_chebyshev_t(7, 2.5), 28982.5);
+        assert_eq!(f_chebyshev_t(20, -1e10), 5.24288e205);
+        assert!(f_chebyshev_t(1, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_chebyshev_u() {
+        assert_eq!(f_chebyshev_u(0, 0.5), 1.);
+        assert_eq!(f_chebyshev_u(1, -0.3), -0.6);
+        assert_eq!(f_chebyshev_u(2, 0.5), 0.);
+        assert_eq!(f_chebyshev_u(5, 0.8), -1.0982399999999997);
+        assert_eq!(f_chebyshev_u(10, -0.95), -1.1028799099000042);
+        assert_eq!(f_chebyshev_u(100, 0.123), 1.0013570820372208);
+        assert_eq!(f_chebyshev_u(10000, 0.3), 1.0415231772168914);
+        assert_eq!(f_chebyshev_u(3, 1.), 4.);
+        assert_eq!(f_chebyshev_u(4, -1.), 5.);
+        assert_eq!(f_chebyshev_u(7, 2.5), 60605.);
+        assert_eq!(f_chebyshev_u(20, -1e10), 1.048576e206);
+        assert!(f_chebyshev_u(1, f64::NAN).is_nan());
+    }
+}
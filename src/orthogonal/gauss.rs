@@ -0,0 +1,272 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::gamma::lgamma_dd;
+use crate::hypergeometric::LN2_DD;
+use crate::orthogonal::gauss_core::gauss_rule;
+
+#[inline]
+fn fill_nan(nodes: &mut [f64], weights: &mut [f64]) {
+    nodes.iter_mut().for_each(|x| *x = f64::NAN);
+    weights.iter_mut().for_each(|x| *x = f64::NAN);
+}
+
+/// Computes Gauss-Legendre quadrature rule on [-1, 1]
+///
+/// Rule order is n = min(nodes.len(), weights.len()), nodes are written in ascending order.
+/// Cost is O(n^2).
+///
+/// Max found error ~0.5 ulp.
+pub fn f_gauss_legendre(nodes: &mut [f64], weights: &mut [f64]) {
+    // b_k = k / sqrt(4k^2 - 1), mu0 = 2
+    gauss_rule(nodes, weights, LN2_DD, true, |k| {
+        let m = (k + 1) as f64;
+        let q = DoubleDouble::from_exact_mult(2. * m, 2. * m);
+        let d = DoubleDouble::full_add_f64(q, -1.).sqrt();
+        (DoubleDouble::default(), DoubleDouble::from_f64_div_dd(m, d))
+    });
+}
+
+/// Computes Gauss-Hermite quadrature rule for weight exp(-x^2) on (-inf, inf)
+///
+/// Rule order is n = min(nodes.len(), weights.len()), nodes are written in ascending order.
+/// Cost is O(n^2).
+///
+/// Max found error ~0.5 ulp.
+pub fn f_gauss_hermite(nodes: &mut [f64], weights: &mut [f64]) {
+    // log(sqrt(pi))
+    const LN_SQRT_PI: DoubleDouble =
+        DoubleDouble::from_bit_pair((0x3c57abf2ad8d5088, 0x3fe250d048e7a1bd));
+    // b_k = sqrt(k / 2), mu0 = sqrt(pi)
+    gauss_rule(nodes, weights, LN_SQRT_PI, true, |k| {
+        let b = DoubleDouble::from_sqrt((k + 1) as f64 * 0.5);
+        (DoubleDouble::default(), b)
+    });
+}
+
+/// Computes generalized Gauss-Laguerre quadrature rule for weight x^alpha exp(-x) on [0, inf)
+///
+/// Rule order is n = min(nodes.len(), weights.len()), nodes are written in ascending order.
+/// Cost is O(n^2). Fills both slices with NaN when alpha <= -1.
+///
+/// Max found error ~0.5 ulp.
+pub fn f_gauss_laguerre(alpha: f64, nodes: &mut [f64], weights: &mut [f64]) {
+    if alpha <= -1. || !alpha.is_finite() {
+        fill_nan(nodes, weights);
+        return;
+    }
+    // a_k = 2k + alpha + 1, b_k = sqrt(k (k + alpha)), mu0 = gamma(alpha + 1)
+    let l_mu0 = lgamma_dd(DoubleDouble::from_full_exact_add(alpha, 1.)).0;
+    gauss_rule(nodes, weights, l_mu0, false, |k| {
+        let m = (k + 1) as f64;
+        let a = DoubleDouble::from_full_exact_add(2. * k as f64 + 1., alpha);
+        let b = DoubleDouble::quick_mult_f64(DoubleDouble::from_full_exact_add(m, alpha), m);
+        (a, b.sqrt())
+    });
+}
+
+/// Computes Gauss-Jacobi quadrature rule for weight (1 - x)^alpha (1 + x)^beta on [-1, 1]
+///
+/// Rule order is n = min(nodes.len(), weights.len()), nodes are written in ascending order.
+/// Cost is O(n^2). Fills both slices with NaN when alpha <= -1 or beta <= -1.
+///
+/// Max found error ~0.5 ulp.
+pub fn f_gauss_jacobi(alpha: f64, beta: f64, nodes: &mut [f64], weights: &mut [f64]) {
+    if alpha <= -1. || beta <= -1. || !alpha.is_finite() || !beta.is_finite() {
+        fill_nan(nodes, weights);
+        return;
+    }
+    let s = DoubleDouble::from_full_exact_add(alpha, beta);
+    let ab = DoubleDouble::from_full_exact_sub(beta, alpha);
+    // mu0 = 2^(s + 1) gamma(alpha + 1) gamma(beta + 1) / gamma(s + 2)
+    let mut l_mu0 = DoubleDouble::quick_mult(LN2_DD, DoubleDouble::full_add_f64(s, 1.));
+    l_mu0 = DoubleDouble::add(
+        l_mu0,
+        lgamma_dd(DoubleDouble::from_full_exact_add(alpha, 1.)).0,
+    );
+    l_mu0 = DoubleDouble::add(
+        l_mu0,
+        lgamma_dd(DoubleDouble::from_full_exact_add(beta, 1.)).0,
+    );
+    l_mu0 = DoubleDouble::full_dd_sub(l_mu0, lgamma_dd(DoubleDouble::full_add_f64(s, 2.)).0);
+    gauss_rule(nodes, weights, l_mu0, alpha == beta, |k| {
+        let t = DoubleDouble::full_add_f64(s, 2. * k as f64);
+        let t1 = DoubleDouble::full_add_f64(t, 1.);
+        let t2 = DoubleDouble::full_add_f64(t, 2.);
+        let t3 = DoubleDouble::full_add_f64(t, 3.);
+        let m = (k + 1) as f64;
+        let ma = DoubleDouble::from_full_exact_add(m, alpha);
+        let mb = DoubleDouble::from_full_exact_add(m, beta);
+        // 4 (k + 1) (k + 1 + alpha) (k + 1 + beta)
+        let num = DoubleDouble::quick_mult_f64(DoubleDouble::quick_mult(ma, mb), 4. * m);
+        let t22 = DoubleDouble::quick_mult(t2, t2);
+        if k == 0 {
+            // a_0 = (beta - alpha) / (s + 2),
+            // b_1^2 = 4 (1 + alpha) (1 + beta) / ((s + 2)^2 (s + 3)), (s + 1) cancels
+            let a = DoubleDouble::div(ab, t2);
+            let b = DoubleDouble::div(num, DoubleDouble::quick_mult(t22, t3));
+            return (a, b.sqrt());
+        }
+        // a_k = (beta^2 - alpha^2) / (t (t + 2)),
+        // b_{k+1}^2 = 4 (k + 1) (k + 1 + alpha) (k + 1 + beta) (k + 1 + s) / ((t + 2)^2 (t + 3) (t + 1))
+        let a = DoubleDouble::div(
+            DoubleDouble::quick_mult(ab, s),
+            DoubleDouble::quick_mult(t, t2),
+        );
+        let num = DoubleDouble::quick_mult(num, DoubleDouble::full_add_f64(s, m));
+        let den = DoubleDouble::quick_mult(t22, DoubleDouble::quick_mult(t3, t1));
+        (a, DoubleDouble::div(num, den).sqrt())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauss_legendre() {
+        let mut x = [0.; 5];
+        let mut w = [0.; 5];
+        f_gauss_legendre(&mut x, &mut w);
+        assert_eq!(
+            x,
+            [
+                -0.906179845938664,
+                -0.5384693101056831,
+                0.,
+                0.5384693101056831,
+                0.906179845938664
+            ]
+        );
+        assert_eq!(
+            w,
+            [
+                0.23692688505618908,
+                0.47862867049936647,
+                0.5688888888888889,
+                0.47862867049936647,
+                0.23692688505618908
+            ]
+        );
+        let mut x = [0.; 1];
+        let mut w = [0.; 3];
+        f_gauss_legendre(&mut x, &mut w);
+        assert_eq!(x, [0.]);
+        assert_eq!(w, [2., 0., 0.]);
+    }
+
+    #[test]
+    fn test_gauss_hermite() {
+        let mut x = [0.; 4];
+        let mut w = [0.; 4];
+        f_gauss_hermite(&mut x, &mut w);
+        assert_eq!(
+            x,
+            [
+                -1.6506801238857844,
+                -0.5246476232752904,
+                0.5246476232752904,
+                1.6506801238857844
+            ]
+        );
+        assert_eq!(
+            w,
+            [
+                0.08131283544724517,
+                0.8049140900055128,
+                0.8049140900055128,
+                0.08131283544724517
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gauss_laguerre() {
+        let mut x = [0.; 3];
+        let mut w = [0.; 3];
+        f_gauss_laguerre(0., &mut x, &mut w);
+        assert_eq!(
+            x,
+            [0.4157745567834791, 2.294280360279042, 6.2899450829374794]
+        );
+        assert_eq!(
+            w,
+            [0.711093009929173, 0.27851773356924087, 0.010389256501586135]
+        );
+        f_gauss_laguerre(0.5, &mut x, &mut w);
+        assert_eq!(
+            x,
+            [0.6663259077023708, 2.8007750541502565, 7.032899038147373]
+        );
+        assert_eq!(
+            w,
+            [0.5671862778403113, 0.3053717688445466, 0.01366887876790013]
+        );
+        f_gauss_laguerre(-1., &mut x, &mut w);
+        assert!(x.iter().chain(w.iter()).all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_gauss_jacobi() {
+        let mut x = [0.; 3];
+        let mut w = [0.; 3];
+        f_gauss_jacobi(0.5, -0.5, &mut x, &mut w);
+        assert_eq!(
+            x,
+            [-0.9009688679024191, -0.2225209339563144, 0.6234898018587335]
+        );
+        assert_eq!(
+            w,
+            [1.7063056657443274, 1.0973322242791115, 0.33795476356635434]
+        );
+        let mut x = [0.; 4];
+        let mut w = [0.; 4];
+        f_gauss_jacobi(1., 1., &mut x, &mut w);
+        assert_eq!(
+            x,
+            [
+                -0.7650553239294647,
+                -0.2852315164806451,
+                0.2852315164806451,
+                0.7650553239294647
+            ]
+        );
+        assert_eq!(
+            w,
+            [
+                0.15694991259569396,
+                0.5097167540709727,
+                0.5097167540709727,
+                0.15694991259569396
+            ]
+        );
+        f_gauss_jacobi(0.5, -1.5, &mut x, &mut w);
+        assert!(x.iter().chain(w.iter()).all(|v| v.is_nan()));
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::hypergeometric::{LN2_DD, mul_exp};
+use crate::orthogonal::poly_core::normalize;
+use crate::triangle::f_hypot;
+
+/// Eigenvalues of symmetric tridiagonal matrix by implicit QL with Wilkinson shifts.
+///
+/// d is the diagonal, e[i] couples rows i and i + 1, e[n - 1] is ignored.
+/// Eigenvalues are returned in d sorted ascending, e is destroyed.
+fn tridiagonal_eigenvalues(d: &mut [f64], e: &mut [f64]) {
+    let n = d.len();
+    if n == 0 {
+        return;
+    }
+    e[n - 1] = 0.;
+    for l in 0..n {
+        let mut iter = 0;
+        loop {
+            let mut m = l;
+            while m + 1 < n {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l || iter == 60 {
+                break;
+            }
+            iter += 1;
+            let mut g = (d[l + 1] - d[l]) / (2. * e[l]);
+            let mut r = f_hypot(g, 1.);
+            g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let mut s = 1.;
+            let mut c = 1.;
+            let mut p = 0.;
+            let mut i = m;
+            let mut deflated = false;
+            while i > l {
+                i -= 1;
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f_hypot(f, g);
+                e[i + 1] = r;
+                if r == 0. {
+                    // Recover from underflow
+                    d[i + 1] -= p;
+                    e[m] = 0.;
+                    deflated = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2. * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+            }
+            if deflated {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.;
+        }
+    }
+    d.sort_unstable_by(f64::total_cmp);
+}
+
+/// Evaluates orthonormal recurrence x p_k = b_{k+1} p_{k+1} + a_k p_k + b_k p_{k-1}, p_0 = 1.
+///
+/// Returns (p_n, p_n', p_{n-1}) with common scale 2^e.
+fn orthonormal_eval<F: Fn(usize) -> (DoubleDouble, DoubleDouble)>(
+    n: usize,
+    x: DoubleDouble,
+    coeffs: &F,
+) -> (DoubleDouble, DoubleDouble, DoubleDouble, i64) {
+    let mut p0 = DoubleDouble::default();
+    let mut p1 = DoubleDouble::new(0., 1.);
+    let mut d0 = DoubleDouble::default();
+    let mut d1 = DoubleDouble::default();
+    let mut b0 = DoubleDouble::default();
+    let mut e = 0i64;
+    for k in 0..n {
+        let (a, b) = coeffs(k);
+        let xa = DoubleDouble::full_dd_sub(x, a);
+        // p_{k+1} = ((x - a_k) p_k - b_k p_{k-1}) / b_{k+1}
+        let p2 = DoubleDouble::full_dd_sub(
+            DoubleDouble::quick_mult(xa, p1),
+            DoubleDouble::quick_mult(b0, p0),
+        );
+        let d2 = DoubleDouble::full_dd_sub(
+            DoubleDouble::add(DoubleDouble::quick_mult(xa, d1), p1),
+            DoubleDouble::quick_mult(b0, d0),
+        );
+        p0 = p1;
+        d0 = d1;
+        p1 = DoubleDouble::div(p2, b);
+        d1 = DoubleDouble::div(d2, b);
+        b0 = b;
+        let m = p1.hi.abs().max(d1.hi.abs());
+        if m > f64::from_bits(0x5ff0000000000000) {
+            let (_, k) = normalize(DoubleDouble::new(0., m));
+            let s = f64::from_bits(((1023 - k) as u64) << 52);
+            p0 = DoubleDouble::quick_mult_f64(p0, s);
+            p1 = DoubleDouble::quick_mult_f64(p1, s);
+            d0 = DoubleDouble::quick_mult_f64(d0, s);
+            d1 = DoubleDouble::quick_mult_f64(d1, s);
+            e += k;
+        }
+    }
+    (p1, d1, p0, e)
+}
+
+/// Computes n-point Gauss rule for the weight function with Jacobi matrix coefficients
+/// `coeffs(k)` = (a_k, b_{k+1}) and log of total mass `l_mu0`, n = min(nodes.len(), weights.len()).
+///
+/// Eigenvalues of Jacobi matrix are refined by Newton iteration in double-double,
+/// weights are mu0 / (b_n p_n'(x) p_{n-1}(x)) from Christoffel-Darboux identity.
+/// For symmetric weight functions only the lower half is refined and then mirrored,
+/// so that the middle node of odd rules is exactly zero.
+pub(crate) fn gauss_rule<F: Fn(usize) -> (DoubleDouble, DoubleDouble)>(
+    nodes: &mut [f64],
+    weights: &mut [f64],
+    l_mu0: DoubleDouble,
+    symmetric: bool,
+    coeffs: F,
+) {
+    let n = nodes.len().min(weights.len());
+    let nodes = &mut nodes[..n];
+    let weights = &mut weights[..n];
+    for (k, (d, e)) in nodes.iter_mut().zip(weights.iter_mut()).enumerate() {
+        let (a, b) = coeffs(k);
+        *d = a.hi;
+        *e = b.hi;
+    }
+    tridiagonal_eigenvalues(nodes, weights);
+    let bn = if n > 0 {
+        coeffs(n - 1).1
+    } else {
+        DoubleDouble::default()
+    };
+    let m = if symmetric { n.div_ceil(2) } else { n };
+    for (i, (x0, w)) in nodes.iter_mut().zip(weights.iter_mut()).take(m).enumerate() {
+        let mut x = DoubleDouble::new(0., *x0);
+        if symmetric && 2 * i + 1 == n {
+            x = DoubleDouble::default();
+        }
+        let mut r = orthonormal_eval(n, x, &coeffs);
+        if x.hi != 0. {
+            for _ in 0..10 {
+                let dx = DoubleDouble::div(r.0, r.1);
+                x = DoubleDouble::full_dd_sub(x, dx);
+                r = orthonormal_eval(n, x, &coeffs);
+                if dx.hi.abs() <= f64::from_bits(0x39b0000000000000) * x.hi.abs() {
+                    break;
+                }
+            }
+        }
+        *x0 = x.to_f64();
+        // Factors are normalized separately, their product may overflow
+        let (d, ed) = normalize(r.1);
+        let (p, ep) = normalize(r.2);
+        let (q, eq) = normalize(DoubleDouble::quick_mult(DoubleDouble::quick_mult(d, p), bn));
+        let l = DoubleDouble::mul_f64_add(LN2_DD, -((eq + ed + ep + 2 * r.3) as f64), l_mu0);
+        *w = mul_exp(q.recip(), l);
+    }
+    if symmetric {
+        for i in 0..n / 2 {
+            nodes[n - 1 - i] = -nodes[i];
+            weights[n - 1 - i] = weights[i];
+        }
+    }
+}
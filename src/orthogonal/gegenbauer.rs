@@ -0,0 +1,74 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::orthogonal::poly_core::{recurrence, scaled_to_f64};
+
+/// Computes Gegenbauer (ultraspherical) polynomial C_n^(alpha)(x)
+///
+/// For alpha = 0 polynomials of positive degree vanish.
+///
+/// Max found error ~0.5 ulp, except very close to zeros of C_n^(alpha).
+pub fn f_gegenbauer(n: u32, alpha: f64, x: f64) -> f64 {
+    if x.is_nan() || !alpha.is_finite() {
+        return f64::NAN;
+    }
+    // (k + 1) C_{k+1} = 2 (k + alpha) x C_k - (k + 2 alpha - 1) C_{k-1}
+    let (v, e) = recurrence(n, x, DoubleDouble::new(0., 1.), |k| {
+        let k = k as f64;
+        let d = k + 1.;
+        let a = DoubleDouble::from_full_exact_add(k, alpha);
+        let c = DoubleDouble::from_full_exact_add(k - 1., 2. * alpha);
+        (
+            DoubleDouble::div_dd_f64(DoubleDouble::quick_mult_f64(a, 2.), d),
+            DoubleDouble::default(),
+            DoubleDouble::div_dd_f64(c, d),
+        )
+    });
+    scaled_to_f64(v, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gegenbauer() {
+        assert_eq!(f_gegenbauer(0, 0.5, 0.3), 1.);
+        assert_eq!(f_gegenbauer(1, 1.5, 0.3), 0.8999999999999999);
+        assert_eq!(f_gegenbauer(2, 0.5, 0.5), -0.125);
+        assert_eq!(f_gegenbauer(5, 1., 0.25), 1.03125);
+        assert_eq!(f_gegenbauer(10, 2.5, -0.75), 17.79222597554326);
+        assert_eq!(f_gegenbauer(30, 0.25, 0.1), 0.03578288332945042);
+        assert_eq!(f_gegenbauer(100, 10., 0.6), 24213923535.308025);
+        assert_eq!(f_gegenbauer(3, 0., 0.5), 0.);
+        assert_eq!(f_gegenbauer(6, -2.5, 0.4), 0.18522);
+        assert_eq!(f_gegenbauer(8, 1.5, 3.), 4637205.);
+        assert!(f_gegenbauer(4, 1., f64::NAN).is_nan());
+    }
+}
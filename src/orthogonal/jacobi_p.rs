@@ -0,0 +1,149 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::orthogonal::poly_core::{recurrence, scaled_to_f64};
+
+/// Evaluates P_n^(alpha, beta)(x) as explicit sum
+/// sum (alpha + j + 1)_(n - j) (n + alpha + beta + 1)_j / (j! (n - j)!) ((x - 1) / 2)^j.
+///
+/// Used when recurrence denominators vanish, intermediates are not rescaled.
+fn jacobi_sum(n: u32, alpha: f64, s: DoubleDouble, x: f64) -> f64 {
+    let z = DoubleDouble::quick_mult_f64(DoubleDouble::from_full_exact_sub(x, 1.), 0.5);
+    // (alpha + j + 1)_(n - j) vanishes for j < -alpha when alpha is a negative integer
+    let j0 = if alpha == alpha.round() && alpha < 0. && -alpha <= n as f64 {
+        -alpha as u32
+    } else {
+        0
+    };
+    let mut t = DoubleDouble::new(0., 1.);
+    for i in (j0 + 1)..=n {
+        t = DoubleDouble::quick_mult(t, DoubleDouble::from_full_exact_add(alpha, i as f64));
+        t = DoubleDouble::div_dd_f64(t, (i - j0) as f64);
+    }
+    let a = DoubleDouble::full_add_f64(s, n as f64 + 1.);
+    for i in 0..j0 {
+        t = DoubleDouble::quick_mult(t, DoubleDouble::full_add_f64(a, i as f64));
+        t = DoubleDouble::div_dd_f64(DoubleDouble::quick_mult(t, z), (i + 1) as f64);
+    }
+    let mut r = t;
+    for j in j0..n {
+        // t_{j+1} / t_j = (n - j) (n + s + 1 + j) z / ((alpha + j + 1) (j + 1))
+        let num =
+            DoubleDouble::quick_mult_f64(DoubleDouble::full_add_f64(a, j as f64), (n - j) as f64);
+        let den = DoubleDouble::quick_mult_f64(
+            DoubleDouble::from_full_exact_add(alpha, j as f64 + 1.),
+            j as f64 + 1.,
+        );
+        t = DoubleDouble::div(
+            DoubleDouble::quick_mult(DoubleDouble::quick_mult(t, num), z),
+            den,
+        );
+        r = DoubleDouble::add(r, t);
+    }
+    r.to_f64()
+}
+
+/// Computes Jacobi polynomial P_n^(alpha, beta)(x)
+///
+/// Max found error ~0.5 ulp for alpha, beta > -1, except very close to zeros of P_n^(alpha, beta).
+pub fn f_jacobi_p(n: u32, alpha: f64, beta: f64, x: f64) -> f64 {
+    if x.is_nan() || !alpha.is_finite() || !beta.is_finite() {
+        return f64::NAN;
+    }
+    let s = DoubleDouble::from_full_exact_add(alpha, beta);
+    let sr = s.hi.round();
+    let ds = (s.hi - sr) + s.lo;
+    if n >= 2
+        && ds.abs() < f64::from_bits(0x3e10000000000000)
+        && sr <= -2.
+        && sr >= -2. * (n as f64 - 1.)
+    {
+        // Recurrence denominators vanish or nearly vanish for some k.
+        // P_n^(alpha, beta)(x) = (-1)^n P_n^(beta, alpha)(-x) keeps |(x - 1) / 2| <= 1/2 on [-1, 1]
+        if x < 0. {
+            let r = jacobi_sum(n, beta, s, -x);
+            return if n & 1 != 0 { -r } else { r };
+        }
+        return jacobi_sum(n, alpha, s, x);
+    }
+    let ab = DoubleDouble::from_full_exact_sub(alpha, beta);
+    let (v, e) = recurrence(n, x, DoubleDouble::new(0., 1.), |k| {
+        if k == 0 {
+            // P_1 = (alpha + beta + 2) / 2 x + (alpha - beta) / 2
+            return (
+                DoubleDouble::quick_mult_f64(DoubleDouble::full_add_f64(s, 2.), 0.5),
+                DoubleDouble::quick_mult_f64(ab, 0.5),
+                DoubleDouble::default(),
+            );
+        }
+        // 2 (k + 1) (k + s + 1) t P_{k+1} = (t + 1) ((t + 2) t x + (alpha^2 - beta^2)) P_k
+        //      - 2 (k + alpha) (k + beta) (t + 2) P_{k-1}, t = 2k + s
+        let k = k as f64;
+        let t = DoubleDouble::full_add_f64(s, 2. * k);
+        let d = DoubleDouble::quick_mult_f64(DoubleDouble::full_add_f64(s, k + 1.), 2. * (k + 1.));
+        let t1 = DoubleDouble::full_add_f64(t, 1.);
+        let t2 = DoubleDouble::full_add_f64(t, 2.);
+        let a = DoubleDouble::div(DoubleDouble::quick_mult(t1, t2), d);
+        let b = DoubleDouble::div(
+            DoubleDouble::quick_mult(DoubleDouble::quick_mult(t1, ab), s),
+            DoubleDouble::quick_mult(d, t),
+        );
+        let ka = DoubleDouble::from_full_exact_add(k, alpha);
+        let kb = DoubleDouble::from_full_exact_add(k, beta);
+        let c = DoubleDouble::div(
+            DoubleDouble::quick_mult_f64(
+                DoubleDouble::quick_mult(DoubleDouble::quick_mult(ka, kb), t2),
+                2.,
+            ),
+            DoubleDouble::quick_mult(d, t),
+        );
+        (a, b, c)
+    });
+    scaled_to_f64(v, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jacobi_p() {
+        assert_eq!(f_jacobi_p(0, 0.5, 1.5, 0.3), 1.);
+        assert_eq!(f_jacobi_p(1, 0.5, 1.5, 0.3), 0.09999999999999998);
+        assert_eq!(f_jacobi_p(2, 1., 2., -0.5), 1.3125);
+        assert_eq!(f_jacobi_p(5, 0.5, -0.5, 0.25), 0.3306884765625);
+        assert_eq!(f_jacobi_p(10, 2.5, 3.5, 0.7), 2.679906579591308);
+        assert_eq!(f_jacobi_p(30, -0.5, 0.75, -0.2), -0.11838485085207169);
+        assert_eq!(f_jacobi_p(100, 10., 20., 0.1), -292.83827737307394);
+        assert_eq!(f_jacobi_p(6, -1.5, -0.5, 0.4), -0.028540312499999984);
+        assert_eq!(f_jacobi_p(28, -6., -6., -0.9), -9.757829030629322e-07);
+        assert_eq!(f_jacobi_p(4, 2., 3., 5.), 25715.);
+        assert!(f_jacobi_p(3, 1., 1., f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,212 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::orthogonal::poly_core::{normalize, recurrence, scaled_to_f64};
+
+/// Computes P_l^m(x) for 0 <= m <= l, |x| <= 1 as v * 2^e.
+fn assoc_legendre_scaled(l: u32, m: u32, x: f64) -> (DoubleDouble, i64) {
+    // P_m^m = (-1)^m (2m - 1)!! (1 - x^2)^(m/2)
+    let w = DoubleDouble::quick_mult(
+        DoubleDouble::from_full_exact_sub(1., x),
+        DoubleDouble::from_full_exact_add(1., x),
+    )
+    .sqrt();
+    let mut p = DoubleDouble::new(0., 1.);
+    let mut e = 0i64;
+    for i in 1..=m {
+        p = DoubleDouble::quick_mult(p, w);
+        p = DoubleDouble::quick_mult_f64(p, -(2. * i as f64 - 1.));
+        let a = p.hi.abs();
+        if a > f64::from_bits(0x5ff0000000000000) || a < f64::from_bits(0x1ff0000000000000) {
+            let (v, k) = normalize(p);
+            p = v;
+            e += k;
+        }
+    }
+    // (l - m + 1) P_{l+1}^m = (2l + 1) x P_l^m - (l + m) P_{l-1}^m
+    let (v, ev) = recurrence(l - m, x, p, |k| {
+        let j = m as f64 + k as f64;
+        let d = k as f64 + 1.;
+        (
+            DoubleDouble::from_exact_div(2. * j + 1., d),
+            DoubleDouble::default(),
+            DoubleDouble::from_exact_div(j + m as f64, d),
+        )
+    });
+    (v, e + ev)
+}
+
+/// Computes (l + m)! / (l - m)! for m <= l as v * 2^e.
+fn factorial_ratio(l: u32, m: u32) -> (DoubleDouble, i64) {
+    let mut p = DoubleDouble::new(0., 1.);
+    let mut e = 0i64;
+    for j in (l as u64 - m as u64 + 1)..=(l as u64 + m as u64) {
+        p = DoubleDouble::quick_mult_f64(p, j as f64);
+        if p.hi > f64::from_bits(0x5ff0000000000000) {
+            let (v, k) = normalize(p);
+            p = v;
+            e += k;
+        }
+    }
+    (p, e)
+}
+
+/// Computes Legendre polynomial P_n(x)
+///
+/// Max found error ~0.5 ulp, except very close to zeros of P_n.
+pub fn f_legendre_p(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    // (k + 1) P_{k+1} = (2k + 1) x P_k - k P_{k-1}
+    let (v, e) = recurrence(n, x, DoubleDouble::new(0., 1.), |k| {
+        let k = k as f64;
+        (
+            DoubleDouble::from_exact_div(2. * k + 1., k + 1.),
+            DoubleDouble::default(),
+            DoubleDouble::from_exact_div(k, k + 1.),
+        )
+    });
+    scaled_to_f64(v, e)
+}
+
+/// Computes associated Legendre function P_l^m(x) including Condon-Shortley phase (-1)^m
+///
+/// Negative orders are given by P_l^-m = (-1)^m (l - m)! / (l + m)! P_l^m.
+/// Returns 0 for |m| > l and NaN for |x| > 1.
+///
+/// Max found error ~0.5 ulp, except very close to zeros of P_l^m.
+pub fn f_assoc_legendre(l: u32, m: i32, x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1. {
+        return f64::NAN;
+    }
+    let am = m.unsigned_abs();
+    if am > l {
+        return 0.;
+    }
+    let (v, e) = assoc_legendre_scaled(l, am, x);
+    if m >= 0 {
+        return scaled_to_f64(v, e);
+    }
+    let (f, ef) = factorial_ratio(l, am);
+    let mut r = DoubleDouble::div(v, f);
+    if am & 1 != 0 {
+        r = -r;
+    }
+    scaled_to_f64(r, e - ef)
+}
+
+/// Computes spherical Legendre function
+/// sqrt((2l + 1) / (4 pi) * (l - m)! / (l + m)!) * P_l^m(x)
+///
+/// This is spherical harmonic Y_l^m(theta, 0) for x = cos(theta), including Condon-Shortley phase.
+/// Negative orders are given by (-1)^m times the value of order -m.
+/// Returns 0 for |m| > l and NaN for |x| > 1.
+///
+/// Max found error ~0.5 ulp, except very close to zeros.
+pub fn f_sph_legendre(l: u32, m: i32, x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1. {
+        return f64::NAN;
+    }
+    let am = m.unsigned_abs();
+    if am > l {
+        return 0.;
+    }
+    const INV_4PI: DoubleDouble =
+        DoubleDouble::from_bit_pair((0xbc56b01ec5417056, 0x3fb45f306dc9c883));
+    let (v, e) = assoc_legendre_scaled(l, am, x);
+    let (mut f, mut ef) = factorial_ratio(l, am);
+    if ef & 1 != 0 {
+        f = DoubleDouble::quick_mult_f64(f, 2.);
+        ef -= 1;
+    }
+    let q = DoubleDouble::div(DoubleDouble::quick_mult_f64(INV_4PI, 2. * l as f64 + 1.), f);
+    let mut r = DoubleDouble::quick_mult(v, q.sqrt());
+    if m < 0 && am & 1 != 0 {
+        r = -r;
+    }
+    scaled_to_f64(r, e - ef / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legendre_p() {
+        assert_eq!(f_legendre_p(0, 0.3), 1.);
+        assert_eq!(f_legendre_p(1, -0.7), -0.7);
+        assert_eq!(f_legendre_p(2, 0.5), -0.125);
+        assert_eq!(f_legendre_p(5, 0.25), 0.3397216796875);
+        assert_eq!(f_legendre_p(10, -0.999), 0.9457382231145026);
+        assert_eq!(f_legendre_p(30, 0.1234), 0.11698856684264108);
+        assert_eq!(f_legendre_p(100, 0.7), -0.07713250719977878);
+        assert_eq!(f_legendre_p(1000, -0.33), -0.02463361273490518);
+        assert_eq!(f_legendre_p(25, 1.), 1.);
+        assert_eq!(f_legendre_p(7, -1.), -1.);
+        assert_eq!(f_legendre_p(4, 3.5), 610.9609375);
+        assert_eq!(f_legendre_p(50, -100000.), 8.960951484901959e263);
+        assert_eq!(f_legendre_p(3, 1e-300), -1.5e-300);
+        assert_eq!(f_legendre_p(2, 1e200), f64::INFINITY);
+        assert_eq!(f_legendre_p(3, f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(f_legendre_p(1, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_assoc_legendre() {
+        assert_eq!(f_assoc_legendre(0, 0, 0.5), 1.);
+        assert_eq!(f_assoc_legendre(1, 1, 0.5), -0.8660254037844386);
+        assert_eq!(f_assoc_legendre(2, 1, -0.3), 0.8585452812752511);
+        assert_eq!(f_assoc_legendre(3, -2, 0.75), 0.041015625);
+        assert_eq!(f_assoc_legendre(5, 3, 0.1), 47.06016955969108);
+        assert_eq!(f_assoc_legendre(10, -7, -0.6), -4.681142857142857e-08);
+        assert_eq!(f_assoc_legendre(20, 20, 0.9), 1.960904971202385e16);
+        assert_eq!(f_assoc_legendre(150, 100, 0.25), 7.949290721933197e212);
+        assert_eq!(f_assoc_legendre(400, -300, -0.8), 0.);
+        assert_eq!(f_assoc_legendre(5, 6, 0.2), 0.);
+        assert_eq!(f_assoc_legendre(3, 1, 1.), 0.);
+        assert_eq!(f_assoc_legendre(4, 0, -1.), 1.);
+        assert!(f_assoc_legendre(2, 1, 1.5).is_nan());
+    }
+
+    #[test]
+    fn test_sph_legendre() {
+        assert_eq!(f_sph_legendre(0, 0, 0.5), 0.28209479177387814);
+        assert_eq!(f_sph_legendre(1, 0, 0.5), 0.24430125595145996);
+        assert_eq!(f_sph_legendre(1, 1, 0.5), -0.2992067103010745);
+        assert_eq!(f_sph_legendre(2, -1, -0.3), -0.22108926228358164);
+        assert_eq!(f_sph_legendre(5, 3, 0.1), 0.31009847773638954);
+        assert_eq!(f_sph_legendre(10, -7, -0.6), -0.46592417263733027);
+        assert_eq!(f_sph_legendre(200, 150, 0.25), 0.06416033385313354);
+        assert_eq!(f_sph_legendre(1000, -600, 0.7), 0.12168894795508325);
+        assert_eq!(f_sph_legendre(2000, 2000, 0.5), 2.3077257530206467e-125);
+        assert_eq!(f_sph_legendre(5, 6, 0.2), 0.);
+        assert!(f_sph_legendre(2, 1, -1.5).is_nan());
+    }
+}
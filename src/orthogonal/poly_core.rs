@@ -0,0 +1,147 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::ldexp;
+
+#[inline]
+fn scale(v: DoubleDouble, k: i64) -> DoubleDouble {
+    DoubleDouble::new(ldexp(v.lo, k as i32), ldexp(v.hi, k as i32))
+}
+
+/// Splits v into (v * 2^-k, k) with |v.hi * 2^-k| in [1, 2).
+pub(crate) fn normalize(v: DoubleDouble) -> (DoubleDouble, i64) {
+    if v.hi == 0. || !v.hi.is_finite() {
+        return (v, 0);
+    }
+    let mut k = ((v.hi.to_bits() >> 52) & 0x7ff) as i64 - 1023;
+    if k == -1023 {
+        // subnormal, take exponent of v * 2^64
+        k = (((v.hi * f64::from_bits(0x43f0000000000000)).to_bits() >> 52) & 0x7ff) as i64
+            - 1023
+            - 64;
+    }
+    (scale(v, -k), k)
+}
+
+/// Moves common binary exponent of p0 and p1 into e when they leave [2^-256, 2^256].
+#[inline]
+fn rescale(p0: &mut DoubleDouble, p1: &mut DoubleDouble, e: &mut i64) {
+    let m = p0.hi.abs().max(p1.hi.abs());
+    if m > f64::from_bits(0x5ff0000000000000) || (m < f64::from_bits(0x1ff0000000000000) && m != 0.)
+    {
+        let (_, k) = normalize(DoubleDouble::new(0., m));
+        *p0 = scale(*p0, -k);
+        *p1 = scale(*p1, -k);
+        *e += k;
+    }
+}
+
+/// Computes x^n as v * 2^e for finite nonzero x.
+fn pow_scaled(x: f64, n: u32) -> (DoubleDouble, i64) {
+    let (mut b, mut eb) = normalize(DoubleDouble::new(0., x));
+    let mut r = DoubleDouble::new(0., 1.);
+    let mut er = 0i64;
+    let mut k = n;
+    loop {
+        if k & 1 != 0 {
+            let (v, ev) = normalize(DoubleDouble::quick_mult(r, b));
+            r = v;
+            er += eb + ev;
+        }
+        k >>= 1;
+        if k == 0 {
+            break;
+        }
+        let (v, ev) = normalize(DoubleDouble::quick_mult(b, b));
+        b = v;
+        eb = 2 * eb + ev;
+    }
+    (r, er)
+}
+
+/// Evaluates p_n(x) from p_0 and the three-term recurrence
+/// p_{k+1} = (a_k * x + b_k) * p_k - c_k * p_{k-1}, p_{-1} = 0,
+/// where `coeffs(k)` returns (a_k, b_k, c_k).
+///
+/// For |x| > 1 recurrence runs on p_k / x^k, so intermediates do not overflow
+/// for huge x. Result is returned as v * 2^e.
+pub(crate) fn recurrence<F: FnMut(u32) -> (DoubleDouble, DoubleDouble, DoubleDouble)>(
+    n: u32,
+    x: f64,
+    p0: DoubleDouble,
+    mut coeffs: F,
+) -> (DoubleDouble, i64) {
+    let big = x.abs() > 1.;
+    let (rx, rx2) = if !big || x.is_infinite() {
+        (DoubleDouble::default(), DoubleDouble::default())
+    } else {
+        let r = DoubleDouble::from_recip(x);
+        (r, DoubleDouble::quick_mult(r, r))
+    };
+    let mut q0 = DoubleDouble::default();
+    let mut q1 = p0;
+    let mut e = 0i64;
+    for k in 0..n {
+        let (a, b, c) = coeffs(k);
+        let q2 = if big {
+            // q_{k+1} = (a_k + b_k / x) * q_k - c_k / x^2 * q_{k-1}
+            let t = DoubleDouble::mul_add(b, rx, a);
+            let w = DoubleDouble::quick_mult(DoubleDouble::quick_mult(c, rx2), q0);
+            DoubleDouble::full_dd_sub(DoubleDouble::quick_mult(t, q1), w)
+        } else {
+            let t = DoubleDouble::mul_f64_add(a, x, b);
+            let w = DoubleDouble::quick_mult(c, q0);
+            DoubleDouble::full_dd_sub(DoubleDouble::quick_mult(t, q1), w)
+        };
+        q0 = q1;
+        q1 = q2;
+        rescale(&mut q0, &mut q1, &mut e);
+    }
+    if !big || n == 0 {
+        return (q1, e);
+    }
+    if x.is_infinite() {
+        // q_n is the leading coefficient
+        let xn = if n & 1 != 0 { x } else { f64::INFINITY };
+        return (DoubleDouble::new(0., q1.hi * xn), 0);
+    }
+    let (xn, ex) = pow_scaled(x, n);
+    let (v, ev) = normalize(DoubleDouble::quick_mult(q1, xn));
+    (v, e + ex + ev)
+}
+
+/// Rounds v * 2^e to f64.
+#[inline]
+pub(crate) fn scaled_to_f64(v: DoubleDouble, e: i64) -> f64 {
+    let r = v.to_f64();
+    if e == 0 {
+        return r;
+    }
+    ldexp(r, e.clamp(-2200, 2200) as i32)
+}
@@ -0,0 +1,201 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::sincos_reduce::frac2pi_fixed;
+
+/// Payne-Hanek reduction of finite |x| > pi/4 (x is taken as positive).
+///
+/// Returns q = round(x * 2/pi) mod 4 and r = x - q * pi/2 as DyadicFloat128,
+/// |r| <= pi/4, which is accurate to ~2^-127 relative.
+fn rem_pio2_f128(x: f64) -> (i32, DyadicFloat128) {
+    const PI_OVER_2_F128: DyadicFloat128 = DyadicFloat128 {
+        sign: DyadicSign::Pos,
+        exponent: -127,
+        mantissa: 0xc90f_daa2_2168_c234_c4c6_628b_80dc_1cd1_u128,
+    };
+    let ax = x.abs();
+    // 192 bits below the binary point of x * 2/pi mod 4:
+    // 2 bits of the quadrant and 190 bits of frac(x * 2/pi)
+    let (mut t2, mut t1, mut t0) = if ax >= 2. {
+        // x/(2pi) mod 2, the integer bit is dropped
+        let (hi, lo, tiny) = frac2pi_fixed(DyadicFloat128::new_from_f64(ax));
+        ((hi << 1) | (lo >> 63), (lo << 1) | (tiny >> 63), tiny << 1)
+    } else {
+        // 4x/(2pi) mod 2 = x * 2/pi, its integer bit is the quadrant
+        let (hi, lo, tiny) = frac2pi_fixed(DyadicFloat128::new_from_f64(4. * ax));
+        (hi >> 1, (hi << 63) | (lo >> 1), (lo << 63) | (tiny >> 1))
+    };
+    let mut q = (t2 >> 62) as i32;
+    t2 &= (1u64 << 62) - 1;
+    let mut sign = DyadicSign::Pos;
+    if t2 >> 61 != 0 {
+        // frac >= 1/2, take (frac - 1) for the symmetric range
+        q += 1;
+        let (n0, c0) = (!t0).overflowing_add(1);
+        let (n1, c1) = (!t1).overflowing_add(c0 as u64);
+        t0 = n0;
+        t1 = n1;
+        t2 = (!t2).wrapping_add(c1 as u64) & ((1u64 << 62) - 1);
+        sign = DyadicSign::Neg;
+    }
+    let a = ((t2 as u128) << 64) | t1 as u128;
+    let lz = if a != 0 {
+        a.leading_zeros()
+    } else {
+        128 + t0.leading_zeros()
+    };
+    if lz >= 192 {
+        return (q & 3, DyadicFloat128::zero());
+    }
+    let mantissa = if lz < 64 {
+        (a << lz) | (t0 >> (64 - lz)) as u128
+    } else if lz < 128 {
+        (a << lz) | ((t0 as u128) << (lz - 64))
+    } else {
+        (t0 as u128) << (lz - 64)
+    };
+    // frac = mantissa * 2^(64 - 190 - lz)
+    let frac = DyadicFloat128::new(sign, -126 - lz as i16, mantissa);
+    (q & 3, frac * PI_OVER_2_F128)
+}
+
+/// Reduces argument by pi/2 for any finite input
+///
+/// Returns `(q, hi, lo)` where `hi + lo = x - k * pi/2` for the nearest integer `k`,
+/// `|hi + lo| <= pi/4`, and `q = k mod 4` is the quadrant.
+/// The reduced argument is accurate to about 2^-105 relative.
+/// For infinities and NaN returns `(0, NaN, NaN)`.
+pub fn f_rem_pio2(x: f64) -> (i32, f64, f64) {
+    let ax = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    if ax >= 0x7ff0_0000_0000_0000 {
+        // Inf or NaN
+        return (0, x + f64::NAN, x + f64::NAN);
+    }
+    if ax <= 0x3fe921fb54442d18 {
+        // |x| <= pi/4
+        return (0, x, 0.);
+    }
+    let (q, r) = rem_pio2_f128(x);
+    let hi = r.fast_as_f64();
+    let lo = (r - DyadicFloat128::new_from_f64(hi)).fast_as_f64();
+    if x.is_sign_negative() {
+        ((4 - q) & 3, -hi, -lo)
+    } else {
+        (q, hi, lo)
+    }
+}
+
+/// Reduces argument by pi/2 for any finite input
+///
+/// Returns `(q, r)` where `r = x - k * pi/2` rounded to double precision for
+/// the nearest integer `k`, `|r| <= pi/4`, and `q = k mod 4` is the quadrant.
+/// For infinities and NaN returns `(0, NaN)`.
+pub fn f_rem_pio2f(x: f32) -> (i32, f64) {
+    let (q, hi, _) = f_rem_pio2(x as f64);
+    (q, hi)
+}
+
+/// Computes x mod 2*PI in [-PI, PI] for any finite input
+///
+/// Returns reduced argument as a double-double `(hi, lo)`,
+/// accurate to about 2^-104 relative.
+/// For infinities and NaN returns `(NaN, NaN)`.
+pub fn f_rem2pi(x: f64) -> (f64, f64) {
+    // pi/2 = PI_OVER_2.hi + PI_OVER_2.lo
+    const PI_OVER_2: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0x3c91a62633145c07),
+        f64::from_bits(0x3ff921fb54442d18),
+    );
+    if (x.to_bits() & 0x7fff_ffff_ffff_ffff) <= 0x400921fb54442d18 {
+        // |x| <= pi
+        return (x, 0.);
+    }
+    let (q, hi, lo) = f_rem_pio2(x);
+    if q == 0 {
+        return (hi, lo);
+    }
+    // x mod 2pi = q * pi/2 + r, shift by 2pi for q = 3 and for q = 2 with r > 0
+    let k = match q {
+        1 => 1.,
+        2 if hi > 0. => -2.,
+        2 => 2.,
+        _ => -1.,
+    };
+    let r = DoubleDouble::from_exact_add(hi, lo);
+    let z = DoubleDouble::quick_dd_add(DoubleDouble::quick_mult_f64(PI_OVER_2, k), r);
+    (z.hi, z.lo)
+}
+
+/// Computes x mod 2*PI in [-PI, PI] for any finite input
+///
+/// Returns reduced argument rounded to double precision.
+/// For infinities and NaN returns NaN.
+pub fn f_rem2pif(x: f32) -> f64 {
+    let (hi, _) = f_rem2pi(x as f64);
+    hi
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rem_pio2() {
+        assert_eq!(f_rem_pio2(0.5), (0, 0.5, 0.));
+        assert_eq!(
+            f_rem_pio2(std::f64::consts::FRAC_PI_2),
+            (1, -6.123233995736766e-17, 1.4973849048591698e-33)
+        );
+        assert_eq!(
+            f_rem_pio2(-std::f64::consts::PI),
+            (2, 1.2246467991473532e-16, -2.9947698097183397e-33)
+        );
+        assert_eq!(
+            f_rem_pio2(1e22),
+            (3, 0.5506189342358097, -7.985621383147488e-18)
+        );
+        assert_eq!(
+            f_rem_pio2(f64::MAX),
+            (2, -0.004961975150787273, -3.656438180407946e-19)
+        );
+        assert!(f_rem_pio2(f64::INFINITY).1.is_nan());
+        assert_eq!(f_rem_pio2f(3.), (2, -0.14159265358979323));
+    }
+
+    #[test]
+    fn test_rem2pi() {
+        assert_eq!(f_rem2pi(1.), (1., 0.));
+        assert_eq!(f_rem2pi(7.).0, 0.7168146928204135);
+        assert_eq!(f_rem2pi(-4.).0, 2.2831853071795867);
+        assert_eq!(f_rem2pi(1e22).0, -1.020177392559087);
+        assert_eq!(f_rem2pif(100.), -0.5309649148733836);
+        assert!(f_rem2pi(f64::NAN).0.is_nan());
+    }
+}
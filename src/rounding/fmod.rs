@@ -0,0 +1,230 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+/// Computes floating-point remainder of x/y rounded towards zero
+///
+/// The result is exact and has the sign of x.
+#[inline]
+pub const fn fmod(x: f64, y: f64) -> f64 {
+    let mut uxi = x.to_bits();
+    let mut uyi = y.to_bits();
+    let mut ex = ((uxi >> 52) & 0x7ff) as i64;
+    let mut ey = ((uyi >> 52) & 0x7ff) as i64;
+    let sx = uxi >> 63;
+
+    if uyi << 1 == 0 || y.is_nan() || ex == 0x7ff {
+        return if x.is_nan() || y.is_nan() {
+            x + y
+        } else {
+            f64::NAN
+        };
+    }
+    if uxi << 1 <= uyi << 1 {
+        if uxi << 1 == uyi << 1 {
+            return 0. * x;
+        }
+        return x;
+    }
+
+    /* normalize x and y */
+    if ex == 0 {
+        let mut i = uxi << 12;
+        while i >> 63 == 0 {
+            ex -= 1;
+            i <<= 1;
+        }
+        uxi <<= -ex + 1;
+    } else {
+        uxi &= u64::MAX >> 12;
+        uxi |= 1u64 << 52;
+    }
+    if ey == 0 {
+        let mut i = uyi << 12;
+        while i >> 63 == 0 {
+            ey -= 1;
+            i <<= 1;
+        }
+        uyi <<= -ey + 1;
+    } else {
+        uyi &= u64::MAX >> 12;
+        uyi |= 1u64 << 52;
+    }
+
+    /* x mod y */
+    while ex > ey {
+        let i = uxi.wrapping_sub(uyi);
+        if i >> 63 == 0 {
+            if i == 0 {
+                return 0. * x;
+            }
+            uxi = i;
+        }
+        uxi <<= 1;
+        ex -= 1;
+    }
+    let i = uxi.wrapping_sub(uyi);
+    if i >> 63 == 0 {
+        if i == 0 {
+            return 0. * x;
+        }
+        uxi = i;
+    }
+    while uxi >> 52 == 0 {
+        uxi <<= 1;
+        ex -= 1;
+    }
+
+    /* scale result */
+    if ex > 0 {
+        uxi -= 1u64 << 52;
+        uxi |= (ex as u64) << 52;
+    } else {
+        uxi >>= -ex + 1;
+    }
+    uxi |= sx << 63;
+    f64::from_bits(uxi)
+}
+
+/// Computes floating-point remainder of x/y rounded towards zero
+///
+/// The result is exact and has the sign of x.
+#[inline]
+pub const fn fmodf(x: f32, y: f32) -> f32 {
+    let mut uxi = x.to_bits();
+    let mut uyi = y.to_bits();
+    let mut ex = ((uxi >> 23) & 0xff) as i32;
+    let mut ey = ((uyi >> 23) & 0xff) as i32;
+    let sx = uxi & 0x8000_0000;
+
+    if uyi << 1 == 0 || y.is_nan() || ex == 0xff {
+        return if x.is_nan() || y.is_nan() {
+            x + y
+        } else {
+            f32::NAN
+        };
+    }
+    if uxi << 1 <= uyi << 1 {
+        if uxi << 1 == uyi << 1 {
+            return 0. * x;
+        }
+        return x;
+    }
+
+    /* normalize x and y */
+    if ex == 0 {
+        let mut i = uxi << 9;
+        while i >> 31 == 0 {
+            ex -= 1;
+            i <<= 1;
+        }
+        uxi <<= -ex + 1;
+    } else {
+        uxi &= u32::MAX >> 9;
+        uxi |= 1u32 << 23;
+    }
+    if ey == 0 {
+        let mut i = uyi << 9;
+        while i >> 31 == 0 {
+            ey -= 1;
+            i <<= 1;
+        }
+        uyi <<= -ey + 1;
+    } else {
+        uyi &= u32::MAX >> 9;
+        uyi |= 1u32 << 23;
+    }
+
+    /* x mod y */
+    while ex > ey {
+        let i = uxi.wrapping_sub(uyi);
+        if i >> 31 == 0 {
+            if i == 0 {
+                return 0. * x;
+            }
+            uxi = i;
+        }
+        uxi <<= 1;
+        ex -= 1;
+    }
+    let i = uxi.wrapping_sub(uyi);
+    if i >> 31 == 0 {
+        if i == 0 {
+            return 0. * x;
+        }
+        uxi = i;
+    }
+    while uxi >> 23 == 0 {
+        uxi <<= 1;
+        ex -= 1;
+    }
+
+    /* scale result */
+    if ex > 0 {
+        uxi -= 1u32 << 23;
+        uxi |= (ex as u32) << 23;
+    } else {
+        uxi >>= -ex + 1;
+    }
+    uxi |= sx;
+    f32::from_bits(uxi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmod() {
+        assert_eq!(fmod(5.3, 2.), 1.2999999999999998);
+        assert_eq!(fmod(-5.3, 2.), -1.2999999999999998);
+        assert_eq!(fmod(6., 3.), 0.);
+        assert_eq!(fmod(-6., 3.).to_bits(), (-0f64).to_bits());
+        assert_eq!(fmod(1e300, 0.1), 0.00011215964963492975);
+        assert_eq!(
+            fmod(f64::from_bits(3), f64::from_bits(2)),
+            f64::from_bits(1)
+        );
+        assert_eq!(fmod(1., f64::INFINITY), 1.);
+        assert!(fmod(1., 0.).is_nan());
+        assert!(fmod(f64::INFINITY, 1.).is_nan());
+        assert!(fmod(f64::NAN, 1.).is_nan());
+    }
+
+    #[test]
+    fn test_fmodf() {
+        assert_eq!(fmodf(5.3, 2.), 1.3000002);
+        assert_eq!(fmodf(-7., 2.), -1.);
+        assert_eq!(fmodf(1e30, 7.), 1.);
+        assert_eq!(
+            fmodf(f32::from_bits(5), f32::from_bits(2)),
+            f32::from_bits(1)
+        );
+        assert!(fmodf(1., 0.).is_nan());
+    }
+}
@@ -28,6 +28,8 @@
  */
 mod ceil;
 mod floor;
+mod fmod;
+mod remquo;
 mod rint;
 mod rintf;
 mod round;
@@ -39,6 +41,8 @@ pub(crate) use ceil::CpuCeil;
 pub use ceil::{ceil, ceilf};
 pub(crate) use floor::CpuFloor;
 pub use floor::{floor, floorf};
+pub use fmod::{fmod, fmodf};
+pub use remquo::{remainder, remainderf, remquo, remquof};
 pub use rint::rint;
 pub use rintf::rintf;
 pub(crate) use round::CpuRound;
@@ -0,0 +1,286 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+/// Computes IEEE 754 remainder of x/y and low bits of the quotient
+///
+/// Returns `(r, quo)` where `r = x - n * y` for `n` being x/y rounded to nearest,
+/// ties to even, and `quo` has the sign of x/y and the low 31 bits of `|n|`.
+/// The remainder is exact.
+#[inline]
+pub const fn remquo(x: f64, y: f64) -> (f64, i32) {
+    let mut uxi = x.to_bits();
+    let mut uyi = y.to_bits();
+    let mut ex = ((uxi >> 52) & 0x7ff) as i64;
+    let mut ey = ((uyi >> 52) & 0x7ff) as i64;
+    let sx = uxi >> 63;
+    let sy = uyi >> 63;
+
+    if uyi << 1 == 0 || y.is_nan() || ex == 0x7ff {
+        return (
+            if x.is_nan() || y.is_nan() {
+                x + y
+            } else {
+                f64::NAN
+            },
+            0,
+        );
+    }
+    if uxi << 1 == 0 {
+        return (x, 0);
+    }
+
+    /* normalize x and y */
+    if ex == 0 {
+        let mut i = uxi << 12;
+        while i >> 63 == 0 {
+            ex -= 1;
+            i <<= 1;
+        }
+        uxi <<= -ex + 1;
+    } else {
+        uxi &= u64::MAX >> 12;
+        uxi |= 1u64 << 52;
+    }
+    if ey == 0 {
+        let mut i = uyi << 12;
+        while i >> 63 == 0 {
+            ey -= 1;
+            i <<= 1;
+        }
+        uyi <<= -ey + 1;
+    } else {
+        uyi &= u64::MAX >> 12;
+        uyi |= 1u64 << 52;
+    }
+
+    let mut q = 0u64;
+    if ex < ey {
+        if ex + 1 != ey {
+            return (x, 0);
+        }
+    } else {
+        /* x mod y */
+        while ex > ey {
+            let i = uxi.wrapping_sub(uyi);
+            if i >> 63 == 0 {
+                uxi = i;
+                q += 1;
+            }
+            uxi <<= 1;
+            q <<= 1;
+            ex -= 1;
+        }
+        let i = uxi.wrapping_sub(uyi);
+        if i >> 63 == 0 {
+            uxi = i;
+            q += 1;
+        }
+        if uxi == 0 {
+            ex = -60;
+        } else {
+            while uxi >> 52 == 0 {
+                uxi <<= 1;
+                ex -= 1;
+            }
+        }
+    }
+
+    /* scale result and decide between |x| and |x|-|y| */
+    if ex > 0 {
+        uxi -= 1u64 << 52;
+        uxi |= (ex as u64) << 52;
+    } else {
+        uxi >>= -ex + 1;
+    }
+    let mut r = f64::from_bits(uxi);
+    let ay = y.abs();
+    if ex == ey || (ex + 1 == ey && (2. * r > ay || (2. * r == ay && q & 1 != 0))) {
+        r -= ay;
+        q = q.wrapping_add(1);
+    }
+    let q = (q & 0x7fff_ffff) as i32;
+    let quo = if sx ^ sy != 0 { -q } else { q };
+    (if sx != 0 { -r } else { r }, quo)
+}
+
+/// Computes IEEE 754 remainder of x/y and low bits of the quotient
+///
+/// Returns `(r, quo)` where `r = x - n * y` for `n` being x/y rounded to nearest,
+/// ties to even, and `quo` has the sign of x/y and the low 31 bits of `|n|`.
+/// The remainder is exact.
+#[inline]
+pub const fn remquof(x: f32, y: f32) -> (f32, i32) {
+    let mut uxi = x.to_bits();
+    let mut uyi = y.to_bits();
+    let mut ex = ((uxi >> 23) & 0xff) as i32;
+    let mut ey = ((uyi >> 23) & 0xff) as i32;
+    let sx = uxi >> 31;
+    let sy = uyi >> 31;
+
+    if uyi << 1 == 0 || y.is_nan() || ex == 0xff {
+        return (
+            if x.is_nan() || y.is_nan() {
+                x + y
+            } else {
+                f32::NAN
+            },
+            0,
+        );
+    }
+    if uxi << 1 == 0 {
+        return (x, 0);
+    }
+
+    /* normalize x and y */
+    if ex == 0 {
+        let mut i = uxi << 9;
+        while i >> 31 == 0 {
+            ex -= 1;
+            i <<= 1;
+        }
+        uxi <<= -ex + 1;
+    } else {
+        uxi &= u32::MAX >> 9;
+        uxi |= 1u32 << 23;
+    }
+    if ey == 0 {
+        let mut i = uyi << 9;
+        while i >> 31 == 0 {
+            ey -= 1;
+            i <<= 1;
+        }
+        uyi <<= -ey + 1;
+    } else {
+        uyi &= u32::MAX >> 9;
+        uyi |= 1u32 << 23;
+    }
+
+    let mut q = 0u32;
+    if ex < ey {
+        if ex + 1 != ey {
+            return (x, 0);
+        }
+    } else {
+        /* x mod y */
+        while ex > ey {
+            let i = uxi.wrapping_sub(uyi);
+            if i >> 31 == 0 {
+                uxi = i;
+                q += 1;
+            }
+            uxi <<= 1;
+            q <<= 1;
+            ex -= 1;
+        }
+        let i = uxi.wrapping_sub(uyi);
+        if i >> 31 == 0 {
+            uxi = i;
+            q += 1;
+        }
+        if uxi == 0 {
+            ex = -30;
+        } else {
+            while uxi >> 23 == 0 {
+                uxi <<= 1;
+                ex -= 1;
+            }
+        }
+    }
+
+    /* scale result and decide between |x| and |x|-|y| */
+    if ex > 0 {
+        uxi -= 1u32 << 23;
+        uxi |= (ex as u32) << 23;
+    } else {
+        uxi >>= -ex + 1;
+    }
+    let mut r = f32::from_bits(uxi);
+    let ay = y.abs();
+    if ex == ey || (ex + 1 == ey && (2. * r > ay || (2. * r == ay && q & 1 != 0))) {
+        r -= ay;
+        q = q.wrapping_add(1);
+    }
+    let q = (q & 0x7fff_ffff) as i32;
+    let quo = if sx ^ sy != 0 { -q } else { q };
+    (if sx != 0 { -r } else { r }, quo)
+}
+
+/// Computes IEEE 754 remainder of x/y
+///
+/// Returns `x - n * y` for `n` being x/y rounded to nearest, ties to even.
+/// The result is exact.
+#[inline]
+pub const fn remainder(x: f64, y: f64) -> f64 {
+    remquo(x, y).0
+}
+
+/// Computes IEEE 754 remainder of x/y
+///
+/// Returns `x - n * y` for `n` being x/y rounded to nearest, ties to even.
+/// The result is exact.
+#[inline]
+pub const fn remainderf(x: f32, y: f32) -> f32 {
+    remquof(x, y).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remquo() {
+        assert_eq!(remquo(5.3, 2.), (-0.7000000000000002, 3));
+        assert_eq!(remquo(-5.3, 2.), (0.7000000000000002, -3));
+        assert_eq!(remquo(5., 2.), (1., 2));
+        assert_eq!(remquo(7., -2.), (-1., -4));
+        assert_eq!(remquo(1e300, 0.1), (0.00011215964963492975, 787391957));
+        assert_eq!(remquo(1., f64::INFINITY), (1., 0));
+        assert!(remquo(f64::INFINITY, 1.).0.is_nan());
+        assert!(remquo(1., 0.).0.is_nan());
+    }
+
+    #[test]
+    fn test_remquof() {
+        assert_eq!(remquof(5.3, 2.), (-0.6999998, 3));
+        assert_eq!(remquof(-3., 2.), (1., -2));
+        assert_eq!(remquof(1.5, 1.), (-0.5, 2));
+        assert!(remquof(f32::NAN, 1.).0.is_nan());
+    }
+
+    #[test]
+    fn test_remainder() {
+        assert_eq!(remainder(6., 4.), -2.);
+        assert_eq!(remainder(-6., 3.).to_bits(), (-0f64).to_bits());
+        assert_eq!(remainderf(10., 3.), 1.);
+        assert_eq!(
+            remainderf(f32::from_bits(3), f32::from_bits(2)),
+            -f32::from_bits(1)
+        );
+    }
+}
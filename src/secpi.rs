@@ -0,0 +1,183 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::polyeval::f_polyeval4;
+use crate::sincospi::{reduce_pi_64, sincospi_eval, sincospi_eval_dd};
+use crate::sincospi_tables::SINPI_K_PI_OVER_64;
+
+/**
+Secpi on [0; 0.000244140625]
+
+Taylor series of 1/cos(pi*x) with coefficients
+E(2n) * pi^(2n) / (2n)! rounded to double-double.
+**/
+#[cold]
+fn as_secpi_zero(x: f64) -> f64 {
+    const C: [(u64, u64); 5] = [
+        (0x3cb692b71366cc04, 0x4013bd3cc9be45de),
+        (0xbcd7f600f69fb196, 0x40344b26ca223175),
+        (0x3cf1203fc6679b9b, 0x40545cdda851e6fa),
+        (0xbd145e50974f8bbc, 0x40745eed40b78d68),
+        (0x3d390702d31438ac, 0x40945f28eb407e97),
+    ];
+    let x2 = DoubleDouble::from_exact_mult(x, x);
+    let mut p = DoubleDouble::quick_mul_add(
+        x2,
+        DoubleDouble::from_bit_pair(C[4]),
+        DoubleDouble::from_bit_pair(C[3]),
+    );
+    p = DoubleDouble::quick_mul_add(x2, p, DoubleDouble::from_bit_pair(C[2]));
+    p = DoubleDouble::quick_mul_add(x2, p, DoubleDouble::from_bit_pair(C[1]));
+    p = DoubleDouble::quick_mul_add(x2, p, DoubleDouble::from_bit_pair(C[0]));
+    p = DoubleDouble::mul_add_f64(x2, p, 1.);
+    p.to_f64()
+}
+
+#[cold]
+fn secpi_dd(y: f64, cos_k: DoubleDouble, msin_k: DoubleDouble) -> f64 {
+    let r_sincos = sincospi_eval_dd(y);
+    let msin_k_sin_y = DoubleDouble::quick_mult(msin_k, r_sincos.v_sin);
+    let rr = DoubleDouble::mul_add(cos_k, r_sincos.v_cos, msin_k_sin_y);
+    rr.recip().to_f64()
+}
+
+/// Computes 1/cos(PI*x)
+///
+/// Max found ULP 0.5
+pub fn f_secpi(x: f64) -> f64 {
+    let ix = x.to_bits();
+    let ax = ix & 0x7fff_ffff_ffff_ffff;
+    if ax == 0 {
+        return 1.0;
+    }
+    let e: i32 = (ax >> 52) as i32;
+    // e is the unbiased exponent, we have 2^(e-1023) <= |x| < 2^(e-1022)
+    let m: i64 = ((ix & 0x000fffffffffffff) | (1u64 << 52)) as i64;
+    let mut s = 1063i32.wrapping_sub(e); // 2^(40-s) <= |x| < 2^(41-s)
+    if s < 0 {
+        // |x| >= 2^41
+        if e == 0x7ff {
+            // NaN or Inf
+            if ix.wrapping_shl(12) == 0 {
+                return f64::NAN;
+            }
+            return x + x; // NaN
+        }
+        s = -s - 1; // now 2^(41+s) <= |x| < 2^(42+s)
+        if s > 11 {
+            return 1.0;
+        } // |x| >= 2^53
+        let iq: u64 = (m as u64).wrapping_shl(s as u32).wrapping_add(1024);
+        if (iq & 2047) == 0 {
+            // cos(PI*x) = 0
+            return f64::INFINITY;
+        }
+    }
+    if ax <= 0x3f30000000000000u64 {
+        // |x| <= 2^-12, |x| <= 0.000244140625
+        if ax <= 0x3e2ccf6429be6621u64 {
+            return 1.0 + f64::from_bits(0x3c80000000000000);
+        }
+        let x2 = x * x;
+        let eps = x2 * f64::from_bits(0x3cf0000000000000);
+
+        // sec(PI*x) ~ 1 + x^2 * P(x^2)
+        // coefficients are E(2n) * pi^(2n) / (2n)!
+        let p = x2
+            * f_polyeval4(
+                x2,
+                f64::from_bits(0x4013bd3cc9be45de),
+                f64::from_bits(0x40344b26ca223175),
+                f64::from_bits(0x40545cdda851e6fa),
+                f64::from_bits(0x40745eed40b78d68),
+            );
+        let lb = (p - eps) + 1.;
+        let ub = (p + eps) + 1.;
+        if lb == ub {
+            return lb;
+        }
+        return as_secpi_zero(x);
+    }
+
+    let si: i32 = e.wrapping_sub(1011);
+    if si >= 0 && ((m as u64).wrapping_shl(si as u32) ^ 0x8000000000000000u64) == 0 {
+        // x is half-integer, cos(PI*x) = 0
+        return f64::INFINITY;
+    }
+
+    let (y, k) = reduce_pi_64(x);
+
+    // cos(k * pi/64) = sin(k * pi/64 + pi/2) = sin((k + 32) * pi/64).
+    let msin_k = DoubleDouble::from_bit_pair(
+        SINPI_K_PI_OVER_64[((k as u64).wrapping_add(64) & 127) as usize],
+    );
+    let cos_k = DoubleDouble::from_bit_pair(
+        SINPI_K_PI_OVER_64[((k as u64).wrapping_add(32) & 127) as usize],
+    );
+
+    let r_sincos = sincospi_eval(y);
+
+    let cos_k_cos_y = DoubleDouble::quick_mult(r_sincos.v_cos, cos_k);
+    let cos_k_msin_y = DoubleDouble::quick_mult(r_sincos.v_sin, msin_k);
+
+    // cos_k_cos_y is always >> cos_k_msin_y
+    let mut rr = DoubleDouble::from_exact_add(cos_k_cos_y.hi, cos_k_msin_y.hi);
+    rr.lo += cos_k_cos_y.lo + cos_k_msin_y.lo;
+
+    rr = DoubleDouble::from_exact_add(rr.hi, rr.lo);
+    rr = rr.recip();
+
+    // d(1/c) = -dc/c^2
+    let err = r_sincos.err * rr.hi * rr.hi;
+
+    let ub = rr.hi + (rr.lo + err);
+    let lb = rr.hi + (rr.lo - err);
+
+    if ub == lb {
+        return rr.to_f64();
+    }
+    secpi_dd(y, cos_k, msin_k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secpi() {
+        assert_eq!(f_secpi(0.), 1.);
+        assert_eq!(f_secpi(1.), -1.);
+        assert_eq!(f_secpi(-2.), 1.);
+        assert_eq!(f_secpi(0.5), f64::INFINITY);
+        assert_eq!(f_secpi(-3.5), f64::INFINITY);
+        assert_eq!(f_secpi(0.25), std::f64::consts::SQRT_2);
+        assert!(f_secpi(f64::INFINITY).is_nan());
+        assert!(f_secpi(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,79 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::{EULER_DD, log_abs_dd};
+use crate::sici::sici_core::{
+    CHI_TAYLOR, CHI_ZERO, cos_integral_sum, half_ei_large, taylor_at_zero,
+};
+
+/// Computes hyperbolic cosine integral Chi(x)
+///
+/// Chi(x) = euler + log(x) + integral((cosh(t) - 1)/t, t = 0..x), defined for x > 0.
+pub fn f_chi(x: f64) -> f64 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f64::NAN;
+        }
+        if x == 0. {
+            return f64::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return f64::INFINITY;
+        }
+    }
+    if x > 60. {
+        // Chi(x) = (Ei(x) - E1(x)) / 2 and E1(x) is negligible
+        return half_ei_large(x);
+    }
+    if (x - f64::from_bits(CHI_ZERO.0)).abs() < f64::from_bits(0x3f30000000000000) {
+        // |x - x0| < 2^-12
+        return taylor_at_zero(x, CHI_ZERO, &CHI_TAYLOR).to_f64();
+    }
+    // Chi(x) = euler + log(x) + sum(x^2k / (2k * (2k)!))
+    let dx = DoubleDouble::new(0., x);
+    let s = cos_integral_sum(DoubleDouble::quick_mult(dx, dx));
+    let r = DoubleDouble::full_dd_add(EULER_DD, log_abs_dd(dx));
+    DoubleDouble::full_dd_add(r, s).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chi() {
+        assert_eq!(f_chi(1.), 0.8378669409802082);
+        assert_eq!(f_chi(100.), 1.35777637242694e41);
+        assert_eq!(f_chi(800.), f64::INFINITY);
+        assert_eq!(f_chi(0.), f64::NEG_INFINITY);
+        assert_eq!(f_chi(f64::INFINITY), f64::INFINITY);
+        assert!(f_chi(-1.).is_nan());
+        assert!(f_chi(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::exponents::EULER_DD;
+use crate::logs::simple_fast_log;
+use crate::sici::sici_core::{CHI_TAYLOR, CHI_ZERO};
+use crate::sici::sici_coref::{cos_integral_sumf, half_ei_largef, taylor_at_zerof};
+
+/// Computes hyperbolic cosine integral Chi(x)
+///
+/// Chi(x) = euler + log(x) + integral((cosh(t) - 1)/t, t = 0..x), defined for x > 0.
+pub fn f_chif(x: f32) -> f32 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f32::NAN;
+        }
+        if x == 0. {
+            return f32::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return f32::INFINITY;
+        }
+    }
+    let xd = x as f64;
+    if x > 40. {
+        // Chi(x) = (Ei(x) - E1(x)) / 2 and E1(x) is negligible
+        return half_ei_largef(xd);
+    }
+    if (xd - f64::from_bits(CHI_ZERO.0)).abs() < f64::from_bits(0x3f30000000000000) {
+        // |x - x0| < 2^-12
+        return taylor_at_zerof(xd, CHI_ZERO, &CHI_TAYLOR[..4]) as f32;
+    }
+    // Chi(x) = euler + log(x) + sum(x^2k / (2k * (2k)!))
+    let s = cos_integral_sumf(xd * xd);
+    (EULER_DD.hi + simple_fast_log(xd) + s) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chif() {
+        assert_eq!(f_chif(1.), 0.83786696);
+        assert_eq!(f_chif(0.), f32::NEG_INFINITY);
+        assert_eq!(f_chif(f32::INFINITY), f32::INFINITY);
+        assert!(f_chif(-1.).is_nan());
+        assert!(f_chif(f32::NAN).is_nan());
+    }
+}
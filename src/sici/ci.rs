@@ -0,0 +1,95 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::{EULER_DD, log_abs_dd};
+use crate::sici::ci_coeffs::{CI_ZEROS, CI_ZEROS_TAYLOR};
+use crate::sici::sici_core::{
+    ci_zero_index, cos_integral_sum, sici_auxiliary, sincos_dd, taylor_at_zero,
+};
+
+/// Computes cosine integral Ci(x)
+///
+/// Ci(x) = -integral(cos(t)/t, t = x..inf), defined for x > 0.
+pub fn f_ci(x: f64) -> f64 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f64::NAN;
+        }
+        if x == 0. {
+            return f64::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+    }
+    if x <= 64. {
+        if let Some(idx) = ci_zero_index(x) {
+            // Series and auxiliary functions lose precision near zeros
+            return taylor_at_zero(x, CI_ZEROS[idx], &CI_ZEROS_TAYLOR[idx]).to_f64();
+        }
+    }
+    if x <= 4. {
+        // Ci(x) = euler + log(x) + sum((-x^2)^k / (2k * (2k)!))
+        let dx = DoubleDouble::new(0., x);
+        let s = cos_integral_sum(-DoubleDouble::quick_mult(dx, dx));
+        let r = DoubleDouble::full_dd_add(EULER_DD, log_abs_dd(dx));
+        return DoubleDouble::full_dd_add(r, s).to_f64();
+    }
+    if x >= f64::from_bits(0x5ff0000000000000) {
+        // x >= 2^512, Ci(x) = sin(x)/x * (1 + O(1/x))
+        // x is scaled by 2^-600 to keep the division free of overflow in products
+        let (sin, _) = sincos_dd(x);
+        let q = DoubleDouble::div_dd_f64(sin, x * f64::from_bits(0x1a70000000000000));
+        return q.to_f64() * f64::from_bits(0x1a70000000000000);
+    }
+    // Ci(x) = f(x) * sin(x) - g(x) * cos(x)
+    let aux = sici_auxiliary(x);
+    let fs = DoubleDouble::quick_mult(aux.f, aux.sin);
+    let gc = DoubleDouble::quick_mult(aux.g, aux.cos);
+    DoubleDouble::full_dd_sub(fs, gc).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ci() {
+        assert_eq!(f_ci(1.), 0.33740392290096816);
+        assert_eq!(f_ci(10.), -0.04545643300445537);
+        assert_eq!(f_ci(1000.), 0.0008263155110906822);
+        assert_eq!(f_ci(1e200), -6.4396871853950575e-201);
+        assert_eq!(f_ci(1e305), -7.426673558228153e-306);
+        assert_eq!(f_ci(f64::MAX), 2.760178972127e-311);
+        assert_eq!(f_ci(0.), f64::NEG_INFINITY);
+        assert_eq!(f_ci(f64::INFINITY), 0.);
+        assert!(f_ci(-1.).is_nan());
+        assert!(f_ci(f64::NAN).is_nan());
+    }
+}
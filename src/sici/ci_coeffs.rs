@@ -0,0 +1,302 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+/**
+Positive zeros of Ci(x) up to 64 as triple-double.
+
+Generated by SageMath:
+```python
+mp.prec = 400
+zeros = [findroot(ci, 0.6165), findroot(ci, 3.3842)]
+while True:
+    x = zeros[-1] + 0.5
+    s = sign(ci(x))
+    while sign(ci(x + 0.25)) == s:
+        x += 0.25
+    z = findroot(ci, (x, x + 0.25), solver='anderson')
+    if z > 64:
+        break
+    zeros.append(z)
+
+for z in zeros:
+    hi = float(z)
+    mid = float(z - hi)
+    lo = float(z - hi - mid)
+    print(f"({double_to_hex(hi)}, {double_to_hex(mid)}, {double_to_hex(lo)}),")
+```
+**/
+pub(crate) static CI_ZEROS: [(u64, u64, u64); 21] = [
+    (0x3fe3ba69b6517559, 0xbc8844beee0210fe, 0xb918618ec4c1503b),
+    (0x400b12cd2f74f769, 0x3cac6bdbd631b168, 0x3923022dd167ca3b),
+    (0x4019b54c00fa1b8f, 0xbcab04c7c28c33f9, 0x39325c7c73d6bc0d),
+    (0x40230d1839f3c745, 0xbcc219a4bc6529dd, 0x39516b8b89051ba4),
+    (0x4029497ef8561c15, 0xbccd66a711c68465, 0x396b781cf5f30d5f),
+    (0x402f8a6b44f8838b, 0xbca5871b3b11fc66, 0x394333216a04dd8e),
+    (0x4032e6dfdba7e1e7, 0xbcb5e97387a67a24, 0xb9483598e9aec87f),
+    (0x4036094077363e73, 0x3cc477f3df1dbbf6, 0xb9543b2d9ade1b64),
+    (0x40392c15973002ec, 0xbccf2c04ee418aa7, 0x396bb8f7e9090860),
+    (0x403c4f39873a2ae2, 0x3cd9753cbccdea07, 0x39666bbbbc59d1d1),
+    (0x403f72953186d859, 0xbcc0ac4aca3ffbbc, 0x395300f521116bff),
+    (0x40414b0cd789bdbe, 0xbce2b7b3b2b28c58, 0x39776079b209c7b4),
+    (0x4042dcde7ad9d486, 0x3cd046955a1c1dd6, 0xb966e21812dd6685),
+    (0x40446ebc01f3b034, 0x3ce38352b435a574, 0x394129a9923b4d35),
+    (0x404600a2e85f27f3, 0x3c902bce01f6ea8f, 0xb92c0ade54e0a9b4),
+    (0x40479291533626c6, 0xbcdd184f890929cb, 0xb9548ff261a0e9bb),
+    (0x40492485dcea0b41, 0x3ce26149c78c5a19, 0x39752af9502b0d51),
+    (0x404ab67f73306c6a, 0xbceb63407efb08a2, 0x398fd903d0799b40),
+    (0x404c487d40247b82, 0x3cab76b68ea07b1e, 0xb930b210fe11fc41),
+    (0x404dda7e9a8b0691, 0x3cee1060c84cfa57, 0xb95458d43459a042),
+    (0x404f6c82fac1daac, 0xbcddc3a863bdea6e, 0x3979b04420afd06b),
+];
+
+/**
+Taylor expansions of Ci(x) at its zeros, coefficients for (x - x0)^k, k = 1..8.
+
+Generated by SageMath:
+```python
+for z in zeros:
+    poly = taylor(ci, z, 8)
+    print("[")
+    for k in range(1, 9):
+        print_double_double("", poly[k])
+    print("],")
+```
+**/
+pub(crate) static CI_ZEROS_TAYLOR: [[(u64, u64); 8]; 21] = [
+    [
+        (0x3c94d4a76df49368, 0x3ff52cc8723e5ac4),
+        (0xbc52fbc89fb9b2ef, 0xbff8ad1692c6f4a0),
+        (0x3c47be14e328e844, 0x3ff7279b2ccbc718),
+        (0x3c8ae9bc10003f4c, 0xbffb8b138edf6899),
+        (0x3c95db36984ac09c, 0x4001f5741a64f005),
+        (0xbc7081c2898aaa4d, 0xc0084911255c0c92),
+        (0xbca201f79381d04a, 0x4010e19a2e348d19),
+        (0xbca6257fcd0f2945, 0xc017f59523350015),
+    ],
+    [
+        (0xbc708276a9a61ec6, 0xbfd25b97c28501b4),
+        (0x3c3c3b123d73fe5e, 0x3fb3ef529b2333f3),
+        (0x3c47182534213080, 0x3fa09f772bdaa7aa),
+        (0x3c2e403d6c9fbc9b, 0xbf84caff42425f01),
+        (0xbb454f3f3ac7aa9d, 0x3ee4664f2dcb93e6),
+        (0xbb986aa8e85e368b, 0x3f19373e6e7f75fc),
+        (0xbb8447fc0382196a, 0x3f0110be708c81dd),
+        (0x3b2b00e3aacdd7bb, 0xbee5576679888636),
+    ],
+    [
+        (0x3c58a4043e814e8f, 0x3fc3b5c8b36b2552),
+        (0xbc343408052e6b78, 0xbf97b0230d0fad9f),
+        (0x3c125446d94f58f4, 0xbf97d2b07ee147c3),
+        (0xbc0ec5358802bfc8, 0x3f6ddaa7c69afea0),
+        (0x3bcb2639fbb4cb9a, 0x3f4b2f17df901f85),
+        (0x3bc2bfa792abe3c6, 0xbf2228d863aebf50),
+        (0x3b7967d5566e7492, 0xbee952ea2f4f78e9),
+        (0xbb616165f7eff568, 0x3ec26e81446d9001),
+    ],
+    [
+        (0x3c17eb90036f117a, 0xbfbabd153e655f2b),
+        (0xbc0a9db4da955d14, 0x3f860ba7e3a1025e),
+        (0x3c2723e3e4c8dc81, 0x3f910de5b6db06cd),
+        (0xbbfe40dad1ceab98, 0xbf5cb22e1a8aecd1),
+        (0xbbeed8c7865d21d0, 0xbf47b37e0d6c2351),
+        (0xbbb9b6deab519a19, 0x3f146f14b6676705),
+        (0xbb81d8e7f96f7e37, 0x3eecc04bcfdeda41),
+        (0xbb4179a34b99c68c, 0xbeb9861950a79089),
+    ],
+    [
+        (0x3c5136f084d76f00, 0x3fb42fed4601897f),
+        (0x3c0dbd3e35448bf3, 0xbf7943001f41dcba),
+        (0xbc21d395cf639180, 0xbf8a4012ded62f95),
+        (0xbbada2af3d26563d, 0x3f509eb4d0b28695),
+        (0xbbbb82bcec485208, 0x3f436e09354d6a59),
+        (0xbb90adee7d31257e, 0xbf08ee23f3eaab17),
+        (0xbb65f0eb38140422, 0xbeea0d46bf3106b4),
+        (0xbb5dd7a3c5bcc172, 0x3eb0f5f5160748be),
+    ],
+    [
+        (0x3c46db159805143e, 0xbfb0338fed413f48),
+        (0xbbf1a73f79fa2ed1, 0x3f7050ae486f532e),
+        (0xbc2d458bda51c2e2, 0x3f8541cd39383cf2),
+        (0xbbdb45eb605802f8, 0xbf459295cd63f721),
+        (0x3bd75804cb8dc849, 0xbf402fea833bcabe),
+        (0xbb7c45008c7e3717, 0x3f009082bd33dfa6),
+        (0x3b8b7a0b0cc9ca00, 0x3ee6bb856fb48254),
+        (0x3b24e3cc57cfc5bd, 0xbea778792173379d),
+    ],
+    [
+        (0xbc1ebeff8b5434c8, 0x3fab0cdda9bc3e04),
+        (0x3bfeab08c0c35cea, 0xbf66c6bc5a56986a),
+        (0x3c2303874378c820, 0xbf81d52a34a8b4ca),
+        (0xbbd823930917546e, 0x3f3e31b556ae28c6),
+        (0x3bd6e6cc456c35b7, 0x3f3b935f4c010cde),
+        (0xbb9207b9d4b4032d, 0xbef77abb1bbdaa4c),
+        (0x3b8623231019752b, 0xbee3dab0a052cb45),
+        (0xbb2c6bdfa4514cc7, 0x3ea101c3cf25e229),
+    ],
+    [
+        (0xbc3a51e10aa199c6, 0xbfa7360732d26be7),
+        (0xbc07e4c7ab2b3517, 0x3f60c95b4ef44c4b),
+        (0xbc1d349a961816f5, 0x3f7eb1b3110d5559),
+        (0x3bde9711b03913b3, 0xbf3649c3913719a7),
+        (0x3bcbcb228c400a4f, 0xbf37f305729135f2),
+        (0xbb8cdf20f993c556, 0x3ef1769f8ff01a2f),
+        (0x3b8738a5e46f37c8, 0x3ee18149b2dbc649),
+        (0xbb380912b7c8d303, 0xbe99a3c1c73458d2),
+    ],
+    [
+        (0xbc31123130d41147, 0x3fa452f589d06a48),
+        (0xbbfc9c0468e8da5b, 0xbf59c2074786234c),
+        (0x3bff8b27fa20fc35, 0xbf7aed9e90584bc7),
+        (0x3bda947c8c0c5295, 0x3f311df05a31226a),
+        (0xbbdfbb743b171f6a, 0x3f35228f6fc3af0c),
+        (0x3b8768d62d520f58, 0xbeeaf476a928d78b),
+        (0x3b5917eeb4d7ab1f, 0xbedf32ef8593539a),
+        (0xbb2d9b9abf91ac75, 0x3e93f5e754f38e09),
+    ],
+    [
+        (0xbc3bc137c6ffed1f, 0xbfa2131ca81afe9f),
+        (0x3bd4a28b09dc07ae, 0x3f54619b41a12d90),
+        (0x3c14c1fa49f7e754, 0x3f77fac3d16abf32),
+        (0xbbac3788bf4b5bab, 0xbf2b1b3e0d98d0ed),
+        (0xbbbe6b5868acc324, 0xbf32e589a80592e0),
+        (0x3b8ad82447f8d0e8, 0x3ee56a20f3cac000),
+        (0xbb692359e7654d9c, 0x3edc14ebf1b1d943),
+        (0xbb2dfbb17a8f5d55, 0xbe8fe7d621225212),
+    ],
+    [
+        (0xbc3382ea3050de66, 0x3fa045dc94a80221),
+        (0xbbcc57b68085fab8, 0xbf5086a630306fd2),
+        (0xbc1a95b877bfb936, 0xbf759c0f7cc8a182),
+        (0x3bc14f107e6d5954, 0x3f25fd58f33f424a),
+        (0xbbd42d42e533aed7, 0x3f3113fb9b625254),
+        (0xbb80adef6c1ef288, 0xbee169f1117382f1),
+        (0x3b607cfb1fb4bb51, 0xbed9802a35d395a0),
+        (0xbb2351037ec55ad6, 0x3e8a0e570cea5f07),
+    ],
+    [
+        (0x3c255a8c88132c5a, 0xbf9d9843f765a9dc),
+        (0x3be3550748582f40, 0x3f4b56354d6191c1),
+        (0x3bfe4e0e94878d99, 0x3f73a9fb6272cfb7),
+        (0x3bca28272350c8fe, 0xbf22319be1d2ab9e),
+        (0x3bcbbadc058a5c06, 0xbf2f259dee1ff0ee),
+        (0xbb7e694c2349aa93, 0x3edcdde0b8bc2c3b),
+        (0x3b66d3b45239e26a, 0x3ed75613e880f161),
+        (0x3b28513b70db94bc, 0xbe85aa2f2dee2927),
+    ],
+    [
+        (0x3bfb5e22ca99a8c2, 0x3f9b2249b87753cf),
+        (0xbbe1a3219603d72b, 0xbf46fbe7f368c13f),
+        (0xbc1f9f65707073a4, 0xbf7209dc89561f04),
+        (0x3b9214fe573201ae, 0x3f1e9a1bbfad4ba2),
+        (0x3ba8a4c32941d527, 0x3f2c9e4f4183bd95),
+        (0x3b503b1b10e67b0a, 0xbed84f405cc3774e),
+        (0x3b7a8bbb66c697d1, 0xbed57fd784a86e80),
+        (0xbb2438d503efd555, 0x3e8249de4884251f),
+    ],
+    [
+        (0xbc34d1f28e5e918c, 0xbf990cf329857041),
+        (0x3b9f7830c127fabc, 0x3f4397d0359a6276),
+        (0x3c1ecc6f4fb1c23a, 0x3f70a9124e5d481d),
+        (0xbbbd6833b56c662a, 0xbf1a17b1e5fc8961),
+        (0xbbb9ebb512012fca, 0xbf2a7718d3c9baf7),
+        (0x3b4d3bac13cd0147, 0x3ed4bfc1ca418e6c),
+        (0x3b6497d16c823f5d, 0x3ed3ec6164502820),
+        (0x3ae2187a9bf49742, 0xbe7f473f5b11d5cf),
+    ],
+    [
+        (0x3c3cc2bb35597a4a, 0x3f97439d20db7028),
+        (0x3beafa4a04e382d3, 0xbf40e67159279617),
+        (0xbbf69a9421b990ed, 0xbf6ef46ea3d716ae),
+        (0xbbb3ade2b5633b8a, 0x3f16829a34006f30),
+        (0xbbb72e95a8ccab97, 0x3f289c45f8af0fbc),
+        (0x3b7a45a2fc24785a, 0xbed1ea466c3c616b),
+        (0x3b798ad88423c846, 0xbed28ec8f1d8508e),
+        (0xbb135e6862641c94, 0x3e7b0be52074eeee),
+    ],
+    [
+        (0xbc0afda25986e428, 0xbf95b71fe091cb58),
+        (0xbbdf042116ca2bb7, 0x3f3d73daf6e811d3),
+        (0x3bf0251878fea970, 0x3f6ce6d6affcd2d5),
+        (0x3b95c749e63af154, 0xbf139e06f9fd04a3),
+        (0xbbc012f23962d215, 0xbf26ff1fa8ff1230),
+        (0x3b56c55cc5e49031, 0x3ecf3f17a3b43fc5),
+        (0xbb7fe5ca3ef34ff1, 0x3ed15d2d11a0267e),
+        (0x3b1b41041188dfe2, 0xbe779d5aa19e13cc),
+    ],
+    [
+        (0x3bd160632ef4ab11, 0x3f945c1a58e9e271),
+        (0xbbd984026f62de58, 0xbf39e46efc36bee3),
+        (0x3c00234232e59592, 0xbf6b1a7c6111daf8),
+        (0xbbbdaf90b7081db0, 0x3f113f726ba9b072),
+        (0x3bca5d9acf4712be, 0x3f2594740c0c8f29),
+        (0xbb63041f9be217e8, 0xbecb7cc6b634a927),
+        (0xbb7985f4f620ed6c, 0xbed04fe5111fa7ed),
+        (0xbaf6f0a30d20c5c3, 0x3e74cb4cb0ad78c4),
+    ],
+    [
+        (0x3c104817d154e53d, 0xbf9329d6e1463e5c),
+        (0x3bcae8ab2b8c4f68, 0x3f36f0bc50fa0fd2),
+        (0xbc0c276164762175, 0x3f6983f579fda6bf),
+        (0x3b7b749a2658e4b6, 0xbf0e90cefeb2e9bc),
+        (0xbbac7a4f040c42b8, 0xbf24539a6299f9d9),
+        (0xbb60aa59c7ab410d, 0x3ec85dea6fb27000),
+        (0x3b691bd993ab55c1, 0x3ecec1db6296d17d),
+        (0xbae2e3c65a361741, 0xbe7272cda7e44fb8),
+    ],
+    [
+        (0xbbf922c729c29f6e, 0x3f92198dffef9a9c),
+        (0xbbcd8a12695076a1, 0xbf3477384917acff),
+        (0xbbf5098e114b80a7, 0xbf681a5ac1576ece),
+        (0x3ba4a154f04a329d, 0x3f0b453f2618b11c),
+        (0x3bba2eef03658ae2, 0x3f2335c661c2ccf2),
+        (0xbb3169508c61ee90, 0xbec5bf8afc8bb5c2),
+        (0x3b6a478d761ddc32, 0xbecd170031499cac),
+        (0xbb04d9a8860de141, 0x3e707a212b6da498),
+    ],
+    [
+        (0xbc1c5c957fb92aa1, 0xbf9125e4bdbff5f6),
+        (0x3bc856d2bd6761f1, 0x3f325ee8a9fab04d),
+        (0x3c04357f2766babf, 0x3f66d6a0a30a8a8c),
+        (0x3b53fb89537c0893, 0xbf087b0478f40bb5),
+        (0xbbb9f294a0885e1c, 0xbf22358f7dbdf006),
+        (0x3b59912891886247, 0x3ec3878cdf8cce6f),
+        (0xbb4295607110c475, 0x3ecb978bdd095b5a),
+        (0x3b0d1767442ae1ea, 0xbe6d9bf9a14fd6ac),
+    ],
+    [
+        (0xbc30b9cbadc2523e, 0x3f904a91c1cf1cd6),
+        (0x3ba64a9b3cc5ffd4, 0xbf3094e4e2e504a1),
+        (0x3c07846f76508da3, 0xbf65b3216fa0c3f2),
+        (0xbba5affb0bcf7170, 0x3f0618fc59a60852),
+        (0xbbb6b7a24147e2c0, 0x3f214e9ae9ce94a6),
+        (0x3b5d9f35a831c6a6, 0xbec1a2218730d606),
+        (0xbb42bcb7b8d0e0d6, 0xbeca3d43c162d195),
+        (0x3ae6e8f7d47c5953, 0x3e6abf6311936699),
+    ],
+];
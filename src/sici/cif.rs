@@ -0,0 +1,80 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::exponents::EULER_DD;
+use crate::logs::simple_fast_log;
+use crate::sici::ci_coeffs::{CI_ZEROS, CI_ZEROS_TAYLOR};
+use crate::sici::sici_core::ci_zero_index;
+use crate::sici::sici_coref::{cos_integral_sumf, sici_auxiliaryf, taylor_at_zerof};
+
+/// Computes cosine integral Ci(x)
+///
+/// Ci(x) = -integral(cos(t)/t, t = x..inf), defined for x > 0.
+pub fn f_cif(x: f32) -> f32 {
+    if !x.is_normal() || x < 0. {
+        if x.is_nan() || x < 0. {
+            return f32::NAN;
+        }
+        if x == 0. {
+            return f32::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return 0.;
+        }
+    }
+    let xd = x as f64;
+    if x <= 64. {
+        if let Some(idx) = ci_zero_index(xd) {
+            // Series and auxiliary functions lose precision near zeros
+            return taylor_at_zerof(xd, CI_ZEROS[idx], &CI_ZEROS_TAYLOR[idx][..4]) as f32;
+        }
+    }
+    if x <= 4. {
+        // Ci(x) = euler + log(x) + sum((-x^2)^k / (2k * (2k)!))
+        let s = cos_integral_sumf(-xd * xd);
+        return (EULER_DD.hi + simple_fast_log(xd) + s) as f32;
+    }
+    // Ci(x) = f(x) * sin(x) - g(x) * cos(x)
+    let aux = sici_auxiliaryf(x);
+    (aux.f * aux.sin - aux.g * aux.cos) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cif() {
+        assert_eq!(f_cif(1.), 0.33740392);
+        assert_eq!(f_cif(10.), -0.045456433);
+        assert_eq!(f_cif(0.), f32::NEG_INFINITY);
+        assert_eq!(f_cif(f32::INFINITY), 0.);
+        assert!(f_cif(-1.).is_nan());
+        assert!(f_cif(f32::NAN).is_nan());
+    }
+}
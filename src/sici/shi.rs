@@ -0,0 +1,65 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::sici::sici_core::{half_ei_large, sin_integral_sum};
+
+/// Computes hyperbolic sine integral Shi(x)
+///
+/// Shi(x) = integral(sinh(t)/t, t = 0..x).
+pub fn f_shi(x: f64) -> f64 {
+    if !x.is_normal() {
+        // Shi(x) ~ x + x^3/18, this also covers signed zeros and infinities
+        return x;
+    }
+    let ax = x.abs();
+    let r = if ax <= 60. {
+        let dx = DoubleDouble::new(0., ax);
+        sin_integral_sum(dx, DoubleDouble::quick_mult(dx, dx)).to_f64()
+    } else {
+        // Shi(x) = (Ei(x) + E1(x)) / 2 and E1(x) is negligible
+        half_ei_large(ax)
+    };
+    f64::copysign(r, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shi() {
+        assert_eq!(f_shi(1.), 1.0572508753757286);
+        assert_eq!(f_shi(-1.), -1.0572508753757286);
+        assert_eq!(f_shi(100.), 1.35777637242694e41);
+        assert_eq!(f_shi(800.), f64::INFINITY);
+        assert_eq!(f_shi(0.), 0.);
+        assert_eq!(f_shi(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(f_shi(f64::NAN).is_nan());
+    }
+}
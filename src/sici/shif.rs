@@ -0,0 +1,62 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::sici::sici_coref::{half_ei_largef, sin_integral_sumf};
+
+/// Computes hyperbolic sine integral Shi(x)
+///
+/// Shi(x) = integral(sinh(t)/t, t = 0..x).
+pub fn f_shif(x: f32) -> f32 {
+    if !x.is_normal() {
+        // Shi(x) ~ x + x^3/18, this also covers signed zeros and infinities
+        return x;
+    }
+    let ax = x.abs();
+    let xd = ax as f64;
+    let r = if ax <= 40. {
+        sin_integral_sumf(xd, xd * xd) as f32
+    } else {
+        // Shi(x) = (Ei(x) + E1(x)) / 2 and E1(x) is negligible
+        half_ei_largef(xd)
+    };
+    f32::copysign(r, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shif() {
+        assert_eq!(f_shif(1.), 1.0572509);
+        assert_eq!(f_shif(-1.), -1.0572509);
+        assert_eq!(f_shif(100.), f32::INFINITY);
+        assert_eq!(f_shif(0.), 0.);
+        assert!(f_shif(f32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::sici::sici_core::{PI_OVER_2_DD, sici_auxiliary, sin_integral_sum};
+
+/// Computes sine integral Si(x)
+///
+/// Si(x) = integral(sin(t)/t, t = 0..x).
+pub fn f_si(x: f64) -> f64 {
+    if !x.is_normal() {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x.is_infinite() {
+            return f64::copysign(PI_OVER_2_DD.hi, x);
+        }
+        // Si(x) ~ x - x^3/18
+        return x;
+    }
+    let ax = x.abs();
+    if ax >= f64::from_bits(0x5ff0000000000000) {
+        // |x| >= 2^512, f(x) * cos(x) + g(x) * sin(x) ~ cos(x)/x is far below ulp(pi/2)
+        return f64::copysign(PI_OVER_2_DD.hi, x);
+    }
+    let r = if ax <= 4. {
+        let dx = DoubleDouble::new(0., ax);
+        sin_integral_sum(dx, -DoubleDouble::quick_mult(dx, dx))
+    } else {
+        // Si(x) = pi/2 - f(x) * cos(x) - g(x) * sin(x)
+        let aux = sici_auxiliary(ax);
+        let fc = DoubleDouble::quick_mult(aux.f, aux.cos);
+        let gs = DoubleDouble::quick_mult(aux.g, aux.sin);
+        DoubleDouble::full_dd_sub(DoubleDouble::full_dd_sub(PI_OVER_2_DD, fc), gs)
+    };
+    f64::copysign(r.to_f64(), x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_si() {
+        assert_eq!(f_si(1.), 0.946083070367183);
+        assert_eq!(f_si(-1.), -0.946083070367183);
+        assert_eq!(f_si(10.), 1.6583475942188741);
+        assert_eq!(f_si(1000.), 1.5702331219687713);
+        assert_eq!(f_si(1e305), std::f64::consts::FRAC_PI_2);
+        assert_eq!(f_si(-f64::MAX), -std::f64::consts::FRAC_PI_2);
+        assert_eq!(f_si(0.), 0.);
+        assert_eq!(f_si(f64::INFINITY), std::f64::consts::FRAC_PI_2);
+        assert_eq!(f_si(f64::NEG_INFINITY), -std::f64::consts::FRAC_PI_2);
+        assert!(f_si(f64::NAN).is_nan());
+    }
+}
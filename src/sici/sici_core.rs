@@ -0,0 +1,355 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::exponents::{ei_asympt_scaled, log_abs_dd, mul_exp_pos};
+use crate::rounding::CpuRound;
+use crate::sici::ci_coeffs::CI_ZEROS;
+use crate::sin::range_reduction_small;
+use crate::sin_table::SIN_K_PI_OVER_128;
+use crate::sincos_reduce::LargeArgumentReduction;
+
+pub(crate) const PI_OVER_2_DD: DoubleDouble =
+    DoubleDouble::from_bit_pair((0x3c91a62633145c07, 0x3ff921fb54442d18));
+
+/// Positive zero of Chi(x), x0 = 0.52382257138986440645...
+pub(crate) const CHI_ZERO: (u64, u64, u64) =
+    (0x3fe0c3278da0d5c1, 0xbc7ec000b0a540ec, 0xb8fe0d2c5681cf25);
+
+/// Taylor expansion of Chi(x) at its zero x0.
+///
+/// Generated by SageMath:
+/// ```text
+/// R = RealField(400)
+/// x0 = find_root(lambda t: cosh_integral(R(t)), 0.5, 0.6)
+/// for k in range(1, 9):
+///     c = derivative(cosh_integral(x), x, k).subs(x=x0) / factorial(k)
+/// ```
+pub(crate) static CHI_TAYLOR: [(u64, u64); 8] = [
+    (0x3ca2701ac871192d, 0x40016a7e31bf3493),
+    (0x3c78f1dcf064e5e4, 0xbff8e08125ccdc44),
+    (0xbc83ce02c86e5e87, 0x4002bbaa774d63ff),
+    (0xbca31e203b2a3361, 0xc00a790f97d14727),
+    (0xbcbf7ccf3e1bf08c, 0x401449a8862e5f8e),
+    (0xbc73fdb11083641d, 0xc0202278dc8aa3f1),
+    (0x3cc3b2ae5996e3c3, 0x402a6701d5f59977),
+    (0x3cc7dfda666e65e4, 0xc0360d28ed058917),
+];
+
+/// Finds index of a zero of Ci(x) in `CI_ZEROS` closer than 2^-12 to x, for 0 < x <= 64.
+#[inline]
+pub(crate) fn ci_zero_index(x: f64) -> Option<usize> {
+    const ONE_OVER_PI: f64 = f64::from_bits(0x3fd45f306dc9c883);
+    // Zeros of Ci(x) beyond the first one are close to k*pi + 0.1
+    let idx = if x < 2. {
+        0
+    } else {
+        unsafe {
+            ((x - 0.1) * ONE_OVER_PI)
+                .cpu_round()
+                .min((CI_ZEROS.len() - 1) as f64)
+                .to_int_unchecked::<usize>()
+        }
+    };
+    if (x - f64::from_bits(CI_ZEROS[idx].0)).abs() < f64::from_bits(0x3f30000000000000) {
+        // |x - x0| < 2^-12
+        return Some(idx);
+    }
+    None
+}
+
+/// Evaluates Taylor expansion sum(c_k * (x - x0)^k, k = 1..n) at a zero x0 given as triple-double.
+#[inline]
+pub(crate) fn taylor_at_zero(x: f64, x0: (u64, u64, u64), c: &[(u64, u64)]) -> DoubleDouble {
+    // d = x - x0 with x0 taken in triple-double precision
+    let d0 = DoubleDouble::from_full_exact_sub(x, f64::from_bits(x0.0));
+    let d1 = DoubleDouble::full_add_f64(d0, -f64::from_bits(x0.1));
+    let d = DoubleDouble::full_add_f64(d1, -f64::from_bits(x0.2));
+    let (last, rest) = c.split_last().unwrap();
+    let mut p = DoubleDouble::from_bit_pair(*last);
+    for &ci in rest.iter().rev() {
+        p = DoubleDouble::mul_add(p, d, DoubleDouble::from_bit_pair(ci));
+    }
+    DoubleDouble::quick_mult(p, d)
+}
+
+/// Computes sum(t^k * x / ((2k + 1) * (2k + 1)!), k = 0..inf).
+///
+/// With t = -x^2 this is Si(x), with t = x^2 this is Shi(x).
+#[inline]
+pub(crate) fn sin_integral_sum(x: DoubleDouble, t: DoubleDouble) -> DoubleDouble {
+    let mut term = x;
+    let mut sum = x;
+    let mut k = 1f64;
+    while k < 200. {
+        // term = t^k * x / (2k + 1)!
+        term =
+            DoubleDouble::div_dd_f64(DoubleDouble::quick_mult(term, t), (2. * k) * (2. * k + 1.));
+        let v = DoubleDouble::div_dd_f64(term, 2. * k + 1.);
+        sum = DoubleDouble::full_dd_add(sum, v);
+        if v.hi.abs() <= f64::from_bits(0x3910000000000000) * sum.hi.abs() {
+            // |v| < 2^-110 * |sum|
+            break;
+        }
+        k += 1.;
+    }
+    sum
+}
+
+/// Computes sum(t^k / (2k * (2k)!), k = 1..inf).
+///
+/// With t = -x^2 this is Ci(x) - euler - log(x), with t = x^2 this is Chi(x) - euler - log(x).
+#[inline]
+pub(crate) fn cos_integral_sum(t: DoubleDouble) -> DoubleDouble {
+    let mut term = DoubleDouble::div_dd_f64(t, 2.);
+    let mut sum = DoubleDouble::div_dd_f64(term, 2.);
+    let mut k = 2f64;
+    while k < 200. {
+        // term = t^k / (2k)!
+        term =
+            DoubleDouble::div_dd_f64(DoubleDouble::quick_mult(term, t), (2. * k - 1.) * (2. * k));
+        let v = DoubleDouble::div_dd_f64(term, 2. * k);
+        sum = DoubleDouble::full_dd_add(sum, v);
+        if v.hi.abs() <= f64::from_bits(0x3910000000000000) * sum.hi.abs() {
+            // |v| < 2^-110 * |sum|
+            break;
+        }
+        k += 1.;
+    }
+    sum
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct ComplexDD {
+    pub(crate) re: DoubleDouble,
+    pub(crate) im: DoubleDouble,
+}
+
+impl ComplexDD {
+    #[inline]
+    pub(crate) fn mul(self, b: ComplexDD) -> ComplexDD {
+        let re = DoubleDouble::full_dd_sub(
+            DoubleDouble::quick_mult(self.re, b.re),
+            DoubleDouble::quick_mult(self.im, b.im),
+        );
+        let im = DoubleDouble::full_dd_add(
+            DoubleDouble::quick_mult(self.re, b.im),
+            DoubleDouble::quick_mult(self.im, b.re),
+        );
+        ComplexDD { re, im }
+    }
+
+    #[inline]
+    pub(crate) fn recip(self) -> ComplexDD {
+        let den =
+            DoubleDouble::mul_add(self.re, self.re, DoubleDouble::quick_mult(self.im, self.im));
+        let r = den.recip();
+        ComplexDD {
+            re: DoubleDouble::quick_mult(self.re, r),
+            im: -DoubleDouble::quick_mult(self.im, r),
+        }
+    }
+
+    /// Computes self * a + b
+    #[inline]
+    pub(crate) fn mul_f64_add(self, a: f64, b: ComplexDD) -> ComplexDD {
+        ComplexDD {
+            re: DoubleDouble::mul_f64_add(self.re, a, b.re),
+            im: DoubleDouble::mul_f64_add(self.im, a, b.im),
+        }
+    }
+}
+
+/// Computes auxiliary functions f(x), g(x) for 4 <= x <= 100.
+///
+/// e^(ix) * E1(ix) = g(x) - i*f(x) is evaluated as continued fraction
+/// 1/(1+ix-) 1/(3+ix-) 4/(5+ix-) ... by modified Lentz's method.
+fn sici_auxiliary_cf(x: f64) -> (DoubleDouble, DoubleDouble) {
+    let mut b = ComplexDD {
+        re: DoubleDouble::new(0., 1.),
+        im: DoubleDouble::new(0., x),
+    };
+    let mut d = b.recip();
+    let mut h = d;
+    // First step is done explicitly, since initial value of c is infinity
+    b.re.hi += 2.;
+    d = d.mul_f64_add(-1., b).recip();
+    let mut c = b;
+    h = h.mul(c.mul(d));
+    let mut i = 2f64;
+    while i < 500. {
+        let a = -i * i;
+        b.re.hi += 2.;
+        d = d.mul_f64_add(a, b).recip();
+        c = c.recip().mul_f64_add(a, b);
+        let del = c.mul(d);
+        h = h.mul(del);
+        if (del.re.hi - 1.).abs() + del.im.hi.abs() <= f64::from_bits(0x3950000000000000) {
+            // |del - 1| < 2^-106
+            break;
+        }
+        i += 1.;
+    }
+    (-h.im, h.re)
+}
+
+/// Computes auxiliary functions f(x), g(x) for x > 100 using asymptotic expansions.
+///
+/// f(x) ~ 1/x * sum((-1)^k * (2k)! / x^(2k)),
+/// g(x) ~ 1/x^2 * sum((-1)^k * (2k + 1)! / x^(2k)).
+fn sici_auxiliary_asympt(x: f64) -> (DoubleDouble, DoubleDouble) {
+    let r = DoubleDouble::from_recip(x);
+    let mr2 = -DoubleDouble::quick_mult(r, r);
+    let mut tf = DoubleDouble::new(0., 1.);
+    let mut sf = tf;
+    let mut tg = DoubleDouble::new(0., 1.);
+    let mut sg = tg;
+    let mut k = 1f64;
+    while k < 60. {
+        tf = DoubleDouble::quick_mult_f64(
+            DoubleDouble::quick_mult(tf, mr2),
+            (2. * k - 1.) * (2. * k),
+        );
+        tg = DoubleDouble::quick_mult_f64(
+            DoubleDouble::quick_mult(tg, mr2),
+            (2. * k) * (2. * k + 1.),
+        );
+        sf = DoubleDouble::add(sf, tf);
+        sg = DoubleDouble::add(sg, tg);
+        if tg.hi.abs() <= f64::from_bits(0x3910000000000000) {
+            // |term| < 2^-110
+            break;
+        }
+        k += 1.;
+    }
+    let f = DoubleDouble::quick_mult(sf, r);
+    let g = DoubleDouble::quick_mult(sg, DoubleDouble::quick_mult(r, r));
+    (f, g)
+}
+
+/// Computes auxiliary functions f(x), g(x) and sin(x), cos(x) for x >= 4.
+///
+/// Si(x) = pi/2 - f(x) * cos(x) - g(x) * sin(x),
+/// Ci(x) = f(x) * sin(x) - g(x) * cos(x).
+#[inline]
+pub(crate) fn sici_auxiliary(x: f64) -> SiCiAuxiliary {
+    let (f, g) = if x <= 100. {
+        sici_auxiliary_cf(x)
+    } else {
+        sici_auxiliary_asympt(x)
+    };
+    let (sin, cos) = sincos_dd(x);
+    SiCiAuxiliary { f, g, sin, cos }
+}
+
+/// Evaluates sin(u) and cos(u) for |u| <= pi/256 with Taylor polynomials up to u^13 and u^12.
+///
+/// This is more accurate than `sincos_eval_dd`, since Ci(x) near its zeros is sensitive to errors in both.
+#[inline]
+fn sincos_eval_accurate(u: DoubleDouble) -> (DoubleDouble, DoubleDouble) {
+    const SIN_C: [(u64, u64); 7] = [
+        (0x0000000000000000, 0x3ff0000000000000),
+        (0xbc65555555555555, 0xbfc5555555555555),
+        (0x3c01111111111111, 0x3f81111111111111),
+        (0xbb6a01a01a01a01a, 0xbf2a01a01a01a01a),
+        (0xbb6c154f8ddc6c00, 0x3ec71de3a556c734),
+        (0x3afc062e06d1f209, 0xbe5ae64567f544e4),
+        (0x3a8f28e0cc748ebe, 0x3de6124613a86d09),
+    ];
+    const COS_C: [(u64, u64); 7] = [
+        (0x0000000000000000, 0x3ff0000000000000),
+        (0x0000000000000000, 0xbfe0000000000000),
+        (0x3c45555555555555, 0x3fa5555555555555),
+        (0x3bef49f49f49f49f, 0xbf56c16c16c16c17),
+        (0x3b3a01a01a01a01a, 0x3efa01a01a01a01a),
+        (0xbb3cbbc05b4fa99a, 0xbe927e4fb7789f5c),
+        (0xbac2aec959e14c06, 0x3e21eed8eff8d898),
+    ];
+    let u2 = DoubleDouble::quick_mult(u, u);
+    let mut ps = DoubleDouble::from_bit_pair(SIN_C[6]);
+    let mut pc = DoubleDouble::from_bit_pair(COS_C[6]);
+    for i in (0..6).rev() {
+        ps = DoubleDouble::mul_add(ps, u2, DoubleDouble::from_bit_pair(SIN_C[i]));
+        pc = DoubleDouble::mul_add(pc, u2, DoubleDouble::from_bit_pair(COS_C[i]));
+    }
+    (DoubleDouble::quick_mult(ps, u), pc)
+}
+
+/// Computes sin(x) and cos(x) in double-double precision.
+#[inline]
+pub(crate) fn sincos_dd(x: f64) -> (DoubleDouble, DoubleDouble) {
+    let (y, k) = if x < f64::from_bits(0x40f0000000000000) {
+        // |x| < 2^16
+        range_reduction_small(x)
+    } else {
+        let mut argument_reduction = LargeArgumentReduction::default();
+        let (k, y) = argument_reduction.reduce(x);
+        (y, k)
+    };
+    let (sin_y, cos_y) = sincos_eval_accurate(y);
+
+    // cos(k * pi/128) = sin(k * pi/128 + pi/2) = sin((k + 64) * pi/128).
+    let sin_k = DoubleDouble::from_bit_pair(SIN_K_PI_OVER_128[(k & 255) as usize]);
+    let cos_k =
+        DoubleDouble::from_bit_pair(SIN_K_PI_OVER_128[((k.wrapping_add(64)) & 255) as usize]);
+
+    // sin(x) = sin(y) * cos(k*pi/128) + cos(y) * sin(k*pi/128)
+    // cos(x) = cos(y) * cos(k*pi/128) - sin(y) * sin(k*pi/128)
+    let sin = DoubleDouble::full_dd_add(
+        DoubleDouble::quick_mult(sin_y, cos_k),
+        DoubleDouble::quick_mult(cos_y, sin_k),
+    );
+    let cos = DoubleDouble::full_dd_sub(
+        DoubleDouble::quick_mult(cos_y, cos_k),
+        DoubleDouble::quick_mult(sin_y, sin_k),
+    );
+    (sin, cos)
+}
+
+pub(crate) struct SiCiAuxiliary {
+    pub(crate) f: DoubleDouble,
+    pub(crate) g: DoubleDouble,
+    pub(crate) sin: DoubleDouble,
+    pub(crate) cos: DoubleDouble,
+}
+
+/// Computes Ei(x) / 2 for x > 60, which equals Shi(x) and Chi(x) to full precision there.
+#[inline]
+pub(crate) fn half_ei_large(x: f64) -> f64 {
+    if x > 720. {
+        return f64::INFINITY;
+    }
+    // Ei(x) / 2 = e^(x - log(2x)) * sum(k!/x^k)
+    let dx = DoubleDouble::new(0., x);
+    let log_2x = log_abs_dd(DoubleDouble::new(0., 2. * x));
+    let z = DoubleDouble::full_dd_sub(dx, log_2x);
+    if z.hi > 709.782712893384 {
+        return f64::INFINITY;
+    }
+    mul_exp_pos(ei_asympt_scaled(dx), z)
+}
@@ -0,0 +1,159 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::exponents::{core_expdf, ei_asympt_scaledf};
+use crate::sin_cosf::sincosf_eval;
+
+/// Evaluates Taylor expansion sum(c_k * (x - x0)^k, k = 1..n) at a zero x0 given as triple-double,
+/// using only leading parts of the coefficients.
+#[inline]
+pub(crate) fn taylor_at_zerof(x: f64, x0: (u64, u64, u64), c: &[(u64, u64)]) -> f64 {
+    // x - x0.hi is exact by Sterbenz lemma
+    let d = (x - f64::from_bits(x0.0)) - f64::from_bits(x0.1);
+    let (last, rest) = c.split_last().unwrap();
+    let mut p = f64::from_bits(last.1);
+    for &ci in rest.iter().rev() {
+        p = f_fmla(p, d, f64::from_bits(ci.1));
+    }
+    p * d
+}
+
+/// Computes sum(t^k * x / ((2k + 1) * (2k + 1)!), k = 0..inf).
+///
+/// With t = -x^2 this is Si(x), with t = x^2 this is Shi(x).
+#[inline]
+pub(crate) fn sin_integral_sumf(x: f64, t: f64) -> f64 {
+    let mut term = x;
+    let mut sum = x;
+    let mut k = 1f64;
+    while k < 100. {
+        // term = t^k * x / (2k + 1)!
+        term = term * t / ((2. * k) * (2. * k + 1.));
+        let v = term / (2. * k + 1.);
+        sum += v;
+        if v.abs() <= f64::from_bits(0x3c90000000000000) * sum.abs() {
+            // |v| < 2^-54 * |sum|
+            break;
+        }
+        k += 1.;
+    }
+    sum
+}
+
+/// Computes sum(t^k / (2k * (2k)!), k = 1..inf).
+///
+/// With t = -x^2 this is Ci(x) - euler - log(x), with t = x^2 this is Chi(x) - euler - log(x).
+#[inline]
+pub(crate) fn cos_integral_sumf(t: f64) -> f64 {
+    let mut term = t / 2.;
+    let mut sum = term / 2.;
+    let mut k = 2f64;
+    while k < 100. {
+        // term = t^k / (2k)!
+        term = term * t / ((2. * k - 1.) * (2. * k));
+        let v = term / (2. * k);
+        sum += v;
+        if v.abs() <= f64::from_bits(0x3c90000000000000) * sum.abs() {
+            // |v| < 2^-54 * |sum|
+            break;
+        }
+        k += 1.;
+    }
+    sum
+}
+
+/// Computes auxiliary functions f(x), g(x) for x >= 4.
+///
+/// e^(ix) * E1(ix) = g(x) - i*f(x) is evaluated as continued fraction
+/// 1/(1+ix-) 1/(3+ix-) 4/(5+ix-) ... by modified Lentz's method.
+fn sici_auxiliary_cff(x: f64) -> (f64, f64) {
+    #[inline]
+    fn recip(re: f64, im: f64) -> (f64, f64) {
+        let den = f_fmla(re, re, im * im);
+        (re / den, -im / den)
+    }
+    #[inline]
+    fn mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+        (f_fmla(a.0, b.0, -a.1 * b.1), f_fmla(a.0, b.1, a.1 * b.0))
+    }
+    let mut b = (1f64, x);
+    let mut d = recip(b.0, b.1);
+    let mut h = d;
+    // First step is done explicitly, since initial value of c is infinity
+    b.0 += 2.;
+    d = recip(b.0 - d.0, b.1 - d.1);
+    let mut c = b;
+    h = mul(h, mul(c, d));
+    let mut i = 2f64;
+    while i < 300. {
+        let a = -i * i;
+        b.0 += 2.;
+        d = recip(f_fmla(a, d.0, b.0), f_fmla(a, d.1, b.1));
+        let rc = recip(c.0, c.1);
+        c = (f_fmla(a, rc.0, b.0), f_fmla(a, rc.1, b.1));
+        let del = mul(c, d);
+        h = mul(h, del);
+        if (del.0 - 1.).abs() + del.1.abs() <= f64::from_bits(0x3c90000000000000) {
+            // |del - 1| < 2^-54
+            break;
+        }
+        i += 1.;
+    }
+    (-h.1, h.0)
+}
+
+pub(crate) struct SiCiAuxiliaryF {
+    pub(crate) f: f64,
+    pub(crate) g: f64,
+    pub(crate) sin: f64,
+    pub(crate) cos: f64,
+}
+
+/// Computes auxiliary functions f(x), g(x) and sin(x), cos(x) for x >= 4.
+///
+/// Si(x) = pi/2 - f(x) * cos(x) - g(x) * sin(x),
+/// Ci(x) = f(x) * sin(x) - g(x) * cos(x).
+#[inline]
+pub(crate) fn sici_auxiliaryf(x: f32) -> SiCiAuxiliaryF {
+    let xd = x as f64;
+    let (f, g) = sici_auxiliary_cff(xd);
+    let rs = sincosf_eval(xd, x.to_bits() & 0x7fff_ffff);
+    let sin = f_fmla(rs.sin_y, rs.cos_k, f_fmla(rs.cosm1_y, rs.sin_k, rs.sin_k));
+    let cos = f_fmla(rs.sin_y, -rs.sin_k, f_fmla(rs.cosm1_y, rs.cos_k, rs.cos_k));
+    SiCiAuxiliaryF { f, g, sin, cos }
+}
+
+/// Computes Ei(x) / 2 for x > 40, which equals Shi(x) and Chi(x) to full precision there.
+#[inline]
+pub(crate) fn half_ei_largef(x: f64) -> f32 {
+    if x > 95. {
+        return f32::INFINITY;
+    }
+    (core_expdf(x) / (2. * x) * ei_asympt_scaledf(x)) as f32
+}
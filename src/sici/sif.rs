@@ -0,0 +1,71 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::sici::sici_coref::{sici_auxiliaryf, sin_integral_sumf};
+
+/// Computes sine integral Si(x)
+///
+/// Si(x) = integral(sin(t)/t, t = 0..x).
+pub fn f_sif(x: f32) -> f32 {
+    if !x.is_normal() {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        if x.is_infinite() {
+            return f32::copysign(std::f32::consts::FRAC_PI_2, x);
+        }
+        // Si(x) ~ x - x^3/18
+        return x;
+    }
+    let ax = x.abs();
+    let r = if ax <= 4. {
+        let xd = ax as f64;
+        sin_integral_sumf(xd, -xd * xd)
+    } else {
+        // Si(x) = pi/2 - f(x) * cos(x) - g(x) * sin(x)
+        const PI_OVER_2: f64 = f64::from_bits(0x3ff921fb54442d18);
+        let aux = sici_auxiliaryf(ax);
+        PI_OVER_2 - aux.f * aux.cos - aux.g * aux.sin
+    };
+    f32::copysign(r as f32, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sif() {
+        assert_eq!(f_sif(1.), 0.94608307);
+        assert_eq!(f_sif(-1.), -0.94608307);
+        assert_eq!(f_sif(10.), 1.6583476);
+        assert_eq!(f_sif(0.), 0.);
+        assert_eq!(f_sif(f32::INFINITY), std::f32::consts::FRAC_PI_2);
+        assert!(f_sif(f32::NAN).is_nan());
+    }
+}
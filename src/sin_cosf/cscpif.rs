@@ -0,0 +1,66 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::is_integerf;
+use crate::sin_cosf::sinpif::fast_sinpif;
+
+/// Computes 1/sin(PI*x)
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_cscpif(x: f32) -> f32 {
+    let x_abs = x.to_bits() & 0x7fff_ffffu32;
+
+    // Numbers greater or equal to 2^23 are always integers or NaN
+    if x_abs >= 0x4b00_0000u32 || is_integerf(x) {
+        if x_abs >= 0x7f80_0000u32 {
+            return x + f32::NAN;
+        }
+        // sin(PI*x) = 0
+        return f32::copysign(f32::INFINITY, x);
+    }
+
+    (1. / fast_sinpif(x)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f_cscpif() {
+        assert_eq!(f_cscpif(0.), f32::INFINITY);
+        assert_eq!(f_cscpif(-0.), f32::NEG_INFINITY);
+        assert_eq!(f_cscpif(3.), f32::INFINITY);
+        assert_eq!(f_cscpif(0.5), 1.);
+        assert_eq!(f_cscpif(-1.5), 1.);
+        assert_eq!(f_cscpif(0.25), std::f32::consts::SQRT_2);
+        assert!(f_cscpif(f32::INFINITY).is_nan());
+        assert!(f_cscpif(f32::NAN).is_nan());
+    }
+}
@@ -32,8 +32,11 @@ mod cosf;
 mod cosm1f;
 mod cospif;
 mod cscf;
+mod cscpif;
 mod secf;
+mod secpif;
 mod sincf;
+mod sincosdf;
 mod sincosf;
 mod sincosf_eval;
 mod sincospif;
@@ -48,9 +51,14 @@ pub use cosf::f_cosf;
 pub use cosm1f::f_cosm1f;
 pub use cospif::f_cospif;
 pub use cscf::f_cscf;
+pub use cscpif::f_cscpif;
 pub use secf::f_secf;
+pub use secpif::f_secpif;
 pub use sincf::f_sincf;
+pub(crate) use sincosdf::reduce_degreesf;
+pub use sincosdf::{f_cosdf, f_sincosdf, f_sindf};
 pub use sincosf::f_sincosf;
+pub(crate) use sincosf_eval::sincosf_eval;
 pub use sincospif::f_sincospif;
 pub use sincpif::f_sincpif;
 pub use sinf::f_sinf;
@@ -0,0 +1,180 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::rounding::CpuRound;
+use crate::sin_cosf::sincosf_eval::{SinCosf, sincosf_eval_reduced};
+
+/// Reduces argument in degrees to (k + y) * 5.625, where 5.625 degrees = pi/32
+///
+/// Returns y in [-0.5, 0.5] and k.
+#[inline(always)]
+pub(crate) fn reduce_degreesf(x: f32) -> (f64, i64) {
+    // x mod 360 is exact
+    let r = x as f64 % 360.;
+    // 8/45 ~ 1/5.625
+    const ONE_OVER_5P625: f64 = f64::from_bits(0x3fc6c16c16c16c17);
+    let kd = (r * ONE_OVER_5P625).cpu_round();
+    // r - k * 5.625 is exact
+    let y = f_fmla(kd, -5.625, r) * ONE_OVER_5P625;
+    (y, kd as i64)
+}
+
+#[inline(always)]
+fn sincosdf_eval(x: f32) -> (f64, i64, SinCosf) {
+    let (y, k) = reduce_degreesf(x);
+    (y, k, sincosf_eval_reduced(y, k))
+}
+
+/// Computes sine of argument in degrees
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_sindf(x: f32) -> f32 {
+    let x_abs = x.to_bits() & 0x7fff_ffff;
+    if x_abs >= 0x7f80_0000u32 {
+        // Inf or NaN
+        return x + f32::NAN;
+    }
+    if x_abs == 0 {
+        return x;
+    }
+
+    let (y, k, rs) = sincosdf_eval(x);
+
+    if y == 0. {
+        // Exact multiples of 90 degrees
+        match k & 63 {
+            0 | 32 => return f32::copysign(0., x),
+            16 => return 1.,
+            48 => return -1.,
+            _ => {}
+        }
+    }
+
+    //   sin(x) = sin((k + y)*pi/32)
+    //          = sin(y*pi/32) * cos(k*pi/32) + cos(y*pi/32) * sin(k*pi/32)
+    f_fmla(rs.sin_y, rs.cos_k, f_fmla(rs.cosm1_y, rs.sin_k, rs.sin_k)) as f32
+}
+
+/// Computes cosine of argument in degrees
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_cosdf(x: f32) -> f32 {
+    let x_abs = x.to_bits() & 0x7fff_ffff;
+    if x_abs >= 0x7f80_0000u32 {
+        // Inf or NaN
+        return x + f32::NAN;
+    }
+    if x_abs == 0 {
+        return 1.;
+    }
+
+    let (y, k, rs) = sincosdf_eval(x);
+
+    if y == 0. {
+        // Exact multiples of 90 degrees
+        match k & 63 {
+            0 => return 1.,
+            32 => return -1.,
+            16 | 48 => return 0.,
+            _ => {}
+        }
+    }
+
+    //   cos(x) = cos((k + y)*pi/32)
+    //          = cos(y*pi/32) * cos(k*pi/32) - sin(y*pi/32) * sin(k*pi/32)
+    f_fmla(rs.sin_y, -rs.sin_k, f_fmla(rs.cosm1_y, rs.cos_k, rs.cos_k)) as f32
+}
+
+/// Computes sine and cosine of argument in degrees at the same time
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_sincosdf(x: f32) -> (f32, f32) {
+    let x_abs = x.to_bits() & 0x7fff_ffff;
+    if x_abs >= 0x7f80_0000u32 {
+        // Inf or NaN
+        return (x + f32::NAN, x + f32::NAN);
+    }
+    if x_abs == 0 {
+        return (x, 1.);
+    }
+
+    let (y, k, rs) = sincosdf_eval(x);
+
+    if y == 0. {
+        // Exact multiples of 90 degrees
+        match k & 63 {
+            0 => return (f32::copysign(0., x), 1.),
+            16 => return (1., 0.),
+            32 => return (f32::copysign(0., x), -1.),
+            48 => return (-1., 0.),
+            _ => {}
+        }
+    }
+
+    let sf = f_fmla(rs.sin_y, rs.cos_k, f_fmla(rs.cosm1_y, rs.sin_k, rs.sin_k)) as f32;
+    let cs = f_fmla(rs.sin_y, -rs.sin_k, f_fmla(rs.cosm1_y, rs.cos_k, rs.cos_k)) as f32;
+    (sf, cs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sindf() {
+        assert_eq!(f_sindf(30.), 0.5);
+        assert_eq!(f_sindf(90.), 1.);
+        assert_eq!(f_sindf(-90.), -1.);
+        assert_eq!(f_sindf(180.), 0.);
+        assert_eq!(f_sindf(-180.), -0.);
+        assert!(f_sindf(f32::INFINITY).is_nan());
+        assert!(f_sindf(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cosdf() {
+        assert_eq!(f_cosdf(60.), 0.5);
+        assert_eq!(f_cosdf(0.), 1.);
+        assert_eq!(f_cosdf(90.), 0.);
+        assert_eq!(f_cosdf(180.), -1.);
+        assert!(f_cosdf(f32::INFINITY).is_nan());
+        assert!(f_cosdf(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_sincosdf() {
+        let v = f_sincosdf(123.456);
+        assert_eq!(v.0, f_sindf(123.456));
+        assert_eq!(v.1, f_cosdf(123.456));
+        assert_eq!(f_sincosdf(270.), (-1., 0.));
+    }
+}
@@ -120,50 +120,7 @@ pub(crate) fn sincosf_eval(x: f64, x_abs: u32) -> SinCosf {
     let argument_reduction = ArgumentReducer { x, x_abs };
 
     let (y, k) = argument_reduction.reduce();
-
-    let y_sqr = y * y;
-
-    // After range reduction, k = round(x * 32 / pi) and y = (x * 32 / pi) - k.
-    // So k is an integer and -0.5 <= y <= 0.5.
-    // Then sin(x) = sin((k + y)*pi/32)
-    //             = sin(y*pi/32) * cos(k*pi/32) + cos(y*pi/32) * sin(k*pi/32)
-
-    let sin_k = f64::from_bits(SIN_K_PI_OVER32[((k as u64) & 63) as usize]);
-    // cos(k * pi/32) = sin(k * pi/32 + pi/2) = sin((k + 16) * pi/32).
-    // cos_k = cos(k * pi/32)
-    let cos_k = f64::from_bits(SIN_K_PI_OVER32[((k.wrapping_add(16) as u64) & 63) as usize]);
-
-    // Degree-6 minimax even polynomial for sin(y*pi/32)/y generated by Sollya
-    // with:
-    // > Q = fpminimax(sin(y*pi/32)/y, [|0, 2, 4, 6|], [|D...|], [0, 0.5]);
-    //
-    // See ./notes/sincosf.sollya
-    let sin_y = y * f_polyeval4(
-        y_sqr,
-        f64::from_bits(0x3fb921fb54442d18),
-        f64::from_bits(0xbf24abbce625abb1),
-        f64::from_bits(0x3e7466bc624f2776),
-        f64::from_bits(0xbdb32c3a619d4a7e),
-    );
-    // Degree-6 minimax even polynomial for cos(y*pi/32) generated by Sollya with:
-    // > P = fpminimax(cos(x*pi/32), [|0, 2, 4, 6|], [|1, D...|], [0, 0.5]);
-    // Note that cosm1_y = cos(y*pi/32) - 1.
-    //
-    // See ./notes/sincosf.sollya
-    let cosm1_y = y_sqr
-        * f_polyeval3(
-            y_sqr,
-            f64::from_bits(0xbf73bd3cc9be430b),
-            f64::from_bits(0x3ed03c1f070c2e27),
-            f64::from_bits(0xbe155cc84bd94200),
-        );
-
-    SinCosf {
-        sin_k,
-        cos_k,
-        sin_y,
-        cosm1_y,
-    }
+    sincosf_eval_reduced(y, k)
 }
 
 #[inline(always)]
@@ -171,7 +128,12 @@ pub(crate) fn sincospif_eval(x: f64) -> SinCosf {
     let argument_reduction = ArgumentReducerPi { x };
 
     let (y, k) = argument_reduction.reduce();
+    sincosf_eval_reduced(y, k)
+}
 
+/// Evaluates sin((k + y)*pi/32) and cos((k + y)*pi/32) parts for already reduced argument
+#[inline(always)]
+pub(crate) fn sincosf_eval_reduced(y: f64, k: i64) -> SinCosf {
     let y_sqr = y * y;
 
     // After range reduction, k = round(x * 32 / pi) and y = (x * 32 / pi) - k.
@@ -0,0 +1,233 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+use crate::sincospi::{sincospi_eval, sincospi_eval_dd};
+use crate::sincospi_tables::SINPI_K_PI_OVER_64;
+
+#[inline]
+fn i128_to_dd(v: i128) -> DoubleDouble {
+    let hi = v as f64;
+    let lo = (v - hi as i128) as f64;
+    DoubleDouble::new(lo, hi)
+}
+
+#[cold]
+#[inline(never)]
+fn sincospi_frac_dd(y: DoubleDouble, sin_k: DoubleDouble, cos_k: DoubleDouble) -> (f64, f64) {
+    const PI: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0x3ca1a62633145c07),
+        f64::from_bits(0x400921fb54442d18),
+    );
+    let mut r_sincos = sincospi_eval_dd(y.hi);
+    // sin(pi*(y_hi + y_lo)) ~ sin(pi*y_hi) + pi*y_lo*cos(pi*y_hi)
+    // cos(pi*(y_hi + y_lo)) ~ cos(pi*y_hi) - pi*y_lo*sin(pi*y_hi)
+    let pi_y_lo = PI.hi * y.lo;
+    r_sincos.v_sin = DoubleDouble::add(
+        r_sincos.v_sin,
+        DoubleDouble::quick_mult_f64(r_sincos.v_cos, pi_y_lo),
+    );
+    r_sincos.v_cos = DoubleDouble::add(
+        r_sincos.v_cos,
+        DoubleDouble::quick_mult_f64(r_sincos.v_sin, -pi_y_lo),
+    );
+
+    let cos_k_sin_y = DoubleDouble::quick_mult(cos_k, r_sincos.v_sin);
+    let rr_sin = DoubleDouble::mul_add(sin_k, r_sincos.v_cos, cos_k_sin_y);
+
+    let msin_k_sin_y = DoubleDouble::quick_mult(-sin_k, r_sincos.v_sin);
+    let rr_cos = DoubleDouble::mul_add(cos_k, r_sincos.v_cos, msin_k_sin_y);
+
+    (rr_sin.to_f64(), rr_cos.to_f64())
+}
+
+/// Computes sin(PI*r/(2*q)) and cos(PI*r/(2*q)) for 0 <= r <= q/2
+#[inline]
+fn sincospi_octant(r: i128, q: i128) -> (f64, f64) {
+    // x = r/(2q) in [0, 1/4], k = round(64*x), y = x - k/64
+    let k = (64 * r + q) / (2 * q);
+    let num = 64 * r - 2 * q * k;
+
+    let sin_k = DoubleDouble::from_bit_pair(SINPI_K_PI_OVER_64[k as usize]);
+    let cos_k = DoubleDouble::from_bit_pair(SINPI_K_PI_OVER_64[(k + 32) as usize]);
+
+    if num == 0 {
+        return (sin_k.hi, cos_k.hi);
+    }
+
+    // y = num / (128*q)
+    let y = if q < (1i128 << 53) {
+        let z = DoubleDouble::from_exact_div(num as f64, q as f64);
+        DoubleDouble::new(z.lo * (1. / 128.), z.hi * (1. / 128.))
+    } else {
+        let z = DoubleDouble::div(i128_to_dd(num), i128_to_dd(q));
+        DoubleDouble::new(z.lo * (1. / 128.), z.hi * (1. / 128.))
+    };
+
+    let mut r_sincos = sincospi_eval(y.hi);
+    // Correction for the lower part of y:
+    // sin(pi*(y_hi + y_lo)) ~ sin(pi*y_hi) + pi*y_lo*cos(pi*y_hi)
+    // cos(pi*(y_hi + y_lo)) ~ cos(pi*y_hi) - pi*y_lo*sin(pi*y_hi)
+    let pi_y_lo = f64::from_bits(0x400921fb54442d18) * y.lo;
+    r_sincos.v_sin.lo += pi_y_lo * r_sincos.v_cos.hi;
+    r_sincos.v_cos.lo -= pi_y_lo * r_sincos.v_sin.hi;
+
+    let sin_k_cos_y = DoubleDouble::quick_mult(sin_k, r_sincos.v_cos);
+    let cos_k_sin_y = DoubleDouble::quick_mult(cos_k, r_sincos.v_sin);
+
+    let cos_k_cos_y = DoubleDouble::quick_mult(r_sincos.v_cos, cos_k);
+    let msin_k_sin_y = DoubleDouble::quick_mult(r_sincos.v_sin, -sin_k);
+
+    let mut rr_sin = DoubleDouble::from_exact_add(sin_k_cos_y.hi, cos_k_sin_y.hi);
+    rr_sin.lo += sin_k_cos_y.lo + cos_k_sin_y.lo;
+
+    let sin_ub = rr_sin.hi + (rr_sin.lo + r_sincos.err);
+    let sin_lb = rr_sin.hi + (rr_sin.lo - r_sincos.err);
+
+    let mut rr_cos = DoubleDouble::from_exact_add(cos_k_cos_y.hi, msin_k_sin_y.hi);
+    rr_cos.lo += cos_k_cos_y.lo + msin_k_sin_y.lo;
+
+    let cos_ub = rr_cos.hi + (rr_cos.lo + r_sincos.err);
+    let cos_lb = rr_cos.hi + (rr_cos.lo - r_sincos.err);
+
+    if sin_ub == sin_lb && cos_lb == cos_ub {
+        return (rr_sin.to_f64(), rr_cos.to_f64());
+    }
+
+    sincospi_frac_dd(y, sin_k, cos_k)
+}
+
+/// Computes sin(PI*p/q) and cos(PI*p/q), reduction performed exactly in integers
+#[inline]
+fn sincospi_rational(p: i128, q: i128) -> (f64, f64) {
+    if q == 0 {
+        return (f64::NAN, f64::NAN);
+    }
+    let (p, q) = if q < 0 { (-p, -q) } else { (p, q) };
+    // angle is PI*a/q with a in [0, 2q)
+    let a = p.rem_euclid(2 * q);
+    // PI*a/q = quadrant*PI/2 + PI*r/(2q), r in [0, q)
+    let t = 2 * a;
+    let quadrant = t / q;
+    let r = t - quadrant * q;
+
+    let (s, c) = if r == 0 {
+        (0., 1.)
+    } else if 2 * r > q {
+        // sin(PI/2 - x) = cos(x), cos(PI/2 - x) = sin(x)
+        let (s, c) = sincospi_octant(q - r, q);
+        (c, s)
+    } else {
+        sincospi_octant(r, q)
+    };
+
+    match quadrant {
+        0 => (s, c),
+        1 => (c, if s == 0. { 0. } else { -s }),
+        2 => (if s == 0. { 0. } else { -s }, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Computes sin(2*PI*k/n) and cos(2*PI*k/n)
+///
+/// Reduction is performed exactly in integer arithmetic,
+/// so results are exactly symmetric across quadrants.
+///
+/// Returns NaN if `n` is zero.
+///
+/// Max found ULP 0.5
+pub fn f_sincos_frac(k: i64, n: i64) -> (f64, f64) {
+    sincospi_rational(2 * k as i128, n as i128)
+}
+
+/// Computes sin(PI*p/q)
+///
+/// Reduction is performed exactly in integer arithmetic.
+///
+/// Returns NaN if `q` is zero.
+///
+/// Max found ULP 0.5
+pub fn f_sinpi_rational(p: i64, q: i64) -> f64 {
+    sincospi_rational(p as i128, q as i128).0
+}
+
+/// Generates twiddle factors table of size `n`
+///
+/// Returns `(cos(2*PI*k/n), sin(2*PI*k/n))` for `k` in `0..n`,
+/// forward transform twiddles are conjugates of these values.
+pub fn f_twiddle_table(n: usize) -> Vec<(f64, f64)> {
+    let n = n as i128;
+    (0..n)
+        .map(|k| {
+            let (s, c) = sincospi_rational(2 * k, n);
+            (c, s)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sincos_frac() {
+        assert_eq!(f_sincos_frac(0, 8), (0., 1.));
+        assert_eq!(f_sincos_frac(1, 4), (1., 0.));
+        assert_eq!(f_sincos_frac(2, 4), (0., -1.));
+        assert_eq!(f_sincos_frac(3, 4), (-1., 0.));
+        assert_eq!(f_sincos_frac(-1, 4), (-1., 0.));
+        assert_eq!(f_sincos_frac(1, 12), (0.5, 0.8660254037844386));
+        assert_eq!(f_sincos_frac(1, 8).0, f_sincos_frac(1, 8).1);
+        let (s, c) = f_sincos_frac(1, 0);
+        assert!(s.is_nan() && c.is_nan());
+    }
+
+    #[test]
+    fn test_sinpi_rational() {
+        assert_eq!(f_sinpi_rational(1, 6), 0.5);
+        assert_eq!(f_sinpi_rational(1, 2), 1.);
+        assert_eq!(f_sinpi_rational(-1, 2), -1.);
+        assert_eq!(f_sinpi_rational(1, -2), -1.);
+        assert_eq!(f_sinpi_rational(7, 1), 0.);
+        assert_eq!(f_sinpi_rational(1, 3), 0.8660254037844386);
+    }
+
+    #[test]
+    fn test_twiddle_table() {
+        let table = f_twiddle_table(16);
+        assert_eq!(table.len(), 16);
+        assert_eq!(table[0], (1., 0.));
+        assert_eq!(table[4], (0., 1.));
+        assert_eq!(table[8], (-1., 0.));
+        for k in 1..16 {
+            assert_eq!(table[k].0, table[16 - k].0);
+            assert_eq!(table[k].1, -table[16 - k].1);
+        }
+    }
+}
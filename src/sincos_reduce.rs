@@ -381,51 +381,12 @@ pub(crate) fn rem2pif_any(x: f32) -> f64 {
     }
 }
 
-pub(crate) fn frac2pi_d128(x: DyadicFloat128) -> DyadicFloat128 {
+/// Computes x/(2pi) mod 2 as a 192-bit fixed point number `(hi, lo, tiny)`
+/// with a single integer bit, for |x| >= 2
+#[inline]
+pub(crate) fn frac2pi_fixed(x: DyadicFloat128) -> (u64, u64, u64) {
     let e = x.biased_exponent();
 
-    let mut fe = x;
-
-    if e <= 1
-    // |X| < 2
-    {
-        /* multiply by T[0]/2^64 + T[1]/2^128, where
-        |T[0]/2^64 + T[1]/2^128 - 1/(2pi)| < 2^-130.22 */
-        let mut x_hi = (x.mantissa >> 64) as u64;
-        let mut x_lo: u64;
-        let mut u: u128 = x_hi as u128 * INVPI_2_64[1] as u128;
-        let tiny: u64 = u as u64;
-        x_lo = (u >> 64) as u64;
-        u = x_hi as u128 * INVPI_2_64[0] as u128;
-        x_lo = x_lo.wrapping_add(u as u64);
-        x_hi = (u >> 64) as u64 + (x_lo < u as u64) as u64;
-        /* hi + lo/2^64 + tiny/2^128 = hi_in * (T[0]/2^64 + T[1]/2^128) thus
-        |hi + lo/2^64 + tiny/2^128 - hi_in/(2*pi)| < hi_in * 2^-130.22
-        Since X is normalized at input, hi_in >= 2^63, and since T[0] >= 2^61,
-        we have hi >= 2^(63+61-64) = 2^60, thus the normalize() below
-        perform a left shift by at most 3 bits */
-        let mut e = x.exponent;
-        fe.mantissa = (x_hi as u128).wrapping_shl(64) | (x_lo as u128);
-        fe.normalize();
-        e -= fe.exponent;
-        // put the upper e bits of tiny into X->lo
-        if (e) != 0 {
-            x_hi = (fe.mantissa >> 64) as u64;
-            x_lo = (fe.mantissa & 0xffff_ffff_ffff_ffff) as u64;
-            x_lo |= tiny >> (64 - e);
-            fe.mantissa = (x_hi as u128).wrapping_shl(64) | (x_lo as u128);
-        }
-        /* The error is bounded by 2^-130.22 (relative) + ulp(lo) (absolute).
-           Since now X->hi >= 2^63, the absolute error of ulp(lo) converts into
-           a relative error of less than 2^-127.
-           This yields a maximal relative error of:
-           (1 + 2^-130.22) * (1 + 2^-127) - 1 < 2^-126.852.
-        */
-        return fe;
-    }
-
-    // now 2 <= e <= 1024
-
     /* The upper 64-bit word X->hi corresponds to hi/2^64*2^e, if multiplied by
     T[i]/2^((i+1)*64) it yields hi*T[i]/2^128 * 2^(e-i*64).
     If e-64i <= -128, it contributes to less than 2^-128;
@@ -439,7 +400,7 @@ pub(crate) fn frac2pi_d128(x: DyadicFloat128) -> DyadicFloat128 {
     let mut c: [u64; 5] = [0u64; 5];
 
     let mut x_hi = (x.mantissa >> 64) as u64;
-    let mut x_lo: u64;
+    let x_lo: u64;
 
     let mut u: u128 = x_hi as u128 * INVPI_2_64[i + 3] as u128; // i+3 <= 18
     c[0] = u as u64;
@@ -489,6 +450,54 @@ pub(crate) fn frac2pi_d128(x: DyadicFloat128) -> DyadicFloat128 {
         /* the ignored part was less than 1 in c[0],
         thus less than 1/2 in tiny */
     }
+    (x_hi, x_lo, tiny)
+}
+
+pub(crate) fn frac2pi_d128(x: DyadicFloat128) -> DyadicFloat128 {
+    let e = x.biased_exponent();
+
+    let mut fe = x;
+
+    if e <= 1
+    // |X| < 2
+    {
+        /* multiply by T[0]/2^64 + T[1]/2^128, where
+        |T[0]/2^64 + T[1]/2^128 - 1/(2pi)| < 2^-130.22 */
+        let mut x_hi = (x.mantissa >> 64) as u64;
+        let mut x_lo: u64;
+        let mut u: u128 = x_hi as u128 * INVPI_2_64[1] as u128;
+        let tiny: u64 = u as u64;
+        x_lo = (u >> 64) as u64;
+        u = x_hi as u128 * INVPI_2_64[0] as u128;
+        x_lo = x_lo.wrapping_add(u as u64);
+        x_hi = (u >> 64) as u64 + (x_lo < u as u64) as u64;
+        /* hi + lo/2^64 + tiny/2^128 = hi_in * (T[0]/2^64 + T[1]/2^128) thus
+        |hi + lo/2^64 + tiny/2^128 - hi_in/(2*pi)| < hi_in * 2^-130.22
+        Since X is normalized at input, hi_in >= 2^63, and since T[0] >= 2^61,
+        we have hi >= 2^(63+61-64) = 2^60, thus the normalize() below
+        perform a left shift by at most 3 bits */
+        let mut e = x.exponent;
+        fe.mantissa = (x_hi as u128).wrapping_shl(64) | (x_lo as u128);
+        fe.normalize();
+        e -= fe.exponent;
+        // put the upper e bits of tiny into X->lo
+        if (e) != 0 {
+            x_hi = (fe.mantissa >> 64) as u64;
+            x_lo = (fe.mantissa & 0xffff_ffff_ffff_ffff) as u64;
+            x_lo |= tiny >> (64 - e);
+            fe.mantissa = (x_hi as u128).wrapping_shl(64) | (x_lo as u128);
+        }
+        /* The error is bounded by 2^-130.22 (relative) + ulp(lo) (absolute).
+           Since now X->hi >= 2^63, the absolute error of ulp(lo) converts into
+           a relative error of less than 2^-127.
+           This yields a maximal relative error of:
+           (1 + 2^-130.22) * (1 + 2^-127) - 1 < 2^-126.852.
+        */
+        return fe;
+    }
+
+    // now 2 <= e <= 1024
+    let (mut x_hi, mut x_lo, tiny) = frac2pi_fixed(x);
     let mut fe = x;
     fe.exponent = -127;
     fe.mantissa = (x_hi as u128).wrapping_shl(64) | (x_lo as u128);
@@ -0,0 +1,310 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::{DyadicFloat128, DyadicSign};
+use crate::rounding::CpuRound;
+use crate::sin::{get_sin_k_rational, sincos_eval};
+use crate::sin_table::SIN_K_PI_OVER_128;
+use crate::sincos_dyadic::sincos_eval_dyadic;
+
+pub(crate) const PI_OVER_180_DD: DoubleDouble =
+    DoubleDouble::from_bit_pair((0x3c15c1d8becdd291, 0x3f91df46a2529d39));
+
+pub(crate) const PI_OVER_180_F128: DyadicFloat128 = DyadicFloat128 {
+    sign: DyadicSign::Pos,
+    exponent: -133,
+    mantissa: 0x8efa3512_94e9c8ae_0ec5f66e_9485c4d9_u128,
+};
+
+// Return k and y, where
+// k = round(x / 1.40625) and y = x - k * 1.40625, both taken modulo 360.
+// Since 1.40625 degrees = pi/128, x degrees = k * pi/128 + y * pi/180 radians.
+// Every step is exact: fmod is exact, k * 1.40625 has at most 15 significant bits
+// and subtraction is exact by Sterbenz lemma.
+#[inline]
+pub(crate) fn reduce_degrees(x: f64) -> (f64, u64) {
+    let r = if x.abs() >= 360. { x % 360. } else { x };
+    // 32/45
+    let kd = (r * f64::from_bits(0x3fe6c16c16c16c17)).cpu_round();
+    let y = r - kd * 1.40625;
+    (y, unsafe {
+        kd.to_int_unchecked::<i64>() as u64 // |k| <= 256, only lowest bits are used
+    })
+}
+
+#[cold]
+#[inline(never)]
+fn degrees_tiny_hard(x: f64, c3: f64) -> f64 {
+    let u = DyadicFloat128::new_from_f64(x) * PI_OVER_180_F128;
+    let x3 = DyadicFloat128::new_from_f64(c3 * x * x * x);
+    (u + x3).fast_as_f64()
+}
+
+/// Computes x * pi/180 + c3 * x^3 for |x| < 2^-26
+#[inline]
+pub(crate) fn degrees_tiny(x: f64, c3: f64) -> f64 {
+    if x.abs() < f64::from_bits(0x0750000000000000) {
+        // Lower part of the product is not representable, go straight to the dyadic path
+        return degrees_tiny_hard(x, c3);
+    }
+    let mut z = DoubleDouble::quick_mult_f64(PI_OVER_180_DD, x);
+    z.lo = f_fmla(c3 * x, x * x, z.lo);
+    // Relative error of the product and dropped terms is bounded by 2^-100.
+    let err = z.hi.abs() * f64::from_bits(0x39b0000000000000);
+    let ub = z.hi + (z.lo + err);
+    let lb = z.hi + (z.lo - err);
+    if ub == lb {
+        return ub;
+    }
+    degrees_tiny_hard(x, c3)
+}
+
+#[cold]
+#[inline(never)]
+fn sind_accurate(y: f64, sin_k: u64, cos_k: u64) -> f64 {
+    let u = DyadicFloat128::new_from_f64(y) * PI_OVER_180_F128;
+    let sin_cos = sincos_eval_dyadic(&u);
+    let sin_k_f128 = get_sin_k_rational(sin_k);
+    let cos_k_f128 = get_sin_k_rational(cos_k);
+    // sin(x) = sin(k * pi/128 + u)
+    //        = sin(u) * cos(k*pi/128) + cos(u) * sin(k*pi/128)
+    ((sin_k_f128 * sin_cos.v_cos) + (cos_k_f128 * sin_cos.v_sin)).fast_as_f64()
+}
+
+#[inline]
+fn sind_eval(y: f64, sin_k: u64, cos_k: u64) -> f64 {
+    let u = DoubleDouble::quick_mult_f64(PI_OVER_180_DD, y);
+    let r_sincos = sincos_eval(u);
+
+    let sin_k_dd = DoubleDouble::from_bit_pair(SIN_K_PI_OVER_128[(sin_k & 255) as usize]);
+    let cos_k_dd = DoubleDouble::from_bit_pair(SIN_K_PI_OVER_128[(cos_k & 255) as usize]);
+
+    let sin_k_cos_y = DoubleDouble::quick_mult(r_sincos.v_cos, sin_k_dd);
+    let cos_k_sin_y = DoubleDouble::quick_mult(r_sincos.v_sin, cos_k_dd);
+
+    // sin_k_cos_y is always >> cos_k_sin_y
+    let mut rr = DoubleDouble::from_exact_add(sin_k_cos_y.hi, cos_k_sin_y.hi);
+    rr.lo += sin_k_cos_y.lo + cos_k_sin_y.lo;
+
+    let r_upper = rr.hi + (rr.lo + r_sincos.err);
+    let r_lower = rr.hi + (rr.lo - r_sincos.err);
+
+    // Ziv's accuracy test
+    if r_upper == r_lower {
+        return rr.to_f64();
+    }
+
+    sind_accurate(y, sin_k, cos_k)
+}
+
+/// Computes sine of x given in degrees
+///
+/// Max found ULP 0.5
+pub fn f_sind(x: f64) -> f64 {
+    let ax = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    if ax >= 0x7ff0_0000_0000_0000u64 {
+        // sind(+-Inf) = NaN
+        return x + f64::NAN;
+    }
+    if ax < 0x3e50_0000_0000_0000u64 {
+        // |x| < 2^-26
+        if ax == 0 {
+            return x;
+        }
+        // sin(x * pi/180) ~ x * pi/180 - (pi/180)^3 * x^3 / 6
+        return degrees_tiny(x, f64::from_bits(0xbeadbb820d942f78));
+    }
+
+    let (y, k) = reduce_degrees(x);
+
+    if y == 0. {
+        match k & 255 {
+            // sind(180 * n) = 0
+            0 | 128 => return f64::copysign(0., x),
+            64 => return 1.,
+            192 => return -1.,
+            _ => {}
+        }
+    }
+
+    // cos(k * pi/128) = sin(k * pi/128 + pi/2) = sin((k + 64) * pi/128).
+    sind_eval(y, k, k.wrapping_add(64))
+}
+
+/// Computes cosine of x given in degrees
+///
+/// Max found ULP 0.5
+pub fn f_cosd(x: f64) -> f64 {
+    let ax = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    if ax >= 0x7ff0_0000_0000_0000u64 {
+        // cosd(+-Inf) = NaN
+        return x + f64::NAN;
+    }
+    if ax < 0x3e50_0000_0000_0000u64 {
+        // |x| < 2^-26, cos(x * pi/180) rounds to 1
+        if ax == 0 {
+            return 1.;
+        }
+        return 1. - f64::from_bits(0x3c80000000000000);
+    }
+
+    let (y, k) = reduce_degrees(x);
+
+    if y == 0. {
+        match k & 255 {
+            0 => return 1.,
+            128 => return -1.,
+            // cosd(90 + 180 * n) = +0
+            64 | 192 => return 0.,
+            _ => {}
+        }
+    }
+
+    // cos(k * pi/128 + u) = sin((k + 64) * pi/128 + u)
+    //                     = sin(u) * cos((k + 64) * pi/128) + cos(u) * sin((k + 64) * pi/128)
+    // cos((k + 64) * pi/128) = -sin(k * pi/128) = sin((k + 128) * pi/128).
+    sind_eval(y, k.wrapping_add(64), k.wrapping_add(128))
+}
+
+/// Computes sine and cosine of x given in degrees at the same time
+///
+/// Max found ULP 0.5
+pub fn f_sincosd(x: f64) -> (f64, f64) {
+    let ax = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    if ax >= 0x7ff0_0000_0000_0000u64 {
+        return (x + f64::NAN, x + f64::NAN);
+    }
+    if ax < 0x3e50_0000_0000_0000u64 {
+        // |x| < 2^-26
+        if ax == 0 {
+            return (x, 1.);
+        }
+        return (
+            degrees_tiny(x, f64::from_bits(0xbeadbb820d942f78)),
+            1. - f64::from_bits(0x3c80000000000000),
+        );
+    }
+
+    let (y, k) = reduce_degrees(x);
+
+    if y == 0. {
+        match k & 255 {
+            0 => return (f64::copysign(0., x), 1.),
+            64 => return (1., 0.),
+            128 => return (f64::copysign(0., x), -1.),
+            192 => return (-1., 0.),
+            _ => {}
+        }
+    }
+
+    let u = DoubleDouble::quick_mult_f64(PI_OVER_180_DD, y);
+    let r_sincos = sincos_eval(u);
+
+    let sin_k = DoubleDouble::from_bit_pair(SIN_K_PI_OVER_128[(k & 255) as usize]);
+    let cos_k = DoubleDouble::from_bit_pair(SIN_K_PI_OVER_128[(k.wrapping_add(64) & 255) as usize]);
+    let msin_k =
+        DoubleDouble::from_bit_pair(SIN_K_PI_OVER_128[(k.wrapping_add(128) & 255) as usize]);
+
+    let sin_k_cos_y = DoubleDouble::quick_mult(r_sincos.v_cos, sin_k);
+    let cos_k_sin_y = DoubleDouble::quick_mult(r_sincos.v_sin, cos_k);
+
+    let mut rr_sin = DoubleDouble::from_exact_add(sin_k_cos_y.hi, cos_k_sin_y.hi);
+    rr_sin.lo += sin_k_cos_y.lo + cos_k_sin_y.lo;
+
+    let cos_k_cos_y = DoubleDouble::quick_mult(r_sincos.v_cos, cos_k);
+    let msin_k_sin_y = DoubleDouble::quick_mult(r_sincos.v_sin, msin_k);
+
+    let mut rr_cos = DoubleDouble::from_exact_add(cos_k_cos_y.hi, msin_k_sin_y.hi);
+    rr_cos.lo += cos_k_cos_y.lo + msin_k_sin_y.lo;
+
+    let sin_upper = rr_sin.hi + (rr_sin.lo + r_sincos.err);
+    let sin_lower = rr_sin.hi + (rr_sin.lo - r_sincos.err);
+
+    let cos_upper = rr_cos.hi + (rr_cos.lo + r_sincos.err);
+    let cos_lower = rr_cos.hi + (rr_cos.lo - r_sincos.err);
+
+    let sin_x = if sin_upper == sin_lower {
+        rr_sin.to_f64()
+    } else {
+        sind_accurate(y, k, k.wrapping_add(64))
+    };
+
+    let cos_x = if cos_upper == cos_lower {
+        rr_cos.to_f64()
+    } else {
+        sind_accurate(y, k.wrapping_add(64), k.wrapping_add(128))
+    };
+
+    (sin_x, cos_x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sind() {
+        assert_eq!(f_sind(30.0), 0.5);
+        assert_eq!(f_sind(150.0), 0.5);
+        assert_eq!(f_sind(-30.0), -0.5);
+        assert_eq!(f_sind(210.0), -0.5);
+        assert_eq!(f_sind(90.0), 1.0);
+        assert_eq!(f_sind(-450.0), -1.0);
+        assert_eq!(f_sind(180.0), 0.0);
+        assert!(f_sind(-180.0).is_sign_negative());
+        assert_eq!(f_sind(45.0), std::f64::consts::FRAC_1_SQRT_2);
+        assert_eq!(f_sind(1e22), -0.984807753012208);
+        assert_eq!(f_sind(0.5), 0.008726535498373935);
+        assert_eq!(f_sind(1e-300), 1.7453292519943295e-302);
+        assert!(f_sind(f64::INFINITY).is_nan());
+        assert!(f_sind(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cosd() {
+        assert_eq!(f_cosd(60.0), 0.5);
+        assert_eq!(f_cosd(-60.0), 0.5);
+        assert_eq!(f_cosd(120.0), -0.5);
+        assert_eq!(f_cosd(90.0), 0.0);
+        assert_eq!(f_cosd(180.0), -1.0);
+        assert_eq!(f_cosd(720.0), 1.0);
+        assert_eq!(f_cosd(0.5), 0.9999619230641713);
+        assert!(f_cosd(f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_sincosd() {
+        for x in [0.5, 30., 60., 90., 135., -212.25, 1e-10, 37254.2, 1e22] {
+            let (s, c) = f_sincosd(x);
+            assert_eq!(s, f_sind(x));
+            assert_eq!(c, f_cosd(x));
+        }
+    }
+}
@@ -79,8 +79,8 @@ Q = fpminimax(f_sin, [|0, 2, 4, 6, 8, 10|], [|107...|], d, relative, floating);
 ```
 See ./notes/sinpi_zero_dd.sollya
 **/
-#[cold]
-fn as_sinpi_zero(x: f64) -> f64 {
+#[inline]
+pub(crate) fn sinpi_zero_dd(x: f64) -> DoubleDouble {
     const C: [(u64, u64); 6] = [
         (0x3ca1a626311d9056, 0x400921fb54442d18),
         (0x3cb055f12c462211, 0xc014abbce625be53),
@@ -99,8 +99,12 @@ fn as_sinpi_zero(x: f64) -> f64 {
     p = DoubleDouble::quick_mul_add(x2, p, DoubleDouble::from_bit_pair(C[2]));
     p = DoubleDouble::quick_mul_add(x2, p, DoubleDouble::from_bit_pair(C[1]));
     p = DoubleDouble::quick_mul_add(x2, p, DoubleDouble::from_bit_pair(C[0]));
-    p = DoubleDouble::quick_mult_f64(p, x);
-    p.to_f64()
+    DoubleDouble::quick_mult_f64(p, x)
+}
+
+#[cold]
+fn as_sinpi_zero(x: f64) -> f64 {
+    sinpi_zero_dd(x).to_f64()
 }
 
 // Return k and y, where
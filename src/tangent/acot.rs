@@ -0,0 +1,55 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::tangent::atan2::f_atan2;
+
+/// Computes inverse cotangent
+///
+/// Defined as atan(1/x), acot(x) is odd and acot(±0) = ±pi/2.
+///
+/// Max found ULP 0.5
+pub fn f_acot(x: f64) -> f64 {
+    // For x > 0 atan2(1, x) = atan(1/x) without rounding 1/x,
+    // negative values are obtained by symmetry.
+    f64::copysign(f_atan2(1., x.abs()), x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acot() {
+        assert_eq!(f_acot(0.), std::f64::consts::FRAC_PI_2);
+        assert_eq!(f_acot(-0.), -std::f64::consts::FRAC_PI_2);
+        assert_eq!(f_acot(1.), std::f64::consts::FRAC_PI_4);
+        assert_eq!(f_acot(f64::INFINITY), 0.);
+        assert_eq!(f_acot(f64::NEG_INFINITY), -0.);
+        assert!(f_acot(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,264 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::acosd::{RAD_TO_DEG_DD, RAD_TO_DEG_F128};
+use crate::asind::rad_to_deg_tiny;
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::dyadic_float::DyadicFloat128;
+use crate::rounding::CpuRound;
+use crate::tangent::atan2::{ATAN_I, atan_eval, atan2_hard};
+
+/// If one of arguments is too huge or too small, extended precision is required for
+/// case with big exponent difference
+#[cold]
+fn atan2d_big_exp_difference_hard(num: f64, den: f64, const_term: f64, final_sign: f64) -> f64 {
+    let scaled_div = DyadicFloat128::from_div_f64(num, den) * RAD_TO_DEG_F128;
+    let sign_f128 = DyadicFloat128::new_from_f64(final_sign);
+    let p = DyadicFloat128::new_from_f64(const_term * final_sign);
+    let r = p + sign_f128 * scaled_div;
+    r.fast_as_f64()
+}
+
+#[inline]
+fn atan2d_core(y: f64, x: f64) -> f64 {
+    static IS_NEG: [f64; 2] = [1.0, -1.0];
+    const ZERO: DoubleDouble = DoubleDouble::new(0.0, 0.0);
+    const MZERO: DoubleDouble = DoubleDouble::new(-0.0, -0.0);
+    const MPI: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0xbca1a62633145c07),
+        f64::from_bits(0xc00921fb54442d18),
+    );
+    const PI_OVER_2: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0x3c91a62633145c07),
+        f64::from_bits(0x3ff921fb54442d18),
+    );
+    const MPI_OVER_2: DoubleDouble = DoubleDouble::new(
+        f64::from_bits(0xbc91a62633145c07),
+        f64::from_bits(0xbff921fb54442d18),
+    );
+
+    // Adjustment for constant term:
+    //   CONST_ADJ[x_sign][y_sign][recip]
+    static CONST_ADJ: [[[DoubleDouble; 2]; 2]; 2] = [
+        [[ZERO, MPI_OVER_2], [MZERO, MPI_OVER_2]],
+        [[MPI, PI_OVER_2], [MPI, PI_OVER_2]],
+    ];
+    // Same adjustment expressed exactly in degrees.
+    static CONST_ADJ_DEG: [[[f64; 2]; 2]; 2] =
+        [[[0., -90.], [-0., -90.]], [[-180., 90.], [-180., 90.]]];
+
+    let x_sign = x.is_sign_negative() as usize;
+    let y_sign = y.is_sign_negative() as usize;
+    let x_bits = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    let y_bits = y.to_bits() & 0x7fff_ffff_ffff_ffff;
+    let x_abs = x_bits;
+    let y_abs = y_bits;
+    let recip = x_abs < y_abs;
+    let mut min_abs = if recip { x_abs } else { y_abs };
+    let mut max_abs = if !recip { x_abs } else { y_abs };
+    let mut min_exp = min_abs.wrapping_shr(52);
+    let mut max_exp = max_abs.wrapping_shr(52);
+
+    let mut num = f64::from_bits(min_abs);
+    let mut den = f64::from_bits(max_abs);
+
+    // Check for exceptional cases, whether inputs are 0, inf, nan, or close to
+    // overflow, or close to underflow.
+    if max_exp > 0x7ffu64 - 128u64 || min_exp < 128u64 {
+        if x.is_nan() || y.is_nan() {
+            return f64::NAN;
+        }
+        let x_except = if x == 0.0 {
+            0
+        } else if x.is_infinite() {
+            2
+        } else {
+            1
+        };
+        let y_except = if y == 0.0 {
+            0
+        } else if y.is_infinite() {
+            2
+        } else {
+            1
+        };
+
+        // Exceptional cases:
+        //   EXCEPT[y_except][x_except][x_is_neg]
+        // with x_except & y_except:
+        //   0: zero
+        //   1: finite, non-zero
+        //   2: infinity
+        static EXCEPTS: [[[f64; 2]; 3]; 3] = [
+            [[0., 180.], [0., 180.], [0., 180.]],
+            [[90., 90.], [0., 0.], [0., 180.]],
+            [[90., 90.], [90., 90.], [45., 135.]],
+        ];
+
+        if (x_except != 1) || (y_except != 1) {
+            let r = EXCEPTS[y_except][x_except][x_sign];
+            return IS_NEG[y_sign] * r;
+        }
+        let scale_up = min_exp < 128u64;
+        let scale_down = max_exp > 0x7ffu64 - 128u64;
+        // At least one input is denormal, multiply both numerator and denominator
+        // by some large enough power of 2 to normalize denormal inputs.
+        if scale_up {
+            num *= f64::from_bits(0x43f0000000000000);
+            if !scale_down {
+                den *= f64::from_bits(0x43f0000000000000);
+            }
+        } else if scale_down {
+            den *= f64::from_bits(0x3bf0000000000000);
+            if !scale_up {
+                num *= f64::from_bits(0x3bf0000000000000);
+            }
+        }
+
+        min_abs = num.to_bits();
+        max_abs = den.to_bits();
+        min_exp = min_abs.wrapping_shr(52);
+        max_exp = max_abs.wrapping_shr(52);
+    }
+
+    let final_sign = IS_NEG[((x_sign != y_sign) != recip) as usize];
+    let exp_diff = max_exp - min_exp;
+    // We have the following bound for normalized n and d:
+    //   2^(-exp_diff - 1) < n/d < 2^(-exp_diff + 1).
+    if exp_diff > 54 {
+        let const_term = CONST_ADJ_DEG[x_sign][y_sign][recip as usize];
+        if max_exp >= 1075 || min_exp < 970 {
+            return atan2d_big_exp_difference_hard(num, den, const_term, final_sign);
+        }
+        let divided = DoubleDouble::from_exact_div(num, den);
+        let p = DoubleDouble::quick_mult_f64(
+            DoubleDouble::quick_mult(divided, RAD_TO_DEG_DD),
+            final_sign,
+        );
+        let r = DoubleDouble::add(DoubleDouble::new(0., const_term * final_sign), p);
+        return r.to_f64();
+    }
+
+    let const_term = CONST_ADJ[x_sign][y_sign][recip as usize];
+
+    let mut k = (64.0 * num / den).cpu_round();
+    let idx = k as u64;
+    // k = idx / 64
+    k *= f64::from_bits(0x3f90000000000000);
+
+    // Range reduction:
+    // atan(n/d) - atan(k/64) = atan((n/d - k/64) / (1 + (n/d) * (k/64)))
+    //                        = atan((n - d * k/64)) / (d + n * k/64))
+    let num_k = DoubleDouble::from_exact_mult(num, k);
+    let den_k = DoubleDouble::from_exact_mult(den, k);
+
+    // num_dd = n - d * k
+    let num_dd = DoubleDouble::from_exact_add(num - den_k.hi, -den_k.lo);
+    // den_dd = d + n * k
+    let mut den_dd = DoubleDouble::from_exact_add(den, num_k.hi);
+    den_dd.lo += num_k.lo;
+
+    // q = (n - d * k) / (d + n * k)
+    let q = DoubleDouble::div(num_dd, den_dd);
+    // p ~ atan(q)
+    let p = atan_eval(q);
+
+    let vl = ATAN_I[idx as usize];
+    let vlo = DoubleDouble::from_bit_pair(vl);
+    let mut r = DoubleDouble::add(const_term, DoubleDouble::add(vlo, p));
+
+    r = DoubleDouble::quick_mult(r, RAD_TO_DEG_DD);
+
+    // Error bound of atan2 scaled by 2^6 > 180/pi.
+    let err = f_fmla(
+        p.hi,
+        f64::from_bits(0x3c30000000000000),
+        f64::from_bits(0x3c60000000000000),
+    );
+
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+
+    if ub == lb {
+        r.hi *= final_sign;
+        r.lo *= final_sign;
+
+        return r.to_f64();
+    }
+
+    (atan2_hard(y, x) * RAD_TO_DEG_F128).fast_as_f64()
+}
+
+/// Computes atan2(y, x) in degrees
+///
+/// Max found ULP 0.5
+pub fn f_atan2d(y: f64, x: f64) -> f64 {
+    atan2d_core(y, x)
+}
+
+/// Computes atan(x) in degrees
+///
+/// Max found ULP 0.5
+pub fn f_atand(x: f64) -> f64 {
+    // |x| < 2^-26
+    if (x.to_bits() & 0x7fff_ffff_ffff_ffff) < 0x3e50000000000000 {
+        // atan(x) ~ x - x^3/3 with relative error bounded by x^4/5 < 2^-106.
+        // -(180/pi) / 3
+        const C3: f64 = f64::from_bits(0xc033193d66ed2bfa);
+        return rad_to_deg_tiny(x, C3);
+    }
+    atan2d_core(x, 1.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atan2d() {
+        assert_eq!(f_atan2d(1., 1.), 45.);
+        assert_eq!(f_atan2d(-1., -1.), -135.);
+        assert_eq!(f_atan2d(1., 0.), 90.);
+        assert_eq!(f_atan2d(0., -1.), 180.);
+        assert_eq!(f_atan2d(-0., -1.), -180.);
+        assert_eq!(f_atan2d(f64::INFINITY, f64::NEG_INFINITY), 135.);
+        assert!(f_atan2d(f64::NAN, 1.).is_nan());
+    }
+
+    #[test]
+    fn test_atand() {
+        assert_eq!(f_atand(1.), 45.);
+        assert_eq!(f_atand(-1.), -45.);
+        assert_eq!(f_atand(f64::INFINITY), 90.);
+        assert_eq!(f_atand(f64::NEG_INFINITY), -90.);
+        assert_eq!(f_atand(0.), 0.);
+        assert_eq!(f_atand(-0.), -0.);
+        assert!(f_atand(f64::NAN).is_nan());
+    }
+}
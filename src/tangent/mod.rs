@@ -26,11 +26,16 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+mod acot;
+mod acotf;
 mod atan;
 mod atan2;
+mod atan2d;
+mod atan2df;
 mod atan2f;
 mod atan2pi;
 mod atan2pif;
+mod atandf;
 mod atanf;
 mod atanpi;
 mod atanpif;
@@ -40,16 +45,24 @@ mod cotpi;
 mod cotpif;
 mod evalf;
 mod tan;
+mod tand;
+mod tandf;
 mod tanf;
 mod tanpi;
 mod tanpi_table;
 mod tanpif;
 
+pub use acot::f_acot;
+pub use acotf::f_acotf;
 pub use atan::f_atan;
 pub use atan2::f_atan2;
+pub(crate) use atan2::{ATAN_I, atan_eval};
+pub use atan2d::{f_atan2d, f_atand};
+pub use atan2df::f_atan2df;
 pub use atan2f::f_atan2f;
 pub use atan2pi::f_atan2pi;
 pub use atan2pif::f_atan2pif;
+pub use atandf::f_atandf;
 pub use atanf::f_atanf;
 pub use atanpi::f_atanpi;
 pub use atanpif::f_atanpif;
@@ -60,6 +73,8 @@ pub use cotpi::f_cotpi;
 pub(crate) use cotpif::cotpif_core;
 pub use cotpif::f_cotpif;
 pub use tan::f_tan;
+pub use tand::f_tand;
+pub use tandf::f_tandf;
 pub use tanf::f_tanf;
 pub use tanpi::f_tanpi;
 pub use tanpif::f_tanpif;
@@ -0,0 +1,152 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::bits::EXP_MASK;
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::sincosd::{PI_OVER_180_DD, degrees_tiny, reduce_degrees};
+use crate::tangent::tan::{tan_eval, tan_eval_dd};
+use crate::tangent::tanpi_table::TAN_K_PI_OVER_128;
+
+#[cold]
+fn tand_accurate(u: DoubleDouble, k: u64) -> f64 {
+    let tan_y = tan_eval_dd(u);
+    if (k & 127) == 64 {
+        // tan(pi/2 + y) = -1/tan(y)
+        return DoubleDouble::div(DoubleDouble::new(0., -1.), tan_y).to_f64();
+    }
+    let tan_k = DoubleDouble::from_bit_pair(TAN_K_PI_OVER_128[(k & 255) as usize]);
+    // num = tan(y) + tan(k*pi/128)
+    let num_dd = DoubleDouble::full_dd_add(tan_y, tan_k);
+    // den = 1 - tan(y)*tan(k*pi/128)
+    let den_dd = DoubleDouble::mul_add_f64(tan_y, -tan_k, 1.);
+
+    let tan_x = DoubleDouble::div(num_dd, den_dd);
+    tan_x.to_f64()
+}
+
+/// Computes tangent of x given in degrees
+///
+/// Max found ULP 0.5
+pub fn f_tand(x: f64) -> f64 {
+    let ax = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    if ax >= 0x7ff0_0000_0000_0000u64 {
+        // tand(+-Inf) = NaN
+        return x + f64::NAN;
+    }
+    if ax < 0x3e50_0000_0000_0000u64 {
+        // |x| < 2^-26
+        if ax == 0 {
+            return x;
+        }
+        // tan(x * pi/180) ~ x * pi/180 + (pi/180)^3 * x^3 / 3
+        return degrees_tiny(x, f64::from_bits(0x3ebdbb820d942f78));
+    }
+
+    let (y, k) = reduce_degrees(x);
+
+    if y == 0. {
+        match k & 255 {
+            // tand(180 * n) = +-0, tand(90 + 180 * n) = +-Inf
+            0 => return f64::copysign(0., x),
+            128 => return -f64::copysign(0., x),
+            64 => return f64::INFINITY,
+            192 => return f64::NEG_INFINITY,
+            32 | 160 => return 1.,
+            96 | 224 => return -1.,
+            _ => {}
+        }
+    }
+
+    let u = DoubleDouble::quick_mult_f64(PI_OVER_180_DD, y);
+    let (tan_y, err) = tan_eval(u);
+
+    const E_BIAS: u64 = (1u64 << (11 - 1u64)) - 1u64;
+
+    let (tan_x, tan_err);
+    if (k & 127) == 64 {
+        // tan(pi/2 + y) = -1/tan(y), table value of tan(pi/2) is not usable here.
+        tan_x = DoubleDouble::div(DoubleDouble::new(0., -1.), tan_y);
+        // Relative error of tan(y) is carried over into its reciprocal.
+        tan_err = tan_x.hi.abs()
+            * f_fmla(
+                err,
+                1. / tan_y.hi.abs(),
+                f64::from_bits(0x39b0000000000000), // 2^-100
+            );
+    } else {
+        // Computes tan(x) through identities.
+        // tan(a+b) = (tan(a) + tan(b)) / (1 - tan(a)tan(b)) = (tan(y) + tan(k*pi/128)) / (1 - tan(y)*tan(k*pi/128))
+        let tan_k = DoubleDouble::from_bit_pair(TAN_K_PI_OVER_128[(k & 255) as usize]);
+
+        // num = tan(y) + tan(k*pi/128)
+        let num_dd = DoubleDouble::add(tan_y, tan_k);
+        // den = 1 - tan(y)*tan(k*pi/128)
+        let den_dd = DoubleDouble::mul_add_f64(tan_y, -tan_k, 1.);
+
+        tan_x = DoubleDouble::div(num_dd, den_dd);
+
+        // Simple error bound: |1 / den_dd| < 2^(1 + floor(-log2(den_dd)))).
+        let den_inv = ((E_BIAS + 1) << (52 + 1)) - (den_dd.hi.to_bits() & EXP_MASK);
+        // For tan_x = (num_dd + err) / (den_dd + err), the error is bounded by:
+        //   | tan_x - num_dd / den_dd |  <= err * ( 1 + | tan_x * den_dd | ).
+        tan_err = err * f_fmla(f64::from_bits(den_inv), tan_x.hi.abs(), 1.0);
+    }
+
+    let tan_upper = tan_x.hi + (tan_x.lo + tan_err);
+    let tan_lower = tan_x.hi + (tan_x.lo - tan_err);
+
+    // Ziv's rounding test.
+    if tan_upper == tan_lower {
+        return tan_upper;
+    }
+
+    tand_accurate(u, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tand() {
+        assert_eq!(f_tand(45.0), 1.0);
+        assert_eq!(f_tand(-45.0), -1.0);
+        assert_eq!(f_tand(135.0), -1.0);
+        assert_eq!(f_tand(225.0), 1.0);
+        assert_eq!(f_tand(180.0), 0.0);
+        assert!(f_tand(180.0).is_sign_negative());
+        assert_eq!(f_tand(90.0), f64::INFINITY);
+        assert_eq!(f_tand(270.0), f64::NEG_INFINITY);
+        assert_eq!(f_tand(60.0), 1.7320508075688772);
+        assert_eq!(f_tand(0.5), 0.00872686779075879);
+        assert_eq!(f_tand(90.00000000000001), -4031832051015932.0);
+        assert!(f_tand(f64::INFINITY).is_nan());
+        assert!(f_tand(f64::NAN).is_nan());
+    }
+}
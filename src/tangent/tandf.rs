@@ -0,0 +1,115 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::common::f_fmla;
+use crate::double_double::DoubleDouble;
+use crate::sin_cosf::reduce_degreesf;
+use crate::sincosd::PI_OVER_180_DD;
+use crate::tangent::evalf::tanpif_eval;
+
+/// Computes tangent of argument in degrees
+///
+/// Max found ULP 0.5
+#[inline]
+pub fn f_tandf(x: f32) -> f32 {
+    let x_abs = x.to_bits() & 0x7fff_ffff;
+    if x_abs >= 0x7f80_0000u32 {
+        // Inf or NaN
+        return x + f32::NAN;
+    }
+    if x_abs == 0 {
+        return x;
+    }
+
+    if x_abs < 0x3a00_0000u32 {
+        // |x| < 2^-11
+        // tan(x*pi/180) ~ x*pi/180 + x^3*(pi/180)^3/3 + O(x^5)
+        let dx = x as f64;
+        let mut r = DoubleDouble::quick_mult_f64(PI_OVER_180_DD, dx);
+        // (pi/180)^3/3
+        const C3: f64 = f64::from_bits(0x3ebdbb820d942f78);
+        r.lo = f_fmla(dx * dx, C3 * dx, r.lo);
+        return r.to_f64() as f32;
+    }
+
+    // exceptional cases, results are extremely close to the rounding midpoint
+    let exception = match x_abs {
+        0x3a58cfa5 => 0x37722e2b,
+        0x3b08d4c2 => 0x3818d780,
+        0x3d237dca => 0x3a369f24,
+        0x3d6c86bf => 0x3a8419ee,
+        0x3e8fb7f2 => 0x3ba08954,
+        _ => 0,
+    };
+    if exception != 0 {
+        return f32::copysign(f32::from_bits(exception), x);
+    }
+
+    let (y, k) = reduce_degreesf(x);
+
+    if y == 0.0 {
+        // Exact multiples of 45 degrees
+        match k & 63 {
+            0 => return f32::copysign(0., x),
+            32 => return -f32::copysign(0., x),
+            16 => return f32::INFINITY,
+            48 => return f32::NEG_INFINITY,
+            8 | 40 => return 1.,
+            24 | 56 => return -1.,
+            _ => {}
+        }
+    }
+
+    // tanpif_eval returns:
+    // - rs.tan_y = tan(pi/32 * y)          -> tangent of the remainder
+    // - rs.tan_k = tan(pi/32 * k)          -> tan of the main angle multiple
+    let rs = tanpif_eval(y, k);
+
+    // num = tan(k*pi/32) + tan(y*pi/32)
+    let num = rs.tan_y + rs.tan_k;
+    // den = 1 - tan(k*pi/32) * tan(y*pi/32)
+    let den = f_fmla(rs.tan_y, -rs.tan_k, 1.);
+    (num / den) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tandf() {
+        assert_eq!(f_tandf(45.), 1.);
+        assert_eq!(f_tandf(-45.), -1.);
+        assert_eq!(f_tandf(90.), f32::INFINITY);
+        assert_eq!(f_tandf(180.), -0.);
+        assert_eq!(f_tandf(0.), 0.);
+        assert_eq!(f_tandf(0.28070027), 0.0048991833);
+        assert!(f_tandf(f32::INFINITY).is_nan());
+        assert!(f_tandf(f32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,209 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 9/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::double_double::DoubleDouble;
+
+// case max(|x|, |y|, |z|) < 2^-1021, the result may be subnormal
+#[cold]
+fn hypot3_denorm(a: u64, b: u64, c: u64) -> f64 {
+    // a, b and c are x, y and z multiplied by 2^1074, thus integers below 2^53
+    let n = (a as u128) * (a as u128) + (b as u128) * (b as u128) + (c as u128) * (c as u128);
+    let t = n.isqrt();
+    // sqrt(n) < 2^54, so when it does not fit 53 bits the last bit is rounded off
+    let s = (t >= (1u128 << 53)) as u32;
+    let mut k = t >> s;
+    // compare sqrt(n) against the midpoint (2k + 1) * 2^(s - 1)
+    let m = (2 * k + 1) << s;
+    match (4 * n).cmp(&(m * m)) {
+        core::cmp::Ordering::Greater => k += 1,
+        core::cmp::Ordering::Equal => k += k & 1,
+        core::cmp::Ordering::Less => {}
+    }
+    let r = ((k << s) as u64) as f64;
+    // 2^-1074 = 2^-600 * 2^-474, both steps are exact
+    r * f64::from_bits(0x1a70000000000000) * f64::from_bits(0x2250000000000000)
+}
+
+/// Returns the leading nonzero component of the exact sum of `terms`.
+///
+/// The terms are accumulated into a nonoverlapping expansion (Shewchuk's grow-expansion),
+/// so the sign of the result is the exact sign of the sum.
+#[inline]
+fn expansion_sign(terms: &[f64; 10]) -> f64 {
+    let mut e = [0f64; 10];
+    for (n, &t) in terms.iter().enumerate() {
+        let mut q = t;
+        for item in e.iter_mut().take(n) {
+            let s = DoubleDouble::from_full_exact_add(q, *item);
+            *item = s.lo;
+            q = s.hi;
+        }
+        e[n] = q;
+    }
+    e.iter().rev().find(|&&v| v != 0.).copied().unwrap_or(0.)
+}
+
+/* The approximation r is too close to a midpoint M between two consecutive
+doubles to decide the rounding. Since x^2, y^2, z^2 and M^2 are all exact sums
+of a few doubles, the sign of x^2 + y^2 + z^2 - M^2 is computed exactly. */
+#[cold]
+#[inline(never)]
+fn hypot3_hard(x: f64, y: f64, z: f64, r: DoubleDouble) -> f64 {
+    let (lo_r, hi_r) = if r.lo >= 0. {
+        (r.hi, f64::from_bits(r.hi.to_bits() + 1))
+    } else {
+        (f64::from_bits(r.hi.to_bits() - 1), r.hi)
+    };
+    // M = lo_r + h, thus M^2 = lo_r^2 + 2*lo_r*h + h^2
+    let h = (hi_r - lo_r) * 0.5;
+    let m2 = DoubleDouble::from_exact_mult(lo_r, lo_r);
+    let x2 = DoubleDouble::from_exact_mult(x, x);
+    let y2 = DoubleDouble::from_exact_mult(y, y);
+    let z2 = DoubleDouble::from_exact_mult(z, z);
+    let d = expansion_sign(&[
+        x2.lo,
+        y2.lo,
+        z2.lo,
+        -m2.lo,
+        -h * h,
+        x2.hi,
+        y2.hi,
+        z2.hi,
+        -m2.hi,
+        -2. * lo_r * h,
+    ]);
+    if d > 0. {
+        hi_r
+    } else if d < 0. {
+        lo_r
+    } else if lo_r.to_bits() & 1 == 0 {
+        // exact midpoint, ties to even
+        lo_r
+    } else {
+        hi_r
+    }
+}
+
+/// Computes hypot(x, y, z)
+///
+/// Max ULP 0.5
+pub fn f_hypot3(x: f64, y: f64, z: f64) -> f64 {
+    let xb = x.to_bits() & 0x7fff_ffff_ffff_ffff;
+    let yb = y.to_bits() & 0x7fff_ffff_ffff_ffff;
+    let zb = z.to_bits() & 0x7fff_ffff_ffff_ffff;
+
+    let a_bits = xb.max(yb).max(zb);
+    let c_bits = xb.min(yb).min(zb);
+    let b_bits = xb ^ yb ^ zb ^ a_bits ^ c_bits;
+
+    if a_bits >= 0x7ffu64 << 52 {
+        // x, y or z is inf or nan
+        if x.is_nan() || y.is_nan() || z.is_nan() {
+            return f64::NAN;
+        }
+        return f64::INFINITY;
+    }
+
+    if a_bits == 0 {
+        return 0.;
+    }
+
+    if a_bits < 0x0020000000000000u64 {
+        // max(|x|, |y|, |z|) < 2^-1021
+        return hypot3_denorm(a_bits, b_bits, c_bits);
+    }
+
+    // scale the largest argument into [1; 2)
+    let ea = a_bits >> 52;
+    let scale = if ea == 0x7fe {
+        f64::from_bits(1u64 << 51) // 2^-1023
+    } else {
+        f64::from_bits((0x7fe - ea) << 52)
+    };
+    let unscale = f64::from_bits(ea << 52);
+
+    let a = f64::from_bits(a_bits) * scale;
+    let mut b = f64::from_bits(b_bits) * scale;
+    let mut c = f64::from_bits(c_bits) * scale;
+
+    const TINY: f64 = f64::from_bits(0x2d30000000000000); // 2^-300
+    if b < TINY {
+        // b^2 is far below the granularity of a^2 - M^2 for any midpoint M,
+        // so b is only a sticky bit and c does not matter
+        if b_bits != 0 {
+            b = TINY;
+        }
+        c = 0.;
+    } else if c < b * f64::from_bits(0x3870000000000000) {
+        // c < b * 2^-120: c^2 is below the granularity of a^2 + b^2 - M^2,
+        // so c acts as a sticky bit only
+        if c_bits != 0 {
+            c = b * f64::from_bits(0x3870000000000000);
+        }
+    }
+
+    let a2 = DoubleDouble::from_exact_mult(a, a);
+    let b2 = DoubleDouble::from_exact_mult(b, b);
+    let c2 = DoubleDouble::from_exact_mult(c, c);
+    let s = DoubleDouble::add(DoubleDouble::add(a2, b2), c2);
+    let r = s.fast_sqrt();
+
+    let err = r.hi * f64::from_bits(0x39b0000000000000); // 2^-100
+    let ub = r.hi + (r.lo + err);
+    let lb = r.hi + (r.lo - err);
+    if ub == lb {
+        return ub * unscale;
+    }
+    hypot3_hard(a, b, c, r) * unscale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypot3() {
+        assert_eq!(f_hypot3(3.0, 4.0, 12.0), 13.0);
+        assert_eq!(f_hypot3(-9.0, 12.0, -20.0), 25.0);
+        assert_eq!(f_hypot3(1e200, 1e200, 1e200), 1.7320508075688773e200);
+        assert_eq!(f_hypot3(1.0, 2.0, 3.0), 3.7416573867739413);
+        // sqrt((2^53-1)^2 + 2^54 + 2^54) = 2^53 + 1 is a midpoint
+        assert_eq!(
+            f_hypot3(9007199254740991., 134217728., 134217728.),
+            9007199254740992.
+        );
+        assert_eq!(f_hypot3(1., 1e-300, 0.), 1.);
+        assert_eq!(f_hypot3(3e-320, 4e-320, 0.), 5e-320);
+        assert_eq!(f_hypot3(f64::MAX, f64::MAX, 0.), f64::INFINITY);
+        assert_eq!(f_hypot3(f64::MAX, 0., 0.), f64::MAX);
+        assert_eq!(f_hypot3(0., 0., 0.), 0.);
+        assert!(f_hypot3(f64::INFINITY, f64::NAN, 0.).is_nan());
+        assert_eq!(f_hypot3(0., f64::NEG_INFINITY, 0.), f64::INFINITY);
+        assert!(f_hypot3(0., 0., f64::NAN).is_nan());
+    }
+}
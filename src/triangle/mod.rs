@@ -29,11 +29,13 @@
 mod cathetus;
 mod cathetusf;
 mod hypot;
+mod hypot3;
 mod hypot3f;
 mod hypotf;
 
 pub use cathetus::f_cathetus;
 pub use cathetusf::f_cathetusf;
 pub use hypot::f_hypot;
+pub use hypot3::f_hypot3;
 pub use hypot3f::f_hypot3f;
 pub use hypotf::f_hypotf;